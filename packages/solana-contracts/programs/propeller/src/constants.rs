@@ -1,6 +1,7 @@
 use {num_traits::FromPrimitive, rust_decimal::Decimal};
 
-pub const CURRENT_SWIM_PAYLOAD_VERSION: u8 = 1;
+/// number of tokens of the pools the propeller routes through. pools with more tokens
+/// (see `two_pool::MAX_TOKEN_COUNT`) are rejected when they're registered with the propeller
 pub const TOKEN_COUNT: usize = 2;
 
 // seed prefixes
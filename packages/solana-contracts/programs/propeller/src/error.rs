@@ -72,7 +72,7 @@ pub enum PropellerError {
     #[msg("Not a valid Switchboard account")]
     InvalidSwitchboardAccount,
 
-    #[msg("Switchboard feed has not been updated in 5 minutes")]
+    #[msg("Switchboard feed has not been updated within max_staleness")]
     StaleFeed,
 
     #[msg("Switchboard feed exceeded provided confidence interval")]
@@ -152,4 +152,85 @@ pub enum PropellerError {
 
     #[msg("Invalid Aggregator")]
     InvalidAggregator,
+
+    #[msg("Fees exceed the max fee set in the swim payload")]
+    MaxFeeExceeded,
+
+    #[msg("Invalid aggregator config. max_staleness must be > 0 and max_confidence_interval >= 0")]
+    InvalidAggregatorConfig,
+
+    #[msg("Not enough time has passed since prepare instruction")]
+    InsufficientDelay,
+
+    #[msg("Nothing to enact")]
+    InvalidEnact,
+
+    #[msg("Invalid pause key")]
+    InvalidPauseKey,
+
+    #[msg("Inbound transfers are paused")]
+    InboundIsPaused,
+
+    #[msg("Outbound transfers are paused")]
+    OutboundIsPaused,
+
+    #[msg("Swim payload receiver data is too long")]
+    SwimReceiverDataTooLong,
+
+    #[msg("Missing swim receiver program & caller accounts")]
+    MissingSwimReceiverAccounts,
+
+    #[msg("Swim receiver program != swimPayload.owner")]
+    InvalidSwimReceiver,
+
+    #[msg("Invalid swim receiver caller")]
+    InvalidSwimReceiverCaller,
+
+    #[msg("Swim payload expiry must be > 0")]
+    InvalidSwimPayloadExpiry,
+
+    #[msg("Swim payload has not expired yet")]
+    SwimPayloadNotExpired,
+
+    #[msg("Only the exclusive relayer of the swim payload can run this ix until its exclusivity deadline")]
+    RelayerExclusivityActive,
+
+    #[msg("Invalid pyth price account")]
+    InvalidPythAccount,
+
+    #[msg("Invalid SOL/USD pool")]
+    InvalidSolUsdPool,
+
+    #[msg("Not enough price observations for the SOL/USD pool TWAP")]
+    InsufficientOracleHistory,
+
+    #[msg("None of the oracles returned a valid price")]
+    NoValidOraclePrice,
+
+    #[msg("Invalid oracle config")]
+    InvalidOracleConfig,
+
+    #[msg("Fee price cache max age must be > 0")]
+    InvalidFeePriceCacheMaxAge,
+
+    #[msg("Fee price cache is stale")]
+    StaleFeePriceCache,
+
+    #[msg("Invalid rate limit config")]
+    InvalidRateLimitConfig,
+
+    #[msg("Transfer exceeds a rate limit")]
+    RateLimitExceeded,
+
+    #[msg("Swim payload is held until its release timestamp")]
+    SwimPayloadHeld,
+
+    #[msg("Target chain map doesn't match the chain of the transfer")]
+    InvalidTargetChainMap,
+
+    #[msg("Invalid route for Token Id Map")]
+    InvalidTokenIdMapRoute,
+
+    #[msg("Invalid accounts for the route of the Token Id Map")]
+    InvalidRouteAccounts,
 }
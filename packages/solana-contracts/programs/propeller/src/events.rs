@@ -0,0 +1,221 @@
+use {
+    crate::{oracle::OraclePolicy, PauseDirection, RateLimitAction, RateLimitConfig, UpdateFeesParams},
+    anchor_lang::prelude::*,
+    two_pool::BorshDecimal,
+};
+
+// Structured events emitted by the wormhole transfer/complete, process_swim_payload & admin ixs
+// so that indexers don't have to reconstruct them from `msg!` logs.
+// All amounts are in swimUSD atomic units unless stated otherwise.
+
+#[event]
+pub struct TransferNativeWithPayloadEvent {
+    pub propeller: Pubkey,
+    pub payer: Pubkey,
+    pub nonce: u32,
+    pub amount: u64,
+    pub target_chain: u16,
+    pub owner: [u8; 32],
+    pub propeller_enabled: bool,
+    pub gas_kickstart: bool,
+    pub max_fee: u64,
+    pub target_token_id: u16,
+    pub min_output_amount: u64,
+    pub memo: Option<[u8; 16]>,
+}
+
+#[event]
+pub struct CompleteNativeWithPayloadEvent {
+    pub propeller: Pubkey,
+    pub payer: Pubkey,
+    pub claim: Pubkey,
+    pub swim_payload_message: Pubkey,
+    pub owner: Pubkey,
+    pub vaa_emitter_chain: u16,
+    pub vaa_sequence: u64,
+    /// fees paid to the payer's fee tracker. 0 if the owner completed the transfer themselves
+    pub fees: u64,
+    /// amount written to the `SwimPayloadMessage` (i.e. after fees)
+    pub transfer_amount: u64,
+}
+
+#[event]
+pub struct ProcessSwimPayloadEvent {
+    pub propeller: Pubkey,
+    pub payer: Pubkey,
+    pub swim_payload_message: Pubkey,
+    pub owner: Pubkey,
+    /// `None` for the fallback where swimUSD is transferred to the owner directly
+    pub pool: Option<Pubkey>,
+    pub target_token_id: u16,
+    pub fees: u64,
+    /// amount used for the pool ix/transfer (i.e. after fees)
+    pub transfer_amount: u64,
+    /// amount of the output token received by the owner
+    pub output_amount: u64,
+}
+
+#[event]
+pub struct InvokeSwimReceiverEvent {
+    pub propeller: Pubkey,
+    pub swim_payload_message: Pubkey,
+    /// receiver program (i.e. the swim payload owner)
+    pub receiver: Pubkey,
+    pub receiver_token_account: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct UpdateAggregatorConfigEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub max_staleness: i64,
+    pub max_confidence_interval: BorshDecimal,
+}
+
+#[event]
+pub struct UpdateOracleConfigEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub oracle_policy: OraclePolicy,
+    pub fallback_oracle: Pubkey,
+    pub sol_usd_pool: Pubkey,
+    pub sol_usd_pool_sol_index: u8,
+    pub sol_usd_pool_twap_window: u32,
+    pub sol_usd_pool_max_staleness: i64,
+}
+
+#[event]
+pub struct PrepareAdminTransitionEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub upcoming_admin: Pubkey,
+    pub admin_transition_ts: i64,
+}
+
+#[event]
+pub struct EnactAdminTransitionEvent {
+    pub propeller: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct UpdateGasKickstartAmountEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub previous_gas_kickstart_amount: u64,
+    pub gas_kickstart_amount: u64,
+}
+
+#[event]
+pub struct UpdateSwimPayloadExpiryEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub previous_swim_payload_expiry: i64,
+    pub swim_payload_expiry: i64,
+}
+
+#[event]
+pub struct UpdateFeePriceCacheMaxAgeEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub previous_fee_price_cache_max_age: i64,
+    pub fee_price_cache_max_age: i64,
+}
+
+#[event]
+pub struct UpdateFeesEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub fees: UpdateFeesParams,
+}
+
+#[event]
+pub struct UpdateMarginalPricePoolEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub marginal_price_pool: Pubkey,
+    pub marginal_price_pool_token_index: u8,
+    pub marginal_price_pool_token_mint: Pubkey,
+    pub marginal_price_twap_window: u32,
+}
+
+#[event]
+pub struct UpdateFeeVaultEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub previous_fee_vault: Pubkey,
+    pub new_fee_vault: Pubkey,
+}
+
+#[event]
+pub struct UpdateAggregatorEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub previous_aggregator: Pubkey,
+    pub new_aggregator: Pubkey,
+}
+
+#[event]
+pub struct SetPausedEvent {
+    pub propeller: Pubkey,
+    pub pause_key: Pubkey,
+    pub direction: PauseDirection,
+    pub paused: bool,
+}
+
+#[event]
+pub struct ChangePauseKeyEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub previous_pause_key: Pubkey,
+    pub new_pause_key: Pubkey,
+}
+
+#[event]
+pub struct RefreshFeePriceCacheEvent {
+    pub propeller: Pubkey,
+    pub fee_price_cache: Pubkey,
+    pub previous_swim_usd_atomic_per_lamport: BorshDecimal,
+    pub swim_usd_atomic_per_lamport: BorshDecimal,
+    pub last_update_ts: i64,
+}
+
+#[event]
+pub struct UpdateRateLimitsEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub inbound: RateLimitConfig,
+    pub outbound: RateLimitConfig,
+    pub rate_limit_action: RateLimitAction,
+    pub large_transfer_threshold: u64,
+    pub large_transfer_delay: i64,
+}
+
+#[event]
+pub struct UpdateTargetChainRateLimitsEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub target_chain: u16,
+    pub inbound: RateLimitConfig,
+    pub outbound: RateLimitConfig,
+}
+
+#[event]
+pub struct SwimPayloadHeldEvent {
+    pub propeller: Pubkey,
+    pub swim_payload_message: Pubkey,
+    pub vaa_emitter_chain: u16,
+    pub transfer_amount: u64,
+    pub release_ts: i64,
+}
+
+#[event]
+pub struct ReleaseSwimPayloadEvent {
+    pub propeller: Pubkey,
+    pub admin: Pubkey,
+    pub swim_payload_message: Pubkey,
+    pub previous_release_ts: i64,
+}
@@ -0,0 +1,22 @@
+use {
+    crate::{common_admin::*, events::ChangePauseKeyEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct ChangePauseKey<'info> {
+    pub common_admin: CommonAdmin<'info>,
+}
+
+pub fn handle_change_pause_key(ctx: Context<ChangePauseKey>, new_pause_key: Pubkey) -> Result<()> {
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    let previous_pause_key = propeller.pause_key;
+    propeller.pause_key = new_pause_key;
+    emit!(ChangePauseKeyEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        previous_pause_key,
+        new_pause_key,
+    });
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use {crate::Propeller, anchor_lang::prelude::*};
+
+#[derive(Accounts)]
+pub struct CommonAdmin<'info> {
+    #[account(
+    mut,
+    seeds = [b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
+    bump = propeller.bump,
+    has_one = admin,
+    )]
+    pub propeller: Box<Account<'info, Propeller>>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,25 @@
+use {
+    crate::{common_admin::*, error::PropellerError, events::EnactAdminTransitionEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct EnactAdminTransition<'info> {
+    pub common_admin: CommonAdmin<'info>,
+}
+
+pub fn handle_enact_admin_transition(ctx: Context<EnactAdminTransition>) -> Result<()> {
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    require_neq!(propeller.admin_transition_ts, 0i64, PropellerError::InvalidEnact);
+
+    let current_ts = Clock::get()?.unix_timestamp;
+
+    require_gte!(current_ts, propeller.admin_transition_ts, PropellerError::InsufficientDelay);
+
+    let previous_admin = propeller.admin;
+    propeller.admin = propeller.prepared_admin;
+    propeller.prepared_admin = Pubkey::default();
+    propeller.admin_transition_ts = 0i64;
+    emit!(EnactAdminTransitionEvent { propeller: propeller.key(), previous_admin, new_admin: propeller.admin });
+    Ok(())
+}
@@ -0,0 +1,27 @@
+pub use {
+    change_pause_key::*, common_admin::*, enact_admin_transition::*, prepare_admin_transition::*,
+    release_swim_payload::*, set_paused::*, update_aggregator::*, update_aggregator_config::*,
+    update_fee_price_cache_max_age::*, update_fee_vault::*, update_fees::*, update_gas_kickstart_amount::*,
+    update_marginal_price_pool::*, update_oracle_config::*, update_rate_limits::*, update_swim_payload_expiry::*,
+    update_target_chain_rate_limits::*,
+};
+
+pub mod change_pause_key;
+pub mod common_admin;
+pub mod enact_admin_transition;
+pub mod prepare_admin_transition;
+pub mod release_swim_payload;
+pub mod set_paused;
+pub mod update_aggregator;
+pub mod update_aggregator_config;
+pub mod update_fee_price_cache_max_age;
+pub mod update_fee_vault;
+pub mod update_fees;
+pub mod update_gas_kickstart_amount;
+pub mod update_marginal_price_pool;
+pub mod update_oracle_config;
+pub mod update_rate_limits;
+pub mod update_swim_payload_expiry;
+pub mod update_target_chain_rate_limits;
+
+pub const ENACT_DELAY: i64 = 3 * 86400;
@@ -0,0 +1,25 @@
+use {
+    crate::{admin::ENACT_DELAY, common_admin::*, events::PrepareAdminTransitionEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct PrepareAdminTransition<'info> {
+    pub common_admin: CommonAdmin<'info>,
+}
+
+pub fn handle_prepare_admin_transition(ctx: Context<PrepareAdminTransition>, upcoming_admin: Pubkey) -> Result<()> {
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    propeller.prepared_admin = upcoming_admin;
+
+    let current_ts = Clock::get()?.unix_timestamp;
+    propeller.admin_transition_ts = current_ts + ENACT_DELAY;
+    emit!(PrepareAdminTransitionEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        upcoming_admin,
+        admin_transition_ts: propeller.admin_transition_ts,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use {
+    crate::{common_admin::*, events::ReleaseSwimPayloadEvent, SwimPayloadMessage},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct ReleaseSwimPayload<'info> {
+    pub common_admin: CommonAdmin<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    b"propeller".as_ref(),
+    b"swim_payload".as_ref(),
+    swim_payload_message.claim.as_ref(),
+    ],
+    bump = swim_payload_message.bump,
+    )]
+    pub swim_payload_message: Box<Account<'info, SwimPayloadMessage>>,
+}
+
+/// Releases a swim payload held by the rate limits before its `release_ts`
+pub fn handle_release_swim_payload(ctx: Context<ReleaseSwimPayload>) -> Result<()> {
+    let swim_payload_message = &mut ctx.accounts.swim_payload_message;
+    let previous_release_ts = swim_payload_message.release_ts;
+    swim_payload_message.release_ts = 0;
+    emit!(ReleaseSwimPayloadEvent {
+        propeller: ctx.accounts.common_admin.propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        swim_payload_message: swim_payload_message.key(),
+        previous_release_ts,
+    });
+    Ok(())
+}
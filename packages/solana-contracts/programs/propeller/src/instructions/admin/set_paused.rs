@@ -0,0 +1,46 @@
+use {
+    crate::{error::PropellerError, events::SetPausedEvent, Propeller},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(
+    mut,
+    seeds = [b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
+    bump = propeller.bump,
+    )]
+    pub propeller: Box<Account<'info, Propeller>>,
+    pub pause_key: Signer<'info>,
+}
+
+impl<'info> SetPaused<'info> {
+    pub fn accounts(ctx: &Context<SetPaused>) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.pause_key.key(),
+            ctx.accounts.propeller.pause_key,
+            PropellerError::InvalidPauseKey
+        );
+        Ok(())
+    }
+}
+
+/// Inbound: completing transfers & processing swim payloads.
+/// Outbound: transferring swimUSD to other chains.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PauseDirection {
+    All,
+    Inbound,
+    Outbound,
+}
+
+pub fn handle_set_paused(ctx: Context<SetPaused>, direction: PauseDirection, paused: bool) -> Result<()> {
+    let propeller = &mut ctx.accounts.propeller;
+    match direction {
+        PauseDirection::All => propeller.is_paused = paused,
+        PauseDirection::Inbound => propeller.is_inbound_paused = paused,
+        PauseDirection::Outbound => propeller.is_outbound_paused = paused,
+    }
+    emit!(SetPausedEvent { propeller: propeller.key(), pause_key: ctx.accounts.pause_key.key(), direction, paused });
+    Ok(())
+}
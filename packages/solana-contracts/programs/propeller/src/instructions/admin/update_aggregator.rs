@@ -0,0 +1,29 @@
+use {
+    crate::{common_admin::*, error::PropellerError, events::UpdateAggregatorEvent},
+    anchor_lang::prelude::*,
+    switchboard_v2::{AggregatorAccountData, SWITCHBOARD_PROGRAM_ID},
+};
+
+#[derive(Accounts)]
+pub struct UpdateAggregator<'info> {
+    pub common_admin: CommonAdmin<'info>,
+
+    #[account(
+    constraint =
+    *aggregator.to_account_info().owner == SWITCHBOARD_PROGRAM_ID @ PropellerError::InvalidSwitchboardAccount
+    )]
+    pub aggregator: AccountLoader<'info, AggregatorAccountData>,
+}
+
+pub fn handle_update_aggregator(ctx: Context<UpdateAggregator>) -> Result<()> {
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    let previous_aggregator = propeller.aggregator;
+    propeller.aggregator = ctx.accounts.aggregator.key();
+    emit!(UpdateAggregatorEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        previous_aggregator,
+        new_aggregator: propeller.aggregator,
+    });
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use {
+    crate::{common_admin::*, error::PropellerError, events::UpdateAggregatorConfigEvent},
+    anchor_lang::prelude::*,
+    two_pool::BorshDecimal,
+};
+
+#[derive(Accounts)]
+pub struct UpdateAggregatorConfig<'info> {
+    pub common_admin: CommonAdmin<'info>,
+}
+
+pub fn validate_aggregator_config(max_staleness: i64, max_confidence_interval: &BorshDecimal) -> Result<()> {
+    require_gt!(max_staleness, 0i64, PropellerError::InvalidAggregatorConfig);
+    require_gte!(max_confidence_interval.mantissa, 0i128, PropellerError::InvalidAggregatorConfig);
+    Ok(())
+}
+
+pub fn handle_update_aggregator_config(
+    ctx: Context<UpdateAggregatorConfig>,
+    max_staleness: i64,
+    max_confidence_interval: BorshDecimal,
+) -> Result<()> {
+    validate_aggregator_config(max_staleness, &max_confidence_interval)?;
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    propeller.max_staleness = max_staleness;
+    propeller.max_confidence_interval = max_confidence_interval;
+    emit!(UpdateAggregatorConfigEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        max_staleness,
+        max_confidence_interval,
+    });
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use {
+    crate::{common_admin::*, error::PropellerError, events::UpdateFeePriceCacheMaxAgeEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateFeePriceCacheMaxAge<'info> {
+    pub common_admin: CommonAdmin<'info>,
+}
+
+pub fn validate_fee_price_cache_max_age(fee_price_cache_max_age: i64) -> Result<()> {
+    require_gt!(fee_price_cache_max_age, 0i64, PropellerError::InvalidFeePriceCacheMaxAge);
+    Ok(())
+}
+
+pub fn handle_update_fee_price_cache_max_age(
+    ctx: Context<UpdateFeePriceCacheMaxAge>,
+    fee_price_cache_max_age: i64,
+) -> Result<()> {
+    validate_fee_price_cache_max_age(fee_price_cache_max_age)?;
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    let previous_fee_price_cache_max_age = propeller.fee_price_cache_max_age;
+    propeller.fee_price_cache_max_age = fee_price_cache_max_age;
+    emit!(UpdateFeePriceCacheMaxAgeEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        previous_fee_price_cache_max_age,
+        fee_price_cache_max_age,
+    });
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use {
+    crate::{common_admin::*, events::UpdateFeeVaultEvent},
+    anchor_lang::prelude::*,
+    anchor_spl::token::TokenAccount,
+};
+
+#[derive(Accounts)]
+pub struct UpdateFeeVault<'info> {
+    pub common_admin: CommonAdmin<'info>,
+
+    #[account(
+    token::mint = common_admin.propeller.swim_usd_mint,
+    token::authority = common_admin.propeller,
+    )]
+    pub fee_vault: Box<Account<'info, TokenAccount>>,
+}
+
+pub fn handle_update_fee_vault(ctx: Context<UpdateFeeVault>) -> Result<()> {
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    let previous_fee_vault = propeller.fee_vault;
+    propeller.fee_vault = ctx.accounts.fee_vault.key();
+    emit!(UpdateFeeVaultEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        previous_fee_vault,
+        new_fee_vault: propeller.fee_vault,
+    });
+    Ok(())
+}
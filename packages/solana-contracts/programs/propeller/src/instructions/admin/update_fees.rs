@@ -0,0 +1,32 @@
+use {
+    crate::{common_admin::*, events::UpdateFeesEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateFees<'info> {
+    pub common_admin: CommonAdmin<'info>,
+}
+
+/// all fees are in LAMPORTS and NOT including rent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, Eq, PartialEq)]
+pub struct UpdateFeesParams {
+    pub secp_verify_init_fee: u64,
+    pub secp_verify_fee: u64,
+    pub post_vaa_fee: u64,
+    pub init_ata_fee: u64,
+    pub complete_with_payload_fee: u64,
+    pub process_swim_payload_fee: u64,
+}
+
+pub fn handle_update_fees(ctx: Context<UpdateFees>, params: UpdateFeesParams) -> Result<()> {
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    propeller.secp_verify_init_fee = params.secp_verify_init_fee;
+    propeller.secp_verify_fee = params.secp_verify_fee;
+    propeller.post_vaa_fee = params.post_vaa_fee;
+    propeller.init_ata_fee = params.init_ata_fee;
+    propeller.complete_with_payload_fee = params.complete_with_payload_fee;
+    propeller.process_swim_payload_fee = params.process_swim_payload_fee;
+    emit!(UpdateFeesEvent { propeller: propeller.key(), admin: ctx.accounts.common_admin.admin.key(), fees: params });
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use {
+    crate::{common_admin::*, events::UpdateGasKickstartAmountEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateGasKickstartAmount<'info> {
+    pub common_admin: CommonAdmin<'info>,
+}
+
+pub fn handle_update_gas_kickstart_amount(
+    ctx: Context<UpdateGasKickstartAmount>,
+    gas_kickstart_amount: u64,
+) -> Result<()> {
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    let previous_gas_kickstart_amount = propeller.gas_kickstart_amount;
+    propeller.gas_kickstart_amount = gas_kickstart_amount;
+    emit!(UpdateGasKickstartAmountEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        previous_gas_kickstart_amount,
+        gas_kickstart_amount,
+    });
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use {
+    crate::{common_admin::*, error::PropellerError, events::UpdateMarginalPricePoolEvent, TOKEN_COUNT},
+    anchor_lang::prelude::*,
+    two_pool::state::TwoPool,
+};
+
+#[derive(Accounts)]
+pub struct UpdateMarginalPricePool<'info> {
+    pub common_admin: CommonAdmin<'info>,
+
+    // owner & discriminator are checked by `Account`. the pool's seeds aren't re-derived since
+    // the IDL generation can't resolve seeds from the fields of another program's account
+    pub pool: Box<Account<'info, TwoPool>>,
+}
+
+impl<'info> UpdateMarginalPricePool<'info> {
+    pub fn accounts(ctx: &Context<UpdateMarginalPricePool>, marginal_price_pool_token_index: u8) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let propeller = &ctx.accounts.common_admin.propeller;
+        // propeller only passes `TOKEN_COUNT` pool token accounts to the marginal prices cpi
+        require_eq!(pool.token_count as usize, TOKEN_COUNT, PropellerError::InvalidMarginalPricePoolAccounts);
+        require_gt!(
+            TOKEN_COUNT,
+            marginal_price_pool_token_index as usize,
+            PropellerError::InvalidMarginalPricePoolAccounts
+        );
+        // the swimUSD price is either the lp price of the pool or the price of its first token (metapool)
+        require!(
+            pool.lp_mint_key == propeller.swim_usd_mint || pool.token_mint_keys[0] == propeller.swim_usd_mint,
+            PropellerError::InvalidMarginalPricePoolAccounts
+        );
+        Ok(())
+    }
+}
+
+pub fn handle_update_marginal_price_pool(
+    ctx: Context<UpdateMarginalPricePool>,
+    marginal_price_pool_token_index: u8,
+    marginal_price_twap_window: u32,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    propeller.marginal_price_pool = pool.key();
+    propeller.marginal_price_pool_token_index = marginal_price_pool_token_index;
+    propeller.marginal_price_pool_token_mint = pool.token_mint_keys[marginal_price_pool_token_index as usize];
+    propeller.marginal_price_twap_window = marginal_price_twap_window;
+    emit!(UpdateMarginalPricePoolEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        marginal_price_pool: propeller.marginal_price_pool,
+        marginal_price_pool_token_index,
+        marginal_price_pool_token_mint: propeller.marginal_price_pool_token_mint,
+        marginal_price_twap_window,
+    });
+    Ok(())
+}
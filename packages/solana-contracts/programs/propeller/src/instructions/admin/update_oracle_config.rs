@@ -0,0 +1,111 @@
+use {
+    crate::{
+        common_admin::*,
+        error::PropellerError,
+        events::UpdateOracleConfigEvent,
+        oracle::{OraclePolicy, Pyth, PythPrice},
+        TOKEN_COUNT,
+    },
+    anchor_lang::prelude::*,
+    two_pool::state::TwoPool,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct UpdateOracleConfigParams {
+    pub oracle_policy: OraclePolicy,
+    pub sol_usd_pool_sol_index: u8,
+    pub sol_usd_pool_twap_window: u32,
+    pub sol_usd_pool_max_staleness: i64,
+}
+
+/// Pass the system program (i.e. `Pubkey::default()`) as `fallback_oracle` and/or `sol_usd_pool`
+/// to stop using them.
+#[derive(Accounts)]
+pub struct UpdateOracleConfig<'info> {
+    pub common_admin: CommonAdmin<'info>,
+
+    /// CHECK: pyth SOL/USD price account. checked in `UpdateOracleConfig::accounts`
+    pub fallback_oracle: UncheckedAccount<'info>,
+
+    /// CHECK: two pool of SOL & a USD stablecoin. checked in `UpdateOracleConfig::accounts`
+    pub sol_usd_pool: UncheckedAccount<'info>,
+}
+
+impl<'info> UpdateOracleConfig<'info> {
+    pub fn accounts(ctx: &Context<UpdateOracleConfig>, params: &UpdateOracleConfigParams) -> Result<()> {
+        let fallback_oracle = &ctx.accounts.fallback_oracle;
+        if fallback_oracle.key() != Pubkey::default() {
+            require_keys_eq!(*fallback_oracle.owner, Pyth::id(), PropellerError::InvalidPythAccount);
+            PythPrice::try_from_account_data(&fallback_oracle.try_borrow_data()?)?;
+        }
+        let sol_usd_pool = &ctx.accounts.sol_usd_pool;
+        if sol_usd_pool.key() != Pubkey::default() {
+            let pool = Account::<TwoPool>::try_from(sol_usd_pool)?;
+            require_eq!(pool.token_count as usize, TOKEN_COUNT, PropellerError::InvalidSolUsdPool);
+            require_gt!(TOKEN_COUNT, params.sol_usd_pool_sol_index as usize, PropellerError::InvalidSolUsdPool);
+            require_keys_eq!(
+                pool.token_mint_keys[params.sol_usd_pool_sol_index as usize],
+                spl_token::native_mint::id(),
+                PropellerError::InvalidSolUsdPool
+            );
+            require_gt!(params.sol_usd_pool_twap_window, 0u32, PropellerError::InvalidOracleConfig);
+            require_gt!(params.sol_usd_pool_max_staleness, 0i64, PropellerError::InvalidOracleConfig);
+        }
+        Ok(())
+    }
+}
+
+/// Policies other than `Primary` need at least one oracle besides the aggregator
+pub fn validate_oracle_config(
+    oracle_policy: OraclePolicy,
+    fallback_oracle: &Pubkey,
+    sol_usd_pool: &Pubkey,
+) -> Result<()> {
+    if oracle_policy != OraclePolicy::Primary {
+        require!(
+            *fallback_oracle != Pubkey::default() || *sol_usd_pool != Pubkey::default(),
+            PropellerError::InvalidOracleConfig
+        );
+    }
+    Ok(())
+}
+
+pub fn handle_update_oracle_config(ctx: Context<UpdateOracleConfig>, params: UpdateOracleConfigParams) -> Result<()> {
+    let fallback_oracle = ctx.accounts.fallback_oracle.key();
+    let sol_usd_pool = ctx.accounts.sol_usd_pool.key();
+    validate_oracle_config(params.oracle_policy, &fallback_oracle, &sol_usd_pool)?;
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    propeller.oracle_policy = params.oracle_policy;
+    propeller.fallback_oracle = fallback_oracle;
+    propeller.sol_usd_pool = sol_usd_pool;
+    propeller.sol_usd_pool_sol_index = params.sol_usd_pool_sol_index;
+    propeller.sol_usd_pool_twap_window = params.sol_usd_pool_twap_window;
+    propeller.sol_usd_pool_max_staleness = params.sol_usd_pool_max_staleness;
+    emit!(UpdateOracleConfigEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        oracle_policy: params.oracle_policy,
+        fallback_oracle,
+        sol_usd_pool,
+        sol_usd_pool_sol_index: params.sol_usd_pool_sol_index,
+        sol_usd_pool_twap_window: params.sol_usd_pool_twap_window,
+        sol_usd_pool_max_staleness: params.sol_usd_pool_max_staleness,
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_oracle_config() {
+        let oracle = Pubkey::new_unique();
+        let none = Pubkey::default();
+        assert!(validate_oracle_config(OraclePolicy::Primary, &none, &none).is_ok());
+        assert!(validate_oracle_config(OraclePolicy::PrimaryWithFallback, &none, &none).is_err());
+        assert!(validate_oracle_config(OraclePolicy::Median, &none, &none).is_err());
+        assert!(validate_oracle_config(OraclePolicy::PrimaryWithFallback, &oracle, &none).is_ok());
+        assert!(validate_oracle_config(OraclePolicy::Median, &none, &oracle).is_ok());
+    }
+}
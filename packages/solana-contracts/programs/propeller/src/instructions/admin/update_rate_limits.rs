@@ -0,0 +1,47 @@
+use {
+    crate::{common_admin::*, error::PropellerError, events::UpdateRateLimitsEvent, RateLimitAction, RateLimitConfig},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateRateLimits<'info> {
+    pub common_admin: CommonAdmin<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct UpdateRateLimitsParams {
+    pub inbound: RateLimitConfig,
+    pub outbound: RateLimitConfig,
+    pub rate_limit_action: RateLimitAction,
+    /// 0 => large transfers aren't held
+    pub large_transfer_threshold: u64,
+    pub large_transfer_delay: i64,
+}
+
+impl<'info> UpdateRateLimits<'info> {
+    pub fn accounts(_ctx: &Context<UpdateRateLimits>, params: &UpdateRateLimitsParams) -> Result<()> {
+        params.inbound.validate()?;
+        params.outbound.validate()?;
+        require_gte!(params.large_transfer_delay, 0i64, PropellerError::InvalidRateLimitConfig);
+        Ok(())
+    }
+}
+
+pub fn handle_update_rate_limits(ctx: Context<UpdateRateLimits>, params: UpdateRateLimitsParams) -> Result<()> {
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    propeller.inbound_rate_limit.set_limit(params.inbound);
+    propeller.outbound_rate_limit.set_limit(params.outbound);
+    propeller.rate_limit_action = params.rate_limit_action;
+    propeller.large_transfer_threshold = params.large_transfer_threshold;
+    propeller.large_transfer_delay = params.large_transfer_delay;
+    emit!(UpdateRateLimitsEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        inbound: params.inbound,
+        outbound: params.outbound,
+        rate_limit_action: params.rate_limit_action,
+        large_transfer_threshold: params.large_transfer_threshold,
+        large_transfer_delay: params.large_transfer_delay,
+    });
+    Ok(())
+}
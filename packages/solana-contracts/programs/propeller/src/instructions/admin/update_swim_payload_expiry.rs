@@ -0,0 +1,31 @@
+use {
+    crate::{common_admin::*, error::PropellerError, events::UpdateSwimPayloadExpiryEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateSwimPayloadExpiry<'info> {
+    pub common_admin: CommonAdmin<'info>,
+}
+
+pub fn validate_swim_payload_expiry(swim_payload_expiry: i64) -> Result<()> {
+    require_gt!(swim_payload_expiry, 0i64, PropellerError::InvalidSwimPayloadExpiry);
+    Ok(())
+}
+
+pub fn handle_update_swim_payload_expiry(
+    ctx: Context<UpdateSwimPayloadExpiry>,
+    swim_payload_expiry: i64,
+) -> Result<()> {
+    validate_swim_payload_expiry(swim_payload_expiry)?;
+    let propeller = &mut ctx.accounts.common_admin.propeller;
+    let previous_swim_payload_expiry = propeller.swim_payload_expiry;
+    propeller.swim_payload_expiry = swim_payload_expiry;
+    emit!(UpdateSwimPayloadExpiryEvent {
+        propeller: propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        previous_swim_payload_expiry,
+        swim_payload_expiry,
+    });
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use {
+    crate::{common_admin::*, events::UpdateTargetChainRateLimitsEvent, RateLimitConfig, TargetChainMap},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateTargetChainRateLimits<'info> {
+    pub common_admin: CommonAdmin<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    b"propeller".as_ref(),
+    common_admin.propeller.key().as_ref(),
+    &target_chain_map.target_chain.to_le_bytes()
+    ],
+    bump = target_chain_map.bump,
+    )]
+    pub target_chain_map: Account<'info, TargetChainMap>,
+}
+
+impl<'info> UpdateTargetChainRateLimits<'info> {
+    pub fn accounts(
+        _ctx: &Context<UpdateTargetChainRateLimits>,
+        inbound: &RateLimitConfig,
+        outbound: &RateLimitConfig,
+    ) -> Result<()> {
+        inbound.validate()?;
+        outbound.validate()?;
+        Ok(())
+    }
+}
+
+pub fn handle_update_target_chain_rate_limits(
+    ctx: Context<UpdateTargetChainRateLimits>,
+    inbound: RateLimitConfig,
+    outbound: RateLimitConfig,
+) -> Result<()> {
+    let target_chain_map = &mut ctx.accounts.target_chain_map;
+    target_chain_map.inbound_rate_limit.set_limit(inbound);
+    target_chain_map.outbound_rate_limit.set_limit(outbound);
+    emit!(UpdateTargetChainRateLimitsEvent {
+        propeller: ctx.accounts.common_admin.propeller.key(),
+        admin: ctx.accounts.common_admin.admin.key(),
+        target_chain: target_chain_map.target_chain,
+        inbound,
+        outbound,
+    });
+    Ok(())
+}
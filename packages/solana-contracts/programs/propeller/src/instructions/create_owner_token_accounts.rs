@@ -1,15 +1,10 @@
 pub use switchboard_v2::{AggregatorAccountData, SwitchboardDecimal, SWITCHBOARD_PROGRAM_ID};
 use {
-    crate::{
-        constants::LAMPORTS_PER_SOL_DECIMAL, convert_fees_to_swim_usd_atomic, get_lamports_intermediate_token_price,
-        get_marginal_price_decimal, get_marginal_prices, get_swim_usd_mint_decimals, FeeTracker,
-    },
+    crate::{constants::LAMPORTS_PER_SOL_DECIMAL, FeePriceCache, FeeTracker},
     anchor_spl::{
         associated_token::{get_associated_token_address, AssociatedToken},
         token::Transfer,
     },
-    num_traits::{FromPrimitive, ToPrimitive},
-    rust_decimal::Decimal,
     solana_program::{instruction::Instruction, program::invoke_signed},
     two_pool::BorshDecimal,
 };
@@ -29,7 +24,6 @@ use {
         PostVAAData,
         PostedVAAData,
         Propeller,
-        RawSwimPayload,
         TOKEN_COUNT,
     },
     anchor_lang::{prelude::*, solana_program::program::invoke},
@@ -46,7 +40,6 @@ pub struct PropellerCreateOwnerTokenAccounts<'info> {
     #[account(
     seeds = [ b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
     bump = propeller.bump,
-    has_one = aggregator @ PropellerError::InvalidAggregator
     )]
     pub propeller: Box<Account<'info, Propeller>>,
     #[account(mut)]
@@ -136,7 +129,7 @@ pub struct PropellerCreateOwnerTokenAccounts<'info> {
     pub pool_token_1_mint: Box<Account<'info, Mint>>,
     pub pool_lp_mint: Box<Account<'info, Mint>>,
 
-    #[account(address = swim_payload_message.owner)]
+    #[account(address = swim_payload_message.token_account_owner())]
     pub user: SystemAccount<'info>,
     #[account(mut)]
     /// CHECK: may possibly need to initialize
@@ -153,26 +146,10 @@ pub struct PropellerCreateOwnerTokenAccounts<'info> {
 
     /* for sol -> token_bridge_mint conversion */
     #[account(
-    constraint =
-    *aggregator.to_account_info().owner == SWITCHBOARD_PROGRAM_ID @ PropellerError::InvalidSwitchboardAccount
+    seeds = [b"propeller".as_ref(), b"fee_price_cache".as_ref(), propeller.key().as_ref()],
+    bump = fee_price_cache.bump
     )]
-    pub aggregator: AccountLoader<'info, AggregatorAccountData>,
-
-    #[account(
-    mut,
-    seeds = [
-    b"two_pool".as_ref(),
-    marginal_price_pool_token_0_account.mint.as_ref(),
-    marginal_price_pool_token_1_account.mint.as_ref(),
-    marginal_price_pool_lp_mint.key().as_ref(),
-    ],
-    bump = marginal_price_pool.bump,
-    seeds::program = two_pool_program.key()
-    )]
-    pub marginal_price_pool: Box<Account<'info, TwoPool>>,
-    pub marginal_price_pool_token_0_account: Box<Account<'info, TokenAccount>>,
-    pub marginal_price_pool_token_1_account: Box<Account<'info, TokenAccount>>,
-    pub marginal_price_pool_lp_mint: Box<Account<'info, Mint>>,
+    pub fee_price_cache: Account<'info, FeePriceCache>,
 
     pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
     #[account(executable, address = spl_memo::id())]
@@ -182,17 +159,10 @@ pub struct PropellerCreateOwnerTokenAccounts<'info> {
 
 impl<'info> PropellerCreateOwnerTokenAccounts<'info> {
     pub fn accounts(ctx: &Context<PropellerCreateOwnerTokenAccounts>) -> Result<()> {
-        require_keys_eq!(ctx.accounts.user.key(), ctx.accounts.swim_payload_message.owner);
+        ctx.accounts.propeller.require_inbound_not_paused()?;
+        ctx.accounts.swim_payload_message.require_relayer(&ctx.accounts.payer.key(), Clock::get()?.unix_timestamp)?;
+        require_keys_eq!(ctx.accounts.user.key(), ctx.accounts.swim_payload_message.token_account_owner());
         require_keys_eq!(ctx.accounts.pool.key(), ctx.accounts.token_id_map.pool);
-        let propeller = &ctx.accounts.propeller;
-        validate_marginal_prices_pool_accounts(
-            &propeller,
-            &ctx.accounts.marginal_price_pool.key(),
-            &[
-                ctx.accounts.marginal_price_pool_token_0_account.mint,
-                ctx.accounts.marginal_price_pool_token_1_account.mint,
-            ],
-        )?;
         let expected_user_token_0_ata =
             get_associated_token_address(&ctx.accounts.user.key(), &ctx.accounts.pool_token_0_mint.key());
         require_keys_eq!(expected_user_token_0_ata, ctx.accounts.user_pool_token_0_account.key());
@@ -206,7 +176,7 @@ impl<'info> PropellerCreateOwnerTokenAccounts<'info> {
         Ok(())
     }
     pub fn validate(&self) -> Result<()> {
-        require_keys_eq!(self.user.key(), self.swim_payload_message.owner);
+        require_keys_eq!(self.user.key(), self.swim_payload_message.token_account_owner());
         let expected_user_token_0_ata = get_associated_token_address(&self.user.key(), &self.pool_token_0_mint.key());
         require_keys_eq!(expected_user_token_0_ata, self.user_pool_token_0_account.key());
         let expected_user_token_1_ata = get_associated_token_address(&self.user.key(), &self.pool_token_1_mint.key());
@@ -216,17 +186,6 @@ impl<'info> PropellerCreateOwnerTokenAccounts<'info> {
         Ok(())
     }
 
-    fn into_marginal_prices(&self) -> CpiContext<'_, '_, '_, 'info, two_pool::cpi::accounts::MarginalPrices<'info>> {
-        let program = self.two_pool_program.to_account_info();
-        let accounts = two_pool::cpi::accounts::MarginalPrices {
-            pool: self.marginal_price_pool.to_account_info(),
-            pool_token_account_0: self.marginal_price_pool_token_0_account.to_account_info(),
-            pool_token_account_1: self.marginal_price_pool_token_1_account.to_account_info(),
-            lp_mint: self.marginal_price_pool_lp_mint.to_account_info(),
-        };
-        CpiContext::new(program, accounts)
-    }
-
     // pub fn convert_fees_to_swim_usd_atomic(&self, fee_in_lamports: u64) -> Result<u64> {
     //     let propeller = &self.propeller;
     //
@@ -250,7 +209,7 @@ impl<'info> PropellerCreateOwnerTokenAccounts<'info> {
     //
     //     let mut res = 0u64;
     //
-    //     let lamports_intermediate_token_price = get_lamports_intermediate_token_price(&self.aggregator, i64::MAX)?;
+    //     let lamports_intermediate_token_price = get_lamports_intermediate_token_price(&self.oracle_accounts(), propeller)?;
     //     let fee_in_swim_usd_decimal = lamports_intermediate_token_price
     //         .checked_mul(fee_in_lamports_decimal)
     //         .and_then(|x| x.checked_div(intermediate_token_price_decimal))
@@ -280,6 +239,7 @@ impl<'info> PropellerCreateOwnerTokenAccounts<'info> {
     // }
 
     fn track_and_transfer_fees(&mut self, fees_in_swim_usd: u64) -> Result<()> {
+        self.swim_payload_message.add_fees(fees_in_swim_usd)?;
         let fee_tracker = &mut self.fee_tracker;
         let updated_fees_owed =
             fee_tracker.fees_owed.checked_add(fees_in_swim_usd).ok_or(PropellerError::IntegerOverflow)?;
@@ -316,7 +276,11 @@ impl<'info> PropellerCreateOwnerTokenAccounts<'info> {
 /// Note: we still allow this to be called even if all token accounts already exist. But since this is wasteful,
 /// we penalize the engine by not reimbursing them anything in that situation so that they are incentivized to
 /// check if any of the require token accounts don't exist.
-pub fn handle_propeller_create_owner_token_accounts(ctx: Context<PropellerCreateOwnerTokenAccounts>) -> Result<()> {
+/// If the `token_id_map` routes through more pools, the mint of the target token & the owner's ATA for it
+/// are passed as the remaining accounts so that the ATA can be created too.
+pub fn handle_propeller_create_owner_token_accounts<'info>(
+    ctx: Context<'_, '_, '_, 'info, PropellerCreateOwnerTokenAccounts<'info>>,
+) -> Result<()> {
     let mut create_owner_token_account_total_fees_in_lamports = 0u64;
     //TODO: enforce that this step can only be done after CompleteNativeWithPayload is done?
     //
@@ -377,9 +341,37 @@ pub fn handle_propeller_create_owner_token_accounts(ctx: Context<PropellerCreate
         token_program.clone(),
         init_ata_fee,
     )?;
+    let init_output_token_account_fees = if ctx.accounts.token_id_map.next_steps.is_empty() {
+        0
+    } else {
+        require_gte!(ctx.remaining_accounts.len(), 2, PropellerError::InvalidRouteAccounts);
+        let output_mint = &ctx.remaining_accounts[0];
+        let user_output_token_account = &ctx.remaining_accounts[1];
+        require_keys_eq!(
+            output_mint.key(),
+            ctx.accounts.token_id_map.output_mint(),
+            PropellerError::InvalidRouteAccounts
+        );
+        require_keys_eq!(
+            user_output_token_account.key(),
+            get_associated_token_address(&user.key(), &output_mint.key()),
+            PropellerError::InvalidRouteAccounts
+        );
+        initialize_user_ata_and_get_fees(
+            user_output_token_account.clone(),
+            payer.clone(),
+            user.clone(),
+            output_mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            init_ata_fee,
+        )?
+    };
+    msg!("init_output_token_account_fees: {}", init_output_token_account_fees);
     create_owner_token_account_total_fees_in_lamports = init_token_account_0_fees
         .checked_add(init_token_account_1_fees)
         .and_then(|f| f.checked_add(init_lp_token_account_fees))
+        .and_then(|f| f.checked_add(init_output_token_account_fees))
         .ok_or(PropellerError::IntegerOverflow)?;
 
     msg!(
@@ -425,15 +417,12 @@ pub fn handle_propeller_create_owner_token_accounts(ctx: Context<PropellerCreate
     //     create_owner_token_account_total_fees_in_token_bridge_mint,
     // )?;
 
-    let create_owner_token_account_total_fees_in_swim_usd = convert_fees_to_swim_usd_atomic(
-        create_owner_token_account_total_fees_in_lamports,
-        &ctx.accounts.propeller,
-        &ctx.accounts.marginal_price_pool_lp_mint,
-        ctx.accounts.into_marginal_prices(),
-        &ctx.accounts.marginal_price_pool,
-        &ctx.accounts.aggregator,
-        i64::MAX,
-    )?;
+    let create_owner_token_account_total_fees_in_swim_usd =
+        ctx.accounts.fee_price_cache.convert_fees_to_swim_usd_atomic(
+            create_owner_token_account_total_fees_in_lamports,
+            ctx.accounts.propeller.fee_price_cache_max_age,
+            Clock::get()?.unix_timestamp,
+        )?;
     ctx.accounts.track_and_transfer_fees(create_owner_token_account_total_fees_in_swim_usd)?;
 
     let transfer_amount = ctx.accounts.swim_payload_message.transfer_amount;
@@ -461,115 +450,40 @@ fn initialize_user_ata_and_get_fees<'info>(
     token_program: AccountInfo<'info>,
     create_ata_fee: u64,
 ) -> Result<u64> {
-    //TODO: figure out actual cost of create ata txn.
-    let create_ata_fee = 10000u64;
+    if owner_token_account_exists(&user_unchecked_token_account, &user.key())? {
+        return Ok(0u64);
+    }
+    let ix = spl_associated_token_account::instruction::create_associated_token_account(
+        &payer.key(),
+        &user.key(),
+        &mint.key(),
+    );
+    invoke(&ix, &[payer, user_unchecked_token_account, user, mint, system_program, token_program])?;
+    Ok(get_create_owner_token_account_fees(&Rent::get()?))
+}
+
+/// Returns true if `user_unchecked_token_account` is an initialized token account owned by `user`
+/// & false if it's empty (i.e. still needs to be created).
+pub fn owner_token_account_exists(user_unchecked_token_account: &AccountInfo, user: &Pubkey) -> Result<bool> {
     let ata_data_len = user_unchecked_token_account.data_len();
     if ata_data_len == TokenAccount::LEN {
-        let token_account =
-            TokenAccount::try_deserialize(&mut &**user_unchecked_token_account.data.try_borrow_mut().unwrap())?;
-        require_keys_eq!(token_account.owner, user.key(), PropellerError::IncorrectOwnerForCreateTokenAccount);
-        return Ok(0u64);
+        let token_account = TokenAccount::try_deserialize(&mut &**user_unchecked_token_account.try_borrow_data()?)?;
+        require_keys_eq!(token_account.owner, *user, PropellerError::IncorrectOwnerForCreateTokenAccount);
+        Ok(true)
     } else if ata_data_len != 0 {
         //TODO: spl_token_2022?
         // panic!("data_len != 0 && != TokenAcount::LEN");
-        return err!(PropellerError::InvalidTokenAccountDataLen);
+        err!(PropellerError::InvalidTokenAccountDataLen)
     } else {
-        let ix = spl_associated_token_account::instruction::create_associated_token_account(
-            &payer.key(),
-            &user.key(),
-            &mint.key(),
-        );
-        invoke(&ix, &[payer, user_unchecked_token_account, user, mint, system_program, token_program])?;
-        let fees = Rent::get()?.minimum_balance(TokenAccount::LEN) + create_ata_fee;
-        Ok(fees)
+        Ok(false)
     }
 }
 
-fn get_fees_in_swim_usd(fee_in_lamports: u64, ctx: &Context<PropellerCreateOwnerTokenAccounts>) -> Result<u64> {
-    msg!("fee_in_lamports: {:?}", fee_in_lamports);
-
-    let propeller = &ctx.accounts.propeller;
-    let lp_mint_key = ctx.accounts.marginal_price_pool_lp_mint.key();
-
-    let swim_usd_mint_key = propeller.swim_usd_mint;
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.two_pool_program.to_account_info(),
-        two_pool::cpi::accounts::MarginalPrices {
-            pool: ctx.accounts.marginal_price_pool.to_account_info(),
-            pool_token_account_0: ctx.accounts.marginal_price_pool_token_0_account.to_account_info(),
-            pool_token_account_1: ctx.accounts.marginal_price_pool_token_1_account.to_account_info(),
-            lp_mint: ctx.accounts.marginal_price_pool_lp_mint.to_account_info(),
-        },
-    );
-    let result = two_pool::cpi::marginal_prices(cpi_ctx)?;
-    // let marginal_prices = result.get().marginal_prices;
-    let marginal_prices = result.get();
-
-    msg!("marginal_prices: {:?}", marginal_prices);
-
-    let marginal_price: Decimal = get_marginal_price_decimal(
-        &ctx.accounts.marginal_price_pool,
-        &marginal_prices,
-        &propeller,
-        // propeller.marginal_price_pool_token_index as usize,
-        &ctx.accounts.marginal_price_pool_lp_mint.key(),
-        // &token_bridge_mint_key,
-    )?;
-    msg!("marginal_price: {}", marginal_price);
-
-    //swimUSD is lp token of marginal price pool
-    let mut res = 0u64;
-    let feed = &ctx.accounts.aggregator.load()?;
-
-    // get result
-    // note - for tests this is currently hardcoded to 100
-    // this val is SOL/USD price
-    // 100 => 1 SOL/100 USD (usdc)
-    // let v2 = feed.get_result()?.try_into()?;
-    let sol_usd_price: Decimal = feed.get_result()?.try_into()?;
-    let name = feed.name;
-
-    let lamports_usd_price =
-        sol_usd_price.checked_div(LAMPORTS_PER_SOL_DECIMAL).ok_or(PropellerError::IntegerOverflow)?;
-    msg!("sol_usd_price:{},lamports_usd_price: {}", sol_usd_price, lamports_usd_price);
-    // check whether the feed has been updated in the last 300 seconds
-    feed.check_staleness(
-        Clock::get().unwrap().unix_timestamp,
-        // 300
-        i64::MAX,
-    )
-    .map_err(|_| error!(PropellerError::StaleFeed))?;
-    // check feed does not exceed max_confidence_interval
-    // if let Some(max_confidence_interval) = params.max_confidence_interval {
-    // 	feed.check_confidence_interval(SwitchboardDecimal::from_f64(max_confidence_interval))
-    // 	.map_err(|_| error!(PropellerError::ConfidenceIntervalExceeded))?;
-    // }
-
-    let fee_in_lamports_decimal = Decimal::from_u64(fee_in_lamports).ok_or(PropellerError::ConversionError)?;
-    msg!("fee_in_lamports(u64): {:?} fee_in_lamports_decimal: {:?}", fee_in_lamports, fee_in_lamports_decimal);
-    let fee_in_swim_usd_decimal = marginal_price
-        .checked_mul(lamports_usd_price)
-        .and_then(|v| v.checked_mul(fee_in_lamports_decimal))
-        .ok_or(PropellerError::IntegerOverflow)?;
-    // .checked_mul(Decimal::from_u64(fee_in_lamports).ok_or(PropellerError::IntegerOverflow)?)
-    // .ok_or(PropellerError::IntegerOverflow)?;
-    let swim_usd_mint_decimals = get_swim_usd_mint_decimals(
-        &swim_usd_mint_key,
-        &ctx.accounts.marginal_price_pool,
-        &ctx.accounts.marginal_price_pool_lp_mint,
-    )?;
-    msg!("swim_usd_mint_decimals: {:?}", swim_usd_mint_decimals);
-
-    let ten_pow_decimals =
-        Decimal::from_u64(10u64.pow(swim_usd_mint_decimals as u32)).ok_or(PropellerError::IntegerOverflow)?;
-    let fee_in_swim_usd_atomic = fee_in_swim_usd_decimal
-        .checked_mul(ten_pow_decimals)
-        .and_then(|v| v.to_u64())
-        .ok_or(PropellerError::ConversionError)?;
-
-    msg!("fee_in_swim_usd_decimal: {:?} fee_in_swim_usd_atomic: {:?}", fee_in_swim_usd_decimal, fee_in_swim_usd_atomic);
-    res = fee_in_swim_usd_atomic;
-    Ok(res)
+/// Fees (in lamports) charged for creating one of the owner's token accounts
+pub fn get_create_owner_token_account_fees(rent: &Rent) -> u64 {
+    //TODO: figure out actual cost of create ata txn.
+    let create_ata_fee = 10000u64;
+    rent.minimum_balance(TokenAccount::LEN) + create_ata_fee
 }
 
 #[derive(Accounts)]
@@ -578,7 +492,6 @@ pub struct PropellerCreateOwnerSwimUsdAta<'info> {
     seeds = [ b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
     bump = propeller.bump,
     has_one = swim_usd_mint @ PropellerError::InvalidSwimUsdMint,
-    has_one = aggregator @ PropellerError::InvalidAggregator,
     )]
     pub propeller: Box<Account<'info, Propeller>>,
     #[account(mut)]
@@ -655,7 +568,7 @@ pub struct PropellerCreateOwnerSwimUsdAta<'info> {
     /// propeller_create_owner_token_accounts instead
     pub token_id_map: UncheckedAccount<'info>,
     pub swim_usd_mint: Box<Account<'info, Mint>>,
-    #[account(address = swim_payload_message.owner)]
+    #[account(address = swim_payload_message.token_account_owner())]
     pub owner: SystemAccount<'info>,
     #[account(
     init,
@@ -672,28 +585,10 @@ pub struct PropellerCreateOwnerSwimUsdAta<'info> {
 
     /* for sol -> token_bridge_mint conversion */
     #[account(
-    constraint =
-    *aggregator.to_account_info().owner == SWITCHBOARD_PROGRAM_ID @ PropellerError::InvalidSwitchboardAccount
+    seeds = [b"propeller".as_ref(), b"fee_price_cache".as_ref(), propeller.key().as_ref()],
+    bump = fee_price_cache.bump
     )]
-    pub aggregator: AccountLoader<'info, AggregatorAccountData>,
-
-    // pub marginal_price_pool: MarginalPricePool<'info>,
-    #[account(
-    mut,
-    seeds = [
-    b"two_pool".as_ref(),
-    marginal_price_pool_token_0_account.mint.as_ref(),
-    marginal_price_pool_token_1_account.mint.as_ref(),
-    marginal_price_pool_lp_mint.key().as_ref(),
-    ],
-    bump = marginal_price_pool.bump,
-    seeds::program = two_pool_program.key()
-    )]
-    pub marginal_price_pool: Box<Account<'info, TwoPool>>,
-    pub marginal_price_pool_token_0_account: Box<Account<'info, TokenAccount>>,
-    pub marginal_price_pool_token_1_account: Box<Account<'info, TokenAccount>>,
-    pub marginal_price_pool_lp_mint: Box<Account<'info, Mint>>,
-    pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
+    pub fee_price_cache: Account<'info, FeePriceCache>,
     #[account(executable, address = spl_memo::id())]
     ///CHECK: memo program
     pub memo: UncheckedAccount<'info>,
@@ -704,7 +599,9 @@ pub struct PropellerCreateOwnerSwimUsdAta<'info> {
 
 impl<'info> PropellerCreateOwnerSwimUsdAta<'info> {
     pub fn accounts(ctx: &Context<PropellerCreateOwnerSwimUsdAta>) -> Result<()> {
-        require_keys_eq!(ctx.accounts.owner.key(), ctx.accounts.swim_payload_message.owner);
+        ctx.accounts.propeller.require_inbound_not_paused()?;
+        ctx.accounts.swim_payload_message.require_relayer(&ctx.accounts.payer.key(), Clock::get()?.unix_timestamp)?;
+        require_keys_eq!(ctx.accounts.owner.key(), ctx.accounts.swim_payload_message.token_account_owner());
         let (expected_token_id_map_address, _bump) = Pubkey::find_program_address(
             &[
                 b"propeller".as_ref(),
@@ -716,80 +613,20 @@ impl<'info> PropellerCreateOwnerSwimUsdAta<'info> {
         );
         //Note: the address should at least be valid even though it doesn't exist.
         require_keys_eq!(expected_token_id_map_address, ctx.accounts.token_id_map.key());
-        let propeller = &ctx.accounts.propeller;
-        validate_marginal_prices_pool_accounts(
-            &propeller,
-            &ctx.accounts.marginal_price_pool.key(),
-            &[
-                ctx.accounts.marginal_price_pool_token_0_account.mint,
-                ctx.accounts.marginal_price_pool_token_1_account.mint,
-            ],
-        )?;
         msg!("Passed PropellerCreateOwnerTokenAccounts::accounts() check");
         Ok(())
     }
 
-    fn into_marginal_prices(&self) -> CpiContext<'_, '_, '_, 'info, two_pool::cpi::accounts::MarginalPrices<'info>> {
-        let program = self.two_pool_program.to_account_info();
-        let accounts = two_pool::cpi::accounts::MarginalPrices {
-            pool: self.marginal_price_pool.to_account_info(),
-            pool_token_account_0: self.marginal_price_pool_token_0_account.to_account_info(),
-            pool_token_account_1: self.marginal_price_pool_token_1_account.to_account_info(),
-            lp_mint: self.marginal_price_pool_lp_mint.to_account_info(),
-        };
-        CpiContext::new(program, accounts)
-    }
-
     pub fn convert_fees_to_swim_usd_atomic(&self, fee_in_lamports: u64) -> Result<u64> {
-        let propeller = &self.propeller;
-
-        msg!("fee_in_lamports: {:?}", fee_in_lamports);
-        let marginal_price_pool_lp_mint = &self.marginal_price_pool_lp_mint;
-
-        let swim_usd_mint_key = propeller.swim_usd_mint;
-        let marginal_prices = get_marginal_prices(self.into_marginal_prices())?;
-
-        let intermediate_token_price_decimal: Decimal = get_marginal_price_decimal(
-            &self.marginal_price_pool,
-            &marginal_prices,
-            &propeller,
-            &marginal_price_pool_lp_mint.key(),
-        )?;
-
-        msg!("intermediate_token_price_decimal: {:?}", intermediate_token_price_decimal);
-
-        let fee_in_lamports_decimal = Decimal::from_u64(fee_in_lamports).ok_or(PropellerError::ConversionError)?;
-        msg!("fee_in_lamports(u64): {:?} fee_in_lamports_decimal: {:?}", fee_in_lamports, fee_in_lamports_decimal);
-
-        let mut res = 0u64;
-
-        let lamports_intermediate_token_price = get_lamports_intermediate_token_price(&self.aggregator, i64::MAX)?;
-        let fee_in_swim_usd_decimal = lamports_intermediate_token_price
-            .checked_mul(fee_in_lamports_decimal)
-            .and_then(|x| x.checked_div(intermediate_token_price_decimal))
-            .ok_or(PropellerError::IntegerOverflow)?;
-
-        let swim_usd_decimals =
-            get_swim_usd_mint_decimals(&swim_usd_mint_key, &self.marginal_price_pool, &marginal_price_pool_lp_mint)?;
-        msg!("swim_usd_decimals: {:?}", swim_usd_decimals);
-
-        let ten_pow_decimals =
-            Decimal::from_u64(10u64.pow(swim_usd_decimals as u32)).ok_or(PropellerError::IntegerOverflow)?;
-        let fee_in_swim_usd_atomic = fee_in_swim_usd_decimal
-            .checked_mul(ten_pow_decimals)
-            .and_then(|v| v.to_u64())
-            .ok_or(PropellerError::ConversionError)?;
-
-        msg!(
-            "fee_in_swim_usd_decimal: {:?} fee_in_swim_usd_atomic: {:?}",
-            fee_in_swim_usd_decimal,
-            fee_in_swim_usd_atomic
-        );
-        res = fee_in_swim_usd_atomic;
-        Ok(res)
+        self.fee_price_cache.convert_fees_to_swim_usd_atomic(
+            fee_in_lamports,
+            self.propeller.fee_price_cache_max_age,
+            Clock::get()?.unix_timestamp,
+        )
     }
 
     pub fn handle_fees(&mut self, fees_in_swim_usd: u64) -> Result<()> {
+        self.swim_payload_message.add_fees(fees_in_swim_usd)?;
         let fee_tracker = &mut self.fee_tracker;
         let updated_fees_owed =
             fee_tracker.fees_owed.checked_add(fees_in_swim_usd).ok_or(PropellerError::IntegerOverflow)?;
@@ -810,7 +647,7 @@ impl<'info> PropellerCreateOwnerSwimUsdAta<'info> {
         )
     }
     // pub fn validate(&self) -> Result<()> {
-    //     require_keys_eq!(self.user.key(), self.swim_payload_message.owner);
+    //     require_keys_eq!(self.user.key(), self.swim_payload_message.token_account_owner());
     //     let expected_user_token_0_ata = get_associated_token_address(&self.user.key(), &self.pool_token_0_mint.key());
     //     require_keys_eq!(expected_user_token_0_ata, self.user_pool_token_0_account.key());
     //     let expected_user_token_1_ata = get_associated_token_address(&self.user.key(), &self.pool_token_1_mint.key());
@@ -831,17 +668,7 @@ pub fn handle_propeller_create_owner_swim_usd_ata(ctx: Context<PropellerCreateOw
         .minimum_balance(TokenAccount::LEN)
         .checked_add(ctx.accounts.propeller.init_ata_fee)
         .ok_or(PropellerError::IntegerOverflow)?;
-    // let init_token_bridge_ata_total_fee_in_token_bridge_mint =
-    //     ctx.accounts.convert_fees_to_swim_usd_atomic(fee_in_lamports)?;
-    let fees_in_swim_usd_atomic = convert_fees_to_swim_usd_atomic(
-        fees_in_lamports,
-        &ctx.accounts.propeller,
-        &ctx.accounts.marginal_price_pool_lp_mint,
-        ctx.accounts.into_marginal_prices(),
-        &ctx.accounts.marginal_price_pool,
-        &ctx.accounts.aggregator,
-        i64::MAX,
-    )?;
+    let fees_in_swim_usd_atomic = ctx.accounts.convert_fees_to_swim_usd_atomic(fees_in_lamports)?;
     ctx.accounts.handle_fees(fees_in_swim_usd_atomic)?;
 
     let transfer_amount = ctx.accounts.swim_payload_message.transfer_amount;
@@ -0,0 +1,220 @@
+use {
+    crate::{
+        error::*, events::RefreshFeePriceCacheEvent, get_swim_usd_atomic_per_lamport, oracle::OracleAccounts,
+        validate_marginal_prices_pool_accounts, Propeller,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, TokenAccount},
+    num_traits::{FromPrimitive, ToPrimitive},
+    rust_decimal::Decimal,
+    switchboard_v2::{AggregatorAccountData, SWITCHBOARD_PROGRAM_ID},
+    two_pool::{state::TwoPool, BorshDecimal},
+};
+
+/// decimals the cached swimUSD per lamport rate is rounded to
+pub const FEE_PRICE_CACHE_DECIMALS: u32 = 12;
+
+#[derive(Accounts)]
+pub struct InitializeFeePriceCache<'info> {
+    #[account(
+    seeds = [b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
+    bump = propeller.bump,
+    )]
+    pub propeller: Box<Account<'info, Propeller>>,
+
+    #[account(
+    init,
+    payer = payer,
+    seeds = [b"propeller".as_ref(), b"fee_price_cache".as_ref(), propeller.key().as_ref()],
+    bump,
+    space = 8 + FeePriceCache::LEN
+    )]
+    pub fee_price_cache: Account<'info, FeePriceCache>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_fee_price_cache(ctx: Context<InitializeFeePriceCache>) -> Result<()> {
+    let fee_price_cache = &mut ctx.accounts.fee_price_cache;
+    fee_price_cache.bump = *ctx.bumps.get("fee_price_cache").unwrap();
+    fee_price_cache.propeller = ctx.accounts.propeller.key();
+    fee_price_cache.swim_usd_atomic_per_lamport = BorshDecimal { mantissa: 0, scale: 0 };
+    // stale until the first `refresh_fee_price_cache`
+    fee_price_cache.last_update_ts = 0;
+    Ok(())
+}
+
+/// Latest swimUSD per lamport rate used by the relay ixs to convert their (lamport) fees to swimUSD
+#[account]
+pub struct FeePriceCache {
+    pub bump: u8,
+    pub propeller: Pubkey,
+    // swimUSD atomic units per lamport
+    pub swim_usd_atomic_per_lamport: BorshDecimal,
+    pub last_update_ts: i64,
+}
+
+impl FeePriceCache {
+    pub const LEN: usize = 1 + 32 + (16 + 4) + 8;
+
+    pub fn get_swim_usd_atomic_per_lamport(&self) -> Result<Decimal> {
+        Decimal::try_from_i128_with_scale(
+            self.swim_usd_atomic_per_lamport.mantissa,
+            self.swim_usd_atomic_per_lamport.scale,
+        )
+        .map_err(|_| error!(PropellerError::ConversionError))
+    }
+
+    /// Fails if the cache was last refreshed more than `max_age` seconds ago
+    pub fn convert_fees_to_swim_usd_atomic(&self, fee_in_lamports: u64, max_age: i64, now: i64) -> Result<u64> {
+        require_gte!(max_age, now.saturating_sub(self.last_update_ts), PropellerError::StaleFeePriceCache);
+        let fee_in_lamports_decimal = Decimal::from_u64(fee_in_lamports).ok_or(PropellerError::ConversionError)?;
+        let fee_in_swim_usd_atomic = self
+            .get_swim_usd_atomic_per_lamport()?
+            .checked_mul(fee_in_lamports_decimal)
+            .and_then(|v| v.to_u64())
+            .ok_or(PropellerError::ConversionError)?;
+        msg!("fee_in_lamports: {:?} fee_in_swim_usd_atomic: {:?}", fee_in_lamports, fee_in_swim_usd_atomic);
+        Ok(fee_in_swim_usd_atomic)
+    }
+}
+
+#[derive(Accounts)]
+pub struct RefreshFeePriceCache<'info> {
+    #[account(
+    seeds = [b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
+    bump = propeller.bump,
+    has_one = aggregator @ PropellerError::InvalidAggregator
+    )]
+    pub propeller: Box<Account<'info, Propeller>>,
+
+    #[account(
+    mut,
+    seeds = [b"propeller".as_ref(), b"fee_price_cache".as_ref(), propeller.key().as_ref()],
+    bump = fee_price_cache.bump
+    )]
+    pub fee_price_cache: Account<'info, FeePriceCache>,
+
+    #[account(
+    constraint =
+    *aggregator.to_account_info().owner == SWITCHBOARD_PROGRAM_ID @ PropellerError::InvalidSwitchboardAccount
+    )]
+    pub aggregator: AccountLoader<'info, AggregatorAccountData>,
+    /// CHECK: checked against `propeller.fallback_oracle` if the propeller uses one
+    pub fallback_oracle: UncheckedAccount<'info>,
+    /// CHECK: checked against `propeller.sol_usd_pool` if the propeller uses one
+    pub sol_usd_pool: UncheckedAccount<'info>,
+
+    #[account(
+    seeds = [
+    b"two_pool".as_ref(),
+    marginal_price_pool_token_0_account.mint.as_ref(),
+    marginal_price_pool_token_1_account.mint.as_ref(),
+    marginal_price_pool_lp_mint.key().as_ref(),
+    ],
+    bump = marginal_price_pool.bump,
+    seeds::program = two_pool_program.key()
+    )]
+    pub marginal_price_pool: Box<Account<'info, TwoPool>>,
+    pub marginal_price_pool_token_0_account: Box<Account<'info, TokenAccount>>,
+    pub marginal_price_pool_token_1_account: Box<Account<'info, TokenAccount>>,
+    pub marginal_price_pool_lp_mint: Box<Account<'info, Mint>>,
+    pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
+}
+
+impl<'info> RefreshFeePriceCache<'info> {
+    pub fn accounts(ctx: &Context<RefreshFeePriceCache>) -> Result<()> {
+        validate_marginal_prices_pool_accounts(
+            &ctx.accounts.propeller,
+            &ctx.accounts.marginal_price_pool.key(),
+            &[
+                ctx.accounts.marginal_price_pool_token_0_account.mint,
+                ctx.accounts.marginal_price_pool_token_1_account.mint,
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn oracle_accounts(&self) -> OracleAccounts<'_, 'info> {
+        OracleAccounts {
+            aggregator: &self.aggregator,
+            fallback_oracle: &self.fallback_oracle,
+            sol_usd_pool: &self.sol_usd_pool,
+        }
+    }
+
+    fn into_marginal_prices(&self) -> CpiContext<'_, '_, '_, 'info, two_pool::cpi::accounts::MarginalPrices<'info>> {
+        let program = self.two_pool_program.to_account_info();
+        let accounts = two_pool::cpi::accounts::MarginalPrices {
+            pool: self.marginal_price_pool.to_account_info(),
+            pool_token_account_0: self.marginal_price_pool_token_0_account.to_account_info(),
+            pool_token_account_1: self.marginal_price_pool_token_1_account.to_account_info(),
+            lp_mint: self.marginal_price_pool_lp_mint.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+/// Permissionless crank that refreshes the `FeePriceCache` from the propeller's oracles & marginal price pool
+pub fn handle_refresh_fee_price_cache(ctx: Context<RefreshFeePriceCache>) -> Result<()> {
+    let swim_usd_atomic_per_lamport = get_swim_usd_atomic_per_lamport(
+        &ctx.accounts.propeller,
+        &ctx.accounts.marginal_price_pool_lp_mint,
+        ctx.accounts.into_marginal_prices(),
+        &ctx.accounts.marginal_price_pool,
+        &ctx.accounts.oracle_accounts(),
+    )?
+    .round_dp(FEE_PRICE_CACHE_DECIMALS);
+    require!(swim_usd_atomic_per_lamport > Decimal::ZERO, PropellerError::NoValidOraclePrice);
+    let now = Clock::get()?.unix_timestamp;
+
+    let fee_price_cache = &mut ctx.accounts.fee_price_cache;
+    let previous_swim_usd_atomic_per_lamport = fee_price_cache.swim_usd_atomic_per_lamport;
+    fee_price_cache.swim_usd_atomic_per_lamport =
+        BorshDecimal { mantissa: swim_usd_atomic_per_lamport.mantissa(), scale: swim_usd_atomic_per_lamport.scale() };
+    fee_price_cache.last_update_ts = now;
+    emit!(RefreshFeePriceCacheEvent {
+        propeller: ctx.accounts.propeller.key(),
+        fee_price_cache: fee_price_cache.key(),
+        previous_swim_usd_atomic_per_lamport,
+        swim_usd_atomic_per_lamport: fee_price_cache.swim_usd_atomic_per_lamport,
+        last_update_ts: now,
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee_price_cache(mantissa: i128, scale: u32, last_update_ts: i64) -> FeePriceCache {
+        FeePriceCache {
+            bump: 255,
+            propeller: Pubkey::new_unique(),
+            swim_usd_atomic_per_lamport: BorshDecimal { mantissa, scale },
+            last_update_ts,
+        }
+    }
+
+    #[test]
+    fn test_convert_fees_to_swim_usd_atomic() {
+        // 0.0325 swimUSD atomic per lamport (i.e. $32.5/SOL w/ a 6 decimal swimUSD)
+        let cache = fee_price_cache(325, 4, 1_000);
+        assert_eq!(cache.convert_fees_to_swim_usd_atomic(1_000_000, 60, 1_060).unwrap(), 32_500);
+        // truncated
+        assert_eq!(cache.convert_fees_to_swim_usd_atomic(99, 60, 1_000).unwrap(), 3);
+        assert_eq!(cache.convert_fees_to_swim_usd_atomic(0, 60, 1_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_convert_fees_to_swim_usd_atomic_stale() {
+        let cache = fee_price_cache(325, 4, 1_000);
+        assert!(cache.convert_fees_to_swim_usd_atomic(1_000_000, 60, 1_061).is_err());
+        // never refreshed
+        let cache = fee_price_cache(0, 0, 0);
+        assert!(cache.convert_fees_to_swim_usd_atomic(1_000_000, 60, 1_000).is_err());
+    }
+}
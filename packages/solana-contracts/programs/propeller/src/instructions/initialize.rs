@@ -1,12 +1,15 @@
 use {
-    crate::{error::*, Propeller},
+    crate::{
+        error::*, validate_aggregator_config, validate_fee_price_cache_max_age, validate_swim_payload_expiry,
+        Propeller, RateLimit, RateLimitAction,
+    },
     anchor_lang::prelude::*,
     anchor_spl::{
         associated_token::{create, AssociatedToken, Create},
         token::{Mint, Token, TokenAccount},
     },
     switchboard_v2::{AggregatorAccountData, SWITCHBOARD_PROGRAM_ID},
-    two_pool::state::TwoPool,
+    two_pool::{state::TwoPool, BorshDecimal},
 };
 
 //TODO: add fee vault.
@@ -42,6 +45,8 @@ pub struct Initialize<'info> {
     pub propeller_fee_vault: Box<Account<'info, TokenAccount>>,
 
     pub admin: Signer<'info>,
+    /// CHECK: pause_key
+    pub pause_key: UncheckedAccount<'info>,
     pub swim_usd_mint: Box<Account<'info, Mint>>,
 
     #[account(mut)]
@@ -84,6 +89,9 @@ impl<'info> Initialize<'info> {
             params.marginal_price_pool_token_mint
         );
         //TODO: verify that propeller.swim_usd_mint is either pool.lpMint or pool.token_mint[0].
+        validate_aggregator_config(params.max_staleness, &params.max_confidence_interval)?;
+        validate_swim_payload_expiry(params.swim_payload_expiry)?;
+        validate_fee_price_cache_max_age(params.fee_price_cache_max_age)?;
         Ok(())
     }
 }
@@ -97,11 +105,16 @@ pub struct InitializeParams {
     pub complete_with_payload_fee: u64,
     pub init_ata_fee: u64,
     pub process_swim_payload_fee: u64,
+    pub swim_payload_expiry: i64,
+    pub fee_price_cache_max_age: i64,
     // pub propeller_min_transfer_amount: u64,
     // pub propeller_eth_min_transfer_amount: u64,
     pub marginal_price_pool: Pubkey,
     pub marginal_price_pool_token_index: u8,
     pub marginal_price_pool_token_mint: Pubkey,
+    pub marginal_price_twap_window: u32,
+    pub max_staleness: i64,
+    pub max_confidence_interval: BorshDecimal,
     // pub evm_routing_contract_address: [u8; 32],
 }
 
@@ -112,6 +125,12 @@ pub fn handle_initialize(ctx: Context<Initialize>, params: InitializeParams) ->
     propeller.bump = *ctx.bumps.get("propeller").unwrap();
     propeller.nonce = 0;
     propeller.admin = ctx.accounts.admin.key();
+    propeller.prepared_admin = Pubkey::default();
+    propeller.admin_transition_ts = 0;
+    propeller.pause_key = ctx.accounts.pause_key.key();
+    propeller.is_paused = false;
+    propeller.is_inbound_paused = false;
+    propeller.is_outbound_paused = false;
     //TODO: these should be passed in as params or read based on features used when deploying?
     propeller.wormhole = propeller.wormhole()?;
     propeller.token_bridge = propeller.token_bridge()?;
@@ -127,14 +146,25 @@ pub fn handle_initialize(ctx: Context<Initialize>, params: InitializeParams) ->
     propeller.post_vaa_fee = params.post_vaa_fee;
     propeller.complete_with_payload_fee = params.complete_with_payload_fee;
     propeller.process_swim_payload_fee = params.process_swim_payload_fee;
+    propeller.swim_payload_expiry = params.swim_payload_expiry;
+    propeller.fee_price_cache_max_age = params.fee_price_cache_max_age;
+    // no rate limits until configured by the admin
+    propeller.inbound_rate_limit = RateLimit::default();
+    propeller.outbound_rate_limit = RateLimit::default();
+    propeller.rate_limit_action = RateLimitAction::Reject;
+    propeller.large_transfer_threshold = 0;
+    propeller.large_transfer_delay = 0;
     // propeller.propeller_min_transfer_amount = params.propeller_min_transfer_amount;
     // propeller.propeller_eth_min_transfer_amount = params.propeller_eth_min_transfer_amount;
     propeller.marginal_price_pool = params.marginal_price_pool;
     propeller.marginal_price_pool_token_index = params.marginal_price_pool_token_index;
     propeller.marginal_price_pool_token_mint = params.marginal_price_pool_token_mint;
+    propeller.marginal_price_twap_window = params.marginal_price_twap_window;
     // propeller.evm_routing_contract_address = params.evm_routing_contract_address;
     propeller.fee_vault = ctx.accounts.propeller_fee_vault.key();
     propeller.aggregator = ctx.accounts.aggregator.key();
+    propeller.max_staleness = params.max_staleness;
+    propeller.max_confidence_interval = params.max_confidence_interval;
     Ok(())
 }
 
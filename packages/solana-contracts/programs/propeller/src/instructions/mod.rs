@@ -1,14 +1,19 @@
 pub use {
-    create_owner_token_accounts::*, fee_tracker::*, initialize::*, process_swim_payload::*, target_chain_map::*,
-    token_id_map::*, utils::*, wormhole::*,
+    admin::*, create_owner_token_accounts::*, fee_price_cache::*, fee_tracker::*, initialize::*,
+    process_swim_payload::*, quote_propeller_fees::*, route::*, target_chain_map::*, token_id_map::*, utils::*,
+    wormhole::*,
 };
 
 pub mod process_swim_payload;
 // pub mod composite;
+pub mod admin;
 pub mod initialize;
 // pub mod pool;
 pub mod create_owner_token_accounts;
+pub mod fee_price_cache;
 pub mod fee_tracker;
+pub mod quote_propeller_fees;
+pub mod route;
 pub mod target_chain_map;
 pub mod token_id_map;
 pub mod two_pool_cpi;
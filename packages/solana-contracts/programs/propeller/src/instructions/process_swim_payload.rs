@@ -1,22 +1,20 @@
 pub use switchboard_v2::{AggregatorAccountData, SwitchboardDecimal, SWITCHBOARD_PROGRAM_ID};
 use {
-    crate::{
-        constants::LAMPORTS_PER_SOL_DECIMAL, convert_fees_to_swim_usd_atomic, get_marginal_price_decimal,
-        get_swim_usd_mint_decimals, FeeTracker,
-    },
+    crate::{constants::LAMPORTS_PER_SOL_DECIMAL, FeePriceCache, FeeTracker},
     anchor_lang::system_program,
     anchor_spl::{associated_token::AssociatedToken, token::Transfer},
-    num_traits::{FromPrimitive, ToPrimitive},
-    rust_decimal::Decimal,
 };
 use {
     crate::{
         deserialize_message_payload,
         // env::*,
         error::*,
+        events::ProcessSwimPayloadEvent,
         get_message_data,
         get_transfer_with_payload_from_message_account,
         hash_vaa,
+        invoke_swim_receiver,
+        route::{load_route, PoolStepAccounts},
         state::{SwimClaim, SwimPayloadMessage, *},
         token_bridge::TokenBridge,
         token_id_map::{PoolInstruction, TokenIdMap},
@@ -25,7 +23,6 @@ use {
         PostVAAData,
         PostedVAAData,
         Propeller,
-        RawSwimPayload,
         TOKEN_COUNT,
     },
     anchor_lang::{prelude::*, solana_program::program::invoke},
@@ -157,13 +154,13 @@ pub struct ProcessSwimPayload<'info> {
     // needs to be a signer since its a "keypair" account
     pub user_transfer_authority: Signer<'info>,
 
-    #[account(mut, token::mint = pool_token_account_0.mint, token::authority = swim_payload_message.owner)]
+    #[account(mut, token::mint = pool_token_account_0.mint, token::authority = swim_payload_message.token_account_owner())]
     pub user_token_account_0: Box<Account<'info, TokenAccount>>,
 
-    #[account(mut, token::mint = pool_token_account_1.mint, token::authority = swim_payload_message.owner)]
+    #[account(mut, token::mint = pool_token_account_1.mint, token::authority = swim_payload_message.token_account_owner())]
     pub user_token_account_1: Box<Account<'info, TokenAccount>>,
 
-    #[account(mut, token::mint = pool.lp_mint_key, token::authority = swim_payload_message.owner)]
+    #[account(mut, token::mint = pool.lp_mint_key, token::authority = swim_payload_message.token_account_owner())]
     pub user_lp_token_account: Box<Account<'info, TokenAccount>>,
 
     pub token_program: Program<'info, Token>,
@@ -174,6 +171,8 @@ pub struct ProcessSwimPayload<'info> {
 
 impl<'info> ProcessSwimPayload<'info> {
     pub fn accounts(ctx: &Context<ProcessSwimPayload>) -> Result<()> {
+        ctx.accounts.propeller.require_inbound_not_paused()?;
+        ctx.accounts.swim_payload_message.require_released(Clock::get()?.unix_timestamp)?;
         // verify claim
         // verify message
         require_keys_eq!(ctx.accounts.swim_payload_message.claim.key(), ctx.accounts.claim.key());
@@ -194,30 +193,55 @@ impl<'info> ProcessSwimPayload<'info> {
         Ok(())
     }
 
+    /// Executes the pool ix of the `token_id_map` followed by the steps of its `route`.
+    /// The output of every step but the last one is sent to the input account of the next step
+    /// & `min_output_amount` is only checked against the output of the last step.
     pub fn transfer_tokens(
         &self,
         output_token_index: u16,
         transfer_amount: u64,
         min_output_amount: u64,
+        route: &[PoolStepAccounts<'info>],
     ) -> Result<u64> {
         let token_id_mapping = &self.token_id_map;
         let pool_ix = &token_id_mapping.pool_ix;
         let pool_token_mint = &token_id_mapping.pool_token_mint;
         let pool_token_index = token_id_mapping.pool_token_index;
+        let redeemer = self.redeemer.to_account_info();
+        let signer_seeds: &[&[&[u8]]] = &[&[&b"redeemer".as_ref(), &[self.propeller.redeemer_bump]]];
 
         //TODO: decide if using user_transfer_auth
         // remove user_transfer_authority account if not.
 
-        self.execute_transfer_or_pool_ix(
+        let first_step_output_account = route.first().map(|next_step| next_step.input_account().to_account_info());
+        let mut output_amount = self.execute_transfer_or_pool_ix(
             transfer_amount,
-            min_output_amount,
+            if route.is_empty() { min_output_amount } else { 0 },
             output_token_index,
             pool_ix,
             pool_token_index,
             pool_token_mint,
-            &self.redeemer.to_account_info(),
-            &[&[&b"redeemer".as_ref(), &[self.propeller.redeemer_bump]]],
-        )
+            first_step_output_account.as_ref(),
+            &redeemer,
+            signer_seeds,
+        )?;
+        for (i, step) in route.iter().enumerate() {
+            let (output_account, step_min_output_amount) = match route.get(i + 1) {
+                Some(next_step) => (next_step.input_account().to_account_info(), 0),
+                None => (step.output_account().to_account_info(), min_output_amount),
+            };
+            msg!("Executing route step {}: {:?}", i + 1, step.step);
+            output_amount = step.execute(
+                output_amount,
+                step_min_output_amount,
+                &output_account,
+                &redeemer,
+                signer_seeds,
+                &self.two_pool_program.to_account_info(),
+                &self.token_program.to_account_info(),
+            )?;
+        }
+        Ok(output_amount)
         // self.transfer_with_user_auth(
         //     transfer_amount,
         //     min_output_amount,
@@ -261,6 +285,7 @@ impl<'info> ProcessSwimPayload<'info> {
             pool_ix,
             pool_token_index,
             pool_token_mint,
+            None,
             user_transfer_authority,
             &[],
         );
@@ -276,6 +301,7 @@ impl<'info> ProcessSwimPayload<'info> {
         output_amount_res
     }
 
+    /// `output_account` replaces the owner's token account the output of the pool ix is sent to
     fn execute_transfer_or_pool_ix(
         &self,
         transfer_amount: u64,
@@ -284,17 +310,28 @@ impl<'info> ProcessSwimPayload<'info> {
         pool_ix: &PoolInstruction,
         pool_token_index: u8,
         pool_token_mint: &Pubkey,
+        output_account: Option<&AccountInfo<'info>>,
         user_transfer_authority: &AccountInfo<'info>,
         signer_seeds: &[&[&[u8]]],
     ) -> Result<u64> {
         let swim_payload_owner = self.swim_payload_message.owner;
         require_gt!(TOKEN_COUNT, pool_token_index as usize);
+        let with_output_account = |mut user_token_accounts: [AccountInfo<'info>; TOKEN_COUNT]| {
+            if let Some(output_account) = output_account {
+                user_token_accounts[pool_token_index as usize] = output_account.clone();
+            }
+            user_token_accounts
+        };
         match pool_ix {
             PoolInstruction::RemoveExactBurn => {
                 msg!("Executing RemoveExactBurn");
                 require_keys_eq!(self.pool.token_mint_keys[pool_token_index as usize], *pool_token_mint);
 
                 // TODO: handle other checks
+                let [user_token_account_0, user_token_account_1] = with_output_account([
+                    self.user_token_account_0.to_account_info(),
+                    self.user_token_account_1.to_account_info(),
+                ]);
                 Ok(two_pool::cpi::remove_exact_burn(
                     CpiContext::new_with_signer(
                         self.two_pool_program.to_account_info(),
@@ -305,8 +342,8 @@ impl<'info> ProcessSwimPayload<'info> {
                             lp_mint: self.lp_mint.to_account_info(),
                             governance_fee: self.governance_fee.to_account_info(),
                             user_transfer_authority: user_transfer_authority.to_account_info(),
-                            user_token_account_0: self.user_token_account_0.to_account_info(),
-                            user_token_account_1: self.user_token_account_1.to_account_info(),
+                            user_token_account_0,
+                            user_token_account_1,
                             user_lp_token_account: self.redeemer_escrow.to_account_info(),
                             token_program: self.token_program.to_account_info(),
                         },
@@ -321,6 +358,10 @@ impl<'info> ProcessSwimPayload<'info> {
             PoolInstruction::SwapExactInput => {
                 msg!("Executing SwapExactInput");
                 require_keys_eq!(self.pool.token_mint_keys[pool_token_index as usize], *pool_token_mint);
+                let [user_token_account_0, user_token_account_1] = with_output_account([
+                    self.redeemer_escrow.to_account_info(),
+                    self.user_token_account_1.to_account_info(),
+                ]);
 
                 Ok(two_pool::cpi::swap_exact_input(
                     CpiContext::new_with_signer(
@@ -332,13 +373,13 @@ impl<'info> ProcessSwimPayload<'info> {
                             lp_mint: self.lp_mint.to_account_info(),
                             governance_fee: self.governance_fee.to_account_info(),
                             user_transfer_authority: user_transfer_authority.to_account_info(),
-                            user_token_account_0: self.redeemer_escrow.to_account_info(),
-                            user_token_account_1: self.user_token_account_1.to_account_info(),
+                            user_token_account_0,
+                            user_token_account_1,
                             token_program: self.token_program.to_account_info(),
                         },
                         signer_seeds,
                     ),
-                    [transfer_amount, 0u64],
+                    vec![transfer_amount, 0u64],
                     pool_token_index,
                     min_output_amount,
                 )?
@@ -351,6 +392,85 @@ impl<'info> ProcessSwimPayload<'info> {
         }
     }
 
+    /// Returns the amount of the target token the owner would receive for `transfer_amount`
+    /// if `transfer_tokens` was executed now
+    fn calculate_output_amount(&self, transfer_amount: u64, route: &[PoolStepAccounts<'info>]) -> Result<u64> {
+        let current_ts = Clock::get()?.unix_timestamp;
+        route.iter().try_fold(self.calculate_first_step_output_amount(transfer_amount)?, |input_amount, step| {
+            step.calculate_output_amount(input_amount, current_ts)
+        })
+    }
+
+    fn calculate_first_step_output_amount(&self, transfer_amount: u64) -> Result<u64> {
+        let pool_token_index = self.token_id_map.pool_token_index as usize;
+        require_gt!(TOKEN_COUNT, pool_token_index);
+        let pool_balances = [self.pool_token_account_0.amount, self.pool_token_account_1.amount];
+        let lp_total_supply = self.lp_mint.supply;
+        let current_ts = Clock::get()?.unix_timestamp;
+        match self.token_id_map.pool_ix {
+            PoolInstruction::RemoveExactBurn => Ok(two_pool::instructions::calculate_remove_exact_burn(
+                &self.pool,
+                transfer_amount,
+                pool_token_index,
+                &pool_balances,
+                lp_total_supply,
+                current_ts,
+            )?
+            .0),
+            PoolInstruction::SwapExactInput => Ok(two_pool::instructions::calculate_swap_exact_input(
+                &self.pool,
+                &[transfer_amount, 0u64],
+                pool_token_index,
+                &pool_balances,
+                lp_total_supply,
+                current_ts,
+            )?
+            .0),
+            PoolInstruction::Transfer => Ok(transfer_amount),
+        }
+    }
+
+    /// Transfers `transfer_amount` swimUSD from the redeemer escrow to the owner
+    /// instead of executing the pool ix of the `token_id_map`
+    fn transfer_swim_usd_to_owner(&self, transfer_amount: u64) -> Result<u64> {
+        self.transfer_swim_usd_tokens(
+            transfer_amount,
+            &self.redeemer.to_account_info(),
+            &[&[&b"redeemer".as_ref(), &[self.propeller.redeemer_bump]]],
+        )
+    }
+
+    /// Returns the owner's swimUSD token account. swimUSD is the lp token of the pool for
+    /// `RemoveExactBurn`/`Transfer` & token 0 of the metapool for `SwapExactInput`
+    fn get_owner_swim_usd_account(&self) -> Result<&Account<'info, TokenAccount>> {
+        let swim_usd_mint = self.get_swim_usd_mint();
+        if self.user_lp_token_account.mint == swim_usd_mint {
+            return Ok(&self.user_lp_token_account);
+        }
+        require_keys_eq!(self.user_token_account_0.mint, swim_usd_mint, PropellerError::InvalidSwimUsdMint);
+        Ok(&self.user_token_account_0)
+    }
+
+    /// Returns the owner's token account that received the output of `transfer_tokens`
+    /// or of `transfer_swim_usd_to_owner` if `executed_pool_ix` is false
+    fn get_owner_output_account<'a>(
+        &'a self,
+        executed_pool_ix: bool,
+        route: &'a [PoolStepAccounts<'info>],
+    ) -> Result<&'a Account<'info, TokenAccount>> {
+        if !executed_pool_ix || matches!(self.token_id_map.pool_ix, PoolInstruction::Transfer) {
+            return self.get_owner_swim_usd_account();
+        }
+        if let Some(last_step) = route.last() {
+            return Ok(last_step.output_account());
+        }
+        match self.token_id_map.pool_token_index {
+            0 => Ok(&self.user_token_account_0),
+            1 => Ok(&self.user_token_account_1),
+            _ => err!(PropellerError::InvalidOutputTokenIndex),
+        }
+    }
+
     fn transfer_swim_usd_tokens(
         &self,
         transfer_amount: u64,
@@ -359,7 +479,7 @@ impl<'info> ProcessSwimPayload<'info> {
     ) -> Result<u64> {
         let cpi_accounts = Transfer {
             from: self.redeemer_escrow.to_account_info(),
-            to: self.user_lp_token_account.to_account_info(),
+            to: self.get_owner_swim_usd_account()?.to_account_info(),
             authority: user_transfer_authority.to_account_info(),
         };
         token::transfer(
@@ -381,8 +501,8 @@ impl<'info> ProcessSwimPayload<'info> {
     }
 }
 
-pub fn handle_process_swim_payload(
-    ctx: Context<ProcessSwimPayload>,
+pub fn handle_process_swim_payload<'info>(
+    ctx: Context<'_, '_, '_, 'info, ProcessSwimPayload<'info>>,
     target_token_id: u16,
     min_output_amount: u64,
 ) -> Result<u64> {
@@ -394,15 +514,44 @@ pub fn handle_process_swim_payload(
     msg!("claim_data: {:?}", claim_data);
 
     let transfer_amount = ctx.accounts.swim_payload_message.transfer_amount;
+    // anyone can process the swim payload so the caller can only raise the
+    // `min_output_amount` the owner set on the source chain, never lower it
+    let min_output_amount = min_output_amount.max(propeller_message.min_output_amount);
+    msg!("min_output_amount: {}", min_output_amount);
 
     let owner = propeller_message.owner;
     let token_program = &ctx.accounts.token_program;
     msg!("transfer_amount: {}", transfer_amount);
 
-    let output_amount = ctx.accounts.transfer_tokens(target_token_id, transfer_amount, min_output_amount)?;
+    let (route, swim_receiver_accounts) = load_route(
+        &ctx.accounts.token_id_map,
+        &ctx.accounts.redeemer.key(),
+        &ctx.accounts.swim_payload_message.token_account_owner(),
+        ctx.remaining_accounts,
+    )?;
+    let output_amount = ctx.accounts.transfer_tokens(target_token_id, transfer_amount, min_output_amount, &route)?;
 
     let swim_claim_bump = *ctx.bumps.get("swim_claim").unwrap();
     ctx.accounts.init_swim_claim(swim_claim_bump)?;
+    emit!(ProcessSwimPayloadEvent {
+        propeller: ctx.accounts.propeller.key(),
+        payer: ctx.accounts.payer.key(),
+        swim_payload_message: ctx.accounts.swim_payload_message.key(),
+        owner,
+        pool: Some(ctx.accounts.pool.key()),
+        target_token_id,
+        fees: 0,
+        transfer_amount,
+        output_amount,
+    });
+    // a failing receiver reverts the delivery. see `invoke_swim_receiver` for the escape hatch
+    invoke_swim_receiver(
+        &ctx.accounts.propeller.key(),
+        &ctx.accounts.swim_payload_message,
+        ctx.accounts.get_owner_output_account(true, &route)?,
+        output_amount,
+        swim_receiver_accounts,
+    )?;
 
     Ok(output_amount)
 }
@@ -413,13 +562,15 @@ pub struct PropellerProcessSwimPayload<'info> {
 
     // #[account(mut)]
     // pub token_bridge_mint: Box<Account<'info, Mint>>,
-    /// Assuming that USD:USDC 1:1
-    ///CHECK: account for getting gas -> USD price
     #[account(
-    constraint =
-    *aggregator.to_account_info().owner == SWITCHBOARD_PROGRAM_ID @ PropellerError::InvalidSwitchboardAccount
+    seeds = [
+    b"propeller".as_ref(),
+    b"fee_price_cache".as_ref(),
+    process_swim_payload.propeller.key().as_ref()
+    ],
+    bump = fee_price_cache.bump
     )]
-    pub aggregator: AccountLoader<'info, AggregatorAccountData>,
+    pub fee_price_cache: Account<'info, FeePriceCache>,
 
     #[account(
     mut,
@@ -454,26 +605,8 @@ pub struct PropellerProcessSwimPayload<'info> {
     )]
     pub fee_tracker: Box<Account<'info, FeeTracker>>,
 
-    #[account(
-    mut,
-    seeds = [
-    b"two_pool".as_ref(),
-    marginal_price_pool_token_0_account.mint.as_ref(),
-    marginal_price_pool_token_1_account.mint.as_ref(),
-    marginal_price_pool_lp_mint.key().as_ref(),
-    ],
-    bump = marginal_price_pool.bump,
-    seeds::program = process_swim_payload.two_pool_program.key()
-    )]
-    pub marginal_price_pool: Box<Account<'info, TwoPool>>,
-    #[account(address = marginal_price_pool.token_keys[0])]
-    pub marginal_price_pool_token_0_account: Box<Account<'info, TokenAccount>>,
-    #[account(address = marginal_price_pool.token_keys[1])]
-    pub marginal_price_pool_token_1_account: Box<Account<'info, TokenAccount>>,
-    #[account(address = marginal_price_pool.lp_mint_key)]
-    pub marginal_price_pool_lp_mint: Box<Account<'info, Mint>>,
     /// This is for transferring lamports for kickstart
-    #[account(mut, address = process_swim_payload.swim_payload_message.owner)]
+    #[account(mut, address = process_swim_payload.swim_payload_message.token_account_owner())]
     pub owner: SystemAccount<'info>,
     #[account(executable, address = spl_memo::id())]
     ///CHECK: memo program
@@ -482,25 +615,17 @@ pub struct PropellerProcessSwimPayload<'info> {
 
 impl<'info> PropellerProcessSwimPayload<'info> {
     pub fn accounts(ctx: &Context<PropellerProcessSwimPayload>, target_token_id: u16) -> Result<()> {
+        let process_swim_payload = &ctx.accounts.process_swim_payload;
+        process_swim_payload.propeller.require_inbound_not_paused()?;
+        let current_ts = Clock::get()?.unix_timestamp;
+        process_swim_payload.swim_payload_message.require_released(current_ts)?;
+        process_swim_payload.swim_payload_message.require_relayer(&process_swim_payload.payer.key(), current_ts)?;
         ctx.accounts.validate()?;
-        require_keys_eq!(
-            ctx.accounts.process_swim_payload.propeller.aggregator,
-            ctx.accounts.aggregator.key(),
-            PropellerError::InvalidAggregator
-        );
         require_eq!(
             ctx.accounts.process_swim_payload.swim_payload_message.target_token_id,
             target_token_id,
             // PropellerError::InvalidTargetTokenId
         );
-        validate_marginal_prices_pool_accounts(
-            &ctx.accounts.process_swim_payload.propeller,
-            &ctx.accounts.marginal_price_pool.key(),
-            &[
-                ctx.accounts.marginal_price_pool_token_0_account.mint,
-                ctx.accounts.marginal_price_pool_token_1_account.mint,
-            ],
-        )?;
         msg!("Finished PropellerProcessSwimPayload::accounts()");
         Ok(())
     }
@@ -524,6 +649,7 @@ impl<'info> PropellerProcessSwimPayload<'info> {
     /// returns fees_in_token_bridge_mint
     fn handle_fees(&mut self) -> Result<u64> {
         let fees_in_swim_usd_atomic = self.calculate_fees()?;
+        self.process_swim_payload.swim_payload_message.add_fees(fees_in_swim_usd_atomic)?;
         let propeller = &self.process_swim_payload.propeller;
         let token_program = &self.process_swim_payload.token_program;
         msg!("fees_in_swim_usd_atomic: {:?}", fees_in_swim_usd_atomic);
@@ -556,18 +682,9 @@ impl<'info> PropellerProcessSwimPayload<'info> {
         let swim_payload_message = &self.process_swim_payload.swim_payload_message;
         let propeller_process_swim_payload_fees = propeller.process_swim_payload_fee;
 
-        let two_pool_program = &self.process_swim_payload.two_pool_program;
-        let marginal_price_pool = &self.marginal_price_pool;
-        let marginal_price_pool_token_0_account = &self.marginal_price_pool_token_0_account;
-        let marginal_price_pool_token_1_account = &self.marginal_price_pool_token_1_account;
-        let marginal_price_pool_lp_mint = &self.marginal_price_pool_lp_mint;
-
         let swim_claim_rent_exempt_fees = rent.minimum_balance(8 + SwimClaim::LEN);
         let gas_kickstart_amount = if swim_payload_message.gas_kickstart { propeller.gas_kickstart_amount } else { 0 };
-        let fee_in_lamports = swim_claim_rent_exempt_fees
-            .checked_add(propeller_process_swim_payload_fees)
-            .and_then(|x| x.checked_add(gas_kickstart_amount))
-            .ok_or(PropellerError::IntegerOverflow)?;
+        let fee_in_lamports = propeller.get_process_swim_payload_fees(&rent, swim_payload_message.gas_kickstart)?;
 
         msg!(
             "
@@ -582,26 +699,11 @@ impl<'info> PropellerProcessSwimPayload<'info> {
             fee_in_lamports
         );
 
-        let cpi_ctx = CpiContext::new(
-            two_pool_program.to_account_info(),
-            two_pool::cpi::accounts::MarginalPrices {
-                pool: marginal_price_pool.to_account_info(),
-                pool_token_account_0: marginal_price_pool_token_0_account.to_account_info(),
-                pool_token_account_1: marginal_price_pool_token_1_account.to_account_info(),
-                lp_mint: marginal_price_pool_lp_mint.to_account_info(),
-            },
-        );
-        let fees_in_swim_usd_atomic = convert_fees_to_swim_usd_atomic(
+        self.fee_price_cache.convert_fees_to_swim_usd_atomic(
             fee_in_lamports,
-            &propeller,
-            &marginal_price_pool_lp_mint,
-            // ctx.accounts.into_marginal_prices(),
-            cpi_ctx,
-            &marginal_price_pool,
-            &self.aggregator,
-            i64::MAX,
-        )?;
-        Ok(fees_in_swim_usd_atomic)
+            propeller.fee_price_cache_max_age,
+            Clock::get()?.unix_timestamp,
+        )
     }
 
     fn transfer_gas_kickstart(&self) -> Result<()> {
@@ -659,13 +761,14 @@ TODO:
   a. initialize a SwimClaim PDA
 
  */
-pub fn handle_propeller_process_swim_payload(
-    ctx: Context<PropellerProcessSwimPayload>,
+pub fn handle_propeller_process_swim_payload<'info>(
+    ctx: Context<'_, '_, '_, 'info, PropellerProcessSwimPayload<'info>>,
     target_token_id: u16,
 ) -> Result<u64> {
     let swim_payload_message = &ctx.accounts.process_swim_payload.swim_payload_message;
     let is_gas_kickstart = swim_payload_message.gas_kickstart;
     let target_token_id = swim_payload_message.target_token_id;
+    let min_output_amount = swim_payload_message.min_output_amount;
 
     let claim_data = ClaimData::try_from_slice(&mut ctx.accounts.process_swim_payload.claim.data.borrow())
         .map_err(|_| error!(PropellerError::InvalidClaimData))?;
@@ -678,6 +781,7 @@ pub fn handle_propeller_process_swim_payload(
     let swim_payload_owner = swim_payload_message.owner;
     let token_program = &ctx.accounts.process_swim_payload.token_program;
     msg!("original transfer_amount: {:?}", transfer_amount);
+    let mut fees = 0u64;
     if swim_payload_owner != ctx.accounts.process_swim_payload.payer.key() {
         let fees_in_token_bridge = &ctx.accounts.handle_fees()?;
         // let fees_in_token_bridge = calculate_fees2(&ctx)?;
@@ -685,6 +789,7 @@ pub fn handle_propeller_process_swim_payload(
         if is_gas_kickstart {
             ctx.accounts.transfer_gas_kickstart()?;
         }
+        fees = *fees_in_token_bridge;
         transfer_amount =
             transfer_amount.checked_sub(*fees_in_token_bridge).ok_or(error!(PropellerError::InsufficientFunds))?;
     } else {
@@ -693,13 +798,53 @@ pub fn handle_propeller_process_swim_payload(
     }
 
     msg!("transfer_amount - fee = {}", transfer_amount);
-    let min_output_amount = 0u64;
-    let output_amount =
-        ctx.accounts.process_swim_payload.transfer_tokens(target_token_id, transfer_amount, min_output_amount)?;
+    // if the pool ix would not yield the `min_output_amount` the owner set on the source chain,
+    // fallback to transferring swimUSD so the swim payload can't get stuck
+    let process_swim_payload = &ctx.accounts.process_swim_payload;
+    let (route, swim_receiver_accounts) = load_route(
+        &process_swim_payload.token_id_map,
+        &process_swim_payload.redeemer.key(),
+        &process_swim_payload.swim_payload_message.token_account_owner(),
+        ctx.remaining_accounts,
+    )?;
+    let (pool, output_amount) = match process_swim_payload.calculate_output_amount(transfer_amount, &route) {
+        Ok(expected_output_amount) if expected_output_amount >= min_output_amount => (
+            Some(process_swim_payload.pool.key()),
+            process_swim_payload.transfer_tokens(target_token_id, transfer_amount, min_output_amount, &route)?,
+        ),
+        expected_output_amount => {
+            msg!(
+                "expected_output_amount: {:?} < min_output_amount: {}. Transferring swimUSD instead",
+                expected_output_amount,
+                min_output_amount
+            );
+            (None, process_swim_payload.transfer_swim_usd_to_owner(transfer_amount)?)
+        }
+    };
 
     let swim_claim_bump = *ctx.bumps.get("swim_claim").unwrap();
     ctx.accounts.process_swim_payload.init_swim_claim(swim_claim_bump)?;
     ctx.accounts.log_memo()?;
+    emit!(ProcessSwimPayloadEvent {
+        propeller: ctx.accounts.process_swim_payload.propeller.key(),
+        payer: ctx.accounts.process_swim_payload.payer.key(),
+        swim_payload_message: ctx.accounts.process_swim_payload.swim_payload_message.key(),
+        owner: swim_payload_owner,
+        pool,
+        target_token_id,
+        fees,
+        transfer_amount,
+        output_amount,
+    });
+    let process_swim_payload = &ctx.accounts.process_swim_payload;
+    // a failing receiver reverts the delivery. see `invoke_swim_receiver` for the escape hatch
+    invoke_swim_receiver(
+        &process_swim_payload.propeller.key(),
+        &process_swim_payload.swim_payload_message,
+        process_swim_payload.get_owner_output_account(pool.is_some(), &route)?,
+        output_amount,
+        swim_receiver_accounts,
+    )?;
 
     msg!("output_amount: {}", output_amount);
     Ok(output_amount)
@@ -710,8 +855,6 @@ pub struct PropellerProcessSwimPayloadFallback<'info> {
     #[account(
     seeds = [ b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
     bump = propeller.bump,
-    has_one = marginal_price_pool,
-    has_one = aggregator @ PropellerError::InvalidAggregator
     )]
     pub propeller: Box<Account<'info, Propeller>>,
     #[account(mut)]
@@ -751,7 +894,6 @@ pub struct PropellerProcessSwimPayloadFallback<'info> {
     ],
     bump = swim_payload_message.bump,
     has_one = swim_payload_message_payer,
-    has_one = owner,
     has_one = claim,
     )]
     pub swim_payload_message: Box<Account<'info, SwimPayloadMessage>>,
@@ -787,17 +929,16 @@ pub struct PropellerProcessSwimPayloadFallback<'info> {
     pub user_swim_usd_ata: Box<Account<'info, TokenAccount>>,
 
     pub token_program: Program<'info, Token>,
-    pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
     #[account(executable, address = spl_memo::id())]
     ///CHECK: memo program
     pub memo: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 
     #[account(
-    constraint =
-    *aggregator.to_account_info().owner == SWITCHBOARD_PROGRAM_ID @ PropellerError::InvalidSwitchboardAccount
+    seeds = [b"propeller".as_ref(), b"fee_price_cache".as_ref(), propeller.key().as_ref()],
+    bump = fee_price_cache.bump
     )]
-    pub aggregator: AccountLoader<'info, AggregatorAccountData>,
+    pub fee_price_cache: Account<'info, FeePriceCache>,
 
     #[account(
     mut,
@@ -819,24 +960,6 @@ pub struct PropellerProcessSwimPayloadFallback<'info> {
     )]
     pub fee_tracker: Box<Account<'info, FeeTracker>>,
 
-    #[account(
-    mut,
-    seeds = [
-    b"two_pool".as_ref(),
-    marginal_price_pool_token_0_account.mint.as_ref(),
-    marginal_price_pool_token_1_account.mint.as_ref(),
-    marginal_price_pool_lp_mint.key().as_ref(),
-    ],
-    bump = marginal_price_pool.bump,
-    seeds::program = two_pool_program.key()
-    )]
-    pub marginal_price_pool: Box<Account<'info, TwoPool>>,
-    #[account(address = marginal_price_pool.token_keys[0])]
-    pub marginal_price_pool_token_0_account: Box<Account<'info, TokenAccount>>,
-    #[account(address = marginal_price_pool.token_keys[1])]
-    pub marginal_price_pool_token_1_account: Box<Account<'info, TokenAccount>>,
-    #[account(address = marginal_price_pool.lp_mint_key)]
-    pub marginal_price_pool_lp_mint: Box<Account<'info, Mint>>,
     /// This is for transferring lamports for kickstart
     #[account(mut)]
     pub owner: SystemAccount<'info>,
@@ -844,7 +967,11 @@ pub struct PropellerProcessSwimPayloadFallback<'info> {
 
 impl<'info> PropellerProcessSwimPayloadFallback<'info> {
     pub fn accounts(ctx: &Context<PropellerProcessSwimPayloadFallback>) -> Result<()> {
-        require_keys_eq!(ctx.accounts.owner.key(), ctx.accounts.swim_payload_message.owner);
+        ctx.accounts.propeller.require_inbound_not_paused()?;
+        let current_ts = Clock::get()?.unix_timestamp;
+        ctx.accounts.swim_payload_message.require_released(current_ts)?;
+        ctx.accounts.swim_payload_message.require_relayer(&ctx.accounts.payer.key(), current_ts)?;
+        require_keys_eq!(ctx.accounts.owner.key(), ctx.accounts.swim_payload_message.token_account_owner());
         let (expected_token_id_map_address, _bump) = Pubkey::find_program_address(
             &[
                 b"propeller".as_ref(),
@@ -860,10 +987,28 @@ impl<'info> PropellerProcessSwimPayloadFallback<'info> {
         Ok(())
     }
 
+    /// Used by `propeller_process_expired_swim_payload` instead of `accounts()`.
+    /// The `token_id_map` is not used so it can be any account.
+    pub fn accounts_expired(ctx: &Context<PropellerProcessSwimPayloadFallback>) -> Result<()> {
+        ctx.accounts.propeller.require_inbound_not_paused()?;
+        require_keys_eq!(ctx.accounts.owner.key(), ctx.accounts.swim_payload_message.token_account_owner());
+        let current_ts = Clock::get()?.unix_timestamp;
+        require!(
+            ctx.accounts.swim_payload_message.is_expired(ctx.accounts.propeller.swim_payload_expiry, current_ts),
+            PropellerError::SwimPayloadNotExpired
+        );
+        Ok(())
+    }
+
     /// Calculates, transfer and tracks fees
     /// returns fees_in_swim_usd_mint
-    fn handle_fees(&mut self) -> Result<u64> {
-        let fees_in_token_bridge = self.calculate_fees()?;
+    /// if `cap_at_max_fee` the fees are reduced to what's left of `max_fee` instead of failing
+    fn handle_fees(&mut self, cap_at_max_fee: bool) -> Result<u64> {
+        let mut fees_in_token_bridge = self.calculate_fees()?;
+        if cap_at_max_fee {
+            fees_in_token_bridge = fees_in_token_bridge.min(self.swim_payload_message.remaining_fees());
+        }
+        self.swim_payload_message.add_fees(fees_in_token_bridge)?;
         let propeller = &self.propeller;
         let token_program = &self.token_program;
         msg!("fees_in_token_bridge: {:?}", fees_in_token_bridge);
@@ -896,18 +1041,9 @@ impl<'info> PropellerProcessSwimPayloadFallback<'info> {
         let swim_payload_message = &self.swim_payload_message;
         let propeller_process_swim_payload_fees = propeller.process_swim_payload_fee;
 
-        let two_pool_program = &self.two_pool_program;
-        let marginal_price_pool = &self.marginal_price_pool;
-        let marginal_price_pool_token_0_account = &self.marginal_price_pool_token_0_account;
-        let marginal_price_pool_token_1_account = &self.marginal_price_pool_token_1_account;
-        let marginal_price_pool_lp_mint = &self.marginal_price_pool_lp_mint;
-
         let swim_claim_rent_exempt_fees = rent.minimum_balance(8 + SwimClaim::LEN);
         let gas_kickstart_amount = if swim_payload_message.gas_kickstart { propeller.gas_kickstart_amount } else { 0 };
-        let fee_in_lamports = swim_claim_rent_exempt_fees
-            .checked_add(propeller_process_swim_payload_fees)
-            .and_then(|x| x.checked_add(gas_kickstart_amount))
-            .ok_or(PropellerError::IntegerOverflow)?;
+        let fee_in_lamports = propeller.get_process_swim_payload_fees(&rent, swim_payload_message.gas_kickstart)?;
 
         msg!(
             "
@@ -922,80 +1058,11 @@ impl<'info> PropellerProcessSwimPayloadFallback<'info> {
             fee_in_lamports
         );
 
-        let cpi_ctx = CpiContext::new(
-            two_pool_program.to_account_info(),
-            two_pool::cpi::accounts::MarginalPrices {
-                pool: marginal_price_pool.to_account_info(),
-                pool_token_account_0: marginal_price_pool_token_0_account.to_account_info(),
-                pool_token_account_1: marginal_price_pool_token_1_account.to_account_info(),
-                lp_mint: marginal_price_pool_lp_mint.to_account_info(),
-            },
-        );
-        let result = two_pool::cpi::marginal_prices(cpi_ctx)?;
-        // let marginal_prices = result.get().marginal_prices;
-        let marginal_prices = result.get();
-
-        msg!("marginal_prices: {:?}", marginal_prices);
-        let mut res = 0u64;
-        let feed = &self.aggregator.load()?;
-
-        let sol_usd_price: Decimal = feed.get_result()?.try_into()?;
-        let name = feed.name;
-
-        let lamports_usd_price =
-            sol_usd_price.checked_div(LAMPORTS_PER_SOL_DECIMAL).ok_or(PropellerError::IntegerOverflow)?;
-        msg!("sol_usd_price:{},lamports_usd_price: {}", sol_usd_price, lamports_usd_price);
-        // check whether the feed has been updated in the last 300 seconds
-        feed.check_staleness(
-            Clock::get().unwrap().unix_timestamp,
-            // 300
-            i64::MAX,
+        self.fee_price_cache.convert_fees_to_swim_usd_atomic(
+            fee_in_lamports,
+            propeller.fee_price_cache_max_age,
+            Clock::get()?.unix_timestamp,
         )
-        .map_err(|_| error!(PropellerError::StaleFeed))?;
-        // check feed does not exceed max_confidence_interval
-        // if let Some(max_confidence_interval) = params.max_confidence_interval {
-        // 	feed.check_confidence_interval(SwitchboardDecimal::from_f64(max_confidence_interval))
-        // 	.map_err(|_| error!(PropellerError::ConfidenceIntervalExceeded))?;
-        // }
-        let lp_mint_key = marginal_price_pool_lp_mint.key();
-
-        let swim_usd_mint_key = self.propeller.swim_usd_mint;
-        let marginal_price: Decimal = get_marginal_price_decimal(
-            &marginal_price_pool,
-            &marginal_prices,
-            &propeller,
-            // propeller.marginal_price_pool_token_index as usize,
-            &marginal_price_pool_lp_mint.key(),
-            // &token_bridge_mint_key,
-        )?;
-
-        msg!("marginal_price: {}", marginal_price);
-        let fee_in_lamports_decimal = Decimal::from_u64(fee_in_lamports).ok_or(PropellerError::ConversionError)?;
-        msg!("fee_in_lamports(u64): {:?} fee_in_lamports_decimal: {:?}", fee_in_lamports, fee_in_lamports_decimal);
-        let fee_in_swim_usd_mint_decimal = marginal_price
-            .checked_mul(lamports_usd_price)
-            .and_then(|v| v.checked_mul(fee_in_lamports_decimal))
-            .ok_or(PropellerError::IntegerOverflow)?;
-
-        let swim_usd_mint_decimals =
-            get_swim_usd_mint_decimals(&swim_usd_mint_key, &marginal_price_pool, &marginal_price_pool_lp_mint)?;
-
-        msg!("swim_usd_mint_mint_decimals: {:?} ", swim_usd_mint_decimals);
-
-        let ten_pow_decimals =
-            Decimal::from_u64(10u64.pow(swim_usd_mint_decimals as u32)).ok_or(PropellerError::IntegerOverflow)?;
-        let fee_in_swim_usd_atomic = fee_in_swim_usd_mint_decimal
-            .checked_mul(ten_pow_decimals)
-            .and_then(|v| v.to_u64())
-            .ok_or(PropellerError::ConversionError)?;
-
-        msg!(
-            "fee_in_swim_usd_decimal: {:?} fee_in_swim_usd_atomic: {:?}",
-            fee_in_swim_usd_mint_decimal,
-            fee_in_swim_usd_atomic
-        );
-        res = fee_in_swim_usd_atomic;
-        Ok(res)
     }
 
     fn transfer_gas_kickstart(&self) -> Result<()> {
@@ -1134,8 +1201,27 @@ impl<'info> PropellerProcessSwimPayloadFallback<'info> {
     }
 }
 
-pub fn handle_propeller_process_swim_payload_fallback(
-    ctx: Context<PropellerProcessSwimPayloadFallback>,
+pub fn handle_propeller_process_swim_payload_fallback<'info>(
+    ctx: Context<'_, '_, '_, 'info, PropellerProcessSwimPayloadFallback<'info>>,
+) -> Result<u64> {
+    process_swim_payload_fallback(ctx, false)
+}
+
+/// Anyone can force the swimUSD fallback delivery of a swim payload that hasn't been processed
+/// within `propeller.swim_payload_expiry` seconds (e.g. because the pool ix keeps failing).
+/// Fees are capped at the `max_fee` of the swim payload so this can't fail due to fees.
+/// The `swim_receive` callback is skipped so that a receiver program that fails (or can't be
+/// invoked) can't block the delivery. The swimUSD is sent to the ATA of the receiver authority.
+pub fn handle_propeller_process_expired_swim_payload<'info>(
+    ctx: Context<'_, '_, '_, 'info, PropellerProcessSwimPayloadFallback<'info>>,
+) -> Result<u64> {
+    msg!("Processing expired swim payload");
+    process_swim_payload_fallback(ctx, true)
+}
+
+fn process_swim_payload_fallback<'info>(
+    ctx: Context<'_, '_, '_, 'info, PropellerProcessSwimPayloadFallback<'info>>,
+    is_expired: bool,
 ) -> Result<u64> {
     let swim_payload_message = &ctx.accounts.swim_payload_message;
     let is_gas_kickstart = swim_payload_message.gas_kickstart;
@@ -1153,13 +1239,15 @@ pub fn handle_propeller_process_swim_payload_fallback(
     let swim_payload_owner = swim_payload_message.owner;
     let token_program = &ctx.accounts.token_program;
     msg!("original transfer_amount: {:?}", transfer_amount);
+    let mut fees = 0u64;
     if swim_payload_owner != ctx.accounts.payer.key() {
-        let fees_in_token_bridge = &ctx.accounts.handle_fees()?;
+        let fees_in_token_bridge = &ctx.accounts.handle_fees(is_expired)?;
         // let fees_in_token_bridge = calculate_fees2(&ctx)?;
         msg!("fees_in_token_bridge: {:?}", fees_in_token_bridge);
         if is_gas_kickstart {
             ctx.accounts.transfer_gas_kickstart()?;
         }
+        fees = *fees_in_token_bridge;
         transfer_amount =
             transfer_amount.checked_sub(*fees_in_token_bridge).ok_or(error!(PropellerError::InsufficientFunds))?;
     } else {
@@ -1177,6 +1265,28 @@ pub fn handle_propeller_process_swim_payload_fallback(
     let swim_claim_bump = *ctx.bumps.get("swim_claim").unwrap();
     ctx.accounts.init_swim_claim(swim_claim_bump)?;
     ctx.accounts.log_memo()?;
+    emit!(ProcessSwimPayloadEvent {
+        propeller: ctx.accounts.propeller.key(),
+        payer: ctx.accounts.payer.key(),
+        swim_payload_message: ctx.accounts.swim_payload_message.key(),
+        owner: swim_payload_owner,
+        pool: None,
+        target_token_id,
+        fees,
+        transfer_amount,
+        output_amount,
+    });
+    if is_expired {
+        msg!("Swim payload expired. Skipping swim receiver callback");
+    } else {
+        invoke_swim_receiver(
+            &ctx.accounts.propeller.key(),
+            &ctx.accounts.swim_payload_message,
+            &ctx.accounts.user_swim_usd_ata,
+            output_amount,
+            ctx.remaining_accounts,
+        )?;
+    }
 
     msg!("output_amount: {}", output_amount);
     Ok(output_amount)
@@ -0,0 +1,226 @@
+use {
+    crate::{
+        error::*, get_create_owner_token_account_fees, owner_token_account_exists, token_id_map::TokenIdMap, ClaimData,
+        FeePriceCache, PayloadTransferWithPayload, PostedVAAData, Propeller,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::{associated_token::get_associated_token_address, token::Mint},
+    two_pool::state::TwoPool,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct QuotePropellerFeesParams {
+    /// length of the encoded swim payload that will be delivered. Determines the size (& rent)
+    /// of the posted VAA account.
+    pub swim_payload_len: u16,
+    pub gas_kickstart: bool,
+}
+
+/// Fees (in swimUSD atomic units) a propeller engine will deduct when delivering a swim payload,
+/// broken down by the ix that charges them.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct PropellerFeesQuote {
+    pub complete_native_with_payload_fees: u64,
+    /// zero if all of the owner's token accounts already exist
+    pub create_owner_token_accounts_fees: u64,
+    pub process_swim_payload_fees: u64,
+    pub total_fees: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(target_token_id: u16)]
+pub struct QuotePropellerFees<'info> {
+    #[account(
+    seeds = [ b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
+    bump = propeller.bump,
+    )]
+    pub propeller: Box<Account<'info, Propeller>>,
+
+    #[account(
+    seeds = [
+    b"propeller".as_ref(),
+    b"token_id".as_ref(),
+    propeller.key().as_ref(),
+    &target_token_id.to_le_bytes()
+    ],
+    bump = token_id_map.bump,
+    )]
+    pub token_id_map: Box<Account<'info, TokenIdMap>>,
+
+    #[account(
+    seeds = [
+    b"two_pool".as_ref(),
+    pool_token_0_mint.key().as_ref(),
+    pool_token_1_mint.key().as_ref(),
+    pool_lp_mint.key().as_ref(),
+    ],
+    bump = pool.bump,
+    seeds::program = two_pool_program.key()
+    )]
+    pub pool: Box<Account<'info, TwoPool>>,
+    pub pool_token_0_mint: Box<Account<'info, Mint>>,
+    pub pool_token_1_mint: Box<Account<'info, Mint>>,
+    pub pool_lp_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: owner of the token accounts the swim payload will be delivered to.
+    /// (i.e. `SwimPayloadMessage::token_account_owner()`). Only used to derive the ATAs.
+    pub owner: UncheckedAccount<'info>,
+    /// CHECK: may not be initialized yet
+    pub owner_pool_token_0_account: UncheckedAccount<'info>,
+    /// CHECK: may not be initialized yet
+    pub owner_pool_token_1_account: UncheckedAccount<'info>,
+    /// CHECK: may not be initialized yet
+    pub owner_lp_token_account: UncheckedAccount<'info>,
+
+    #[account(
+    seeds = [b"propeller".as_ref(), b"fee_price_cache".as_ref(), propeller.key().as_ref()],
+    bump = fee_price_cache.bump
+    )]
+    pub fee_price_cache: Box<Account<'info, FeePriceCache>>,
+
+    pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
+}
+
+impl<'info> QuotePropellerFees<'info> {
+    pub fn accounts(ctx: &Context<QuotePropellerFees>) -> Result<()> {
+        require_keys_eq!(ctx.accounts.pool.key(), ctx.accounts.token_id_map.pool);
+        let owner = ctx.accounts.owner.key();
+        let expected_owner_token_0_ata = get_associated_token_address(&owner, &ctx.accounts.pool_token_0_mint.key());
+        require_keys_eq!(expected_owner_token_0_ata, ctx.accounts.owner_pool_token_0_account.key());
+        let expected_owner_token_1_ata = get_associated_token_address(&owner, &ctx.accounts.pool_token_1_mint.key());
+        require_keys_eq!(expected_owner_token_1_ata, ctx.accounts.owner_pool_token_1_account.key());
+        let expected_owner_lp_ata = get_associated_token_address(&owner, &ctx.accounts.pool_lp_mint.key());
+        require_keys_eq!(expected_owner_lp_ata, ctx.accounts.owner_lp_token_account.key());
+        if !ctx.accounts.token_id_map.next_steps.is_empty() {
+            // owner's ATA for the output of the last step of the route
+            require_gte!(ctx.remaining_accounts.len(), 1, PropellerError::InvalidRouteAccounts);
+            let expected_owner_output_ata =
+                get_associated_token_address(&owner, &ctx.accounts.token_id_map.output_mint());
+            require_keys_eq!(
+                expected_owner_output_ata,
+                ctx.remaining_accounts[0].key(),
+                PropellerError::InvalidRouteAccounts
+            );
+        }
+        Ok(())
+    }
+
+    fn convert_fees_to_swim_usd_atomic(&self, fee_in_lamports: u64, now: i64) -> Result<u64> {
+        self.fee_price_cache.convert_fees_to_swim_usd_atomic(
+            fee_in_lamports,
+            self.propeller.fee_price_cache_max_age,
+            now,
+        )
+    }
+
+    fn calculate_create_owner_token_accounts_fees(
+        &self,
+        rent: &Rent,
+        owner_output_token_account: Option<&AccountInfo<'info>>,
+    ) -> Result<u64> {
+        let owner = self.owner.key();
+        let mut fee_in_lamports = 0u64;
+        let owner_token_accounts = [
+            self.owner_pool_token_0_account.to_account_info(),
+            self.owner_pool_token_1_account.to_account_info(),
+            self.owner_lp_token_account.to_account_info(),
+        ];
+        for owner_token_account in owner_token_accounts.iter().chain(owner_output_token_account) {
+            if !owner_token_account_exists(owner_token_account, &owner)? {
+                fee_in_lamports = fee_in_lamports
+                    .checked_add(get_create_owner_token_account_fees(rent))
+                    .ok_or(PropellerError::IntegerOverflow)?;
+            }
+        }
+        Ok(fee_in_lamports)
+    }
+}
+
+/// Mirrors the fees charged by `propeller_complete_native_with_payload`,
+/// `propeller_create_owner_token_accounts` & `propeller_process_swim_payload`
+/// using the rate of the `FeePriceCache`.
+pub fn handle_quote_propeller_fees<'info>(
+    ctx: Context<'_, '_, '_, 'info, QuotePropellerFees<'info>>,
+    params: QuotePropellerFeesParams,
+) -> Result<PropellerFeesQuote> {
+    let rent = Rent::get()?;
+    let owner_output_token_account =
+        if ctx.accounts.token_id_map.next_steps.is_empty() { None } else { ctx.remaining_accounts.first() };
+    let now = Clock::get()?.unix_timestamp;
+    let propeller = &ctx.accounts.propeller;
+
+    let message_data_len = get_posted_vaa_data_len(params.swim_payload_len as usize);
+    let complete_fees_in_lamports = rent
+        .minimum_balance(message_data_len)
+        .checked_add(rent.minimum_balance(ClaimData::LEN))
+        .and_then(|x| x.checked_add(propeller.get_complete_native_with_payload_fee()))
+        .ok_or(PropellerError::IntegerOverflow)?;
+    let create_owner_token_accounts_fees_in_lamports =
+        ctx.accounts.calculate_create_owner_token_accounts_fees(&rent, owner_output_token_account)?;
+    let process_fees_in_lamports = propeller.get_process_swim_payload_fees(&rent, params.gas_kickstart)?;
+
+    let complete_native_with_payload_fees =
+        ctx.accounts.convert_fees_to_swim_usd_atomic(complete_fees_in_lamports, now)?;
+    let create_owner_token_accounts_fees =
+        ctx.accounts.convert_fees_to_swim_usd_atomic(create_owner_token_accounts_fees_in_lamports, now)?;
+    let process_swim_payload_fees = ctx.accounts.convert_fees_to_swim_usd_atomic(process_fees_in_lamports, now)?;
+    let total_fees = complete_native_with_payload_fees
+        .checked_add(create_owner_token_accounts_fees)
+        .and_then(|x| x.checked_add(process_swim_payload_fees))
+        .ok_or(PropellerError::IntegerOverflow)?;
+
+    let quote = PropellerFeesQuote {
+        complete_native_with_payload_fees,
+        create_owner_token_accounts_fees,
+        process_swim_payload_fees,
+        total_fees,
+    };
+    msg!("propeller fees quote: {:?}", quote);
+    Ok(quote)
+}
+
+/// Data length of the posted VAA account of a token bridge transfer with a `swim_payload_len` bytes long swim payload
+fn get_posted_vaa_data_len(swim_payload_len: usize) -> usize {
+    PostedVAAData::data_len(PayloadTransferWithPayload::HEADER_LEN + swim_payload_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{swim_payload::SwimPayload, wormhole::MessageData},
+        primitive_types::U256,
+    };
+
+    #[test]
+    fn test_get_posted_vaa_data_len() {
+        let swim_payload =
+            SwimPayload::new_propeller(&Pubkey::new_unique().to_bytes(), true, 1_000, 2, 10, Some([3u8; 16]));
+        let swim_payload_len = swim_payload.try_to_vec().unwrap().len();
+        let payload = PayloadTransferWithPayload {
+            message_type: 3,
+            amount: U256::from(100u64),
+            token_address: [1u8; 32],
+            token_chain: 1,
+            to: crate::ID.to_bytes(),
+            to_chain: 1,
+            from_address: [2u8; 32],
+            payload: swim_payload,
+        };
+        let message = MessageData {
+            vaa_version: 1,
+            consistency_level: 1,
+            vaa_time: 0,
+            vaa_signature_account: Pubkey::new_unique(),
+            submission_time: 0,
+            nonce: 0,
+            sequence: 7,
+            emitter_chain: 2,
+            emitter_address: [4u8; 32],
+            payload: payload.try_to_vec().unwrap(),
+        };
+        let mut data = vec![];
+        PostedVAAData { message }.try_serialize(&mut data).unwrap();
+        assert_eq!(get_posted_vaa_data_len(swim_payload_len), data.len());
+    }
+}
@@ -0,0 +1,208 @@
+use {
+    crate::{
+        error::PropellerError,
+        token_id_map::{PoolInstruction, PoolStep, TokenIdMap},
+        TOKEN_COUNT,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, TokenAccount},
+    two_pool::state::TwoPool,
+};
+
+/// number of accounts passed for each of the `TokenIdMap.next_steps`
+pub const POOL_STEP_ACCOUNTS_LEN: usize = 8;
+
+/// Accounts of one of the `TokenIdMap.next_steps`. Passed as remaining accounts in this order
+/// (same as the pool & user token accounts of `ProcessSwimPayload`).
+///
+/// The input account of every step must be owned by the redeemer since it receives the output of the previous
+/// step. The output account of the last step is the owner's token account of the target token.
+pub struct PoolStepAccounts<'info> {
+    pub step: PoolStep,
+    pub pool: Box<Account<'info, TwoPool>>,
+    pub pool_token_account_0: Box<Account<'info, TokenAccount>>,
+    pub pool_token_account_1: Box<Account<'info, TokenAccount>>,
+    pub lp_mint: Box<Account<'info, Mint>>,
+    /// CHECK: checked by the two pool program
+    pub governance_fee: AccountInfo<'info>,
+    pub user_token_account_0: Box<Account<'info, TokenAccount>>,
+    pub user_token_account_1: Box<Account<'info, TokenAccount>>,
+    pub user_lp_token_account: Box<Account<'info, TokenAccount>>,
+}
+
+impl<'info> PoolStepAccounts<'info> {
+    pub fn try_from(step: &PoolStep, accounts: &[AccountInfo<'info>]) -> Result<Self> {
+        require_eq!(accounts.len(), POOL_STEP_ACCOUNTS_LEN, PropellerError::InvalidRouteAccounts);
+        let pool = Box::new(Account::<TwoPool>::try_from(&accounts[0])?);
+        require_keys_eq!(pool.key(), step.pool, PropellerError::InvalidRouteAccounts);
+        let pool_token_account_0 = Box::new(Account::<TokenAccount>::try_from(&accounts[1])?);
+        let pool_token_account_1 = Box::new(Account::<TokenAccount>::try_from(&accounts[2])?);
+        require_keys_eq!(pool_token_account_0.key(), pool.token_keys[0], PropellerError::InvalidRouteAccounts);
+        require_keys_eq!(pool_token_account_1.key(), pool.token_keys[1], PropellerError::InvalidRouteAccounts);
+        let lp_mint = Box::new(Account::<Mint>::try_from(&accounts[3])?);
+        require_keys_eq!(lp_mint.key(), pool.lp_mint_key, PropellerError::InvalidRouteAccounts);
+        let user_token_account_0 = Box::new(Account::<TokenAccount>::try_from(&accounts[5])?);
+        let user_token_account_1 = Box::new(Account::<TokenAccount>::try_from(&accounts[6])?);
+        let user_lp_token_account = Box::new(Account::<TokenAccount>::try_from(&accounts[7])?);
+        require_keys_eq!(user_token_account_0.mint, pool.token_mint_keys[0], PropellerError::InvalidRouteAccounts);
+        require_keys_eq!(user_token_account_1.mint, pool.token_mint_keys[1], PropellerError::InvalidRouteAccounts);
+        require_keys_eq!(user_lp_token_account.mint, pool.lp_mint_key, PropellerError::InvalidRouteAccounts);
+        Ok(Self {
+            step: *step,
+            pool,
+            pool_token_account_0,
+            pool_token_account_1,
+            lp_mint,
+            governance_fee: accounts[4].clone(),
+            user_token_account_0,
+            user_token_account_1,
+            user_lp_token_account,
+        })
+    }
+
+    fn user_token_account(&self, index: usize) -> &Account<'info, TokenAccount> {
+        if index == 0 {
+            &self.user_token_account_0
+        } else {
+            &self.user_token_account_1
+        }
+    }
+
+    /// Token account the input of the step is taken from
+    pub fn input_account(&self) -> &Account<'info, TokenAccount> {
+        match self.step.pool_ix {
+            PoolInstruction::RemoveExactBurn => &self.user_lp_token_account,
+            _ => self.user_token_account(self.step.input_token_index()),
+        }
+    }
+
+    /// Token account the output of the step is sent to if it's the last step
+    pub fn output_account(&self) -> &Account<'info, TokenAccount> {
+        self.user_token_account(self.step.pool_token_index as usize)
+    }
+
+    /// Returns the output of the step for `input_amount` based on the current pool balances
+    pub fn calculate_output_amount(&self, input_amount: u64, current_ts: i64) -> Result<u64> {
+        let pool_balances = [self.pool_token_account_0.amount, self.pool_token_account_1.amount];
+        let output_token_index = self.step.pool_token_index as usize;
+        match self.step.pool_ix {
+            PoolInstruction::RemoveExactBurn => Ok(two_pool::instructions::calculate_remove_exact_burn(
+                &self.pool,
+                input_amount,
+                output_token_index,
+                &pool_balances,
+                self.lp_mint.supply,
+                current_ts,
+            )?
+            .0),
+            PoolInstruction::SwapExactInput => {
+                let mut exact_input_amounts = [0u64; TOKEN_COUNT];
+                exact_input_amounts[self.step.input_token_index()] = input_amount;
+                Ok(two_pool::instructions::calculate_swap_exact_input(
+                    &self.pool,
+                    &exact_input_amounts,
+                    output_token_index,
+                    &pool_balances,
+                    self.lp_mint.supply,
+                    current_ts,
+                )?
+                .0)
+            }
+            PoolInstruction::Transfer => err!(PropellerError::InvalidTokenIdMapPoolIx),
+        }
+    }
+
+    /// Executes the step on `input_amount` tokens of the `input_account` & sends the output to `output_account`
+    pub fn execute(
+        &self,
+        input_amount: u64,
+        minimum_output_amount: u64,
+        output_account: &AccountInfo<'info>,
+        user_transfer_authority: &AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+        two_pool_program: &AccountInfo<'info>,
+        token_program: &AccountInfo<'info>,
+    ) -> Result<u64> {
+        let output_token_index = self.step.pool_token_index;
+        let mut user_token_accounts =
+            [self.user_token_account_0.to_account_info(), self.user_token_account_1.to_account_info()];
+        user_token_accounts[output_token_index as usize] = output_account.clone();
+        let [user_token_account_0, user_token_account_1] = user_token_accounts;
+        match self.step.pool_ix {
+            PoolInstruction::RemoveExactBurn => Ok(two_pool::cpi::remove_exact_burn(
+                CpiContext::new_with_signer(
+                    two_pool_program.clone(),
+                    two_pool::cpi::accounts::RemoveExactBurn {
+                        pool: self.pool.to_account_info(),
+                        pool_token_account_0: self.pool_token_account_0.to_account_info(),
+                        pool_token_account_1: self.pool_token_account_1.to_account_info(),
+                        lp_mint: self.lp_mint.to_account_info(),
+                        governance_fee: self.governance_fee.clone(),
+                        user_transfer_authority: user_transfer_authority.clone(),
+                        user_token_account_0,
+                        user_token_account_1,
+                        user_lp_token_account: self.user_lp_token_account.to_account_info(),
+                        token_program: token_program.clone(),
+                    },
+                    signer_seeds,
+                ),
+                input_amount,
+                output_token_index,
+                minimum_output_amount,
+            )?
+            .get()),
+            PoolInstruction::SwapExactInput => {
+                let mut exact_input_amounts = vec![0u64; TOKEN_COUNT];
+                exact_input_amounts[self.step.input_token_index()] = input_amount;
+                Ok(two_pool::cpi::swap_exact_input(
+                    CpiContext::new_with_signer(
+                        two_pool_program.clone(),
+                        two_pool::cpi::accounts::SwapExactInput {
+                            pool: self.pool.to_account_info(),
+                            pool_token_account_0: self.pool_token_account_0.to_account_info(),
+                            pool_token_account_1: self.pool_token_account_1.to_account_info(),
+                            lp_mint: self.lp_mint.to_account_info(),
+                            governance_fee: self.governance_fee.clone(),
+                            user_transfer_authority: user_transfer_authority.clone(),
+                            user_token_account_0,
+                            user_token_account_1,
+                            token_program: token_program.clone(),
+                        },
+                        signer_seeds,
+                    ),
+                    exact_input_amounts,
+                    output_token_index,
+                    minimum_output_amount,
+                )?
+                .get())
+            }
+            PoolInstruction::Transfer => err!(PropellerError::InvalidTokenIdMapPoolIx),
+        }
+    }
+}
+
+/// Loads the accounts of the `next_steps` of `token_id_map` from the start of `remaining_accounts`.
+/// Returns them with the rest of the remaining accounts (i.e. the swim receiver accounts).
+pub fn load_route<'a, 'info>(
+    token_id_map: &TokenIdMap,
+    redeemer: &Pubkey,
+    owner: &Pubkey,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(Vec<PoolStepAccounts<'info>>, &'a [AccountInfo<'info>])> {
+    let route_accounts_len = token_id_map.next_steps.len() * POOL_STEP_ACCOUNTS_LEN;
+    require_gte!(remaining_accounts.len(), route_accounts_len, PropellerError::InvalidRouteAccounts);
+    let (route_accounts, other_accounts) = remaining_accounts.split_at(route_accounts_len);
+    let route = token_id_map
+        .next_steps
+        .iter()
+        .zip(route_accounts.chunks(POOL_STEP_ACCOUNTS_LEN))
+        .map(|(step, accounts)| PoolStepAccounts::try_from(step, accounts))
+        .collect::<Result<Vec<_>>>()?;
+    for step_accounts in &route {
+        require_keys_eq!(step_accounts.input_account().owner, *redeemer, PropellerError::InvalidRouteAccounts);
+    }
+    if let Some(last_step_accounts) = route.last() {
+        require_keys_eq!(last_step_accounts.output_account().owner, *owner, PropellerError::InvalidRouteAccounts);
+    }
+    Ok((route, other_accounts))
+}
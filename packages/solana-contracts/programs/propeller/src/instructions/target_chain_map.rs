@@ -1,5 +1,5 @@
 use {
-    crate::{error::PropellerError, Propeller, TOKEN_COUNT},
+    crate::{error::PropellerError, Propeller, RateLimit, TOKEN_COUNT},
     anchor_lang::prelude::*,
     anchor_spl::{
         associated_token::{create, AssociatedToken, Create},
@@ -42,10 +42,14 @@ pub struct TargetChainMap {
     pub bump: u8,
     pub target_chain: u16,
     pub target_address: [u8; 32],
+    /// flow limit on the swimUSD completed from `target_chain`
+    pub inbound_rate_limit: RateLimit,
+    /// flow limit on the swimUSD transferred to `target_chain`
+    pub outbound_rate_limit: RateLimit,
 }
 
 impl TargetChainMap {
-    pub const LEN: usize = 1 + 2 + 32;
+    pub const LEN: usize = 1 + 2 + 32 + RateLimit::LEN + RateLimit::LEN;
 }
 
 pub fn handle_create_target_chain_map(
@@ -58,6 +62,8 @@ pub fn handle_create_target_chain_map(
     target_chain_map.bump = *bump;
     target_chain_map.target_chain = target_chain;
     target_chain_map.target_address = target_address;
+    target_chain_map.inbound_rate_limit = RateLimit::default();
+    target_chain_map.outbound_rate_limit = RateLimit::default();
     Ok(())
 }
 
@@ -93,6 +99,34 @@ pub fn handle_update_target_chain_map(ctx: Context<UpdateTargetChainMap>, routin
     Ok(())
 }
 
-pub fn handle_close_target_chain_map() -> Result<()> {
-    todo!()
+#[derive(Accounts)]
+pub struct CloseTargetChainMap<'info> {
+    #[account(
+    seeds = [b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
+    bump = propeller.bump,
+    has_one = admin,
+    )]
+    pub propeller: Account<'info, Propeller>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    b"propeller".as_ref(),
+    propeller.key().as_ref(),
+    &target_chain_map.target_chain.to_le_bytes()
+    ],
+    bump = target_chain_map.bump,
+    close = rent_recipient,
+    )]
+    pub target_chain_map: Account<'info, TargetChainMap>,
+
+    #[account(mut)]
+    /// CHECK: only receives the rent of the closed `target_chain_map`
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_target_chain_map(_ctx: Context<CloseTargetChainMap>) -> Result<()> {
+    Ok(())
 }
@@ -33,7 +33,7 @@ pub struct CreateTokenIdMap<'info> {
     bump = pool.bump,
     seeds::program = two_pool_program.key(),
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
 
     #[account(
     init,
@@ -52,6 +52,9 @@ pub struct CreateTokenIdMap<'info> {
     pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
 }
 
+/// max number of pool ixs executed to deliver a target token (i.e. `pool_ix` + `next_steps`)
+pub const MAX_POOL_STEPS: usize = 3;
+
 #[account]
 pub struct TokenIdMap {
     pub output_token_index: u16,
@@ -60,19 +63,64 @@ pub struct TokenIdMap {
     pub pool_token_mint: Pubkey,
     pub pool_ix: PoolInstruction,
     pub bump: u8,
+    /// pool ixs executed in order after `pool_ix`, each one on the output of the previous one.
+    /// the output of the last step is the target token
+    pub next_steps: Vec<PoolStep>,
 }
 
 impl TokenIdMap {
-    pub const LEN: usize = 2 + 32 + 1 + 32 + 1 + 1 + 1;
+    pub const LEN: usize = 2 + 32 + 1 + 32 + 1 + 1 + 1 + 4 + (MAX_POOL_STEPS - 1) * PoolStep::LEN;
+
+    /// Mint of the target token delivered to the owner
+    pub fn output_mint(&self) -> Pubkey {
+        self.next_steps.last().map_or(self.pool_token_mint, |step| step.pool_token_mint)
+    }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum PoolInstruction {
     Transfer,
     RemoveExactBurn,
     SwapExactInput,
 }
 
+/// A `RemoveExactBurn` or `SwapExactInput` on `pool` outputting `pool_token_mint`
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PoolStep {
+    pub pool: Pubkey,
+    pub pool_ix: PoolInstruction,
+    pub pool_token_index: u8,
+    pub pool_token_mint: Pubkey,
+}
+
+impl PoolStep {
+    pub const LEN: usize = 32 + 1 + 1 + 32;
+
+    /// Index of the pool token swapped in by a `SwapExactInput` step
+    pub fn input_token_index(&self) -> usize {
+        TOKEN_COUNT - 1 - self.pool_token_index as usize
+    }
+
+    /// Fails if the step can't be executed on `pool` with `input_mint` tokens
+    pub fn validate(&self, pool: &TwoPool, input_mint: &Pubkey) -> Result<()> {
+        require_eq!(pool.token_count as usize, TOKEN_COUNT, PropellerError::InvalidTokenIdMapPool);
+        let pool_token_index = self.pool_token_index as usize;
+        require_gt!(TOKEN_COUNT, pool_token_index, PropellerError::InvalidTokenIdMapPoolTokenIndex);
+        require_keys_eq!(
+            pool.token_mint_keys[pool_token_index],
+            self.pool_token_mint,
+            PropellerError::InvalidTokenIdMapPoolTokenMint
+        );
+        let expected_input_mint = match self.pool_ix {
+            PoolInstruction::RemoveExactBurn => pool.lp_mint_key,
+            PoolInstruction::SwapExactInput => pool.token_mint_keys[self.input_token_index()],
+            PoolInstruction::Transfer => return err!(PropellerError::InvalidTokenIdMapPoolIx),
+        };
+        require_keys_eq!(expected_input_mint, *input_mint, PropellerError::InvalidTokenIdMapRoute);
+        Ok(())
+    }
+}
+
 impl<'info> CreateTokenIdMap<'info> {
     pub fn accounts(
         ctx: &Context<CreateTokenIdMap>,
@@ -81,30 +129,77 @@ impl<'info> CreateTokenIdMap<'info> {
         pool_token_index: u8,
         pool_token_mint: Pubkey,
         pool_ix: PoolInstruction,
+        next_steps: &[PoolStep],
     ) -> Result<()> {
         //TODO: add error codes
         require_keys_eq!(ctx.accounts.propeller.admin, ctx.accounts.admin.key(), PropellerError::InvalidPropellerAdmin);
-        require_keys_eq!(ctx.accounts.pool.key(), pool, PropellerError::InvalidTokenIdMapPool);
-        if let PoolInstruction::Transfer = pool_ix {
-            require_keys_eq!(
-                ctx.accounts.propeller.swim_usd_mint,
-                pool_token_mint,
-                PropellerError::InvalidTokenIdMapPoolTokenMint
-            );
-            return Ok(());
-        }
-
-        let pool_token_index = pool_token_index as usize;
-        require_gt!(TOKEN_COUNT, pool_token_index, PropellerError::InvalidTokenIdMapPoolTokenIndex);
-        require_keys_eq!(
-            ctx.accounts.pool.token_mint_keys[pool_token_index],
+        validate_token_id_map(
+            &ctx.accounts.propeller,
+            &ctx.accounts.pool,
+            pool,
+            pool_token_index,
             pool_token_mint,
-            PropellerError::InvalidTokenIdMapPoolTokenMint
-        );
-        Ok(())
+            pool_ix,
+        )?;
+        validate_next_steps(pool, pool_token_mint, pool_ix, next_steps, ctx.remaining_accounts)
     }
 }
 
+fn validate_token_id_map(
+    propeller: &Propeller,
+    pool_account: &Account<TwoPool>,
+    pool: Pubkey,
+    pool_token_index: u8,
+    pool_token_mint: Pubkey,
+    pool_ix: PoolInstruction,
+) -> Result<()> {
+    require_keys_eq!(pool_account.key(), pool, PropellerError::InvalidTokenIdMapPool);
+    if let PoolInstruction::Transfer = pool_ix {
+        require_keys_eq!(propeller.swim_usd_mint, pool_token_mint, PropellerError::InvalidTokenIdMapPoolTokenMint);
+        return Ok(());
+    }
+
+    // propeller only passes `TOKEN_COUNT` pool & user token accounts to the pool cpis
+    require_eq!(pool_account.token_count as usize, TOKEN_COUNT, PropellerError::InvalidTokenIdMapPool);
+    let pool_token_index = pool_token_index as usize;
+    require_gt!(TOKEN_COUNT, pool_token_index, PropellerError::InvalidTokenIdMapPoolTokenIndex);
+    require_keys_eq!(
+        pool_account.token_mint_keys[pool_token_index],
+        pool_token_mint,
+        PropellerError::InvalidTokenIdMapPoolTokenMint
+    );
+    Ok(())
+}
+
+/// The pools of `next_steps` are passed (in order) as the remaining accounts.
+/// Every pool of the route must be different since the output of the route is calculated
+/// from the balances of the pools before executing any of the steps
+fn validate_next_steps(
+    pool: Pubkey,
+    pool_token_mint: Pubkey,
+    pool_ix: PoolInstruction,
+    next_steps: &[PoolStep],
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    if next_steps.is_empty() {
+        return Ok(());
+    }
+    require_gt!(MAX_POOL_STEPS, next_steps.len(), PropellerError::InvalidTokenIdMapRoute);
+    require!(pool_ix != PoolInstruction::Transfer, PropellerError::InvalidTokenIdMapRoute);
+    require_eq!(remaining_accounts.len(), next_steps.len(), PropellerError::InvalidTokenIdMapRoute);
+    let mut route_pools = vec![pool];
+    let mut input_mint = pool_token_mint;
+    for (step, pool_account_info) in next_steps.iter().zip(remaining_accounts) {
+        let pool_account = Account::<TwoPool>::try_from(pool_account_info)?;
+        require_keys_eq!(pool_account.key(), step.pool, PropellerError::InvalidTokenIdMapPool);
+        require!(!route_pools.contains(&step.pool), PropellerError::InvalidTokenIdMapRoute);
+        step.validate(&pool_account, &input_mint)?;
+        route_pools.push(step.pool);
+        input_mint = step.pool_token_mint;
+    }
+    Ok(())
+}
+
 pub fn handle_create_token_id_map(
     ctx: Context<CreateTokenIdMap>,
     target_token_index: u16,
@@ -112,6 +207,7 @@ pub fn handle_create_token_id_map(
     pool_token_index: u8,
     pool_token_mint: Pubkey,
     pool_ix: PoolInstruction,
+    next_steps: Vec<PoolStep>,
 ) -> Result<()> {
     let mut token_id_map = &mut ctx.accounts.token_id_map;
     token_id_map.output_token_index = target_token_index;
@@ -120,13 +216,136 @@ pub fn handle_create_token_id_map(
     token_id_map.pool_token_mint = pool_token_mint;
     token_id_map.bump = *ctx.bumps.get("token_id_map").unwrap();
     token_id_map.pool_ix = pool_ix;
+    token_id_map.next_steps = next_steps;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateTokenIdMap<'info> {
+    #[account(
+    seeds = [b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
+    bump = propeller.bump,
+    has_one = admin @ PropellerError::InvalidPropellerAdmin,
+    )]
+    pub propeller: Account<'info, Propeller>,
+
+    pub admin: Signer<'info>,
+
+    // owner & discriminator are checked by `Account`. the pool's seeds aren't re-derived since
+    // the IDL generation can't resolve seeds from the fields of another program's account
+    pub pool: Box<Account<'info, TwoPool>>,
+
+    #[account(
+    mut,
+    seeds = [
+    b"propeller".as_ref(),
+    b"token_id".as_ref(),
+    propeller.key().as_ref(),
+    &token_id_map.output_token_index.to_le_bytes()
+    ],
+    bump = token_id_map.bump,
+    )]
+    pub token_id_map: Account<'info, TokenIdMap>,
+}
+
+impl<'info> UpdateTokenIdMap<'info> {
+    pub fn accounts(
+        ctx: &Context<UpdateTokenIdMap>,
+        pool: Pubkey,
+        pool_token_index: u8,
+        pool_token_mint: Pubkey,
+        pool_ix: PoolInstruction,
+        next_steps: &[PoolStep],
+    ) -> Result<()> {
+        validate_token_id_map(
+            &ctx.accounts.propeller,
+            &ctx.accounts.pool,
+            pool,
+            pool_token_index,
+            pool_token_mint,
+            pool_ix,
+        )?;
+        validate_next_steps(pool, pool_token_mint, pool_ix, next_steps, ctx.remaining_accounts)
+    }
+}
+
+pub fn handle_update_token_id_map(
+    ctx: Context<UpdateTokenIdMap>,
+    pool: Pubkey,
+    pool_token_index: u8,
+    pool_token_mint: Pubkey,
+    pool_ix: PoolInstruction,
+    next_steps: Vec<PoolStep>,
+) -> Result<()> {
+    let token_id_map = &mut ctx.accounts.token_id_map;
+    token_id_map.pool = pool;
+    token_id_map.pool_token_index = pool_token_index;
+    token_id_map.pool_token_mint = pool_token_mint;
+    token_id_map.pool_ix = pool_ix;
+    token_id_map.next_steps = next_steps;
     Ok(())
 }
 
-pub fn handle_update_token_id_map() -> Result<()> {
-    todo!()
+#[derive(Accounts)]
+pub struct CloseTokenIdMap<'info> {
+    #[account(
+    seeds = [b"propeller".as_ref(), propeller.swim_usd_mint.as_ref()],
+    bump = propeller.bump,
+    has_one = admin @ PropellerError::InvalidPropellerAdmin,
+    )]
+    pub propeller: Account<'info, Propeller>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+    mut,
+    seeds = [
+    b"propeller".as_ref(),
+    b"token_id".as_ref(),
+    propeller.key().as_ref(),
+    &token_id_map.output_token_index.to_le_bytes()
+    ],
+    bump = token_id_map.bump,
+    close = rent_recipient,
+    )]
+    pub token_id_map: Account<'info, TokenIdMap>,
+
+    #[account(mut)]
+    /// CHECK: only receives the rent of the closed `token_id_map`
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_token_id_map(_ctx: Context<CloseTokenIdMap>) -> Result<()> {
+    Ok(())
 }
 
-pub fn handle_close_token_id_map() -> Result<()> {
-    todo!()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_id_map_output_mint() {
+        let pool_token_mint = Pubkey::new_unique();
+        let mut token_id_map = TokenIdMap {
+            output_token_index: 1,
+            pool: Pubkey::new_unique(),
+            pool_token_index: 0,
+            pool_token_mint,
+            pool_ix: PoolInstruction::RemoveExactBurn,
+            bump: 255,
+            next_steps: vec![],
+        };
+        assert_eq!(token_id_map.output_mint(), pool_token_mint);
+
+        let step = PoolStep {
+            pool: Pubkey::new_unique(),
+            pool_ix: PoolInstruction::SwapExactInput,
+            pool_token_index: 0,
+            pool_token_mint: Pubkey::new_unique(),
+        };
+        assert_eq!(step.input_token_index(), 1);
+        token_id_map.next_steps = vec![step; MAX_POOL_STEPS - 1];
+        assert_eq!(token_id_map.output_mint(), step.pool_token_mint);
+        assert!(token_id_map.try_to_vec().unwrap().len() <= TokenIdMap::LEN);
+    }
 }
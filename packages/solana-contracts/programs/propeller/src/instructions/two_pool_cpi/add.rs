@@ -1,11 +1,15 @@
 use {
-    crate::{constants::PROPELLER_MINIMUM_OUTPUT_AMOUNT, error::*, Propeller},
+    crate::{
+        constants::{PROPELLER_MINIMUM_OUTPUT_AMOUNT, TOKEN_COUNT},
+        error::*,
+        Propeller,
+    },
     anchor_lang::{prelude::*, solana_program::program::invoke},
     anchor_spl::{
         associated_token::get_associated_token_address,
         token::{Mint, Token, TokenAccount},
     },
-    two_pool::{gen_pool_signer_seeds, program::TwoPool as TwoPoolProgram, state::TwoPool, TOKEN_COUNT},
+    two_pool::{gen_pool_signer_seeds, program::TwoPool as TwoPoolProgram, state::TwoPool},
 };
 
 #[derive(Accounts)]
@@ -27,7 +31,7 @@ pub struct Add<'info> {
     bump = pool.bump,
     seeds::program = two_pool_program.key()
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     // /// TODO: could be removed if initialized with pool_v2
     // /// CHECK: checked in CPI
     // pub pool_auth: UncheckedAccount<'info>,
@@ -84,6 +88,26 @@ impl<'info> Add<'info> {
         require_keys_eq!(ctx.accounts.lp_mint.key(), ctx.accounts.propeller.swim_usd_mint);
         Ok(())
     }
+
+    pub fn add(&self, input_amounts: [u64; TOKEN_COUNT], minimum_mint_amount: u64) -> Result<u64> {
+        let cpi_ctx = CpiContext::new(
+            self.two_pool_program.to_account_info(),
+            two_pool::cpi::accounts::Add {
+                pool: self.pool.to_account_info(),
+                pool_token_account_0: self.pool_token_account_0.to_account_info(),
+                pool_token_account_1: self.pool_token_account_1.to_account_info(),
+                lp_mint: self.lp_mint.to_account_info(),
+                governance_fee: self.governance_fee.to_account_info(),
+                user_transfer_authority: self.user_transfer_authority.to_account_info(),
+                user_token_account_0: self.user_token_account_0.to_account_info(),
+                user_token_account_1: self.user_token_account_1.to_account_info(),
+                user_lp_token_account: self.user_lp_token_account.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        );
+        let result = two_pool::cpi::add(cpi_ctx, input_amounts.to_vec(), minimum_mint_amount)?;
+        Ok(result.get())
+    }
 }
 
 // pub fn handle_add(
@@ -130,45 +154,13 @@ pub fn handle_cross_chain_add(
     input_amounts: [u64; TOKEN_COUNT],
     minimum_mint_amount: u64,
 ) -> Result<u64> {
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.two_pool_program.to_account_info(),
-        two_pool::cpi::accounts::Add {
-            pool: ctx.accounts.pool.to_account_info(),
-            pool_token_account_0: ctx.accounts.pool_token_account_0.to_account_info(),
-            pool_token_account_1: ctx.accounts.pool_token_account_1.to_account_info(),
-            lp_mint: ctx.accounts.lp_mint.to_account_info(),
-            governance_fee: ctx.accounts.governance_fee.to_account_info(),
-            user_transfer_authority: ctx.accounts.user_transfer_authority.to_account_info(),
-            user_token_account_0: ctx.accounts.user_token_account_0.to_account_info(),
-            user_token_account_1: ctx.accounts.user_token_account_1.to_account_info(),
-            user_lp_token_account: ctx.accounts.user_lp_token_account.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        },
-    );
-    let result = two_pool::cpi::add(cpi_ctx, input_amounts, minimum_mint_amount)?;
-    let return_val = result.get();
+    let return_val = ctx.accounts.add(input_amounts, minimum_mint_amount)?;
     anchor_lang::prelude::msg!("cross_chain_add return_val: {:?}", return_val);
     Ok(return_val)
 }
 
 pub fn handle_propeller_add(ctx: Context<Add>, input_amounts: [u64; TOKEN_COUNT], max_fee: u64) -> Result<u64> {
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.two_pool_program.to_account_info(),
-        two_pool::cpi::accounts::Add {
-            pool: ctx.accounts.pool.to_account_info(),
-            pool_token_account_0: ctx.accounts.pool_token_account_0.to_account_info(),
-            pool_token_account_1: ctx.accounts.pool_token_account_1.to_account_info(),
-            lp_mint: ctx.accounts.lp_mint.to_account_info(),
-            governance_fee: ctx.accounts.governance_fee.to_account_info(),
-            user_transfer_authority: ctx.accounts.user_transfer_authority.to_account_info(),
-            user_token_account_0: ctx.accounts.user_token_account_0.to_account_info(),
-            user_token_account_1: ctx.accounts.user_token_account_1.to_account_info(),
-            user_lp_token_account: ctx.accounts.user_lp_token_account.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        },
-    );
-    let result = two_pool::cpi::add(cpi_ctx, input_amounts, PROPELLER_MINIMUM_OUTPUT_AMOUNT)?;
-    let output_amount = result.get();
+    let output_amount = ctx.accounts.add(input_amounts, PROPELLER_MINIMUM_OUTPUT_AMOUNT)?;
     anchor_lang::prelude::msg!("propeller_add output_amount: {:?}", output_amount);
     require_gt!(output_amount, max_fee, PropellerError::InsufficientAmount);
     Ok(output_amount)
@@ -0,0 +1,84 @@
+use {
+    crate::{
+        constants::TOKEN_COUNT, error::*, transfer_native_with_payload::*, two_pool_cpi::add::*, RelayerExclusivity,
+        SwimPayload,
+    },
+    anchor_lang::prelude::*,
+};
+
+/// Adds to the swimUSD pool & bridges the exact amount of swimUSD minted in the same ix
+/// so the user never has to hold the intermediate swimUSD between two transactions.
+///
+/// Note: the nested [`TransferNativeWithPayload`] deserializes `(amount: u64, target_chain: u16)`
+/// from the start of the ix data to derive the `target_chain_map` so the ix args must start with
+/// a `u64` followed by the `target_chain`.
+#[derive(Accounts)]
+pub struct AddAndTransfer<'info> {
+    pub add: Add<'info>,
+    pub transfer: TransferNativeWithPayload<'info>,
+}
+
+impl<'info> AddAndTransfer<'info> {
+    pub fn accounts(ctx: &Context<AddAndTransfer>) -> Result<()> {
+        let add = &ctx.accounts.add;
+        let transfer = &ctx.accounts.transfer;
+        transfer.propeller.require_outbound_not_paused()?;
+        require_keys_eq!(add.propeller.key(), transfer.propeller.key(), PropellerError::InvalidAccount);
+        require_keys_eq!(
+            add.lp_mint.key(),
+            transfer.propeller.swim_usd_mint,
+            PropellerError::InvalidAddAndWormholeTransferMint
+        );
+        require_keys_eq!(
+            add.user_lp_token_account.key(),
+            transfer.user_swim_usd_ata.key(),
+            PropellerError::InvalidAccount
+        );
+        Ok(())
+    }
+}
+
+pub fn handle_cross_chain_add_and_transfer(
+    ctx: Context<AddAndTransfer>,
+    minimum_output_amount: u64,
+    target_chain: u16,
+    input_amounts: [u64; TOKEN_COUNT],
+    owner: Vec<u8>,
+) -> Result<u64> {
+    let output_amount = ctx.accounts.add.add(input_amounts, minimum_output_amount)?;
+    msg!("cross_chain_add_and_transfer output_amount: {:?}", output_amount);
+    let swim_payload = SwimPayload::new_cross_chain(&owner);
+    ctx.accounts.transfer.transfer_with_swim_payload(output_amount, target_chain, &swim_payload)?;
+    Ok(output_amount)
+}
+
+pub fn handle_propeller_add_and_transfer(
+    ctx: Context<AddAndTransfer>,
+    minimum_output_amount: u64,
+    target_chain: u16,
+    input_amounts: [u64; TOKEN_COUNT],
+    owner: Vec<u8>,
+    gas_kickstart: bool,
+    max_fee: u64,
+    target_token_id: u16,
+    target_min_output_amount: u64,
+    memo: Option<[u8; 16]>,
+    receiver_data: Option<Vec<u8>>,
+    relayer_exclusivity: Option<RelayerExclusivity>,
+) -> Result<u64> {
+    let output_amount = ctx.accounts.add.add(input_amounts, minimum_output_amount)?;
+    msg!("propeller_add_and_transfer output_amount: {:?}", output_amount);
+    require_gt!(output_amount, max_fee, PropellerError::InsufficientAmount);
+    let swim_payload = SwimPayload::new_outbound_propeller(
+        &owner,
+        gas_kickstart,
+        max_fee,
+        target_token_id,
+        target_min_output_amount,
+        memo,
+        receiver_data,
+        relayer_exclusivity,
+    )?;
+    ctx.accounts.transfer.transfer_with_swim_payload(output_amount, target_chain, &swim_payload)?;
+    Ok(output_amount)
+}
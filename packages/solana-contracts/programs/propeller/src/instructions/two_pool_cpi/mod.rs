@@ -1,8 +1,10 @@
 pub mod add;
+pub mod add_and_transfer;
 pub mod remove_exact_burn;
 pub mod remove_exact_output;
 pub mod remove_uniform;
 pub mod swap_exact_input;
+pub mod swap_exact_input_and_transfer;
 pub mod swap_exact_output;
 
 pub const TOKEN_COUNT: usize = 2;
@@ -1,8 +1,12 @@
 use {
-    crate::{error::*, Propeller},
+    crate::{
+        constants::{TOKEN_BRIDGE_MINT_OUTPUT_TOKEN_INDEX, TOKEN_COUNT},
+        error::*,
+        Propeller,
+    },
     anchor_lang::{prelude::*, solana_program::program::invoke},
     anchor_spl::token::{Mint, Token, TokenAccount},
-    two_pool::{gen_pool_signer_seeds, program::TwoPool as TwoPoolProgram, state::TwoPool, TOKEN_COUNT},
+    two_pool::{gen_pool_signer_seeds, program::TwoPool as TwoPoolProgram, state::TwoPool},
 };
 
 #[derive(Accounts)]
@@ -27,7 +31,7 @@ pub struct RemoveExactOutput<'info> {
     bump = pool.bump,
     seeds::program = two_pool_program.key()
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     #[account(
     mut,
     token::mint = pool.token_mint_keys[0],
@@ -73,49 +77,50 @@ pub struct RemoveExactOutput<'info> {
     // #[account(mut)]
     // pub payer: Signer<'info>,
     pub token_program: Program<'info, Token>,
-    #[account(executable, address = spl_memo::id())]
-    ///CHECK: memo program
-    pub memo: UncheckedAccount<'info>,
     pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
     pub swim_usd_mint: Account<'info, Mint>,
 }
 
-pub fn handle_remove_exact_output(
+impl<'info> RemoveExactOutput<'info> {
+    fn remove_exact_output(&self, maximum_burn_amount: u64, exact_output_amount: u64) -> Result<u64> {
+        let cpi_ctx = CpiContext::new(
+            self.two_pool_program.to_account_info(),
+            two_pool::cpi::accounts::RemoveExactOutput {
+                pool: self.pool.to_account_info(),
+                pool_token_account_0: self.pool_token_account_0.to_account_info(),
+                pool_token_account_1: self.pool_token_account_1.to_account_info(),
+                lp_mint: self.lp_mint.to_account_info(),
+                governance_fee: self.governance_fee.to_account_info(),
+                user_transfer_authority: self.user_transfer_authority.to_account_info(),
+                user_token_account_0: self.user_token_account_0.to_account_info(),
+                user_token_account_1: self.user_token_account_1.to_account_info(),
+                user_lp_token_account: self.user_lp_token_account.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        );
+        let mut exact_output_amounts = vec![0u64; TOKEN_COUNT];
+        exact_output_amounts[TOKEN_BRIDGE_MINT_OUTPUT_TOKEN_INDEX] = exact_output_amount;
+        let result = two_pool::cpi::remove_exact_output(cpi_ctx, maximum_burn_amount, exact_output_amounts)?;
+        let return_val: Vec<u64> = result.get();
+        anchor_lang::prelude::msg!("remove_exact_output return_val: {:?}", return_val);
+        Ok(return_val[TOKEN_BRIDGE_MINT_OUTPUT_TOKEN_INDEX])
+    }
+}
+
+pub fn handle_cross_chain_remove_exact_output(
     ctx: Context<RemoveExactOutput>,
     maximum_burn_amount: u64,
     exact_output_amount: u64,
-    memo: &[u8],
-    propeller_enabled: bool,
-    target_chain: u16,
-) -> Result<Vec<u64>> {
-    // is_transfer_amount_sufficient(
-    //     &ctx.accounts.propeller,
-    //     &ctx.accounts.token_bridge_mint,
-    //     propeller_enabled,
-    //     target_chain,
-    //     exact_output_amount,
-    // )?;
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.two_pool_program.to_account_info(),
-        two_pool::cpi::accounts::RemoveExactOutput {
-            pool: ctx.accounts.pool.to_account_info(),
-            pool_token_account_0: ctx.accounts.pool_token_account_0.to_account_info(),
-            pool_token_account_1: ctx.accounts.pool_token_account_1.to_account_info(),
-            lp_mint: ctx.accounts.lp_mint.to_account_info(),
-            governance_fee: ctx.accounts.governance_fee.to_account_info(),
-            user_transfer_authority: ctx.accounts.user_transfer_authority.to_account_info(),
-            user_token_account_0: ctx.accounts.user_token_account_0.to_account_info(),
-            user_token_account_1: ctx.accounts.user_token_account_1.to_account_info(),
-            user_lp_token_account: ctx.accounts.user_lp_token_account.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        },
-    );
+) -> Result<u64> {
+    ctx.accounts.remove_exact_output(maximum_burn_amount, exact_output_amount)
+}
 
-    let exact_output_amounts = [exact_output_amount, 0];
-    let result = two_pool::cpi::remove_exact_output(cpi_ctx, maximum_burn_amount, exact_output_amounts)?;
-    let return_val = result.get();
-    let memo_ix = spl_memo::build_memo(memo, &[]);
-    invoke(&memo_ix, &[ctx.accounts.memo.to_account_info()])?;
-    anchor_lang::prelude::msg!("remove_exact_output return_val: {:?}", return_val);
-    Ok(return_val)
+pub fn handle_propeller_remove_exact_output(
+    ctx: Context<RemoveExactOutput>,
+    maximum_burn_amount: u64,
+    exact_output_amount: u64,
+    max_fee: u64,
+) -> Result<u64> {
+    require_gt!(exact_output_amount, max_fee, PropellerError::InsufficientAmount);
+    ctx.accounts.remove_exact_output(maximum_burn_amount, exact_output_amount)
 }
@@ -1,12 +1,25 @@
 use {
-    crate::Propeller,
+    crate::{
+        constants::{PROPELLER_MINIMUM_OUTPUT_AMOUNT, TOKEN_BRIDGE_MINT_OUTPUT_TOKEN_INDEX, TOKEN_COUNT},
+        error::*,
+        Propeller,
+    },
     anchor_lang::{prelude::*, solana_program::program::invoke},
     anchor_spl::token::{Mint, Token, TokenAccount},
-    two_pool::{gen_pool_signer_seeds, program::TwoPool as TwoPoolProgram, state::TwoPool, TOKEN_COUNT},
+    two_pool::{gen_pool_signer_seeds, program::TwoPool as TwoPoolProgram, state::TwoPool},
 };
 
 #[derive(Accounts)]
 pub struct RemoveUniform<'info> {
+    #[account(
+    seeds = [
+    b"propeller".as_ref(),
+    pool_token_account_0.mint.as_ref(),
+    ],
+    bump = propeller.bump,
+    has_one = swim_usd_mint @ PropellerError::InvalidSwimUsdMint,
+    )]
+    pub propeller: Account<'info, Propeller>,
     #[account(
     mut,
     seeds = [
@@ -18,7 +31,7 @@ pub struct RemoveUniform<'info> {
     bump = pool.bump,
     seeds::program = two_pool_program.key()
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     #[account(
     mut,
     token::mint = pool.token_mint_keys[0],
@@ -65,38 +78,50 @@ pub struct RemoveUniform<'info> {
     // #[account(mut)]
     // pub payer: Signer<'info>,
     pub token_program: Program<'info, Token>,
-    #[account(executable, address = spl_memo::id())]
-    ///CHECK: memo program
-    pub memo: UncheckedAccount<'info>,
     pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
+    pub swim_usd_mint: Account<'info, Mint>,
 }
 
-pub fn handle_remove_uniform(
+impl<'info> RemoveUniform<'info> {
+    fn remove_uniform(&self, exact_burn_amount: u64, minimum_output_amounts: [u64; TOKEN_COUNT]) -> Result<Vec<u64>> {
+        let cpi_ctx = CpiContext::new(
+            self.two_pool_program.to_account_info(),
+            two_pool::cpi::accounts::RemoveUniform {
+                pool: self.pool.to_account_info(),
+                pool_token_account_0: self.pool_token_account_0.to_account_info(),
+                pool_token_account_1: self.pool_token_account_1.to_account_info(),
+                lp_mint: self.lp_mint.to_account_info(),
+                governance_fee: self.governance_fee.to_account_info(),
+                user_transfer_authority: self.user_transfer_authority.to_account_info(),
+                user_token_account_0: self.user_token_account_0.to_account_info(),
+                user_token_account_1: self.user_token_account_1.to_account_info(),
+                user_lp_token_account: self.user_lp_token_account.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        );
+        let result = two_pool::cpi::remove_uniform(cpi_ctx, exact_burn_amount, minimum_output_amounts.to_vec())?;
+        let return_val = result.get();
+        anchor_lang::prelude::msg!("remove_uniform return_val: {:?}", return_val);
+        Ok(return_val)
+    }
+}
+
+pub fn handle_cross_chain_remove_uniform(
     ctx: Context<RemoveUniform>,
     exact_burn_amount: u64,
     minimum_output_amounts: [u64; TOKEN_COUNT],
-    memo: &[u8],
 ) -> Result<Vec<u64>> {
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.two_pool_program.to_account_info(),
-        two_pool::cpi::accounts::RemoveUniform {
-            pool: ctx.accounts.pool.to_account_info(),
-            pool_token_account_0: ctx.accounts.pool_token_account_0.to_account_info(),
-            pool_token_account_1: ctx.accounts.pool_token_account_1.to_account_info(),
-            lp_mint: ctx.accounts.lp_mint.to_account_info(),
-            governance_fee: ctx.accounts.governance_fee.to_account_info(),
-            user_transfer_authority: ctx.accounts.user_transfer_authority.to_account_info(),
-            user_token_account_0: ctx.accounts.user_token_account_0.to_account_info(),
-            user_token_account_1: ctx.accounts.user_token_account_1.to_account_info(),
-            user_lp_token_account: ctx.accounts.user_lp_token_account.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        },
-    );
+    ctx.accounts.remove_uniform(exact_burn_amount, minimum_output_amounts)
+}
 
-    let result = two_pool::cpi::remove_uniform(cpi_ctx, exact_burn_amount, minimum_output_amounts)?;
-    let return_val = result.get();
-    let memo_ix = spl_memo::build_memo(memo, &[]);
-    invoke(&memo_ix, &[ctx.accounts.memo.to_account_info()])?;
-    anchor_lang::prelude::msg!("remove_uniform return_val: {:?}", return_val);
-    Ok(return_val)
+/// only the swimUSD output (`TOKEN_BRIDGE_MINT_OUTPUT_TOKEN_INDEX`) is bridged so only it has to cover `max_fee`
+pub fn handle_propeller_remove_uniform(
+    ctx: Context<RemoveUniform>,
+    exact_burn_amount: u64,
+    max_fee: u64,
+) -> Result<Vec<u64>> {
+    let output_amounts =
+        ctx.accounts.remove_uniform(exact_burn_amount, [PROPELLER_MINIMUM_OUTPUT_AMOUNT; TOKEN_COUNT])?;
+    require_gt!(output_amounts[TOKEN_BRIDGE_MINT_OUTPUT_TOKEN_INDEX], max_fee, PropellerError::InsufficientAmount);
+    Ok(output_amounts)
 }
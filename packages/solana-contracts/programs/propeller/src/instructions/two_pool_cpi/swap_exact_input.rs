@@ -1,11 +1,11 @@
 use {
     crate::{
-        constants::{PROPELLER_MINIMUM_OUTPUT_AMOUNT, SWAP_EXACT_INPUT_OUTPUT_TOKEN_INDEX},
+        constants::{PROPELLER_MINIMUM_OUTPUT_AMOUNT, SWAP_EXACT_INPUT_OUTPUT_TOKEN_INDEX, TOKEN_COUNT},
         Propeller, PropellerError,
     },
     anchor_lang::{prelude::*, solana_program::program::invoke},
     anchor_spl::token::{Mint, Token, TokenAccount},
-    two_pool::{gen_pool_signer_seeds, program::TwoPool as TwoPoolProgram, state::TwoPool, TOKEN_COUNT},
+    two_pool::{gen_pool_signer_seeds, program::TwoPool as TwoPoolProgram, state::TwoPool},
 };
 
 #[derive(Accounts)]
@@ -30,7 +30,7 @@ pub struct SwapExactInput<'info> {
     bump = pool.bump,
     seeds::program = two_pool_program.key()
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     #[account(
     mut,
     token::mint = pool.token_mint_keys[0],
@@ -75,6 +75,34 @@ pub struct SwapExactInput<'info> {
     pub swim_usd_mint: Account<'info, Mint>,
 }
 
+impl<'info> SwapExactInput<'info> {
+    pub fn swap_exact_input(&self, exact_input_amount: u64, minimum_output_amount: u64) -> Result<u64> {
+        require_gt!(exact_input_amount, 0, PropellerError::InvalidSwapExactInputInputAmount);
+        let exact_input_amounts = vec![0, exact_input_amount];
+        let cpi_ctx = CpiContext::new(
+            self.two_pool_program.to_account_info(),
+            two_pool::cpi::accounts::SwapExactInput {
+                pool: self.pool.to_account_info(),
+                pool_token_account_0: self.pool_token_account_0.to_account_info(),
+                pool_token_account_1: self.pool_token_account_1.to_account_info(),
+                lp_mint: self.lp_mint.to_account_info(),
+                governance_fee: self.governance_fee.to_account_info(),
+                user_transfer_authority: self.user_transfer_authority.to_account_info(),
+                user_token_account_0: self.user_token_account_0.to_account_info(),
+                user_token_account_1: self.user_token_account_1.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        );
+        let result = two_pool::cpi::swap_exact_input(
+            cpi_ctx,
+            exact_input_amounts,
+            SWAP_EXACT_INPUT_OUTPUT_TOKEN_INDEX,
+            minimum_output_amount,
+        )?;
+        Ok(result.get())
+    }
+}
+
 // pub fn handle_swap_exact_input(
 //     ctx: Context<SwapExactInput>,
 //     exact_input_amount: u64,
@@ -127,30 +155,7 @@ pub fn handle_cross_chain_swap_exact_input(
     exact_input_amount: u64,
     minimum_output_amount: u64,
 ) -> Result<u64> {
-    require_gt!(exact_input_amount, 0, PropellerError::InvalidSwapExactInputInputAmount);
-    let exact_input_amounts = [0, exact_input_amount];
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.two_pool_program.to_account_info(),
-        two_pool::cpi::accounts::SwapExactInput {
-            pool: ctx.accounts.pool.to_account_info(),
-            pool_token_account_0: ctx.accounts.pool_token_account_0.to_account_info(),
-            pool_token_account_1: ctx.accounts.pool_token_account_1.to_account_info(),
-            lp_mint: ctx.accounts.lp_mint.to_account_info(),
-            governance_fee: ctx.accounts.governance_fee.to_account_info(),
-            user_transfer_authority: ctx.accounts.user_transfer_authority.to_account_info(),
-            user_token_account_0: ctx.accounts.user_token_account_0.to_account_info(),
-            user_token_account_1: ctx.accounts.user_token_account_1.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        },
-    );
-
-    let result = two_pool::cpi::swap_exact_input(
-        cpi_ctx,
-        exact_input_amounts,
-        SWAP_EXACT_INPUT_OUTPUT_TOKEN_INDEX,
-        minimum_output_amount,
-    )?;
-    let return_val = result.get();
+    let return_val = ctx.accounts.swap_exact_input(exact_input_amount, minimum_output_amount)?;
     anchor_lang::prelude::msg!("swap_exact_input return_val: {:?}", return_val);
     Ok(return_val)
 }
@@ -160,30 +165,7 @@ pub fn handle_propeller_swap_exact_input(
     exact_input_amount: u64,
     max_fee: u64,
 ) -> Result<u64> {
-    require_gt!(exact_input_amount, 0, PropellerError::InvalidSwapExactInputInputAmount);
-    let exact_input_amounts = [0, exact_input_amount];
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.two_pool_program.to_account_info(),
-        two_pool::cpi::accounts::SwapExactInput {
-            pool: ctx.accounts.pool.to_account_info(),
-            pool_token_account_0: ctx.accounts.pool_token_account_0.to_account_info(),
-            pool_token_account_1: ctx.accounts.pool_token_account_1.to_account_info(),
-            lp_mint: ctx.accounts.lp_mint.to_account_info(),
-            governance_fee: ctx.accounts.governance_fee.to_account_info(),
-            user_transfer_authority: ctx.accounts.user_transfer_authority.to_account_info(),
-            user_token_account_0: ctx.accounts.user_token_account_0.to_account_info(),
-            user_token_account_1: ctx.accounts.user_token_account_1.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-        },
-    );
-
-    let result = two_pool::cpi::swap_exact_input(
-        cpi_ctx,
-        exact_input_amounts,
-        SWAP_EXACT_INPUT_OUTPUT_TOKEN_INDEX,
-        PROPELLER_MINIMUM_OUTPUT_AMOUNT,
-    )?;
-    let output_amount = result.get();
+    let output_amount = ctx.accounts.swap_exact_input(exact_input_amount, PROPELLER_MINIMUM_OUTPUT_AMOUNT)?;
     anchor_lang::prelude::msg!("swap_exact_input return_val: {:?}", output_amount);
     require_gt!(output_amount, max_fee, PropellerError::InsufficientAmount);
     Ok(output_amount)
@@ -0,0 +1,78 @@
+use {
+    crate::{
+        error::*, transfer_native_with_payload::*, two_pool_cpi::swap_exact_input::*, RelayerExclusivity, SwimPayload,
+    },
+    anchor_lang::prelude::*,
+};
+
+/// Swaps into swimUSD using the metapool & bridges the exact swimUSD output in the same ix.
+///
+/// Note: the nested [`TransferNativeWithPayload`] deserializes `(amount: u64, target_chain: u16)`
+/// from the start of the ix data to derive the `target_chain_map` so the ix args must start with
+/// a `u64` followed by the `target_chain`.
+#[derive(Accounts)]
+pub struct SwapExactInputAndTransfer<'info> {
+    pub swap: SwapExactInput<'info>,
+    pub transfer: TransferNativeWithPayload<'info>,
+}
+
+impl<'info> SwapExactInputAndTransfer<'info> {
+    pub fn accounts(ctx: &Context<SwapExactInputAndTransfer>) -> Result<()> {
+        let swap = &ctx.accounts.swap;
+        let transfer = &ctx.accounts.transfer;
+        transfer.propeller.require_outbound_not_paused()?;
+        require_keys_eq!(swap.propeller.key(), transfer.propeller.key(), PropellerError::InvalidAccount);
+        // swimUSD is always token 0 of the metapool
+        require_keys_eq!(
+            swap.user_token_account_0.key(),
+            transfer.user_swim_usd_ata.key(),
+            PropellerError::InvalidAccount
+        );
+        Ok(())
+    }
+}
+
+pub fn handle_cross_chain_swap_exact_input_and_transfer(
+    ctx: Context<SwapExactInputAndTransfer>,
+    minimum_output_amount: u64,
+    target_chain: u16,
+    exact_input_amount: u64,
+    owner: Vec<u8>,
+) -> Result<u64> {
+    let output_amount = ctx.accounts.swap.swap_exact_input(exact_input_amount, minimum_output_amount)?;
+    msg!("cross_chain_swap_exact_input_and_transfer output_amount: {:?}", output_amount);
+    let swim_payload = SwimPayload::new_cross_chain(&owner);
+    ctx.accounts.transfer.transfer_with_swim_payload(output_amount, target_chain, &swim_payload)?;
+    Ok(output_amount)
+}
+
+pub fn handle_propeller_swap_exact_input_and_transfer(
+    ctx: Context<SwapExactInputAndTransfer>,
+    minimum_output_amount: u64,
+    target_chain: u16,
+    exact_input_amount: u64,
+    owner: Vec<u8>,
+    gas_kickstart: bool,
+    max_fee: u64,
+    target_token_id: u16,
+    target_min_output_amount: u64,
+    memo: Option<[u8; 16]>,
+    receiver_data: Option<Vec<u8>>,
+    relayer_exclusivity: Option<RelayerExclusivity>,
+) -> Result<u64> {
+    let output_amount = ctx.accounts.swap.swap_exact_input(exact_input_amount, minimum_output_amount)?;
+    msg!("propeller_swap_exact_input_and_transfer output_amount: {:?}", output_amount);
+    require_gt!(output_amount, max_fee, PropellerError::InsufficientAmount);
+    let swim_payload = SwimPayload::new_outbound_propeller(
+        &owner,
+        gas_kickstart,
+        max_fee,
+        target_token_id,
+        target_min_output_amount,
+        memo,
+        receiver_data,
+        relayer_exclusivity,
+    )?;
+    ctx.accounts.transfer.transfer_with_swim_payload(output_amount, target_chain, &swim_payload)?;
+    Ok(output_amount)
+}
@@ -1,36 +1,42 @@
 use {
-    crate::{constants::LAMPORTS_PER_SOL_DECIMAL, error::*, Propeller, TOKEN_COUNT},
+    crate::{
+        constants::LAMPORTS_PER_SOL_DECIMAL,
+        error::*,
+        oracle::{get_sol_usd_price, OracleAccounts},
+        Propeller, TOKEN_COUNT,
+    },
     anchor_lang::prelude::*,
     anchor_spl::token::{Mint, TokenAccount},
-    num_traits::{FromPrimitive, ToPrimitive},
+    num_traits::FromPrimitive,
     rust_decimal::Decimal,
-    switchboard_v2::AggregatorAccountData,
     two_pool::{state::TwoPool, BorshDecimal},
 };
 
+/// Returns the spot marginal prices of the pool or, if `twap_window` is non-zero,
+/// the time weighted average marginal prices over the last `twap_window` seconds.
 pub fn get_marginal_prices<'info>(
     cpi_ctx: CpiContext<'_, '_, '_, 'info, two_pool::cpi::accounts::MarginalPrices<'info>>,
-) -> Result<[BorshDecimal; TOKEN_COUNT]> {
-    let result = two_pool::cpi::marginal_prices(cpi_ctx)?;
+    twap_window: u32,
+) -> Result<Vec<BorshDecimal>> {
+    let result = if twap_window > 0 {
+        two_pool::cpi::observe(cpi_ctx, i64::from(twap_window))?
+    } else {
+        two_pool::cpi::marginal_prices(cpi_ctx)?
+    };
     Ok(result.get())
 }
 
-pub fn convert_fees_to_swim_usd_atomic<'info>(
-    fee_in_lamports: u64,
+/// Returns the amount of swimUSD (in atomic units) a lamport is worth based on the propeller's
+/// oracles & the marginal price pool. Cached in the `FeePriceCache` by `refresh_fee_price_cache`.
+pub fn get_swim_usd_atomic_per_lamport<'info>(
     propeller: &Propeller,
     marginal_price_pool_lp_mint: &Account<'info, Mint>,
     cpi_ctx: CpiContext<'_, '_, '_, 'info, two_pool::cpi::accounts::MarginalPrices<'info>>,
     marginal_price_pool: &TwoPool,
-    aggregator: &AccountLoader<AggregatorAccountData>,
-    max_staleness: i64,
-) -> Result<u64> {
-    // let propeller = &self.propeller;
-
-    msg!("fee_in_lamports: {:?}", fee_in_lamports);
-    let marginal_price_pool_lp_mint = &marginal_price_pool_lp_mint;
-
+    oracles: &OracleAccounts,
+) -> Result<Decimal> {
     let swim_usd_mint_key = propeller.swim_usd_mint;
-    let marginal_prices = get_marginal_prices(cpi_ctx)?;
+    let marginal_prices = get_marginal_prices(cpi_ctx, propeller.marginal_price_twap_window)?;
 
     let intermediate_token_price_decimal: Decimal = get_marginal_price_decimal(
         &marginal_price_pool,
@@ -38,19 +44,9 @@ pub fn convert_fees_to_swim_usd_atomic<'info>(
         &propeller,
         &marginal_price_pool_lp_mint.key(),
     )?;
-
     msg!("intermediate_token_price_decimal: {:?}", intermediate_token_price_decimal);
 
-    let fee_in_lamports_decimal = Decimal::from_u64(fee_in_lamports).ok_or(PropellerError::ConversionError)?;
-    msg!("fee_in_lamports(u64): {:?} fee_in_lamports_decimal: {:?}", fee_in_lamports, fee_in_lamports_decimal);
-
-    let mut res = 0u64;
-
-    let lamports_intermediate_token_price = get_lamports_intermediate_token_price(&aggregator, max_staleness)?;
-    let fee_in_swim_usd_decimal = lamports_intermediate_token_price
-        .checked_mul(fee_in_lamports_decimal)
-        .and_then(|x| x.checked_div(intermediate_token_price_decimal))
-        .ok_or(PropellerError::IntegerOverflow)?;
+    let lamports_intermediate_token_price = get_lamports_intermediate_token_price(oracles, propeller)?;
 
     let swim_usd_decimals =
         get_swim_usd_mint_decimals(&swim_usd_mint_key, &marginal_price_pool, &marginal_price_pool_lp_mint)?;
@@ -58,14 +54,12 @@ pub fn convert_fees_to_swim_usd_atomic<'info>(
 
     let ten_pow_decimals =
         Decimal::from_u64(10u64.pow(swim_usd_decimals as u32)).ok_or(PropellerError::IntegerOverflow)?;
-    let fee_in_swim_usd_atomic = fee_in_swim_usd_decimal
-        .checked_mul(ten_pow_decimals)
-        .and_then(|v| v.to_u64())
-        .ok_or(PropellerError::ConversionError)?;
-
-    msg!("fee_in_swim_usd_decimal: {:?} fee_in_swim_usd_atomic: {:?}", fee_in_swim_usd_decimal, fee_in_swim_usd_atomic);
-    res = fee_in_swim_usd_atomic;
-    Ok(res)
+    let swim_usd_atomic_per_lamport = lamports_intermediate_token_price
+        .checked_div(intermediate_token_price_decimal)
+        .and_then(|x| x.checked_mul(ten_pow_decimals))
+        .ok_or(PropellerError::IntegerOverflow)?;
+    msg!("swim_usd_atomic_per_lamport: {:?}", swim_usd_atomic_per_lamport);
+    Ok(swim_usd_atomic_per_lamport)
 }
 
 pub fn get_swim_usd_mint_decimals(
@@ -87,7 +81,7 @@ pub fn get_swim_usd_mint_decimals(
 
 pub fn get_marginal_price_decimal(
     marginal_price_pool: &TwoPool,
-    marginal_prices: &[BorshDecimal; TOKEN_COUNT],
+    marginal_prices: &[BorshDecimal],
     propeller: &Propeller,
     marginal_price_pool_lp_mint: &Pubkey,
 ) -> Result<Decimal> {
@@ -110,30 +104,12 @@ pub fn get_marginal_price_decimal(
     Ok(marginal_price)
 }
 
-pub fn get_lamports_intermediate_token_price(
-    aggregator: &AccountLoader<AggregatorAccountData>,
-    max_staleness: i64,
-) -> Result<Decimal> {
-    let feed = aggregator.load()?;
-    feed.check_staleness(
-        Clock::get().unwrap().unix_timestamp,
-        // 300
-        i64::MAX,
-    )
-    .map_err(|_| error!(PropellerError::StaleFeed))?;
-
-    // check feed does not exceed max_confidence_interval
-    // if let Some(max_confidence_interval) = params.max_confidence_interval {
-    // 	feed.check_confidence_interval(SwitchboardDecimal::from_f64(max_confidence_interval))
-    // 	.map_err(|_| error!(PropellerError::ConfidenceIntervalExceeded))?;
-    // }
-
-    let sol_usd_price: Decimal = feed.get_result()?.try_into()?;
-    let name = feed.name;
+/// Returns the SOL/USD price of the propeller's oracles (see `get_sol_usd_price`) in USD per lamport.
+pub fn get_lamports_intermediate_token_price(oracles: &OracleAccounts, propeller: &Propeller) -> Result<Decimal> {
+    let sol_usd_price = get_sol_usd_price(propeller, oracles, Clock::get()?.unix_timestamp)?;
 
     let lamports_usd_price =
         sol_usd_price.checked_div(LAMPORTS_PER_SOL_DECIMAL).ok_or(PropellerError::IntegerOverflow)?;
     msg!("sol_usd_price:{},lamports_usd_price: {}", sol_usd_price, lamports_usd_price);
     Ok(lamports_usd_price)
-    // check whether the feed has been updated in the last 300 seconds
 }
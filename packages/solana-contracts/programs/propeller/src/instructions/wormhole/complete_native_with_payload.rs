@@ -1,12 +1,15 @@
 use {
     crate::{
-        constants::LAMPORTS_PER_SOL_DECIMAL, deserialize_message_payload, error::*,
-        get_lamports_intermediate_token_price, get_marginal_price_decimal, get_marginal_prices, get_message_data,
-        get_swim_usd_mint_decimals, get_transfer_with_payload_from_message_account, hash_vaa,
-        instructions::fee_tracker::FeeTracker, state::SwimPayloadMessage, validate_marginal_prices_pool_accounts,
+        constants::LAMPORTS_PER_SOL_DECIMAL,
+        deserialize_message_payload,
+        error::*,
+        events::{CompleteNativeWithPayloadEvent, SwimPayloadHeldEvent},
+        get_message_data, get_transfer_with_payload_from_message_account, hash_vaa,
+        instructions::{fee_price_cache::FeePriceCache, fee_tracker::FeeTracker, target_chain_map::TargetChainMap},
+        state::SwimPayloadMessage,
         Address, ChainID, ClaimData, MessageData, PayloadTransferWithPayload, PostVAAData, PostedMessageData,
-        PostedVAAData, Propeller, RawSwimPayload, TokenBridge, Wormhole, COMPLETE_NATIVE_WITH_PAYLOAD_INSTRUCTION,
-        TOKEN_COUNT,
+        PostedVAAData, Propeller, SwimPayload, TokenBridge, Wormhole, COMPLETE_NATIVE_WITH_PAYLOAD_INSTRUCTION,
+        MAX_RECEIVER_DATA_LEN, TOKEN_COUNT,
     },
     anchor_lang::{
         prelude::*,
@@ -17,9 +20,7 @@ use {
         token::{Mint, Token, TokenAccount, Transfer},
     },
     byteorder::{BigEndian, ReadBytesExt, WriteBytesExt},
-    num_traits::{FromPrimitive, ToPrimitive},
     primitive_types::U256,
-    rust_decimal::Decimal,
     solana_program::program::invoke,
     switchboard_v2::{AggregatorAccountData, SwitchboardDecimal, SWITCHBOARD_PROGRAM_ID},
     two_pool::{state::TwoPool, BorshDecimal},
@@ -29,6 +30,7 @@ use {
 // #[instruction(vaa: PostVAAData)]
 pub struct CompleteNativeWithPayload<'info> {
     #[account(
+    mut,
     seeds = [ b"propeller".as_ref(), propeller.swim_usd_mint.as_ref() ],
     bump = propeller.bump,
     has_one = swim_usd_mint @ PropellerError::InvalidSwimUsdMint,
@@ -170,10 +172,23 @@ pub struct CompleteNativeWithPayload<'info> {
     space = 8 + SwimPayloadMessage::LEN,
     )]
     pub swim_payload_message: Account<'info, SwimPayloadMessage>,
+
+    #[account(
+    mut,
+    seeds = [
+    b"propeller".as_ref(),
+    propeller.key().as_ref(),
+    &source_chain_map.target_chain.to_le_bytes()
+    ],
+    bump = source_chain_map.bump,
+    )]
+    /// `TargetChainMap` of the chain the transfer was sent from. Holds the chain's inbound rate limit
+    pub source_chain_map: Box<Account<'info, TargetChainMap>>,
 }
 
 impl<'info> CompleteNativeWithPayload<'info> {
     pub fn accounts(ctx: &Context<CompleteNativeWithPayload>) -> Result<()> {
+        ctx.accounts.propeller.require_inbound_not_paused()?;
         require!(Self::redeemer_check(ctx), PropellerError::UserRedeemerSignatureNotDetected);
 
         Ok(())
@@ -256,13 +271,40 @@ impl<'info> CompleteNativeWithPayload<'info> {
         Ok(transfer_with_payload)
     }
 
+    /// Records the transfer against the global & source chain inbound rate limits.
+    /// Returns the unix timestamp until which the swim payload is held (0 if it isn't held)
+    fn record_inbound_transfer(&mut self, emitter_chain: u16, transfer_amount: u64, current_ts: i64) -> Result<i64> {
+        require_eq!(self.source_chain_map.target_chain, emitter_chain, PropellerError::InvalidTargetChainMap);
+        let release_ts = self.propeller.record_inbound_transfer(
+            &mut self.source_chain_map.inbound_rate_limit,
+            transfer_amount,
+            current_ts,
+        )?;
+        if release_ts > 0 {
+            emit!(SwimPayloadHeldEvent {
+                propeller: self.propeller.key(),
+                swim_payload_message: self.swim_payload_message.key(),
+                vaa_emitter_chain: emitter_chain,
+                transfer_amount,
+                release_ts,
+            });
+        }
+        Ok(release_ts)
+    }
+
     fn write_swim_payload_message(
         &mut self,
         bump: u8,
         message_data: &MessageData,
         transfer_amount: u64,
-        swim_payload: &RawSwimPayload,
+        sender: Address,
+        swim_payload: &SwimPayload,
     ) -> Result<()> {
+        if let Some(receiver_data) = swim_payload.receiver_data() {
+            require_gte!(MAX_RECEIVER_DATA_LEN, receiver_data.len(), PropellerError::SwimReceiverDataTooLong);
+        }
+        let current_ts = Clock::get()?.unix_timestamp;
+        let release_ts = self.record_inbound_transfer(message_data.emitter_chain, transfer_amount, current_ts)?;
         let swim_payload_message = &mut self.swim_payload_message;
         swim_payload_message.bump = bump;
         swim_payload_message.claim = self.claim.key();
@@ -273,12 +315,30 @@ impl<'info> CompleteNativeWithPayload<'info> {
         swim_payload_message.vaa_emitter_chain = message_data.emitter_chain;
         swim_payload_message.vaa_sequence = message_data.sequence;
         swim_payload_message.transfer_amount = transfer_amount;
-        swim_payload_message.swim_payload_version = swim_payload.swim_payload_version;
-        swim_payload_message.target_token_id = swim_payload.target_token_id;
+        swim_payload_message.swim_payload_version = swim_payload.version as u8;
+        swim_payload_message.target_token_id = swim_payload.target_token_id();
         swim_payload_message.owner = Pubkey::new_from_array(swim_payload.owner);
-        swim_payload_message.memo = swim_payload.memo;
-        swim_payload_message.propeller_enabled = swim_payload.propeller_enabled;
-        swim_payload_message.gas_kickstart = swim_payload.gas_kickstart;
+        swim_payload_message.memo = swim_payload.memo().unwrap_or_default();
+        swim_payload_message.propeller_enabled = swim_payload.propeller_enabled();
+        swim_payload_message.gas_kickstart = swim_payload.gas_kickstart();
+        swim_payload_message.max_fee = swim_payload.max_fee();
+        swim_payload_message.min_output_amount = swim_payload.min_output_amount();
+        swim_payload_message.completed_ts = current_ts;
+        swim_payload_message.total_fees = 0;
+        swim_payload_message.sender = sender;
+        swim_payload_message.receiver_data = swim_payload.receiver_data().cloned();
+        match swim_payload.relayer_exclusivity() {
+            Some(relayer_exclusivity) => {
+                swim_payload_message.exclusive_relayer = Pubkey::new_from_array(relayer_exclusivity.relayer);
+                swim_payload_message.exclusivity_deadline =
+                    message_data.vaa_time as i64 + relayer_exclusivity.duration as i64;
+            }
+            None => {
+                swim_payload_message.exclusive_relayer = Pubkey::default();
+                swim_payload_message.exclusivity_deadline = 0;
+            }
+        }
+        swim_payload_message.release_ts = release_ts;
         Ok(())
     }
 }
@@ -314,7 +374,24 @@ pub fn handle_complete_native_with_payload(ctx: Context<CompleteNativeWithPayloa
     }
 
     let bump = *ctx.bumps.get("swim_payload_message").unwrap();
-    ctx.accounts.write_swim_payload_message(bump, &message_data, transfer_amount, swim_payload)?;
+    ctx.accounts.write_swim_payload_message(
+        bump,
+        &message_data,
+        transfer_amount,
+        transfer_with_payload.from_address,
+        swim_payload,
+    )?;
+    emit!(CompleteNativeWithPayloadEvent {
+        propeller: ctx.accounts.propeller.key(),
+        payer: ctx.accounts.payer.key(),
+        claim: ctx.accounts.claim.key(),
+        swim_payload_message: ctx.accounts.swim_payload_message.key(),
+        owner: Pubkey::new_from_array(swim_payload.owner),
+        vaa_emitter_chain: message_data.emitter_chain,
+        vaa_sequence: message_data.sequence,
+        fees: 0,
+        transfer_amount,
+    });
 
     // let memo = swim_payload.memo;
     // // get target_token_id -> (pool, pool_token_index)
@@ -343,27 +420,14 @@ pub struct PropellerCompleteNativeWithPayload<'info> {
     pub fee_tracker: Account<'info, FeeTracker>,
 
     #[account(
-    constraint =
-    *aggregator.to_account_info().owner == SWITCHBOARD_PROGRAM_ID @ PropellerError::InvalidSwitchboardAccount
-    )]
-    pub aggregator: AccountLoader<'info, AggregatorAccountData>,
-    // pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
-    #[account(
-    mut,
     seeds = [
-    b"two_pool".as_ref(),
-    marginal_price_pool_token_0_account.mint.as_ref(),
-    marginal_price_pool_token_1_account.mint.as_ref(),
-    marginal_price_pool_lp_mint.key().as_ref(),
+    b"propeller".as_ref(),
+    b"fee_price_cache".as_ref(),
+    complete_native_with_payload.propeller.key().as_ref()
     ],
-    bump = marginal_price_pool.bump,
-    seeds::program = two_pool_program.key()
+    bump = fee_price_cache.bump
     )]
-    pub marginal_price_pool: Box<Account<'info, TwoPool>>,
-    pub marginal_price_pool_token_0_account: Box<Account<'info, TokenAccount>>,
-    pub marginal_price_pool_token_1_account: Box<Account<'info, TokenAccount>>,
-    pub marginal_price_pool_lp_mint: Box<Account<'info, Mint>>,
-    pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
+    pub fee_price_cache: Account<'info, FeePriceCache>,
     #[account(executable, address = spl_memo::id())]
     ///CHECK: memo program
     pub memo: UncheckedAccount<'info>,
@@ -372,17 +436,9 @@ pub struct PropellerCompleteNativeWithPayload<'info> {
 impl<'info> PropellerCompleteNativeWithPayload<'info> {
     pub fn accounts(ctx: &Context<PropellerCompleteNativeWithPayload>) -> Result<()> {
         let propeller = &ctx.accounts.complete_native_with_payload.propeller;
-        validate_marginal_prices_pool_accounts(
-            &propeller,
-            &ctx.accounts.marginal_price_pool.key(),
-            &[
-                ctx.accounts.marginal_price_pool_token_0_account.mint,
-                ctx.accounts.marginal_price_pool_token_1_account.mint,
-            ],
-        )?;
+        propeller.require_inbound_not_paused()?;
         require_keys_eq!(ctx.accounts.complete_native_with_payload.fee_recipient.key(), propeller.fee_vault);
         require_keys_eq!(ctx.accounts.complete_native_with_payload.fee_recipient.owner, propeller.key());
-        require_keys_eq!(ctx.accounts.aggregator.key(), propeller.aggregator, PropellerError::InvalidAggregator);
         Ok(())
     }
 
@@ -424,51 +480,11 @@ impl<'info> PropellerCompleteNativeWithPayload<'info> {
             fee_in_lamports
         );
 
-        let marginal_prices = get_marginal_prices(self.into_marginal_prices())?;
-
-        let intermediate_token_price_decimal: Decimal = get_marginal_price_decimal(
-            &self.marginal_price_pool,
-            &marginal_prices,
-            &propeller,
-            // propeller.marginal_price_pool_token_index as usize,
-            &self.marginal_price_pool_lp_mint.key(),
-            // &token_bridge_mint_key,
-        )?;
-
-        msg!("intermediate_token_price_decimal: {:?}", intermediate_token_price_decimal);
-
-        //swimUSD is lp token of marginal price pool
-        let mut res = 0u64;
-
-        let fee_in_lamports_decimal = Decimal::from_u64(fee_in_lamports).ok_or(PropellerError::ConversionError)?;
-        msg!("fee_in_lamports(u64): {:?} fee_in_lamports_decimal: {:?}", fee_in_lamports, fee_in_lamports_decimal);
-        let lamports_intermediate_token_price = get_lamports_intermediate_token_price(&self.aggregator, i64::MAX)?;
-        let fee_in_swim_usd_decimal = lamports_intermediate_token_price
-            .checked_mul(fee_in_lamports_decimal)
-            .and_then(|x| x.checked_div(intermediate_token_price_decimal))
-            .ok_or(PropellerError::IntegerOverflow)?;
-
-        let swim_usd_mint_key = self.complete_native_with_payload.propeller.swim_usd_mint;
-
-        let swim_usd_mint_decimals = get_swim_usd_mint_decimals(
-            &swim_usd_mint_key,
-            &self.marginal_price_pool,
-            &self.marginal_price_pool_lp_mint,
-        )?;
-        //TODO: good lord forgive me for this terrible naming. i will fix later.
-        let ten_pow_decimals =
-            Decimal::from_u64(10u64.pow(swim_usd_mint_decimals as u32)).ok_or(PropellerError::IntegerOverflow)?;
-        let fee_in_swim_usd_atomic = fee_in_swim_usd_decimal
-            .checked_mul(ten_pow_decimals)
-            .and_then(|v| v.to_u64())
-            .ok_or(PropellerError::ConversionError)?;
-        msg!(
-            "fee_in_swim_usd_decimal: {:?} fee_in_swim_usd_atomic: {:?}",
-            fee_in_swim_usd_decimal,
-            fee_in_swim_usd_atomic
-        );
-        res = fee_in_swim_usd_atomic;
-        Ok(res)
+        self.fee_price_cache.convert_fees_to_swim_usd_atomic(
+            fee_in_lamports,
+            propeller.fee_price_cache_max_age,
+            Clock::get()?.unix_timestamp,
+        )
     }
 
     fn handle_fees(&mut self, fees_in_token_bridge_mint: u64) -> Result<()> {
@@ -491,17 +507,6 @@ impl<'info> PropellerCompleteNativeWithPayload<'info> {
             fees_in_token_bridge_mint,
         )
     }
-
-    fn into_marginal_prices(&self) -> CpiContext<'_, '_, '_, 'info, two_pool::cpi::accounts::MarginalPrices<'info>> {
-        let program = self.two_pool_program.to_account_info();
-        let accounts = two_pool::cpi::accounts::MarginalPrices {
-            pool: self.marginal_price_pool.to_account_info(),
-            pool_token_account_0: self.marginal_price_pool_token_0_account.to_account_info(),
-            pool_token_account_1: self.marginal_price_pool_token_1_account.to_account_info(),
-            lp_mint: self.marginal_price_pool_lp_mint.to_account_info(),
-        };
-        CpiContext::new(program, accounts)
-    }
 }
 
 pub fn handle_propeller_complete_native_with_payload(ctx: Context<PropellerCompleteNativeWithPayload>) -> Result<()> {
@@ -513,7 +518,7 @@ pub fn handle_propeller_complete_native_with_payload(ctx: Context<PropellerCompl
     msg!("transfer_with_payload: {:?}", transfer_with_payload);
     let swim_payload = &transfer_with_payload.payload;
     msg!("swim_payload: {:?}", swim_payload);
-    require!(swim_payload.propeller_enabled, PropellerError::NotPropellerEnabled);
+    require!(swim_payload.propeller_enabled(), PropellerError::NotPropellerEnabled);
     let claim_data = ClaimData::try_from_slice(&mut ctx.accounts.complete_native_with_payload.claim.data.borrow())
         .map_err(|_| error!(PropellerError::InvalidClaimData))?;
     msg!("claim_data: {:?}", claim_data);
@@ -534,9 +539,11 @@ pub fn handle_propeller_complete_native_with_payload(ctx: Context<PropellerCompl
     // TODO: if swim payload owner calling though they will need a fee tracker account already.
     let swim_payload_owner = Pubkey::new_from_array(swim_payload.owner);
     let token_program = &ctx.accounts.complete_native_with_payload.token_program;
+    let mut fees = 0u64;
     if swim_payload_owner != ctx.accounts.complete_native_with_payload.payer.key() {
         let fees_in_token_bridge_mint = ctx.accounts.calculate_fees()?;
         ctx.accounts.handle_fees(fees_in_token_bridge_mint)?;
+        fees = fees_in_token_bridge_mint;
 
         msg!("propeller_complete_native_with_payload fees(swimUSD): {:?}", fees_in_token_bridge_mint);
         transfer_amount =
@@ -549,9 +556,26 @@ pub fn handle_propeller_complete_native_with_payload(ctx: Context<PropellerCompl
         bump,
         &message_data,
         transfer_amount,
+        transfer_with_payload.from_address,
         &swim_payload,
     )?;
-    let memo = swim_payload.memo;
+    let complete_native_with_payload = &mut ctx.accounts.complete_native_with_payload;
+    complete_native_with_payload
+        .swim_payload_message
+        .require_relayer(&complete_native_with_payload.payer.key(), Clock::get()?.unix_timestamp)?;
+    complete_native_with_payload.swim_payload_message.add_fees(fees)?;
+    emit!(CompleteNativeWithPayloadEvent {
+        propeller: ctx.accounts.complete_native_with_payload.propeller.key(),
+        payer: ctx.accounts.complete_native_with_payload.payer.key(),
+        claim: ctx.accounts.complete_native_with_payload.claim.key(),
+        swim_payload_message: ctx.accounts.complete_native_with_payload.swim_payload_message.key(),
+        owner: swim_payload_owner,
+        vaa_emitter_chain: message_data.emitter_chain,
+        vaa_sequence: message_data.sequence,
+        fees,
+        transfer_amount,
+    });
+    let memo = swim_payload.memo().unwrap_or_default();
     if memo != [0u8; 16] {
         let memo_ix = spl_memo::build_memo(std::str::from_utf8(hex::encode(memo).as_bytes()).unwrap().as_ref(), &[]);
         invoke(&memo_ix, &[ctx.accounts.memo.to_account_info()])?;
@@ -1,7 +1,7 @@
 use {
     crate::{
-        constants::CURRENT_SWIM_PAYLOAD_VERSION, error::*, target_chain_map::TargetChainMap, Propeller, RawSwimPayload,
-        TokenBridge, Wormhole, TOKEN_COUNT, TRANSFER_NATIVE_WITH_PAYLOAD_INSTRUCTION,
+        error::*, events::TransferNativeWithPayloadEvent, target_chain_map::TargetChainMap, Propeller,
+        RelayerExclusivity, SwimPayload, TokenBridge, Wormhole, TOKEN_COUNT, TRANSFER_NATIVE_WITH_PAYLOAD_INSTRUCTION,
     },
     anchor_lang::{
         prelude::*,
@@ -184,6 +184,7 @@ pub struct TransferNativeWithPayload<'info> {
     pub token_program: Program<'info, Token>,
 
     #[account(
+    mut,
     seeds = [
     b"propeller".as_ref(),
     propeller.key().as_ref(),
@@ -202,6 +203,7 @@ impl<'info> TransferNativeWithPayload<'info> {
     //Note: some of the checks are excessive (checked in CPI etc) and add.rs to compute budget but since we now have access to requesting
     //  up to 1.4M compute budget per transaction, better safe than sorry to perform them.
     pub fn accounts(ctx: &Context<TransferNativeWithPayload>) -> Result<()> {
+        ctx.accounts.propeller.require_outbound_not_paused()?;
         require_keys_eq!(
             ctx.accounts.swim_usd_mint.key(),
             ctx.accounts.propeller.swim_usd_mint,
@@ -271,6 +273,88 @@ impl<'info> TransferNativeWithPayload<'info> {
         Ok(())
     }
 
+    /// Approves the token bridge to transfer `amount` swimUSD from `user_swim_usd_ata`,
+    /// transfers it with `swim_payload` to the routing contract of `target_chain` & revokes the approval.
+    /// Fails if the transfer would exceed the global or the `target_chain` outbound rate limit.
+    pub fn transfer_with_swim_payload(
+        &mut self,
+        amount: u64,
+        target_chain: u16,
+        swim_payload: &SwimPayload,
+    ) -> Result<()> {
+        self.propeller.record_outbound_transfer(
+            &mut self.target_chain_map.outbound_rate_limit,
+            amount,
+            self.clock.unix_timestamp,
+        )?;
+        token::approve(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Approve {
+                    // source
+                    to: self.user_swim_usd_ata.to_account_info(),
+                    delegate: self.authority_signer.to_account_info(),
+                    authority: self.payer.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        msg!("finished approve for authority_signer");
+        msg!("transfer_native_with_payload swim_payload: {:?}", swim_payload);
+        // Note:
+        //     1. nonce is created randomly client side using this
+        //         export function createNonce() {
+        //              const nonceConst = Math.random() * 100000;
+        //              const nonceBuffer = Buffer.alloc(4);
+        //              nonceBuffer.writeUInt32LE(nonceConst, 0);
+        //              return nonceBuffer;
+        //          }
+        //     2. fee is relayerFee
+        //         a. removed in payload3
+        //     3. targetAddress is Uint8Array (on wasm.rs its Vec<u8>
+        //         a. WH client has special handling/formatting for this
+        //             see - wh-sdk/src/utils/array.ts tryNativeToUint8Array(address: string, chain: ChainId | ChainName)
+        //     4. targetChain is number/u16
+        //     5. payload is Vec<u8>
+        let target_address = self.target_chain_map.target_address.clone();
+        let transfer_with_payload_data = TransferWithPayloadData {
+            //TODO: update this.
+            nonce: self.propeller.nonce,
+            amount,
+            target_address,
+            target_chain,
+            payload: swim_payload.try_to_vec()?,
+            //note - if this field is missing then ctx.accounts.sender is used as the vaa.from
+            cpi_program_id: Some(crate::ID),
+        };
+        self.invoke_transfer_native_with_payload(transfer_with_payload_data)?;
+
+        token::revoke(CpiContext::new(
+            self.token_program.to_account_info(),
+            token::Revoke {
+                // source
+                source: self.user_swim_usd_ata.to_account_info(),
+                authority: self.payer.to_account_info(),
+            },
+        ))?;
+        msg!("Revoked authority_signer approval");
+        emit!(TransferNativeWithPayloadEvent {
+            propeller: self.propeller.key(),
+            payer: self.payer.key(),
+            nonce: self.propeller.nonce,
+            amount,
+            target_chain,
+            owner: swim_payload.owner,
+            propeller_enabled: swim_payload.propeller_enabled(),
+            gas_kickstart: swim_payload.gas_kickstart(),
+            max_fee: swim_payload.max_fee(),
+            target_token_id: swim_payload.target_token_id(),
+            min_output_amount: swim_payload.min_output_amount(),
+            memo: swim_payload.memo(),
+        });
+        self.increment_nonce()
+    }
+
     pub fn increment_nonce(&mut self) -> Result<()> {
         let propeller = &mut self.propeller;
         propeller.nonce = propeller.nonce.wrapping_add(1);
@@ -296,83 +380,8 @@ pub fn handle_cross_chain_transfer_native_with_payload(
     owner: Vec<u8>,
 ) -> Result<()> {
     msg!("transfer_native_with_payload");
-    token::approve(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Approve {
-                // source
-                to: ctx.accounts.user_swim_usd_ata.to_account_info(),
-                delegate: ctx.accounts.authority_signer.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-            },
-        ),
-        amount,
-    )?;
-    msg!("finished approve for authority_signer");
-    // let mut target_token_addr = [0u8; 32];
-    // target_token_addr.copy_from_slice(target_token.as_slice());
-    let mut owner_addr = [0u8; 32];
-    owner_addr.copy_from_slice(owner.as_slice());
-
-    let swim_payload =
-        SwimPayload { swim_payload_version: CURRENT_SWIM_PAYLOAD_VERSION, owner: owner_addr, ..Default::default() };
-    // let swim_payload = RawSwimPayload {
-    //     //TODO: this should come from the propeller or global constant?
-    //     swim_payload_version: CURRENT_SWIM_PAYLOAD_VERSION,
-    //     owner: owner_addr,
-    //     propeller_enabled,
-    //     gas_kickstart,
-    //     max_fee,
-    //     target_token_id,
-    //     // min_output_amount: U256::from(0u64),
-    //     memo: memo.clone().try_into().unwrap(),
-    // };
-    msg!("transfer_native_with_payload swim_payload: {:?}", swim_payload);
-    // let mut swim_payload_bytes = [0u8; 32];
-    // let swim_payload_bytes = swim_payload.try_to_vec()?;
-    // anchor_lang::prelude::msg!("swim_payload_bytes {:?}", swim_payload_bytes);
-    //
-    // Note:
-    //     1. nonce is created randomly client side using this
-    //         export function createNonce() {
-    //              const nonceConst = Math.random() * 100000;
-    //              const nonceBuffer = Buffer.alloc(4);
-    //              nonceBuffer.writeUInt32LE(nonceConst, 0);
-    //              return nonceBuffer;
-    //          }
-    //     2. fee is relayerFee
-    //         a. removed in payload3
-    //     3. targetAddress is Uint8Array (on wasm.rs its Vec<u8>
-    //         a. WH client has special handling/formatting for this
-    //             see - wh-sdk/src/utils/array.ts tryNativeToUint8Array(address: string, chain: ChainId | ChainName)
-    //     4. targetChain is number/u16
-    //     5. payload is Vec<u8>
-    // ok
-
-    let target_address = ctx.accounts.target_chain_map.target_address.clone();
-    let transfer_with_payload_data = TransferWithPayloadData {
-        //TODO: update this.
-        nonce: ctx.accounts.propeller.nonce,
-        amount,
-        target_address,
-        target_chain,
-        payload: swim_payload.try_to_vec()?,
-        //note - if this field is missing then ctx.accounts.sender is used as the vaa.from
-        cpi_program_id: Some(crate::ID),
-    };
-    ctx.accounts.invoke_transfer_native_with_payload(transfer_with_payload_data)?;
-
-    token::revoke(CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        token::Revoke {
-            // source
-            source: ctx.accounts.user_swim_usd_ata.to_account_info(),
-            authority: ctx.accounts.payer.to_account_info(),
-        },
-    ))?;
-    msg!("Revoked authority_signer approval");
-    ctx.accounts.increment_nonce()?;
-    Ok(())
+    let swim_payload = SwimPayload::new_cross_chain(&owner);
+    ctx.accounts.transfer_with_swim_payload(amount, target_chain, &swim_payload)
 }
 
 pub fn handle_propeller_transfer_native_with_payload(
@@ -383,97 +392,22 @@ pub fn handle_propeller_transfer_native_with_payload(
     gas_kickstart: bool,
     max_fee: u64,
     target_token_id: u16,
+    min_output_amount: u64,
     memo: Option<[u8; 16]>,
+    receiver_data: Option<Vec<u8>>,
+    relayer_exclusivity: Option<RelayerExclusivity>,
 ) -> Result<()> {
     require_gt!(amount, max_fee, PropellerError::InsufficientAmount);
     msg!("transfer_native_with_payload");
-    token::approve(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Approve {
-                // source
-                to: ctx.accounts.user_swim_usd_ata.to_account_info(),
-                delegate: ctx.accounts.authority_signer.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-            },
-        ),
-        amount,
-    )?;
-    msg!("finished approve for authority_signer");
-    // let mut target_token_addr = [0u8; 32];
-    // target_token_addr.copy_from_slice(target_token.as_slice());
-    let mut owner_addr = [0u8; 32];
-    owner_addr.copy_from_slice(owner.as_slice());
-
-    let swim_payload = SwimPayload {
-        swim_payload_version: CURRENT_SWIM_PAYLOAD_VERSION,
-        owner: owner_addr,
-        propeller_enabled: Some(true),
-        gas_kickstart: Some(gas_kickstart),
-        max_fee: Some(max_fee),
-        target_token_id: Some(target_token_id),
+    let swim_payload = SwimPayload::new_outbound_propeller(
+        &owner,
+        gas_kickstart,
+        max_fee,
+        target_token_id,
+        min_output_amount,
         memo,
-    };
-    msg!("transfer_native_with_payload swim_payload: {:?}", swim_payload);
-
-    let target_address = ctx.accounts.target_chain_map.target_address.clone();
-    let transfer_with_payload_data = TransferWithPayloadData {
-        //TODO: update this.
-        nonce: ctx.accounts.propeller.nonce,
-        amount,
-        target_address,
-        target_chain,
-        payload: swim_payload.try_to_vec()?,
-        //note - if this field is missing then ctx.accounts.sender is used as the vaa.from
-        cpi_program_id: Some(crate::ID),
-    };
-
-    ctx.accounts.invoke_transfer_native_with_payload(transfer_with_payload_data)?;
-
-    token::revoke(CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        token::Revoke {
-            // source
-            source: ctx.accounts.user_swim_usd_ata.to_account_info(),
-            authority: ctx.accounts.payer.to_account_info(),
-        },
-    ))?;
-    msg!("Revoked authority_signer approval");
-    ctx.accounts.increment_nonce()?;
-    Ok(())
-}
-
-#[derive(PartialEq, Debug, Clone, AnchorDeserialize, Default)]
-pub struct SwimPayload {
-    //TOOD: should this come from propeller?
-    //required
-    pub swim_payload_version: u8,
-    pub owner: [u8; 32],
-    // required for all propellerEngines
-    pub propeller_enabled: Option<bool>,
-    pub gas_kickstart: Option<bool>,
-    pub max_fee: Option<u64>,
-    pub target_token_id: Option<u16>,
-    // required for SWIM propellerEngine
-    pub memo: Option<[u8; 16]>,
-}
-
-impl AnchorSerialize for SwimPayload {
-    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        // Payload ID
-        // writer.write_u8(self.swim_payload_version)?;
-        writer.write_u8(self.swim_payload_version)?;
-        writer.write_all(&self.owner)?;
-
-        if self.propeller_enabled.is_some() {
-            writer.write_u8(1)?;
-            writer.write_u8(self.gas_kickstart.unwrap() as u8)?;
-            writer.write_u64::<BigEndian>(self.max_fee.unwrap())?;
-            writer.write_u16::<BigEndian>(self.target_token_id.unwrap())?;
-            if self.memo.is_some() {
-                writer.write_all(&self.memo.unwrap())?;
-            }
-        }
-        Ok(())
-    }
+        receiver_data,
+        relayer_exclusivity,
+    )?;
+    ctx.accounts.transfer_with_swim_payload(amount, target_chain, &swim_payload)
 }
@@ -8,8 +8,8 @@ use anchor_lang::solana_program::{
 };
 use {
     crate::two_pool_cpi::{
-        add::*, remove_exact_burn::*, remove_exact_output::*, remove_uniform::*, swap_exact_input::*,
-        swap_exact_output::*,
+        add::*, add_and_transfer::*, remove_exact_burn::*, remove_exact_output::*, remove_uniform::*,
+        swap_exact_input::*, swap_exact_input_and_transfer::*, swap_exact_output::*,
     },
     anchor_lang::{prelude::*, solana_program},
     // crate::two_pool_cpi::*,
@@ -23,6 +23,8 @@ use {
     // instructions::*,
     solana_program::clock::Epoch,
     state::*,
+    swim_payload::*,
+    swim_receiver::*,
     token_bridge::*,
     two_pool::instructions::AddParams,
     wormhole::*,
@@ -30,13 +32,17 @@ use {
 
 mod constants;
 mod error;
+mod events;
 mod instructions;
+mod oracle;
 mod state;
+mod swim_payload;
+mod swim_receiver;
 mod token_bridge;
 mod wormhole;
 
-use two_pool::state::TwoPool;
-pub use {error::*, instructions::*};
+use two_pool::{state::TwoPool, BorshDecimal};
+pub use {error::*, events::*, instructions::*};
 
 declare_id!("9z6G41AyXk73r1E4nTv81drQPtEqupCSAnsLdGV5WGfK");
 
@@ -59,6 +65,7 @@ pub mod propeller {
         pool_token_index,
         pool_token_mint,
         pool_ix,
+        &next_steps,
     ))]
     pub fn create_token_id_map(
         ctx: Context<CreateTokenIdMap>,
@@ -67,8 +74,42 @@ pub mod propeller {
         pool_token_index: u8,
         pool_token_mint: Pubkey,
         pool_ix: PoolInstruction,
+        next_steps: Vec<PoolStep>,
     ) -> Result<()> {
-        handle_create_token_id_map(ctx, target_token_index, pool, pool_token_index, pool_token_mint, pool_ix)
+        handle_create_token_id_map(
+            ctx,
+            target_token_index,
+            pool,
+            pool_token_index,
+            pool_token_mint,
+            pool_ix,
+            next_steps,
+        )
+    }
+
+    #[inline(never)]
+    #[access_control(
+      UpdateTokenIdMap::accounts(
+        &ctx,
+        pool,
+        pool_token_index,
+        pool_token_mint,
+        pool_ix,
+        &next_steps,
+    ))]
+    pub fn update_token_id_map(
+        ctx: Context<UpdateTokenIdMap>,
+        pool: Pubkey,
+        pool_token_index: u8,
+        pool_token_mint: Pubkey,
+        pool_ix: PoolInstruction,
+        next_steps: Vec<PoolStep>,
+    ) -> Result<()> {
+        handle_update_token_id_map(ctx, pool, pool_token_index, pool_token_mint, pool_ix, next_steps)
+    }
+
+    pub fn close_token_id_map(ctx: Context<CloseTokenIdMap>) -> Result<()> {
+        handle_close_token_id_map(ctx)
     }
 
     pub fn create_target_chain_map(
@@ -83,6 +124,107 @@ pub mod propeller {
         handle_update_target_chain_map(ctx, routing_contract)
     }
 
+    pub fn close_target_chain_map(ctx: Context<CloseTargetChainMap>) -> Result<()> {
+        handle_close_target_chain_map(ctx)
+    }
+
+    /** Admin Ixs **/
+    pub fn prepare_admin_transition(ctx: Context<PrepareAdminTransition>, upcoming_admin: Pubkey) -> Result<()> {
+        handle_prepare_admin_transition(ctx, upcoming_admin)
+    }
+
+    pub fn enact_admin_transition(ctx: Context<EnactAdminTransition>) -> Result<()> {
+        handle_enact_admin_transition(ctx)
+    }
+
+    #[access_control(SetPaused::accounts(&ctx))]
+    pub fn set_paused(ctx: Context<SetPaused>, direction: PauseDirection, paused: bool) -> Result<()> {
+        handle_set_paused(ctx, direction, paused)
+    }
+
+    pub fn change_pause_key(ctx: Context<ChangePauseKey>, new_pause_key: Pubkey) -> Result<()> {
+        handle_change_pause_key(ctx, new_pause_key)
+    }
+
+    pub fn update_gas_kickstart_amount(
+        ctx: Context<UpdateGasKickstartAmount>,
+        gas_kickstart_amount: u64,
+    ) -> Result<()> {
+        handle_update_gas_kickstart_amount(ctx, gas_kickstart_amount)
+    }
+
+    pub fn update_swim_payload_expiry(ctx: Context<UpdateSwimPayloadExpiry>, swim_payload_expiry: i64) -> Result<()> {
+        handle_update_swim_payload_expiry(ctx, swim_payload_expiry)
+    }
+
+    pub fn update_fee_price_cache_max_age(
+        ctx: Context<UpdateFeePriceCacheMaxAge>,
+        fee_price_cache_max_age: i64,
+    ) -> Result<()> {
+        handle_update_fee_price_cache_max_age(ctx, fee_price_cache_max_age)
+    }
+
+    pub fn update_fees(ctx: Context<UpdateFees>, params: UpdateFeesParams) -> Result<()> {
+        handle_update_fees(ctx, params)
+    }
+
+    #[access_control(UpdateMarginalPricePool::accounts(&ctx, marginal_price_pool_token_index))]
+    pub fn update_marginal_price_pool(
+        ctx: Context<UpdateMarginalPricePool>,
+        marginal_price_pool_token_index: u8,
+        marginal_price_twap_window: u32,
+    ) -> Result<()> {
+        handle_update_marginal_price_pool(ctx, marginal_price_pool_token_index, marginal_price_twap_window)
+    }
+
+    pub fn update_fee_vault(ctx: Context<UpdateFeeVault>) -> Result<()> {
+        handle_update_fee_vault(ctx)
+    }
+
+    pub fn update_aggregator(ctx: Context<UpdateAggregator>) -> Result<()> {
+        handle_update_aggregator(ctx)
+    }
+
+    pub fn update_aggregator_config(
+        ctx: Context<UpdateAggregatorConfig>,
+        max_staleness: i64,
+        max_confidence_interval: BorshDecimal,
+    ) -> Result<()> {
+        handle_update_aggregator_config(ctx, max_staleness, max_confidence_interval)
+    }
+
+    #[access_control(UpdateOracleConfig::accounts(&ctx, &params))]
+    pub fn update_oracle_config(ctx: Context<UpdateOracleConfig>, params: UpdateOracleConfigParams) -> Result<()> {
+        handle_update_oracle_config(ctx, params)
+    }
+
+    #[access_control(UpdateRateLimits::accounts(&ctx, &params))]
+    pub fn update_rate_limits(ctx: Context<UpdateRateLimits>, params: UpdateRateLimitsParams) -> Result<()> {
+        handle_update_rate_limits(ctx, params)
+    }
+
+    #[access_control(UpdateTargetChainRateLimits::accounts(&ctx, &inbound, &outbound))]
+    pub fn update_target_chain_rate_limits(
+        ctx: Context<UpdateTargetChainRateLimits>,
+        inbound: RateLimitConfig,
+        outbound: RateLimitConfig,
+    ) -> Result<()> {
+        handle_update_target_chain_rate_limits(ctx, inbound, outbound)
+    }
+
+    pub fn release_swim_payload(ctx: Context<ReleaseSwimPayload>) -> Result<()> {
+        handle_release_swim_payload(ctx)
+    }
+
+    pub fn initialize_fee_price_cache(ctx: Context<InitializeFeePriceCache>) -> Result<()> {
+        handle_initialize_fee_price_cache(ctx)
+    }
+
+    #[access_control(RefreshFeePriceCache::accounts(&ctx))]
+    pub fn refresh_fee_price_cache(ctx: Context<RefreshFeePriceCache>) -> Result<()> {
+        handle_refresh_fee_price_cache(ctx)
+    }
+
     #[inline(never)]
     pub fn initialize_fee_tracker(ctx: Context<InitializeFeeTracker>) -> Result<()> {
         handle_initialize_fee_tracker(ctx)
@@ -156,123 +298,72 @@ pub mod propeller {
         handle_propeller_swap_exact_input(ctx, exact_input_amount, max_fee)
     }
 
-    /*
-    // pub fn swap_exact_output(
-    //     ctx: Context<SwapExactOutput>,
-    //     maximum_input_amount: u64,
-    //     exact_output_amount: u64, // params: SwapExactOutputParams,
-    //     memo: Vec<u8>,
-    //     propeller_enabled: bool,
-    //     target_chain: u16,
-    // ) -> Result<Vec<u64>> {
-    //     handle_swap_exact_output(
-    //         ctx,
-    //         maximum_input_amount,
-    //         exact_output_amount,
-    //         memo.as_slice(),
-    //         propeller_enabled,
-    //         target_chain,
-    //     )
-    // }
+    pub fn cross_chain_swap_exact_output(
+        ctx: Context<SwapExactOutput>,
+        maximum_input_amount: u64,
+        exact_output_amount: u64,
+    ) -> Result<u64> {
+        handle_cross_chain_swap_exact_output(ctx, maximum_input_amount, exact_output_amount)
+    }
 
-    // pub fn cross_chain_swap_exact_output(
-    //     ctx: Context<SwapExactOutput>,
-    //     maximum_input_amount: u64,
-    //     exact_output_amount: u64, // params: SwapExactOutputParams,
-    //     memo: Vec<u8>,
-    // ) -> Result<Vec<u64>> {
-    //     handle_cross_chain_swap_exact_output(
-    //         ctx,
-    //         maximum_input_amount,
-    //         exact_output_amount,
-    //         memo.as_slice(),
-    //         propeller_enabled,
-    //         target_chain,
-    //     )
-    // }
-    //
-    // pub fn propeller_swap_exact_output(
-    //     ctx: Context<SwapExactOutput>,
-    //     maximum_input_amount: u64,
-    //     memo: Vec<u8>,
-    //     max_fee: u64,
-    // ) -> Result<Vec<u64>> {
-    //     handle_propeller_swap_exact_output(
-    //         ctx,
-    //         maximum_input_amount,
-    //         exact_output_amount,
-    //         memo.as_slice(),
-    //         propeller_enabled,
-    //         target_chain,
-    //     )
-    // }
+    pub fn propeller_swap_exact_output(
+        ctx: Context<SwapExactOutput>,
+        maximum_input_amount: u64,
+        exact_output_amount: u64,
+        max_fee: u64,
+    ) -> Result<u64> {
+        handle_propeller_swap_exact_output(ctx, maximum_input_amount, exact_output_amount, max_fee)
+    }
 
-    // pub fn remove_uniform(
-    //     ctx: Context<RemoveUniform>,
-    //     exact_burn_amount: u64,
-    //     minimum_output_amounts: [u64; TOKEN_COUNT],
-    //     memo: Vec<u8>,
-    // ) -> Result<Vec<u64>> {
-    //     handle_remove_uniform(ctx, exact_burn_amount, minimum_output_amounts, memo.as_slice())
-    // }
+    pub fn cross_chain_remove_uniform(
+        ctx: Context<RemoveUniform>,
+        exact_burn_amount: u64,
+        minimum_output_amounts: [u64; TOKEN_COUNT],
+    ) -> Result<Vec<u64>> {
+        handle_cross_chain_remove_uniform(ctx, exact_burn_amount, minimum_output_amounts)
+    }
 
-    // pub fn remove_exact_burn(
-    //     ctx: Context<RemoveExactBurn>,
-    //     exact_burn_amount: u64,
-    //     minimum_output_amount: u64,
-    //     memo: Vec<u8>,
-    //     propeller_enabled: bool,
-    //     target_chain: u16,
-    // ) -> Result<u64> {
-    //     handle_remove_exact_burn(
-    //         ctx,
-    //         exact_burn_amount,
-    //         minimum_output_amount,
-    //         memo.as_slice(),
-    //         propeller_enabled,
-    //         target_chain,
-    //     )
-    // }
-     */
+    pub fn propeller_remove_uniform(
+        ctx: Context<RemoveUniform>,
+        exact_burn_amount: u64,
+        max_fee: u64,
+    ) -> Result<Vec<u64>> {
+        handle_propeller_remove_uniform(ctx, exact_burn_amount, max_fee)
+    }
 
-    //TODO: does remove_exact_burn make sense for metapools?
-    // burn metapool lp token to get token_bridge_mint
+    // burn metapool lp token to get swimUSD
+    pub fn cross_chain_remove_exact_burn(
+        ctx: Context<RemoveExactBurn>,
+        exact_burn_amount: u64,
+        minimum_output_amount: u64,
+    ) -> Result<u64> {
+        handle_cross_chain_remove_exact_burn(ctx, exact_burn_amount, minimum_output_amount)
+    }
 
-    // pub fn cross_chain_remove_exact_burn(
-    //     ctx: Context<RemoveExactBurn>,
-    //     exact_burn_amount: u64,
-    //     minimum_output_amount: u64,
-    //     memo: Vec<u8>,
-    // ) -> Result<u64> {
-    //     handle_cross_chain_remove_exact_burn(ctx, exact_burn_amount, minimum_output_amount, memo.as_slice())
-    // }
-    //
-    // pub fn propeller_remove_exact_burn(
-    //     ctx: Context<RemoveExactBurn>,
-    //     exact_burn_amount: u64,
-    //     memo: Vec<u8>,
-    //     max_fee: u64,
-    // ) -> Result<u64> {
-    //     handle_propeller_remove_exact_burn(ctx, exact_burn_amount, memo.as_slice(), max_fee)
-    // }
+    pub fn propeller_remove_exact_burn(
+        ctx: Context<RemoveExactBurn>,
+        exact_burn_amount: u64,
+        max_fee: u64,
+    ) -> Result<u64> {
+        handle_propeller_remove_exact_burn(ctx, exact_burn_amount, max_fee)
+    }
 
-    // pub fn remove_exact_output(
-    //     ctx: Context<RemoveExactOutput>,
-    //     maximum_burn_amount: u64,
-    //     exact_output_amount: u64,
-    //     memo: Vec<u8>,
-    //     propeller_enabled: bool,
-    //     target_chain: u16,
-    // ) -> Result<Vec<u64>> {
-    //     handle_remove_exact_output(
-    //         ctx,
-    //         maximum_burn_amount,
-    //         exact_output_amount,
-    //         memo.as_slice(),
-    //         propeller_enabled,
-    //         target_chain,
-    //     )
-    // }
+    pub fn cross_chain_remove_exact_output(
+        ctx: Context<RemoveExactOutput>,
+        maximum_burn_amount: u64,
+        exact_output_amount: u64,
+    ) -> Result<u64> {
+        handle_cross_chain_remove_exact_output(ctx, maximum_burn_amount, exact_output_amount)
+    }
+
+    pub fn propeller_remove_exact_output(
+        ctx: Context<RemoveExactOutput>,
+        maximum_burn_amount: u64,
+        exact_output_amount: u64,
+        max_fee: u64,
+    ) -> Result<u64> {
+        handle_propeller_remove_exact_output(ctx, maximum_burn_amount, exact_output_amount, max_fee)
+    }
 
     #[inline(never)]
     #[access_control(TransferNativeWithPayload::accounts(&ctx))]
@@ -296,7 +387,10 @@ pub mod propeller {
         gas_kickstart: bool,
         max_fee: u64,
         target_token_id: u16,
+        min_output_amount: u64,
         memo: Option<[u8; 16]>,
+        receiver_data: Option<Vec<u8>>,
+        relayer_exclusivity: Option<RelayerExclusivity>,
     ) -> Result<()> {
         handle_propeller_transfer_native_with_payload(
             ctx,
@@ -306,7 +400,10 @@ pub mod propeller {
             gas_kickstart,
             max_fee,
             target_token_id,
+            min_output_amount,
             memo,
+            receiver_data,
+            relayer_exclusivity,
         )
     }
 
@@ -318,8 +415,8 @@ pub mod propeller {
 
     #[inline(never)]
     #[access_control(ProcessSwimPayload::accounts(&ctx))]
-    pub fn process_swim_payload(
-        ctx: Context<ProcessSwimPayload>,
+    pub fn process_swim_payload<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProcessSwimPayload<'info>>,
         target_token_id: u16,
         min_output_amount: u64,
     ) -> Result<u64> {
@@ -335,7 +432,9 @@ pub mod propeller {
     /** Valid target_token_id **/
     #[inline(never)]
     #[access_control(PropellerCreateOwnerTokenAccounts::accounts(&ctx))]
-    pub fn propeller_create_owner_token_accounts(ctx: Context<PropellerCreateOwnerTokenAccounts>) -> Result<()> {
+    pub fn propeller_create_owner_token_accounts<'info>(
+        ctx: Context<'_, '_, '_, 'info, PropellerCreateOwnerTokenAccounts<'info>>,
+    ) -> Result<()> {
         handle_propeller_create_owner_token_accounts(ctx)
     }
 
@@ -343,8 +442,8 @@ pub mod propeller {
     /// for propeller_process_swim_payload, require_eq!(target_token_id, propeller_message.target_token_id);
     #[inline(never)]
     #[access_control(PropellerProcessSwimPayload::accounts(&ctx, target_token_id))]
-    pub fn propeller_process_swim_payload(
-        ctx: Context<PropellerProcessSwimPayload>,
+    pub fn propeller_process_swim_payload<'info>(
+        ctx: Context<'_, '_, '_, 'info, PropellerProcessSwimPayload<'info>>,
         target_token_id: u16,
     ) -> Result<u64> {
         handle_propeller_process_swim_payload(ctx, target_token_id)
@@ -363,37 +462,128 @@ pub mod propeller {
     #[access_control(PropellerProcessSwimPayloadFallback::accounts(&ctx))]
     /// This ix is used if a propeller engine detects (off-chain) that the payload.target_token_id is not valid
     /// this will transfer the swimUSD to the owner (will still kickstart if requested)
-    pub fn propeller_process_swim_payload_fallback(ctx: Context<PropellerProcessSwimPayloadFallback>) -> Result<u64> {
+    pub fn propeller_process_swim_payload_fallback<'info>(
+        ctx: Context<'_, '_, '_, 'info, PropellerProcessSwimPayloadFallback<'info>>,
+    ) -> Result<u64> {
         handle_propeller_process_swim_payload_fallback(ctx)
     }
 
-    // #[inline(never)]
-    // pub fn cross_chain_add_and_transfer(
-    //     ctx: Context<AddAndTransfer>,
-    //     input_amounts: [u64; TOKEN_COUNT],
-    //     minimum_mint_amount: u64,
-    //     memo: [u8; 16],
-    //     // memo: Vec<u8>,
-    //     // nonce: u32,
-    //     target_chain: u16,
-    //     // amount: u64,
-    //     owner: Vec<u8>,
-    //     // propeller_enabled: bool,
-    //     // gas_kickstart: bool,
-    //     // max_fee: u64,
-    //     target_token_id: u16,
-    // ) -> Result<()> {
-    //     handle_cross_chain_add_and_transfer(
-    //         ctx,
-    //         input_amounts,
-    //         minimum_mint_amount,
-    //         memo,
-    //         // nonce,
-    //         target_chain,
-    //         owner,
-    //         target_token_id,
-    //     )
-    // }
+    /// Permissionless swimUSD fallback for swim payloads that weren't processed within
+    /// `propeller.swim_payload_expiry` seconds. Fees are capped at the swim payload's `max_fee`
+    /// & the `swim_receive` callback of swim receivers is skipped
+    #[inline(never)]
+    #[access_control(PropellerProcessSwimPayloadFallback::accounts_expired(&ctx))]
+    pub fn propeller_process_expired_swim_payload<'info>(
+        ctx: Context<'_, '_, '_, 'info, PropellerProcessSwimPayloadFallback<'info>>,
+    ) -> Result<u64> {
+        handle_propeller_process_expired_swim_payload(ctx)
+    }
+
+    /// Read-only. Returns (via return data) the swimUSD fees a propeller engine would currently charge
+    /// to deliver a swim payload of `params.swim_payload_len` bytes for `target_token_id`
+    #[inline(never)]
+    #[access_control(QuotePropellerFees::accounts(&ctx))]
+    pub fn quote_propeller_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, QuotePropellerFees<'info>>,
+        target_token_id: u16,
+        params: QuotePropellerFeesParams,
+    ) -> Result<PropellerFeesQuote> {
+        handle_quote_propeller_fees(ctx, params)
+    }
+
+    #[inline(never)]
+    #[access_control(AddAndTransfer::accounts(&ctx))]
+    pub fn cross_chain_add_and_transfer(
+        ctx: Context<AddAndTransfer>,
+        minimum_output_amount: u64,
+        target_chain: u16,
+        input_amounts: [u64; TOKEN_COUNT],
+        owner: Vec<u8>,
+    ) -> Result<u64> {
+        handle_cross_chain_add_and_transfer(ctx, minimum_output_amount, target_chain, input_amounts, owner)
+    }
+
+    #[inline(never)]
+    #[access_control(AddAndTransfer::accounts(&ctx))]
+    pub fn propeller_add_and_transfer(
+        ctx: Context<AddAndTransfer>,
+        minimum_output_amount: u64,
+        target_chain: u16,
+        input_amounts: [u64; TOKEN_COUNT],
+        owner: Vec<u8>,
+        gas_kickstart: bool,
+        max_fee: u64,
+        target_token_id: u16,
+        target_min_output_amount: u64,
+        memo: Option<[u8; 16]>,
+        receiver_data: Option<Vec<u8>>,
+        relayer_exclusivity: Option<RelayerExclusivity>,
+    ) -> Result<u64> {
+        handle_propeller_add_and_transfer(
+            ctx,
+            minimum_output_amount,
+            target_chain,
+            input_amounts,
+            owner,
+            gas_kickstart,
+            max_fee,
+            target_token_id,
+            target_min_output_amount,
+            memo,
+            receiver_data,
+            relayer_exclusivity,
+        )
+    }
+
+    #[inline(never)]
+    #[access_control(SwapExactInputAndTransfer::accounts(&ctx))]
+    pub fn cross_chain_swap_exact_input_and_transfer(
+        ctx: Context<SwapExactInputAndTransfer>,
+        minimum_output_amount: u64,
+        target_chain: u16,
+        exact_input_amount: u64,
+        owner: Vec<u8>,
+    ) -> Result<u64> {
+        handle_cross_chain_swap_exact_input_and_transfer(
+            ctx,
+            minimum_output_amount,
+            target_chain,
+            exact_input_amount,
+            owner,
+        )
+    }
+
+    #[inline(never)]
+    #[access_control(SwapExactInputAndTransfer::accounts(&ctx))]
+    pub fn propeller_swap_exact_input_and_transfer(
+        ctx: Context<SwapExactInputAndTransfer>,
+        minimum_output_amount: u64,
+        target_chain: u16,
+        exact_input_amount: u64,
+        owner: Vec<u8>,
+        gas_kickstart: bool,
+        max_fee: u64,
+        target_token_id: u16,
+        target_min_output_amount: u64,
+        memo: Option<[u8; 16]>,
+        receiver_data: Option<Vec<u8>>,
+        relayer_exclusivity: Option<RelayerExclusivity>,
+    ) -> Result<u64> {
+        handle_propeller_swap_exact_input_and_transfer(
+            ctx,
+            minimum_output_amount,
+            target_chain,
+            exact_input_amount,
+            owner,
+            gas_kickstart,
+            max_fee,
+            target_token_id,
+            target_min_output_amount,
+            memo,
+            receiver_data,
+            relayer_exclusivity,
+        )
+    }
 
     // #[inline(never)]
     // #[access_control(Secp256k1AndVerify::accounts(&ctx))]
@@ -412,439 +602,3 @@ pub mod propeller {
     //     // Ok(())
     // }
 }
-
-// Notes: doesn't fix in one txn with current max txn size of 1232.
-// #[derive(Accounts)]
-// #[instruction(
-//     input_amounts: [u64; TOKEN_COUNT],
-//     minimum_mint_amount: u64,
-//     memo: [u8; 16],
-//     target_chain: u16,
-//     owner: Vec<u8>,
-//     target_token_id: u16
-// )]
-// pub struct AddAndTransfer<'info> {
-//     #[account(
-//     seeds = [ b"propeller".as_ref(), lp_mint.key().as_ref()],
-//     bump = propeller.bump,
-//     )]
-//     pub propeller: Box<Account<'info, Propeller>>,
-//     #[account(
-//     mut,
-//     seeds = [
-//     b"two_pool".as_ref(),
-//     pool_token_account_0.mint.as_ref(),
-//     pool_token_account_1.mint.as_ref(),
-//     lp_mint.key().as_ref(),
-//     ],
-//     bump = pool.bump,
-//     seeds::program = two_pool_program.key()
-//     )]
-//     pub pool: Box<Account<'info, TwoPool>>,
-//     // /// TODO: could be removed if initialized with pool_v2
-//     // /// CHECK: checked in CPI
-//     // pub pool_auth: UncheckedAccount<'info>,
-//     #[account(
-//     mut,
-//     address = get_associated_token_address(&pool.key(), &pool.token_mint_keys[0]),
-//     constraint = pool_token_account_0.key() == pool.token_keys[0],
-//     )]
-//     pub pool_token_account_0: Box<Account<'info, TokenAccount>>,
-//     #[account(
-//     mut,
-//     address = get_associated_token_address(&pool.key(), &pool.token_mint_keys[1]),
-//     constraint = pool_token_account_1.key() == pool.token_keys[1],
-//     )]
-//     pub pool_token_account_1: Box<Account<'info, TokenAccount>>,
-//     #[account(
-//     mut,
-//     address = pool.lp_mint_key,
-//     constraint = propeller.swim_usd_mint == lp_mint.key(),
-//     )]
-//     pub lp_mint: Box<Account<'info, Mint>>,
-//     #[account(
-//     mut,
-//     token::mint = lp_mint,
-//     address = pool.governance_fee_key,
-//     )]
-//     pub governance_fee: Box<Account<'info, TokenAccount>>,
-//     // ///CHECK: checked in CPI
-//     // pub user_transfer_authority: Signer<'info>,
-//     #[account(
-//     mut,
-//     token::mint = pool_token_account_0.mint,
-//     )]
-//     pub user_token_account_0: Box<Account<'info, TokenAccount>>,
-//     #[account(
-//     mut,
-//     token::mint = pool_token_account_1.mint,
-//     )]
-//     pub user_token_account_1: Box<Account<'info, TokenAccount>>,
-//
-//     #[account(
-//     mut,
-//     token::mint = lp_mint,
-//     )]
-//     pub user_lp_token_account: Box<Account<'info, TokenAccount>>,
-//
-//     pub token_program: Program<'info, Token>,
-//     #[account(executable, address = spl_memo::id())]
-//     ///CHECK: memo program
-//     pub memo: UncheckedAccount<'info>,
-//     pub two_pool_program: Program<'info, two_pool::program::TwoPool>,
-//
-//     #[account(mut)]
-//     pub payer: Signer<'info>,
-//     #[account(
-//     mut,
-//     seeds = [ b"config".as_ref() ],
-//     bump,
-//     seeds::program = propeller.token_bridge().unwrap()
-//     )]
-//     /// CHECK: Token Bridge Config
-//     pub token_bridge_config: AccountInfo<'info>,
-//
-//     #[account(
-//     mut,
-//     associated_token::mint = lp_mint,
-//     associated_token::authority = payer
-//     )]
-//     pub user_swim_usd_ata: Box<Account<'info, TokenAccount>>,
-//
-//     // #[account(mut)]
-//     // pub swim_usd_mint: Box<Account<'info, Mint>>,
-//
-//     //TODO: change this associated_token account?
-//     //      is it necessary to do check since CPI will do check?
-//     //
-//     // If token is native to SOL, then this is token bridge custody (ATA) that holds the native token.
-//     #[account(mut)]
-//     //  technical edge-case - on first token_bridge_transfer with the token, this custody account won't be
-//     //  initialized yet. if we can assume that this account is initialized, we can do explicit type check here.
-//     /// CHECK: Will either be token bridge custody account or wrapped meta account
-//     pub custody: UncheckedAccount<'info>,
-//
-//     #[account(executable, address = propeller.token_bridge()?)]
-//     pub token_bridge: Program<'info, TokenBridge>,
-//
-//     #[account(
-//     seeds=[b"custody_signer".as_ref()],
-//     bump,
-//     seeds::program = token_bridge.key()
-//     )]
-//     /// CHECK: Only used for bridging assets native to Solana.
-//     pub custody_signer: UncheckedAccount<'info>,
-//
-//     #[account(
-//     seeds=[b"authority_signer".as_ref()],
-//     bump,
-//     seeds::program = token_bridge.key()
-//     )]
-//     /// CHECK: Token Bridge Authority Signer, delegated approval for transfer
-//     pub authority_signer: AccountInfo<'info>,
-//
-//     #[account(
-//     mut,
-//     seeds = [b"Bridge".as_ref()],
-//     bump,
-//     seeds::program = propeller.wormhole().unwrap()
-//     )]
-//     /// CHECK: Wormhole Config
-//     pub wormhole_config: AccountInfo<'info>,
-//
-//     #[account(mut)]
-//     // Note:
-//     //     using a `Signer`
-//     //     instead of a PDA since a normal token bridge transfer
-//     //     uses a Keypair.generate()
-//     //
-//     //     A new one needs to be used for every transfer
-//     //
-//     //     WH expects this to be an uninitialized account so might
-//     //     be able to use a PDA still in the future.
-//     //     maybe [b"propeller".as_ref(), payer, sequence_value]?
-//     //
-//     pub wormhole_message: Signer<'info>,
-//
-//     #[account(
-//     mut,
-//     seeds = [b"emitter".as_ref()],
-//     bump,
-//     seeds::program = propeller.token_bridge().unwrap()
-//     )]
-//     /// CHECK: Wormhole Emitter is PDA representing the Token Bridge Program
-//     pub wormhole_emitter: AccountInfo<'info>,
-//
-//     #[account(
-//     mut,
-//     seeds = [
-//     b"Sequence".as_ref(),
-//     wormhole_emitter.key().as_ref()
-//     ],
-//     bump,
-//     seeds::program = propeller.wormhole().unwrap()
-//     )]
-//     /// CHECK: Wormhole Sequence Number
-//     pub wormhole_sequence: AccountInfo<'info>,
-//
-//     #[account(
-//     mut,
-//     seeds = [b"fee_collector".as_ref()],
-//     bump,
-//     seeds::program = propeller.wormhole().unwrap()
-//     )]
-//     /// CHECK: Wormhole Fee Collector. leaving as UncheckedAccount since it could be uninitialized for the first transfer.
-//     pub wormhole_fee_collector: AccountInfo<'info>,
-//
-//     /// Transfers with payload also include the address of the account or contract
-//     /// that sent the transfer. Semantically this is identical to "msg.sender" on
-//     /// EVM chains, i.e. it is the address of the immediate caller of the token
-//     /// bridge transaction.
-//     /// Since on Solana, a transaction can have multiple different signers, getting
-//     /// this information is not so straightforward.
-//     /// The strategy we use to figure out the sender of the transaction is to
-//     /// require an additional signer ([`SenderAccount`]) for the transaction.
-//     /// If the transaction was sent by a user wallet directly, then this may just be
-//     /// the wallet's pubkey. If, however, the transaction was initiated by a
-//     /// program, then we require this to be a PDA derived from the sender program's
-//     /// id and the string "sender". In this case, the sender program must also
-//     /// attach its program id to the instruction data. If the PDA verification
-//     /// succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the
-//     /// transaction), then the program's id is attached to the VAA as the sender,
-//     /// otherwise the transaction is rejected.
-//     ///
-//     /// Note that a program may opt to forego the PDA derivation and instead just
-//     /// pass on the original wallet as the wallet account (or any other signer, as
-//     /// long as they don't provide their program_id in the instruction data). The
-//     /// sender address is provided as a means for protocols to verify on the
-//     /// receiving end that the message was emitted by a contract they trust, so
-//     /// foregoing this check is not advised. If the receiving contract needs to know
-//     /// the sender wallet's address too, then that information can be included in
-//     /// the additional payload, along with any other data that the protocol needs to
-//     /// send across. The legitimacy of the attached data can be verified by checking
-//     /// that the sender contract is a trusted one.
-//     ///
-//     /// Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the
-//     /// [[`cpi_program_id`]] field will result in a successful transaction, but in
-//     /// that case the PDA's address will directly be encoded into the payload
-//     /// instead of the sender program's id.
-//     #[account(
-//     seeds = [ b"sender".as_ref()],
-//     bump = propeller.sender_bump,
-//     )]
-//     /// CHECK: Sender Account
-//     pub sender: SystemAccount<'info>,
-//
-//     pub wormhole: Program<'info, Wormhole>,
-//
-//     #[account(
-//     seeds = [
-//     b"propeller".as_ref(),
-//     propeller.key().as_ref(),
-//     &target_chain.to_le_bytes()
-//     ],
-//     bump = target_chain_map.bump
-//     )]
-//     pub target_chain_map: Account<'info, TargetChainMap>,
-//     pub system_program: Program<'info, System>,
-//
-//     pub clock: Sysvar<'info, Clock>,
-//     pub rent: Sysvar<'info, Rent>,
-// }
-//
-// pub fn handle_cross_chain_add_and_transfer(
-//     ctx: Context<AddAndTransfer>,
-//     input_amounts: [u64; TOKEN_COUNT],
-//     minimum_mint_amount: u64,
-//     memo: [u8; 16],
-//     // memo: Vec<u8>,
-//     // nonce: u32,
-//     target_chain: u16,
-//     // amount: u64,
-//     owner: Vec<u8>,
-//     // propeller_enabled: bool,
-//     // gas_kickstart: bool,
-//     // max_fee: u64,
-//     target_token_id: u16,
-// ) -> Result<()> {
-//     token::approve(
-//         CpiContext::new(
-//             ctx.accounts.token_program.to_account_info(),
-//             token::Approve {
-//                 // source
-//                 to: ctx.accounts.user_token_account_0.to_account_info(),
-//                 delegate: ctx.accounts.propeller.to_account_info(),
-//                 authority: ctx.accounts.payer.to_account_info(),
-//             },
-//         ),
-//         input_amounts[0],
-//     )?;
-//     token::approve(
-//         CpiContext::new(
-//             ctx.accounts.token_program.to_account_info(),
-//             token::Approve {
-//                 // source
-//                 to: ctx.accounts.user_token_account_1.to_account_info(),
-//                 delegate: ctx.accounts.propeller.to_account_info(),
-//                 authority: ctx.accounts.payer.to_account_info(),
-//             },
-//         ),
-//         input_amounts[1],
-//     )?;
-//     let lp_mint_key = &ctx.accounts.lp_mint.key();
-//     let propeller_bump = &ctx.accounts.propeller.bump;
-//     let result = two_pool::cpi::add(
-//         CpiContext::new_with_signer(
-//             ctx.accounts.two_pool_program.to_account_info(),
-//             two_pool::cpi::accounts::Add {
-//                 pool: ctx.accounts.pool.to_account_info(),
-//                 pool_token_account_0: ctx.accounts.pool_token_account_0.to_account_info(),
-//                 pool_token_account_1: ctx.accounts.pool_token_account_1.to_account_info(),
-//                 lp_mint: ctx.accounts.lp_mint.to_account_info(),
-//                 governance_fee: ctx.accounts.governance_fee.to_account_info(),
-//                 user_transfer_authority: ctx.accounts.propeller.to_account_info(),
-//                 user_token_account_0: ctx.accounts.user_token_account_0.to_account_info(),
-//                 user_token_account_1: ctx.accounts.user_token_account_1.to_account_info(),
-//                 user_lp_token_account: ctx.accounts.user_lp_token_account.to_account_info(),
-//                 token_program: ctx.accounts.token_program.to_account_info(),
-//             },
-//             &[&[&b"propeller".as_ref(), ctx.accounts.propeller.swim_usd_mint.as_ref(), &[ctx.accounts.propeller.bump]]],
-//             // &[&[&b"propeller".as_ref(), lp_mint_key.as_ref(), &[propeller_bump]]],
-//         ),
-//         input_amounts,
-//         minimum_mint_amount,
-//     )?;
-//     let return_val = result.get();
-//
-//     anchor_lang::prelude::msg!("cross_chain_add return_val: {:?}", return_val);
-//     msg!("transfer_native_with_payload");
-//     token::approve(
-//         CpiContext::new(
-//             ctx.accounts.token_program.to_account_info(),
-//             token::Approve {
-//                 // source
-//                 to: ctx.accounts.user_swim_usd_ata.to_account_info(),
-//                 delegate: ctx.accounts.authority_signer.to_account_info(),
-//                 authority: ctx.accounts.payer.to_account_info(),
-//             },
-//         ),
-//         return_val,
-//     )?;
-//     msg!("finished approve for authority_signer");
-//     // let mut target_token_addr = [0u8; 32];
-//     // target_token_addr.copy_from_slice(target_token.as_slice());
-//     let mut owner_addr = [0u8; 32];
-//     owner_addr.copy_from_slice(owner.as_slice());
-//
-//     let swim_payload = RawSwimPayload {
-//         //TODO: this should come from the propeller or global constant?
-//         swim_payload_version: crate::constants::CURRENT_SWIM_PAYLOAD_VERSION,
-//         owner: owner_addr,
-//         propeller_enabled: false,
-//         gas_kickstart: false,
-//         max_fee: 0,
-//         target_token_id,
-//         // min_output_amount: U256::from(0u64),
-//         memo: memo.clone().try_into().unwrap(),
-//     };
-//     msg!("transfer_native_with_payload swim_payload: {:?}", swim_payload);
-//     let target_address = ctx.accounts.target_chain_map.target_address.clone();
-//     let transfer_with_payload_data = TransferWithPayloadData {
-//         //TODO: update this.
-//         nonce: 0u32,
-//         amount: return_val,
-//         target_address,
-//         target_chain,
-//         payload: swim_payload.try_to_vec()?,
-//         //note - if this field is missing then ctx.accounts.sender is used as the vaa.from
-//         cpi_program_id: Some(crate::ID),
-//     };
-//
-//     let token_bridge_custody = &ctx.accounts.custody;
-//     let wh_token_transfer_acct_infos = vec![
-//         ctx.accounts.payer.to_account_info().clone(),
-//         ctx.accounts.token_bridge_config.to_account_info().clone(),
-//         // ctx.accounts.token_bridge.to_account_info().clone(),
-//         ctx.accounts.user_swim_usd_ata.to_account_info().clone(),
-//         ctx.accounts.lp_mint.to_account_info().clone(),
-//         // ctx.accounts.swim_usd_mint.to_account_info().clone(),
-//         token_bridge_custody.to_account_info().clone(),
-//         ctx.accounts.authority_signer.to_account_info().clone(),
-//         ctx.accounts.custody_signer.to_account_info().clone(),
-//         ctx.accounts.wormhole_config.to_account_info().clone(),
-//         ctx.accounts.wormhole_message.to_account_info().clone(),
-//         ctx.accounts.wormhole_emitter.to_account_info().clone(),
-//         ctx.accounts.wormhole_sequence.to_account_info().clone(),
-//         ctx.accounts.wormhole_fee_collector.to_account_info().clone(),
-//         ctx.accounts.clock.to_account_info().clone(),
-//         ctx.accounts.sender.to_account_info().clone(),
-//         ctx.accounts.rent.to_account_info().clone(),
-//         ctx.accounts.system_program.to_account_info().clone(),
-//         ctx.accounts.wormhole.to_account_info().clone(),
-//         ctx.accounts.token_program.to_account_info().clone(),
-//     ];
-//
-//     invoke_signed(
-//         &Instruction {
-//             program_id: ctx.accounts.token_bridge.key(),
-//             accounts: vec![
-//                 AccountMeta::new(ctx.accounts.payer.key(), true),
-//                 AccountMeta::new_readonly(ctx.accounts.token_bridge_config.key(), false),
-//                 // AccountMeta::new(ctx.accounts.wormhole_config.key(), false),
-//                 AccountMeta::new(ctx.accounts.user_swim_usd_ata.key(), false),
-//                 AccountMeta::new(ctx.accounts.lp_mint.key(), false),
-//                 // AccountMeta::new(ctx.accounts.swim_usd_mint.key(), false),
-//                 AccountMeta::new(token_bridge_custody.key(), false),
-//                 AccountMeta::new_readonly(ctx.accounts.authority_signer.key(), false),
-//                 AccountMeta::new_readonly(ctx.accounts.custody_signer.key(), false),
-//                 AccountMeta::new(ctx.accounts.wormhole_config.key(), false),
-//                 // AccountMeta::new_readonly(ctx.accounts.token_bridge_config.key(), false),
-//                 AccountMeta::new(ctx.accounts.wormhole_message.key(), true),
-//                 AccountMeta::new_readonly(ctx.accounts.wormhole_emitter.key(), false),
-//                 AccountMeta::new(ctx.accounts.wormhole_sequence.key(), false),
-//                 AccountMeta::new(ctx.accounts.wormhole_fee_collector.key(), false),
-//                 AccountMeta::new_readonly(Clock::id(), false),
-//                 AccountMeta::new_readonly(ctx.accounts.sender.key(), true),
-//                 AccountMeta::new_readonly(Rent::id(), false),
-//                 AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
-//                 AccountMeta::new_readonly(ctx.accounts.wormhole.key(), false),
-//                 AccountMeta::new_readonly(spl_token::id(), false),
-//             ],
-//             data: (TRANSFER_NATIVE_WITH_PAYLOAD_INSTRUCTION, transfer_with_payload_data).try_to_vec()?,
-//         },
-//         // &ctx.accounts.to_account_infos(),
-//         &wh_token_transfer_acct_infos,
-//         &[&[&b"sender".as_ref(), &[ctx.accounts.propeller.sender_bump]]],
-//     )?;
-//
-//     token::revoke(CpiContext::new(
-//         ctx.accounts.token_program.to_account_info(),
-//         token::Revoke {
-//             // source
-//             source: ctx.accounts.user_swim_usd_ata.to_account_info(),
-//             authority: ctx.accounts.payer.to_account_info(),
-//         },
-//     ))?;
-//     msg!("Revoked authority_signer approval");
-//     token::revoke(CpiContext::new(
-//         ctx.accounts.token_program.to_account_info(),
-//         token::Revoke {
-//             // source
-//             source: ctx.accounts.user_token_account_0.to_account_info(),
-//             authority: ctx.accounts.payer.to_account_info(),
-//         },
-//     ))?;
-//     token::revoke(CpiContext::new(
-//         ctx.accounts.token_program.to_account_info(),
-//         token::Revoke {
-//             // source
-//             source: ctx.accounts.user_token_account_1.to_account_info(),
-//             authority: ctx.accounts.payer.to_account_info(),
-//         },
-//     ))?;
-//     msg!("Revoked user token accounts approval");
-//     let memo_ix = spl_memo::build_memo(memo.as_slice(), &[]);
-//     invoke(&memo_ix, &[ctx.accounts.memo.to_account_info()])?;
-//
-//     Ok(())
-// }
@@ -0,0 +1,342 @@
+use {
+    crate::{error::PropellerError, Propeller, TOKEN_COUNT},
+    anchor_lang::{prelude::*, solana_program::pubkey},
+    rust_decimal::Decimal,
+    switchboard_v2::{AggregatorAccountData, SwitchboardDecimal},
+    two_pool::{
+        price_oracle::{PriceOracle, PRICE_DECIMALS},
+        state::TwoPool,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct Pyth;
+
+impl anchor_lang::Id for Pyth {
+    #[cfg(all(feature = "localnet", not(feature = "devnet"), not(feature = "mainnet")))]
+    fn id() -> Pubkey {
+        pubkey!("gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s")
+    }
+
+    #[cfg(all(feature = "devnet", not(feature = "localnet"), not(feature = "mainnet")))]
+    fn id() -> Pubkey {
+        pubkey!("gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s")
+    }
+
+    #[cfg(all(feature = "mainnet", not(feature = "localnet"), not(feature = "devnet")))]
+    fn id() -> Pubkey {
+        pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH")
+    }
+}
+
+/// How the SOL/USD prices of the propeller's oracles are combined
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OraclePolicy {
+    /// only the switchboard aggregator is used
+    Primary,
+    /// the aggregator, then the fallback oracle, then the sol usd pool.
+    /// The first one with a valid price is used
+    PrimaryWithFallback,
+    /// median of the prices of all configured oracles with a valid price
+    Median,
+}
+
+impl Default for OraclePolicy {
+    fn default() -> Self {
+        OraclePolicy::Primary
+    }
+}
+
+/// Oracle accounts passed to every ix that converts lamports to swimUSD.
+/// `fallback_oracle` & `sol_usd_pool` are ignored if the propeller doesn't use them.
+pub struct OracleAccounts<'a, 'info> {
+    pub aggregator: &'a AccountLoader<'info, AggregatorAccountData>,
+    pub fallback_oracle: &'a AccountInfo<'info>,
+    pub sol_usd_pool: &'a AccountInfo<'info>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum OracleSource {
+    Switchboard,
+    Pyth,
+    SolUsdPool,
+}
+
+/// in order of precedence for `OraclePolicy::PrimaryWithFallback`
+const ORACLE_SOURCES: [OracleSource; 3] = [OracleSource::Switchboard, OracleSource::Pyth, OracleSource::SolUsdPool];
+
+/// Returns the SOL/USD price (in USD per SOL) according to `propeller.oracle_policy`
+pub fn get_sol_usd_price(propeller: &Propeller, oracles: &OracleAccounts, now: i64) -> Result<Decimal> {
+    require_keys_eq!(oracles.aggregator.key(), propeller.aggregator, PropellerError::InvalidAggregator);
+    match propeller.oracle_policy {
+        OraclePolicy::Primary => get_switchboard_sol_usd_price(oracles.aggregator, propeller, now),
+        OraclePolicy::PrimaryWithFallback => {
+            for source in ORACLE_SOURCES {
+                match get_oracle_sol_usd_price(source, propeller, oracles, now) {
+                    Some(Ok(price)) => return Ok(price),
+                    Some(Err(e)) => msg!("{:?} oracle price is invalid: {:?}", source, e),
+                    None => {}
+                }
+            }
+            err!(PropellerError::NoValidOraclePrice)
+        }
+        OraclePolicy::Median => {
+            let mut prices = vec![];
+            for source in ORACLE_SOURCES {
+                match get_oracle_sol_usd_price(source, propeller, oracles, now) {
+                    Some(Ok(price)) => prices.push(price),
+                    Some(Err(e)) => msg!("{:?} oracle price is invalid: {:?}", source, e),
+                    None => {}
+                }
+            }
+            median(prices)
+        }
+    }
+}
+
+/// `None` if the propeller doesn't have an oracle of type `source`
+fn get_oracle_sol_usd_price(
+    source: OracleSource,
+    propeller: &Propeller,
+    oracles: &OracleAccounts,
+    now: i64,
+) -> Option<Result<Decimal>> {
+    match source {
+        OracleSource::Switchboard => Some(get_switchboard_sol_usd_price(oracles.aggregator, propeller, now)),
+        OracleSource::Pyth if propeller.fallback_oracle != Pubkey::default() => {
+            Some(get_pyth_sol_usd_price(oracles.fallback_oracle, propeller, now))
+        }
+        OracleSource::SolUsdPool if propeller.sol_usd_pool != Pubkey::default() => {
+            Some(get_sol_usd_pool_price(oracles.sol_usd_pool, propeller, now))
+        }
+        _ => None,
+    }
+}
+
+fn median(mut prices: Vec<Decimal>) -> Result<Decimal> {
+    require!(!prices.is_empty(), PropellerError::NoValidOraclePrice);
+    prices.sort();
+    let mid = prices.len() / 2;
+    if prices.len() % 2 == 1 {
+        Ok(prices[mid])
+    } else {
+        prices[mid - 1]
+            .checked_add(prices[mid])
+            .and_then(|sum| sum.checked_div(Decimal::TWO))
+            .ok_or(error!(PropellerError::IntegerOverflow))
+    }
+}
+
+/// Fails if the latest confirmed round is older than `propeller.max_staleness` seconds
+/// or its std deviation exceeds `propeller.max_confidence_interval`.
+pub fn get_switchboard_sol_usd_price(
+    aggregator: &AccountLoader<AggregatorAccountData>,
+    propeller: &Propeller,
+    now: i64,
+) -> Result<Decimal> {
+    let feed = aggregator.load()?;
+    feed.check_staleness(now, propeller.max_staleness).map_err(|_| error!(PropellerError::StaleFeed))?;
+    let max_confidence_interval = &propeller.max_confidence_interval;
+    feed.check_confidence_interval(SwitchboardDecimal::new(
+        max_confidence_interval.mantissa,
+        max_confidence_interval.scale,
+    ))
+    .map_err(|_| error!(PropellerError::ConfidenceIntervalExceeded))?;
+
+    let sol_usd_price: Decimal = feed.get_result()?.try_into()?;
+    Ok(sol_usd_price)
+}
+
+/// Same staleness & confidence interval checks as the switchboard aggregator
+pub fn get_pyth_sol_usd_price(fallback_oracle: &AccountInfo, propeller: &Propeller, now: i64) -> Result<Decimal> {
+    require_keys_eq!(fallback_oracle.key(), propeller.fallback_oracle, PropellerError::InvalidPythAccount);
+    require_keys_eq!(*fallback_oracle.owner, Pyth::id(), PropellerError::InvalidPythAccount);
+    let pyth_price = PythPrice::try_from_account_data(&fallback_oracle.try_borrow_data()?)?;
+    let max_confidence_interval = Decimal::try_from(propeller.max_confidence_interval)?;
+    pyth_price.get_price(propeller.max_staleness, max_confidence_interval, now)
+}
+
+/// Returns the TWAP of `sol_usd_pool`'s SOL price denominated in its stablecoin.
+///
+/// Only the pool's stored price observations are used so no CPI is needed: the TWAP covers
+/// at least `propeller.sol_usd_pool_twap_window` seconds up to `now` (extrapolated from the pool's
+/// last update with the prices it recorded). The last update must be within
+/// `propeller.sol_usd_pool_max_staleness` seconds.
+pub fn get_sol_usd_pool_price(sol_usd_pool: &AccountInfo, propeller: &Propeller, now: i64) -> Result<Decimal> {
+    require_keys_eq!(sol_usd_pool.key(), propeller.sol_usd_pool, PropellerError::InvalidSolUsdPool);
+    let pool = Account::<TwoPool>::try_from(sol_usd_pool)?;
+    get_price_oracle_twap_price(
+        &pool.price_oracle,
+        propeller.sol_usd_pool_sol_index as usize,
+        propeller.sol_usd_pool_twap_window,
+        propeller.sol_usd_pool_max_staleness,
+        now,
+    )
+}
+
+fn get_price_oracle_twap_price(
+    price_oracle: &PriceOracle,
+    sol_index: usize,
+    twap_window: u32,
+    max_staleness: i64,
+    now: i64,
+) -> Result<Decimal> {
+    require_gt!(TOKEN_COUNT, sol_index, PropellerError::InvalidSolUsdPool);
+    let last_update_ts = price_oracle.last_update_ts;
+    require_gte!(max_staleness, now.saturating_sub(last_update_ts), PropellerError::StaleFeed);
+    let target_ts = now.checked_sub(i64::from(twap_window)).ok_or(error!(PropellerError::IntegerOverflow))?;
+    let observation = price_oracle
+        .observations
+        .iter()
+        .filter(|observation| observation.ts != 0 && observation.ts <= target_ts)
+        .max_by_key(|observation| observation.ts)
+        .ok_or(error!(PropellerError::InsufficientOracleHistory))?;
+    let elapsed = (now - observation.ts) as u128;
+    require_gt!(elapsed, 0u128, PropellerError::InsufficientOracleHistory);
+    let price_cumulatives = price_oracle.get_price_cumulatives(now);
+
+    let twap = |i: usize| -> Result<Decimal> {
+        let twap = price_cumulatives[i].wrapping_sub(observation.price_cumulatives[i]) / elapsed;
+        let twap = i128::try_from(twap).map_err(|_| error!(PropellerError::ConversionError))?;
+        Decimal::try_from_i128_with_scale(twap, PRICE_DECIMALS as u32)
+            .map_err(|_| error!(PropellerError::ConversionError))
+    };
+    let sol_twap = twap(sol_index)?;
+    let stable_twap = twap(TOKEN_COUNT - 1 - sol_index)?;
+    require!(!stable_twap.is_zero(), PropellerError::InsufficientOracleHistory);
+    sol_twap.checked_div(stable_twap).ok_or(error!(PropellerError::IntegerOverflow))
+}
+
+pub const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+pub const PYTH_VERSION: u32 = 2;
+pub const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+pub const PYTH_STATUS_TRADING: u32 = 1;
+
+/// Subset of a pyth (v2) price account. Parsed by offset to avoid depending on the pyth sdk.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PythPrice {
+    pub expo: i32,
+    /// publish time of the aggregate price
+    pub timestamp: i64,
+    pub price: i64,
+    pub conf: u64,
+    pub status: u32,
+}
+
+impl PythPrice {
+    const MAGIC_OFFSET: usize = 0;
+    const VERSION_OFFSET: usize = 4;
+    const ACCOUNT_TYPE_OFFSET: usize = 8;
+    const EXPO_OFFSET: usize = 20;
+    const TIMESTAMP_OFFSET: usize = 96;
+    const AGG_PRICE_OFFSET: usize = 208;
+    const AGG_CONF_OFFSET: usize = 216;
+    const AGG_STATUS_OFFSET: usize = 224;
+    pub const LEN: usize = 240;
+
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        require_gte!(data.len(), Self::LEN, PropellerError::InvalidPythAccount);
+        let read_u32 = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        require_eq!(read_u32(Self::MAGIC_OFFSET), PYTH_MAGIC, PropellerError::InvalidPythAccount);
+        require_eq!(read_u32(Self::VERSION_OFFSET), PYTH_VERSION, PropellerError::InvalidPythAccount);
+        require_eq!(read_u32(Self::ACCOUNT_TYPE_OFFSET), PYTH_PRICE_ACCOUNT_TYPE, PropellerError::InvalidPythAccount);
+        Ok(PythPrice {
+            expo: read_u32(Self::EXPO_OFFSET) as i32,
+            timestamp: read_u64(Self::TIMESTAMP_OFFSET) as i64,
+            price: read_u64(Self::AGG_PRICE_OFFSET) as i64,
+            conf: read_u64(Self::AGG_CONF_OFFSET),
+            status: read_u32(Self::AGG_STATUS_OFFSET),
+        })
+    }
+
+    pub fn get_price(&self, max_staleness: i64, max_confidence_interval: Decimal, now: i64) -> Result<Decimal> {
+        require_eq!(self.status, PYTH_STATUS_TRADING, PropellerError::StaleFeed);
+        require_gte!(max_staleness, now.saturating_sub(self.timestamp), PropellerError::StaleFeed);
+        require_gt!(self.price, 0i64, PropellerError::InvalidPythAccount);
+        let price = self.to_decimal(self.price as i128)?;
+        let conf = self.to_decimal(self.conf as i128)?;
+        require!(conf <= max_confidence_interval, PropellerError::ConfidenceIntervalExceeded);
+        Ok(price)
+    }
+
+    fn to_decimal(&self, value: i128) -> Result<Decimal> {
+        require!(self.expo <= 0, PropellerError::InvalidPythAccount);
+        Decimal::try_from_i128_with_scale(value, self.expo.unsigned_abs())
+            .map_err(|_| error!(PropellerError::ConversionError))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, rust_decimal_macros::dec};
+
+    const MAX_STALENESS: i64 = 60;
+
+    impl PythPrice {
+        /// Serializes the price into an otherwise zeroed pyth price account (i.e. a local stand-in)
+        fn to_account_data(&self) -> Vec<u8> {
+            let mut data = vec![0u8; Self::LEN];
+            data[Self::MAGIC_OFFSET..][..4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+            data[Self::VERSION_OFFSET..][..4].copy_from_slice(&PYTH_VERSION.to_le_bytes());
+            data[Self::ACCOUNT_TYPE_OFFSET..][..4].copy_from_slice(&PYTH_PRICE_ACCOUNT_TYPE.to_le_bytes());
+            data[Self::EXPO_OFFSET..][..4].copy_from_slice(&self.expo.to_le_bytes());
+            data[Self::TIMESTAMP_OFFSET..][..8].copy_from_slice(&self.timestamp.to_le_bytes());
+            data[Self::AGG_PRICE_OFFSET..][..8].copy_from_slice(&self.price.to_le_bytes());
+            data[Self::AGG_CONF_OFFSET..][..8].copy_from_slice(&self.conf.to_le_bytes());
+            data[Self::AGG_STATUS_OFFSET..][..4].copy_from_slice(&self.status.to_le_bytes());
+            data
+        }
+    }
+
+    fn pyth_price(timestamp: i64) -> PythPrice {
+        PythPrice { expo: -8, timestamp, price: 32_50000000, conf: 5000000, status: PYTH_STATUS_TRADING }
+    }
+
+    #[test]
+    fn test_pyth_price() {
+        let data = pyth_price(100).to_account_data();
+        let parsed = PythPrice::try_from_account_data(&data).unwrap();
+        assert_eq!(parsed, pyth_price(100));
+        assert_eq!(parsed.get_price(MAX_STALENESS, dec!(1), 150).unwrap(), dec!(32.5));
+        // stale
+        assert!(parsed.get_price(MAX_STALENESS, dec!(1), 161).is_err());
+        // not trading
+        assert!(PythPrice { status: 0, ..parsed }.get_price(MAX_STALENESS, dec!(1), 150).is_err());
+        // conf (2 USD) > max_confidence_interval
+        assert!(PythPrice { conf: 2_00000000, ..parsed }.get_price(MAX_STALENESS, dec!(1), 150).is_err());
+        // wrong magic
+        let mut data = data;
+        data[0] = 0;
+        assert!(PythPrice::try_from_account_data(&data).is_err());
+    }
+
+    #[test]
+    fn test_price_oracle_twap_price() {
+        let one = 10u128.pow(PRICE_DECIMALS as u32);
+        // stable (index 0) has been at 1 & SOL (index 1) at 30 since ts 1_000
+        let mut price_oracle = PriceOracle::new(1_000);
+        price_oracle.price_cumulatives[..2].copy_from_slice(&[one * 1_000, one * 30 * 1_000]);
+        price_oracle.last_update_ts = 2_000;
+        price_oracle.last_marginal_prices[..2].copy_from_slice(&[one, one * 30]);
+        price_oracle.observation_index = 1;
+        price_oracle.observations[1].ts = 1_900;
+        price_oracle.observations[1].price_cumulatives[..2].copy_from_slice(&[one * 900, one * 30 * 900]);
+        // the observation at 1_900 is within the window so the one at 1_000 is used
+        assert_eq!(get_price_oracle_twap_price(&price_oracle, 1, 15 * 60, MAX_STALENESS, 2_010).unwrap(), dec!(30));
+        // stale
+        assert!(get_price_oracle_twap_price(&price_oracle, 1, 15 * 60, MAX_STALENESS, 2_061).is_err());
+        // not enough history
+        assert!(get_price_oracle_twap_price(&price_oracle, 1, 1_011, MAX_STALENESS, 2_010).is_err());
+        // the window ends at `now` (not at the last update) so an idle pool still yields a price
+        assert_eq!(get_price_oracle_twap_price(&price_oracle, 1, 15 * 60, 86_400, 5_000).unwrap(), dec!(30));
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(vec![dec!(3), dec!(1), dec!(2)]).unwrap(), dec!(2));
+        assert_eq!(median(vec![dec!(3), dec!(1)]).unwrap(), dec!(2));
+        assert_eq!(median(vec![dec!(5)]).unwrap(), dec!(5));
+        assert!(median(vec![]).is_err());
+    }
+}
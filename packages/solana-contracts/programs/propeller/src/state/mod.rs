@@ -1,3 +1,4 @@
-pub use self::propeller::*;
+pub use self::{propeller::*, rate_limit::*};
 
 pub mod propeller;
+pub mod rate_limit;
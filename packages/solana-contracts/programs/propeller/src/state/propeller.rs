@@ -1,12 +1,16 @@
 use {
-    crate::{constants::CURRENT_SWIM_PAYLOAD_VERSION, error::PropellerError, Address, TOKEN_COUNT},
+    crate::{
+        error::PropellerError,
+        oracle::OraclePolicy,
+        state::{RateLimit, RateLimitAction},
+        swim_payload::SwimPayload,
+        swim_receiver::{get_swim_receiver_authority, MAX_RECEIVER_DATA_LEN},
+        TOKEN_COUNT,
+    },
     anchor_lang::prelude::*,
     anchor_spl::token::TokenAccount,
-    byteorder::{BigEndian, ReadBytesExt, WriteBytesExt},
-    std::{
-        io::{Cursor, ErrorKind, Read, Write},
-        str::FromStr,
-    },
+    std::str::FromStr,
+    two_pool::BorshDecimal,
 };
 
 // Do i need this to hold configs & state?
@@ -16,7 +20,14 @@ use {
 pub struct Propeller {
     pub bump: u8,
     pub nonce: u32,
-    pub admin: Pubkey,         //32
+    pub admin: Pubkey, //32
+    pub prepared_admin: Pubkey,
+    pub admin_transition_ts: i64,
+    pub pause_key: Pubkey,
+    // pauses both directions. `is_inbound_paused`/`is_outbound_paused` pause only one of them
+    pub is_paused: bool,
+    pub is_inbound_paused: bool,
+    pub is_outbound_paused: bool,
     pub wormhole: Pubkey,      //32
     pub token_bridge: Pubkey,  //32
     pub swim_usd_mint: Pubkey, //32
@@ -34,6 +45,21 @@ pub struct Propeller {
     pub init_ata_fee: u64,
     pub complete_with_payload_fee: u64,
     pub process_swim_payload_fee: u64,
+    // seconds after `SwimPayloadMessage.completed_ts` after which anyone can
+    // force the swimUSD fallback delivery of a swim payload that wasn't processed
+    pub swim_payload_expiry: i64,
+    // max age (in seconds) of the `FeePriceCache` rate the relay ixs will charge fees with
+    pub fee_price_cache_max_age: i64,
+    // global flow limits on the swimUSD completed (inbound) & transferred (outbound) by the propeller.
+    // per chain limits are in the `TargetChainMap`s
+    pub inbound_rate_limit: RateLimit,
+    pub outbound_rate_limit: RateLimit,
+    // what happens to inbound transfers exceeding the global or their chain's inbound rate limit
+    pub rate_limit_action: RateLimitAction,
+    // swim payloads of inbound transfers of at least this amount are held for `large_transfer_delay` seconds.
+    // 0 => disabled
+    pub large_transfer_threshold: u64,
+    pub large_transfer_delay: i64,
     // minimum amount of tokens that must be transferred in token bridge transfer
     // if propeller enabled transfer.
     // Note: No longer using min transfer amounts
@@ -52,37 +78,53 @@ pub struct Propeller {
     pub marginal_price_pool_token_mint: Pubkey,
     // index of token used for calculating gas price
     pub marginal_price_pool_token_index: u8,
+    // window (in seconds) of the TWAP used for marginal prices. 0 => spot marginal prices
+    pub marginal_price_twap_window: u32,
 
     // pub evm_routing_contract_address: [u8; 32],
     pub fee_vault: Pubkey, //32
 
     pub aggregator: Pubkey, //32
-                            //TODO: add this?
-                            // pub fallback_oracle: Pubkey, //32
-
-                            // pub custody_signer_key: Pubkey,     // 32
-                            //    pub custody_signer_bump: u8,        // 1
-
-                            // pub mint_signer_key: Pubkey,     // 32
-                            //    pub mint_signer_bump: u8,        // 1
-
-                            // 	pub authority_signer_key: Pubkey,     // 32
-                            //    pub authority_signer_bump: u8,        // 1
-                            //
-                            // 	pub bridge_config_key: Pubkey,     // 32
-                            //    pub bridge_config_bump: u8,        // 1
-                            //
-                            // 	pub wormhole_config_key: Pubkey,     // 32
-                            //    pub wormhole_config_bump: u8,        // 1
-                            //
-                            // 	pub fee_collector_key: Pubkey,     // 32
-                            //    pub fee_collector_bump: u8,        // 1
-                            //
-                            // 	pub wormhole_emitter_key: Pubkey,     // 32
-                            //    pub wormhole_emitter_bump: u8,        // 1
-                            //
-                            // 	pub wormhole_sequence_key: Pubkey,     // 32
-                            //    pub wormhole_sequence_bump: u8,        // 1
+    // max age (in seconds) of the aggregator's latest confirmed round
+    pub max_staleness: i64,
+    // max std deviation of the aggregator's latest confirmed round (in USD per SOL)
+    pub max_confidence_interval: BorshDecimal,
+    // how the prices of the aggregator, fallback oracle & sol usd pool are combined
+    pub oracle_policy: OraclePolicy,
+    // pyth SOL/USD price account. `Pubkey::default()` => not used
+    pub fallback_oracle: Pubkey, //32
+    // two pool of SOL & a USD stablecoin whose TWAP is used as an oracle. `Pubkey::default()` => not used
+    pub sol_usd_pool: Pubkey, //32
+    // index of SOL in `sol_usd_pool`
+    pub sol_usd_pool_sol_index: u8,
+    // min window (in seconds) of the `sol_usd_pool` TWAP
+    pub sol_usd_pool_twap_window: u32,
+    // max age (in seconds) of the last update of `sol_usd_pool`'s price oracle. separate from
+    // `max_staleness` since the pool's oracle is only updated when the pool is used
+    pub sol_usd_pool_max_staleness: i64,
+    // pub custody_signer_key: Pubkey,     // 32
+    //    pub custody_signer_bump: u8,        // 1
+
+    // pub mint_signer_key: Pubkey,     // 32
+    //    pub mint_signer_bump: u8,        // 1
+
+    // 	pub authority_signer_key: Pubkey,     // 32
+    //    pub authority_signer_bump: u8,        // 1
+    //
+    // 	pub bridge_config_key: Pubkey,     // 32
+    //    pub bridge_config_bump: u8,        // 1
+    //
+    // 	pub wormhole_config_key: Pubkey,     // 32
+    //    pub wormhole_config_bump: u8,        // 1
+    //
+    // 	pub fee_collector_key: Pubkey,     // 32
+    //    pub fee_collector_bump: u8,        // 1
+    //
+    // 	pub wormhole_emitter_key: Pubkey,     // 32
+    //    pub wormhole_emitter_bump: u8,        // 1
+    //
+    // 	pub wormhole_sequence_key: Pubkey,     // 32
+    //    pub wormhole_sequence_bump: u8,        // 1
 }
 // better to save pda keys on chain and always calculate/derive client side?
 //  - if save pubkeys and don't use #[account(seeds=[...])] then need to manually call or save
@@ -92,28 +134,48 @@ impl Propeller {
     pub const LEN: usize = 1 + //bump
         4 + //nonce
         32 + //admin
+        32 + //prepared_admin
+        8 + //admin_transition_ts
+        32 + //pause_key
+        1 + //is_paused
+        1 + //is_inbound_paused
+        1 + //is_outbound_paused
         32 + //wormhole
         32 + //token_bridge
         32 + //swim_usd_mint
-        32 + //marginal_price_pool
-        32 + //marginal_price_token_mint
-        1 + //marginal_price_pool_token_index
         1 + //sender_bump
         1 + //redeemer_bump
         8 + //gas_kickstart_amount
-        8 + //propeller_fee
         8 + // secp_verify_init_fee
         8 + // secp_verify_fee
         8 + // post_vaa_fee
+        8 + // init_ata_fee
         8 + // complete_with_payload_fee
         8 + //process_swim_payload_fee
-        8 + // complete_with_payload_cost
-        8 + // process_swim_payload_cost
+        8 + //swim_payload_expiry
+        8 + //fee_price_cache_max_age
+        RateLimit::LEN + //inbound_rate_limit
+        RateLimit::LEN + //outbound_rate_limit
+        1 + //rate_limit_action
+        8 + //large_transfer_threshold
+        8 + //large_transfer_delay
         // 8 + //propeller_min_transfer_amount
         // 8 +  //propeller_eth_min_transfer_amount
+        32 + //marginal_price_pool
+        32 + //marginal_price_pool_token_mint
+        1 + //marginal_price_pool_token_index
+        4 + //marginal_price_twap_window
         32 + //fee_vault
-        32; //aggregator
-            // 32; // evm_routing_contract_address
+        32 + //aggregator
+        8 + //max_staleness
+        (16 + 4) + //max_confidence_interval
+        1 + //oracle_policy
+        32 + //fallback_oracle
+        32 + //sol_usd_pool
+        1 + //sol_usd_pool_sol_index
+        4 + //sol_usd_pool_twap_window
+        8; //sol_usd_pool_max_staleness
+           // 32; // evm_routing_contract_address
 
     pub fn wormhole(&self) -> Result<Pubkey> {
         // let pubkey = Pubkey::from_str(CORE_BRIDGE_ADDRESS)
@@ -130,9 +192,77 @@ impl Propeller {
         Ok(pubkey)
     }
 
+    /// complete_native_with_payload & process_swim_payload ixs
+    pub fn require_inbound_not_paused(&self) -> Result<()> {
+        require!(!self.is_paused && !self.is_inbound_paused, PropellerError::InboundIsPaused);
+        Ok(())
+    }
+
+    /// transfer_native_with_payload ixs
+    pub fn require_outbound_not_paused(&self) -> Result<()> {
+        require!(!self.is_paused && !self.is_outbound_paused, PropellerError::OutboundIsPaused);
+        Ok(())
+    }
+
+    /// Records an inbound transfer of `amount` from a chain with the inbound rate limit `chain_rate_limit`.
+    /// Returns the unix timestamp until which the swim payload of the transfer is held (0 if it isn't held)
+    ///
+    /// A queued transfer is scheduled for when the exceeded limit(s) have capacity for it and takes up
+    /// that capacity from then on, so every queued transfer is released after the ones queued before it.
+    pub fn record_inbound_transfer(
+        &mut self,
+        chain_rate_limit: &mut RateLimit,
+        amount: u64,
+        current_ts: i64,
+    ) -> Result<i64> {
+        let mut release_ts = 0;
+        for rate_limit in [&self.inbound_rate_limit, &*chain_rate_limit] {
+            if rate_limit.would_exceed(amount, current_ts) {
+                require!(self.rate_limit_action == RateLimitAction::Queue, PropellerError::RateLimitExceeded);
+                release_ts = release_ts.max(rate_limit.next_available_ts(amount, current_ts));
+            }
+        }
+        let record_ts = release_ts.max(current_ts);
+        self.inbound_rate_limit.record(amount, record_ts)?;
+        chain_rate_limit.record(amount, record_ts)?;
+        if self.large_transfer_threshold > 0 && amount >= self.large_transfer_threshold {
+            release_ts = release_ts.max(current_ts.saturating_add(self.large_transfer_delay));
+        }
+        Ok(release_ts)
+    }
+
+    /// Records an outbound transfer of `amount` to a chain with the outbound rate limit `chain_rate_limit`.
+    /// Fails if the global or the chain's outbound rate limit would be exceeded
+    pub fn record_outbound_transfer(
+        &mut self,
+        chain_rate_limit: &mut RateLimit,
+        amount: u64,
+        current_ts: i64,
+    ) -> Result<()> {
+        require!(
+            !self.outbound_rate_limit.would_exceed(amount, current_ts)
+                && !chain_rate_limit.would_exceed(amount, current_ts),
+            PropellerError::RateLimitExceeded
+        );
+        self.outbound_rate_limit.record(amount, current_ts)?;
+        chain_rate_limit.record(amount, current_ts)?;
+        Ok(())
+    }
+
     pub fn get_complete_native_with_payload_fee(&self) -> u64 {
         self.secp_verify_init_fee + self.secp_verify_fee + self.post_vaa_fee + self.complete_with_payload_fee
     }
+
+    /// Fees (in lamports) charged by `propeller_process_swim_payload` for the `SwimClaim` rent,
+    /// the `process_swim_payload_fee` & the gas kickstart if requested.
+    pub fn get_process_swim_payload_fees(&self, rent: &Rent, gas_kickstart: bool) -> Result<u64> {
+        let swim_claim_rent_exempt_fees = rent.minimum_balance(8 + SwimClaim::LEN);
+        let gas_kickstart_amount = if gas_kickstart { self.gas_kickstart_amount } else { 0 };
+        swim_claim_rent_exempt_fees
+            .checked_add(self.process_swim_payload_fee)
+            .and_then(|x| x.checked_add(gas_kickstart_amount))
+            .ok_or(error!(PropellerError::IntegerOverflow))
+    }
 }
 
 #[account]
@@ -165,6 +295,28 @@ pub struct SwimPayloadMessage {
     pub max_fee: u64,
     pub target_token_id: u16,
     pub memo: [u8; 16],
+    /// minimum amount of the target token the owner has to receive whenever the swim payload
+    /// is processed (by the propeller engine or anyone else). 0 for `SwimPayloadVersion::V1` payloads
+    pub min_output_amount: u64,
+    /// unix timestamp of the `CompleteNativeWithPayload` that created this account
+    pub completed_ts: i64,
+    /// sum of all fees (in swimUSD) charged by the propeller engine so far
+    /// across complete, create owner token accounts & process swim payload.
+    /// can never exceed `max_fee`
+    pub total_fees: u64,
+    /// address that initiated the token bridge transfer on the source chain
+    pub sender: [u8; 32],
+    /// set if `owner` is a program implementing the swim receiver callback.
+    /// passed to the receiver after the swim payload is processed
+    pub receiver_data: Option<Vec<u8>>,
+    /// only this relayer (or the owner) can run the propeller ixs until `exclusivity_deadline`.
+    /// `Pubkey::default()` if the swim payload has no relayer exclusivity
+    pub exclusive_relayer: Pubkey,
+    /// unix timestamp after which anyone can run the propeller ixs
+    pub exclusivity_deadline: i64,
+    /// unix timestamp until which the swim payload can't be processed because its transfer
+    /// exceeded a rate limit or was a large transfer. 0 if it isn't held
+    pub release_ts: i64,
     //TODO: add extra bytes for future use?
 }
 
@@ -178,120 +330,73 @@ impl SwimPayloadMessage {
         2 +  // vaa_emitter_chain
         8 +  // vaa_sequence
         8 + // transfer_amount
+        8 + // completed_ts
+        8 + // total_fees
+        32 + // sender
+        1 + 4 + MAX_RECEIVER_DATA_LEN + // receiver_data
+        32 + // exclusive_relayer
+        8 + // exclusivity_deadline
+        8 + // release_ts
         // swim_payload
-        RawSwimPayload::LEN; // swim_payload
-                             // 1 + //version
-                             // 32 + //owner
-                             // 1 + // propeller_enabled
-                             // 1 + // gas_kickstart
-                             // 8 + // max_fee
-                             // 2 +    // target_token_id
-                             // 16; // memo
-                             // SwimPayload::LEN; // swim_payload
-}
+        SwimPayload::LEN; // swim_payload
+                          // 1 + //version
+                          // 32 + //owner
+                          // 1 + // propeller_enabled
+                          // 1 + // gas_kickstart
+                          // 8 + // max_fee
+                          // 2 +    // target_token_id
+                          // 16; // memo
+                          // SwimPayload::LEN; // swim_payload
+
+    /// Adds `fees` to the fees charged for this swim payload so far.
+    /// Fails if the cumulative fees would exceed the `max_fee` set by the user on the source chain.
+    pub fn add_fees(&mut self, fees: u64) -> Result<()> {
+        let total_fees = self.total_fees.checked_add(fees).ok_or(PropellerError::IntegerOverflow)?;
+        require_gte!(self.max_fee, total_fees, PropellerError::MaxFeeExceeded);
+        self.total_fees = total_fees;
+        Ok(())
+    }
 
-//TODO: look into options for versioning.
-//  ex - metaplex metadata versioning - (probably not. its messy).
-#[derive(PartialEq, Debug, Clone, Default)]
-pub struct RawSwimPayload {
-    /* always required fields */
-    pub swim_payload_version: u8,
-    pub owner: Address,
-    /* required for all propellerEnabled */
-    pub propeller_enabled: bool,
-    pub gas_kickstart: bool,
-    pub max_fee: u64,
-    pub target_token_id: u16,
-    /* required for swim propeller */
-    pub memo: [u8; 16],
-}
+    /// Returns how much more fees can be charged before reaching `max_fee`
+    pub fn remaining_fees(&self) -> u64 {
+        self.max_fee.saturating_sub(self.total_fees)
+    }
 
-impl RawSwimPayload {
-    pub const LEN: usize = 1 + //version
-        32 + //owner
-        1 + // propeller_enabled
-        1 + // gas_kickstart
-        8 + // max_fee
-        2 +    // target_token_id
-        16; // memo
-}
+    /// Returns `true` if `swim_payload_expiry` seconds have passed since the swim payload was completed
+    /// (or released if it was held)
+    pub fn is_expired(&self, swim_payload_expiry: i64, current_ts: i64) -> bool {
+        current_ts.saturating_sub(self.completed_ts.max(self.release_ts)) >= swim_payload_expiry
+    }
 
-#[repr(u8)]
-#[derive(PartialEq, Debug, Clone)]
-pub enum SwimPayloadVersion {
-    V0 = 0,
-    V1 = 1,
-}
+    /// Fails if the swim payload is still held by the rate limits at `current_ts`
+    pub fn require_released(&self, current_ts: i64) -> Result<()> {
+        require_gte!(current_ts, self.release_ts, PropellerError::SwimPayloadHeld);
+        Ok(())
+    }
 
-impl AnchorDeserialize for RawSwimPayload {
-    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
-        let mut v = Cursor::new(buf);
-
-        //TODO: add some error handling/checking here if payload version is incorrect.
-        //  https://stackoverflow.com/questions/28028854/how-do-i-match-enum-values-with-an-integer
-        let swim_payload_version = v.read_u8()?;
-        // if swim_payload_version == 1 {
-        //     deseraialize_swim_payload_v1()
-        // }
-        if swim_payload_version != CURRENT_SWIM_PAYLOAD_VERSION {
-            return Err(std::io::Error::new(ErrorKind::InvalidInput, "Wrong Swim Payload Version".to_string()));
+    /// Fails if `payer` isn't allowed to run the propeller ixs for this swim payload at `current_ts`
+    pub fn require_relayer(&self, payer: &Pubkey, current_ts: i64) -> Result<()> {
+        if current_ts < self.exclusivity_deadline {
+            require!(
+                *payer == self.exclusive_relayer || *payer == self.owner,
+                PropellerError::RelayerExclusivityActive
+            );
         }
+        Ok(())
+    }
 
-        let mut owner: [u8; 32] = Address::default();
-        v.read_exact(&mut owner)?;
-
-        /* optional fields */
-        match v.read_u8() {
-            Ok(propeller_enabled_val) => {
-                let propeller_enabled = !(propeller_enabled_val == 0);
-                let gas_kickstart = !(v.read_u8()? == 0);
-                let max_fee = v.read_u64::<BigEndian>()?;
-                let target_token_id = v.read_u16::<BigEndian>()?;
-                // optional memo field
-                let mut memo: [u8; 16] = [0; 16];
-                if let Ok(_) = v.read_exact(&mut memo) {
-                    Ok(RawSwimPayload {
-                        swim_payload_version,
-                        owner,
-                        propeller_enabled,
-                        gas_kickstart,
-                        max_fee,
-                        target_token_id,
-                        memo,
-                    })
-                } else {
-                    Ok(RawSwimPayload {
-                        swim_payload_version,
-                        owner,
-                        propeller_enabled,
-                        gas_kickstart,
-                        max_fee,
-                        target_token_id,
-                        memo: [0; 16],
-                    })
-                }
-            }
-            Err(error) => match error.kind() {
-                ErrorKind::UnexpectedEof => Ok(RawSwimPayload { swim_payload_version, owner, ..Default::default() }),
-                _ => return Err(error),
-            },
-        }
+    pub fn is_swim_receiver(&self) -> bool {
+        self.receiver_data.is_some()
     }
-}
 
-impl AnchorSerialize for RawSwimPayload {
-    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        // Payload ID
-        // writer.write_u8(self.swim_payload_version)?;
-        writer.write_u8(CURRENT_SWIM_PAYLOAD_VERSION)?;
-
-        writer.write_all(&self.owner)?;
-        writer.write_u8(if self.propeller_enabled { 1 } else { 0 })?;
-        writer.write_u8(if self.gas_kickstart { 1 } else { 0 })?;
-        writer.write_u64::<BigEndian>(self.max_fee)?;
-        writer.write_u16::<BigEndian>(self.target_token_id)?;
-        writer.write_all(&self.memo)?;
-        Ok(())
+    /// Authority of the owner's token accounts & recipient of the gas kickstart.
+    /// If the owner is a swim receiver program this is a PDA controlled by the program.
+    pub fn token_account_owner(&self) -> Pubkey {
+        if self.is_swim_receiver() {
+            get_swim_receiver_authority(&self.owner)
+        } else {
+            self.owner
+        }
     }
 }
 
@@ -312,7 +417,56 @@ pub fn validate_marginal_prices_pool_accounts(
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {super::*, crate::swim_payload::SwimPayloadVersion};
+
+    #[test]
+    fn test_propeller_len() {
+        let propeller = Propeller {
+            bump: 0,
+            nonce: 0,
+            admin: Pubkey::default(),
+            prepared_admin: Pubkey::default(),
+            admin_transition_ts: 0,
+            pause_key: Pubkey::default(),
+            is_paused: false,
+            is_inbound_paused: false,
+            is_outbound_paused: false,
+            wormhole: Pubkey::default(),
+            token_bridge: Pubkey::default(),
+            swim_usd_mint: Pubkey::default(),
+            sender_bump: 0,
+            redeemer_bump: 0,
+            gas_kickstart_amount: 0,
+            secp_verify_init_fee: 0,
+            secp_verify_fee: 0,
+            post_vaa_fee: 0,
+            init_ata_fee: 0,
+            complete_with_payload_fee: 0,
+            process_swim_payload_fee: 0,
+            swim_payload_expiry: 0,
+            fee_price_cache_max_age: 0,
+            inbound_rate_limit: RateLimit::default(),
+            outbound_rate_limit: RateLimit::default(),
+            rate_limit_action: RateLimitAction::default(),
+            large_transfer_threshold: 0,
+            large_transfer_delay: 0,
+            marginal_price_pool: Pubkey::default(),
+            marginal_price_pool_token_mint: Pubkey::default(),
+            marginal_price_pool_token_index: 0,
+            marginal_price_twap_window: 0,
+            fee_vault: Pubkey::default(),
+            aggregator: Pubkey::default(),
+            max_staleness: 0,
+            max_confidence_interval: BorshDecimal::default(),
+            oracle_policy: OraclePolicy::Primary,
+            fallback_oracle: Pubkey::default(),
+            sol_usd_pool: Pubkey::default(),
+            sol_usd_pool_sol_index: 0,
+            sol_usd_pool_twap_window: 0,
+            sol_usd_pool_max_staleness: 0,
+        };
+        assert_eq!(propeller.try_to_vec().unwrap().len(), Propeller::LEN);
+    }
 
     #[test]
     fn test_non_propeller_swim_payload() {}
@@ -320,6 +474,93 @@ mod tests {
     #[test]
     fn test_propeller_swim_payload() {}
 
+    fn swim_payload_message() -> SwimPayloadMessage {
+        SwimPayloadMessage {
+            bump: 0,
+            swim_payload_message_payer: Pubkey::default(),
+            claim: Pubkey::default(),
+            vaa_emitter_address: [0u8; 32],
+            vaa_emitter_chain: 0,
+            vaa_sequence: 0,
+            transfer_amount: 100,
+            swim_payload_version: SwimPayloadVersion::V2 as u8,
+            owner: Pubkey::default(),
+            propeller_enabled: true,
+            gas_kickstart: false,
+            max_fee: 10,
+            target_token_id: 0,
+            memo: [0u8; 16],
+            min_output_amount: 0,
+            completed_ts: 1_000,
+            total_fees: 0,
+            sender: [0u8; 32],
+            receiver_data: None,
+            exclusive_relayer: Pubkey::default(),
+            exclusivity_deadline: 0,
+            release_ts: 0,
+        }
+    }
+
+    #[test]
+    fn test_third_party_swim_payload() {
+        let mut swim_payload_message = swim_payload_message();
+        swim_payload_message.owner = Pubkey::new_unique();
+        assert!(!swim_payload_message.is_swim_receiver());
+        assert_eq!(swim_payload_message.token_account_owner(), swim_payload_message.owner);
+
+        swim_payload_message.receiver_data = Some(vec![1, 2, 3]);
+        assert!(swim_payload_message.is_swim_receiver());
+        let (receiver_authority, _) =
+            Pubkey::find_program_address(&[b"swim_receiver".as_ref()], &swim_payload_message.owner);
+        assert_eq!(swim_payload_message.token_account_owner(), receiver_authority);
+    }
+
+    #[test]
+    fn test_swim_payload_message_add_fees() {
+        let mut swim_payload_message = swim_payload_message();
+        swim_payload_message.add_fees(4).unwrap();
+        swim_payload_message.add_fees(6).unwrap();
+        assert_eq!(swim_payload_message.total_fees, 10);
+        assert!(swim_payload_message.add_fees(1).is_err());
+        assert_eq!(swim_payload_message.total_fees, 10);
+        assert_eq!(swim_payload_message.remaining_fees(), 0);
+    }
+
+    #[test]
+    fn test_swim_payload_message_relayer_exclusivity() {
+        let mut swim_payload_message = swim_payload_message();
+        let relayer = Pubkey::new_unique();
+        let other_relayer = Pubkey::new_unique();
+        swim_payload_message.owner = Pubkey::new_unique();
+        swim_payload_message.require_relayer(&other_relayer, 1_000).unwrap();
+
+        swim_payload_message.exclusive_relayer = relayer;
+        swim_payload_message.exclusivity_deadline = 2_000;
+        swim_payload_message.require_relayer(&relayer, 1_000).unwrap();
+        swim_payload_message.require_relayer(&swim_payload_message.owner, 1_000).unwrap();
+        assert!(swim_payload_message.require_relayer(&other_relayer, 1_999).is_err());
+        swim_payload_message.require_relayer(&other_relayer, 2_000).unwrap();
+    }
+
     #[test]
-    fn test_third_party_swim_payload() {}
+    fn test_swim_payload_message_expiry() {
+        let mut swim_payload_message = swim_payload_message();
+        assert!(!swim_payload_message.is_expired(100, 1_099));
+        assert!(swim_payload_message.is_expired(100, 1_100));
+        assert!(swim_payload_message.is_expired(100, 2_000));
+        swim_payload_message.add_fees(4).unwrap();
+        assert_eq!(swim_payload_message.remaining_fees(), 6);
+    }
+
+    #[test]
+    fn test_swim_payload_message_release() {
+        let mut swim_payload_message = swim_payload_message();
+        swim_payload_message.require_released(1_000).unwrap();
+        swim_payload_message.release_ts = 1_500;
+        assert!(swim_payload_message.require_released(1_499).is_err());
+        swim_payload_message.require_released(1_500).unwrap();
+        // expiry starts once released
+        assert!(!swim_payload_message.is_expired(100, 1_599));
+        assert!(swim_payload_message.is_expired(100, 1_600));
+    }
 }
@@ -0,0 +1,228 @@
+use {crate::error::PropellerError, anchor_lang::prelude::*};
+
+/// Flow limit on the amount of swimUSD (in atomic units) that can be transferred within a rolling `window`.
+///
+/// Uses a sliding window counter: the amount transferred in the previous fixed window is weighted by how much
+/// of it still overlaps with the rolling window ending at `now`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct RateLimit {
+    /// max amount per `window`. 0 => no limit
+    pub max_amount: u64,
+    /// in seconds
+    pub window: i64,
+    pub current_window_start: i64,
+    pub current_amount: u64,
+    pub previous_amount: u64,
+}
+
+impl RateLimit {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8;
+
+    pub fn is_enabled(&self) -> bool {
+        self.max_amount > 0
+    }
+
+    /// Updates the limit while keeping the amounts already transferred in the current & previous windows
+    pub fn set_limit(&mut self, config: RateLimitConfig) {
+        if config.window != self.window {
+            // the fixed windows no longer line up
+            self.current_window_start = 0;
+            self.previous_amount = 0;
+        }
+        self.max_amount = config.max_amount;
+        self.window = config.window;
+    }
+
+    /// Moves the fixed windows forward so that `now` falls into the current window
+    fn roll(&mut self, now: i64) {
+        if self.window <= 0 {
+            return;
+        }
+        let elapsed = now.saturating_sub(self.current_window_start);
+        if elapsed < self.window {
+            return;
+        }
+        self.previous_amount = if elapsed < 2 * self.window { self.current_amount } else { 0 };
+        self.current_amount = 0;
+        self.current_window_start += (elapsed / self.window) * self.window;
+    }
+
+    /// Amount transferred within the rolling window ending at `now`
+    pub fn usage(&self, now: i64) -> u64 {
+        let mut rate_limit = *self;
+        rate_limit.roll(now);
+        if rate_limit.window <= 0 {
+            return rate_limit.current_amount;
+        }
+        let elapsed = now.saturating_sub(rate_limit.current_window_start).clamp(0, rate_limit.window) as u128;
+        let window = rate_limit.window as u128;
+        let weighted_previous_amount = rate_limit.previous_amount as u128 * (window - elapsed) / window;
+        (weighted_previous_amount + rate_limit.current_amount as u128).min(u64::MAX as u128) as u64
+    }
+
+    pub fn would_exceed(&self, amount: u64, now: i64) -> bool {
+        self.is_enabled() && self.usage(now).saturating_add(amount) > self.max_amount
+    }
+
+    /// Earliest unix timestamp (>= `now`) at which `amount` can be transferred without exceeding the limit,
+    /// assuming nothing else is recorded in the meantime. An `amount` above `max_amount` has to wait until
+    /// the rolling window is empty.
+    ///
+    /// Amounts recorded at a timestamp after `now` (i.e. queued transfers) count in full until then,
+    /// so a transfer can only be scheduled after the capacity taken up by the transfers queued before it.
+    pub fn next_available_ts(&self, amount: u64, now: i64) -> i64 {
+        if !self.would_exceed(amount, now) || self.window <= 0 {
+            return now;
+        }
+        let mut rate_limit = *self;
+        rate_limit.roll(now);
+        let window = rate_limit.window as u128;
+        let capacity = rate_limit.max_amount.saturating_sub(amount) as u128;
+        let previous_amount = rate_limit.previous_amount as u128;
+        let current_amount = rate_limit.current_amount as u128;
+        let available_ts = if current_amount <= capacity {
+            // enough of the previous window slides out of the rolling window before the current window ends
+            let elapsed =
+                (window * (previous_amount - (capacity - current_amount)) + previous_amount - 1) / previous_amount;
+            rate_limit.current_window_start + elapsed as i64
+        } else {
+            // the current window becomes the previous one & has to slide out of the rolling window
+            let elapsed = (window * (current_amount - capacity) + current_amount - 1) / current_amount;
+            rate_limit.current_window_start + rate_limit.window + elapsed as i64
+        };
+        available_ts.max(now)
+    }
+
+    pub fn record(&mut self, amount: u64, now: i64) -> Result<()> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        self.roll(now);
+        self.current_amount = self.current_amount.checked_add(amount).ok_or(PropellerError::IntegerOverflow)?;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RateLimitConfig {
+    /// 0 => no limit
+    pub max_amount: u64,
+    /// in seconds
+    pub window: i64,
+}
+
+impl RateLimitConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.max_amount == 0 || self.window > 0, PropellerError::InvalidRateLimitConfig);
+        Ok(())
+    }
+}
+
+/// What happens to an inbound transfer that would exceed a rate limit.
+/// Outbound transfers exceeding a rate limit are always rejected.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RateLimitAction {
+    /// `complete_native_with_payload` fails
+    Reject,
+    /// the transfer is completed but its swim payload is held until the exceeded limit(s) have capacity for it
+    /// (after the transfers queued before it)
+    Queue,
+}
+
+impl Default for RateLimitAction {
+    fn default() -> Self {
+        RateLimitAction::Reject
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit(max_amount: u64, window: i64) -> RateLimit {
+        let mut rate_limit = RateLimit::default();
+        rate_limit.set_limit(RateLimitConfig { max_amount, window });
+        rate_limit
+    }
+
+    #[test]
+    fn test_rate_limit_disabled() {
+        let mut rate_limit = RateLimit::default();
+        assert!(!rate_limit.would_exceed(u64::MAX, 1_000));
+        rate_limit.record(u64::MAX, 1_000).unwrap();
+        rate_limit.record(u64::MAX, 1_000).unwrap();
+        assert_eq!(rate_limit.usage(1_000), 0);
+    }
+
+    #[test]
+    fn test_rate_limit_current_window() {
+        let mut rate_limit = rate_limit(1_000, 100);
+        rate_limit.record(600, 1_000).unwrap();
+        assert_eq!(rate_limit.usage(1_050), 600);
+        assert!(!rate_limit.would_exceed(400, 1_050));
+        assert!(rate_limit.would_exceed(401, 1_050));
+    }
+
+    #[test]
+    fn test_rate_limit_sliding_window() {
+        let mut rate_limit = rate_limit(1_000, 100);
+        rate_limit.record(1_000, 1_000).unwrap();
+        assert_eq!(rate_limit.current_window_start, 1_000);
+        // half of the previous window still overlaps the rolling window
+        assert_eq!(rate_limit.usage(1_150), 500);
+        assert!(rate_limit.would_exceed(501, 1_150));
+        rate_limit.record(500, 1_150).unwrap();
+        assert_eq!(rate_limit.current_window_start, 1_100);
+        assert_eq!(rate_limit.previous_amount, 1_000);
+        assert_eq!(rate_limit.current_amount, 500);
+        assert_eq!(rate_limit.usage(1_190), 600);
+        assert_eq!(rate_limit.usage(1_250), 250);
+        // nothing was transferred in the previous window
+        assert_eq!(rate_limit.usage(1_300), 0);
+        assert!(!rate_limit.would_exceed(1_000, 1_300));
+    }
+
+    #[test]
+    fn test_rate_limit_set_limit() {
+        let mut rate_limit = rate_limit(1_000, 100);
+        rate_limit.record(800, 1_000).unwrap();
+        rate_limit.set_limit(RateLimitConfig { max_amount: 2_000, window: 100 });
+        assert_eq!(rate_limit.usage(1_000), 800);
+        assert!(!rate_limit.would_exceed(1_200, 1_000));
+        assert!(RateLimitConfig { max_amount: 1, window: 0 }.validate().is_err());
+        RateLimitConfig { max_amount: 0, window: 0 }.validate().unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit_next_available_ts() {
+        let mut rate_limit = rate_limit(1_000, 100);
+        assert_eq!(rate_limit.next_available_ts(1_000, 1_000), 1_000);
+        rate_limit.record(1_000, 1_000).unwrap();
+        // the previous window has to slide out of the rolling window by 500 / 1_000
+        assert_eq!(rate_limit.next_available_ts(500, 1_050), 1_150);
+        assert_eq!(rate_limit.usage(1_150) + 500, 1_000);
+        assert_eq!(rate_limit.next_available_ts(1_000, 1_050), 1_200);
+        // amounts above the limit have to wait until the rolling window is empty
+        assert_eq!(rate_limit.next_available_ts(2_000, 1_050), 1_200);
+        rate_limit.record(300, 1_150).unwrap();
+        // the previous window has to slide out of the rolling window by 700 / 1_000
+        assert_eq!(rate_limit.next_available_ts(400, 1_150), 1_170);
+        assert_eq!(rate_limit.usage(1_170) + 400, 1_000);
+    }
+
+    #[test]
+    fn test_rate_limit_queued_transfers() {
+        let mut rate_limit = rate_limit(1_000, 100);
+        rate_limit.record(1_000, 1_000).unwrap();
+        // two over-limit transfers are queued one after the other
+        let first_release_ts = rate_limit.next_available_ts(800, 1_010);
+        rate_limit.record(800, first_release_ts).unwrap();
+        let second_release_ts = rate_limit.next_available_ts(800, 1_020);
+        rate_limit.record(800, second_release_ts).unwrap();
+        assert_eq!(first_release_ts, 1_180);
+        assert!(second_release_ts > first_release_ts);
+        assert!(rate_limit.usage(second_release_ts) <= 1_000);
+        // the queued transfers take up the capacity until they're released
+        assert!(rate_limit.would_exceed(1, 1_030));
+    }
+}
@@ -0,0 +1,635 @@
+use {
+    crate::{error::PropellerError, swim_receiver::MAX_RECEIVER_DATA_LEN, Address},
+    anchor_lang::prelude::*,
+    byteorder::{BigEndian, ReadBytesExt, WriteBytesExt},
+    std::io::{Error, ErrorKind, Read, Write},
+};
+
+/// Versions of the swim payload that can be found in a token bridge transfer with payload.
+///
+/// Every version ever emitted by a routing contract has to stay decodable so that
+/// routing contracts on other chains can be upgraded independently of the propeller.
+#[repr(u8)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum SwimPayloadVersion {
+    V1 = 1,
+    /// adds `min_output_amount` after `target_token_id`
+    V2 = 2,
+    /// adds `receiver_data` after `min_output_amount`
+    V3 = 3,
+    /// adds `relayer_exclusivity` after `receiver_data`
+    V4 = 4,
+}
+
+impl TryFrom<u8> for SwimPayloadVersion {
+    type Error = Error;
+
+    fn try_from(version: u8) -> std::io::Result<Self> {
+        match version {
+            1 => Ok(Self::V1),
+            2 => Ok(Self::V2),
+            3 => Ok(Self::V3),
+            4 => Ok(Self::V4),
+            _ => Err(Error::new(ErrorKind::InvalidInput, format!("Unsupported Swim Payload Version {}", version))),
+        }
+    }
+}
+
+/// Swim payload layout (all integers are big endian):
+///
+/// | field             | bytes | versions |
+/// |-------------------|-------|----------|
+/// | version           | 1     | all      |
+/// | owner             | 32    | all      |
+/// | propeller_enabled | 1     | all      |
+/// | gas_kickstart     | 1     | all      |
+/// | max_fee           | 8     | all      |
+/// | target_token_id   | 2     | all      |
+/// | min_output_amount | 8     | V2+      |
+/// | has_receiver_data | 1     | V3+      |
+/// | receiver_data_len | 2     | V3+      |
+/// | receiver_data     | len   | V3+      |
+/// | has_exclusivity   | 1     | V4+      |
+/// | relayer           | 32    | V4+      |
+/// | duration          | 4     | V4+      |
+/// | memo              | 16    | all      |
+///
+/// Everything after the owner is optional as a whole (`propeller` is `None`)
+/// & the memo is optional on its own. `receiver_data_len` & `receiver_data`
+/// are only present if `has_receiver_data` is set, `relayer` & `duration`
+/// only if `has_exclusivity` is set.
+///
+/// The constructors use the lowest version that can encode the payload so that
+/// routing contracts that only decode older versions can still receive it.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SwimPayload {
+    pub version: SwimPayloadVersion,
+    pub owner: Address,
+    pub propeller: Option<PropellerParams>,
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct PropellerParams {
+    pub propeller_enabled: bool,
+    pub gas_kickstart: bool,
+    pub max_fee: u64,
+    pub target_token_id: u16,
+    /// always 0 for `SwimPayloadVersion::V1`
+    pub min_output_amount: u64,
+    /// if set, `owner` is a program implementing the swim receiver callback
+    /// & `receiver_data` is passed to it. always `None` before `SwimPayloadVersion::V3`
+    pub receiver_data: Option<Vec<u8>>,
+    /// always `None` before `SwimPayloadVersion::V4`
+    pub relayer_exclusivity: Option<RelayerExclusivity>,
+    pub memo: Option<[u8; 16]>,
+}
+
+/// Only `relayer` (or the owner) may run the propeller ixs for the swim payload
+/// until `duration` seconds after the wormhole message was observed.
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub struct RelayerExclusivity {
+    /// address of the relayer on the target chain
+    pub relayer: [u8; 32],
+    pub duration: u32,
+}
+
+impl SwimPayload {
+    /// max length of an encoded `SwimPayloadVersion::V2` payload.
+    /// `SwimPayloadVersion::V3` payloads are longer by `1 + 2 + receiver_data.len()`
+    pub const LEN: usize = 1 + //version
+        32 + //owner
+        1 + // propeller_enabled
+        1 + // gas_kickstart
+        8 + // max_fee
+        2 +    // target_token_id
+        8 + // min_output_amount
+        16; // memo
+
+    pub fn new_cross_chain(owner: &[u8]) -> Self {
+        let mut owner_addr = Address::default();
+        owner_addr.copy_from_slice(owner);
+        SwimPayload { version: SwimPayloadVersion::V1, owner: owner_addr, propeller: None }
+    }
+
+    /// `SwimPayloadVersion::V1` unless `min_output_amount` is set
+
+    pub fn new_propeller(
+        owner: &[u8],
+        gas_kickstart: bool,
+        max_fee: u64,
+        target_token_id: u16,
+        min_output_amount: u64,
+        memo: Option<[u8; 16]>,
+    ) -> Self {
+        SwimPayload {
+            propeller: Some(PropellerParams {
+                propeller_enabled: true,
+                gas_kickstart,
+                max_fee,
+                target_token_id,
+                min_output_amount,
+                receiver_data: None,
+                relayer_exclusivity: None,
+                memo,
+            }),
+            version: if min_output_amount > 0 { SwimPayloadVersion::V2 } else { SwimPayloadVersion::V1 },
+            ..Self::new_cross_chain(owner)
+        }
+    }
+
+    /// `receiver` is the program that will be invoked with `receiver_data` once the swim payload is processed
+    pub fn new_receiver(
+        receiver: &[u8],
+        gas_kickstart: bool,
+        max_fee: u64,
+        target_token_id: u16,
+        min_output_amount: u64,
+        receiver_data: Vec<u8>,
+        memo: Option<[u8; 16]>,
+    ) -> Self {
+        let mut swim_payload =
+            Self::new_propeller(receiver, gas_kickstart, max_fee, target_token_id, min_output_amount, memo);
+        swim_payload.version = swim_payload.version.max(SwimPayloadVersion::V3);
+        if let Some(propeller) = swim_payload.propeller.as_mut() {
+            propeller.receiver_data = Some(receiver_data);
+        }
+        swim_payload
+    }
+
+    /// Swim payload of the outbound propeller ixs. With `receiver_data`, `owner` is the program implementing
+    /// the swim receiver callback on the target chain & `receiver_data` is limited to `MAX_RECEIVER_DATA_LEN`.
+    /// `receiver_data` & `relayer_exclusivity` are only encoded if set
+    pub fn new_outbound_propeller(
+        owner: &[u8],
+        gas_kickstart: bool,
+        max_fee: u64,
+        target_token_id: u16,
+        min_output_amount: u64,
+        memo: Option<[u8; 16]>,
+        receiver_data: Option<Vec<u8>>,
+        relayer_exclusivity: Option<RelayerExclusivity>,
+    ) -> Result<Self> {
+        let swim_payload = match receiver_data {
+            Some(receiver_data) => {
+                require_gte!(MAX_RECEIVER_DATA_LEN, receiver_data.len(), PropellerError::SwimReceiverDataTooLong);
+                Self::new_receiver(
+                    owner,
+                    gas_kickstart,
+                    max_fee,
+                    target_token_id,
+                    min_output_amount,
+                    receiver_data,
+                    memo,
+                )
+            }
+            None => Self::new_propeller(owner, gas_kickstart, max_fee, target_token_id, min_output_amount, memo),
+        };
+        Ok(match relayer_exclusivity {
+            Some(RelayerExclusivity { relayer, duration }) => swim_payload.with_relayer_exclusivity(&relayer, duration),
+            None => swim_payload,
+        })
+    }
+
+    /// Restricts the propeller ixs to `relayer` for `duration` seconds. No-op without propeller params
+    pub fn with_relayer_exclusivity(mut self, relayer: &[u8], duration: u32) -> Self {
+        if let Some(propeller) = self.propeller.as_mut() {
+            let mut relayer_addr = Address::default();
+            relayer_addr.copy_from_slice(relayer);
+            propeller.relayer_exclusivity = Some(RelayerExclusivity { relayer: relayer_addr, duration });
+            self.version = self.version.max(SwimPayloadVersion::V4);
+        }
+        self
+    }
+
+    pub fn propeller_enabled(&self) -> bool {
+        self.propeller.as_ref().map_or(false, |propeller| propeller.propeller_enabled)
+    }
+
+    pub fn gas_kickstart(&self) -> bool {
+        self.propeller.as_ref().map_or(false, |propeller| propeller.gas_kickstart)
+    }
+
+    pub fn max_fee(&self) -> u64 {
+        self.propeller.as_ref().map_or(0, |propeller| propeller.max_fee)
+    }
+
+    pub fn target_token_id(&self) -> u16 {
+        self.propeller.as_ref().map_or(0, |propeller| propeller.target_token_id)
+    }
+
+    pub fn min_output_amount(&self) -> u64 {
+        self.propeller.as_ref().map_or(0, |propeller| propeller.min_output_amount)
+    }
+
+    pub fn receiver_data(&self) -> Option<&Vec<u8>> {
+        self.propeller.as_ref().and_then(|propeller| propeller.receiver_data.as_ref())
+    }
+
+    pub fn relayer_exclusivity(&self) -> Option<RelayerExclusivity> {
+        self.propeller.as_ref().and_then(|propeller| propeller.relayer_exclusivity)
+    }
+
+    pub fn memo(&self) -> Option<[u8; 16]> {
+        self.propeller.as_ref().and_then(|propeller| propeller.memo)
+    }
+}
+
+impl AnchorDeserialize for SwimPayload {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let version = SwimPayloadVersion::try_from(buf.read_u8()?)?;
+        let mut owner = Address::default();
+        buf.read_exact(&mut owner)?;
+        if buf.is_empty() {
+            return Ok(SwimPayload { version, owner, propeller: None });
+        }
+
+        let propeller_enabled = buf.read_u8()? != 0;
+        let gas_kickstart = buf.read_u8()? != 0;
+        let max_fee = buf.read_u64::<BigEndian>()?;
+        let target_token_id = buf.read_u16::<BigEndian>()?;
+        let min_output_amount = if version >= SwimPayloadVersion::V2 { buf.read_u64::<BigEndian>()? } else { 0 };
+        let receiver_data = if version >= SwimPayloadVersion::V3 && read_flag(buf)? {
+            let mut receiver_data = vec![0u8; buf.read_u16::<BigEndian>()? as usize];
+            buf.read_exact(&mut receiver_data)?;
+            Some(receiver_data)
+        } else {
+            None
+        };
+        let relayer_exclusivity = if version >= SwimPayloadVersion::V4 && read_flag(buf)? {
+            let mut relayer = Address::default();
+            buf.read_exact(&mut relayer)?;
+            Some(RelayerExclusivity { relayer, duration: buf.read_u32::<BigEndian>()? })
+        } else {
+            None
+        };
+        let memo = if buf.is_empty() {
+            None
+        } else {
+            let mut memo = [0u8; 16];
+            buf.read_exact(&mut memo)?;
+            Some(memo)
+        };
+        Ok(SwimPayload {
+            version,
+            owner,
+            propeller: Some(PropellerParams {
+                propeller_enabled,
+                gas_kickstart,
+                max_fee,
+                target_token_id,
+                min_output_amount,
+                receiver_data,
+                relayer_exclusivity,
+                memo,
+            }),
+        })
+    }
+}
+
+fn read_flag(buf: &mut &[u8]) -> std::io::Result<bool> {
+    match buf.read_u8()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(Error::new(ErrorKind::InvalidInput, "Invalid Swim Payload flag")),
+    }
+}
+
+fn unsupported_field(field: &str, version: SwimPayloadVersion) -> Error {
+    Error::new(ErrorKind::InvalidInput, format!("{} is not supported by Swim Payload {:?}", field, version))
+}
+
+impl AnchorSerialize for SwimPayload {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_u8(self.version as u8)?;
+        writer.write_all(&self.owner)?;
+        if let Some(propeller) = &self.propeller {
+            writer.write_u8(propeller.propeller_enabled as u8)?;
+            writer.write_u8(propeller.gas_kickstart as u8)?;
+            writer.write_u64::<BigEndian>(propeller.max_fee)?;
+            writer.write_u16::<BigEndian>(propeller.target_token_id)?;
+            if self.version >= SwimPayloadVersion::V2 {
+                writer.write_u64::<BigEndian>(propeller.min_output_amount)?;
+            } else if propeller.min_output_amount != 0 {
+                return Err(unsupported_field("min_output_amount", self.version));
+            }
+            if self.version >= SwimPayloadVersion::V3 {
+                writer.write_u8(propeller.receiver_data.is_some() as u8)?;
+                if let Some(receiver_data) = &propeller.receiver_data {
+                    let receiver_data_len = u16::try_from(receiver_data.len())
+                        .map_err(|_| Error::new(ErrorKind::InvalidInput, "receiver_data is too long"))?;
+                    writer.write_u16::<BigEndian>(receiver_data_len)?;
+                    writer.write_all(receiver_data)?;
+                }
+            } else if propeller.receiver_data.is_some() {
+                return Err(unsupported_field("receiver_data", self.version));
+            }
+            if self.version >= SwimPayloadVersion::V4 {
+                writer.write_u8(propeller.relayer_exclusivity.is_some() as u8)?;
+                if let Some(relayer_exclusivity) = &propeller.relayer_exclusivity {
+                    writer.write_all(&relayer_exclusivity.relayer)?;
+                    writer.write_u32::<BigEndian>(relayer_exclusivity.duration)?;
+                }
+            } else if propeller.relayer_exclusivity.is_some() {
+                return Err(unsupported_field("relayer_exclusivity", self.version));
+            }
+            if let Some(memo) = &propeller.memo {
+                writer.write_all(memo)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OWNER: Address = [1u8; 32];
+    const MEMO: [u8; 16] = [2u8; 16];
+    const RELAYER: Address = [9u8; 32];
+
+    fn propeller_params(version: SwimPayloadVersion, memo: Option<[u8; 16]>) -> PropellerParams {
+        PropellerParams {
+            propeller_enabled: true,
+            gas_kickstart: true,
+            max_fee: 1_000,
+            target_token_id: 0x0102,
+            min_output_amount: if version == SwimPayloadVersion::V1 { 0 } else { 5_000 },
+            receiver_data: None,
+            relayer_exclusivity: None,
+            memo,
+        }
+    }
+
+    fn all_payloads() -> Vec<SwimPayload> {
+        let mut payloads = vec![];
+        for version in [SwimPayloadVersion::V1, SwimPayloadVersion::V2, SwimPayloadVersion::V3, SwimPayloadVersion::V4]
+        {
+            payloads.push(SwimPayload { version, owner: OWNER, propeller: None });
+            for memo in [None, Some(MEMO)] {
+                payloads.push(SwimPayload { version, owner: OWNER, propeller: Some(propeller_params(version, memo)) });
+            }
+        }
+        for receiver_data in [vec![], vec![3u8; 100]] {
+            for memo in [None, Some(MEMO)] {
+                payloads.push(SwimPayload::new_receiver(&OWNER, false, 1_000, 1, 5_000, receiver_data.clone(), memo));
+            }
+        }
+        for memo in [None, Some(MEMO)] {
+            payloads.push(
+                SwimPayload::new_propeller(&OWNER, true, 1_000, 1, 5_000, memo).with_relayer_exclusivity(&RELAYER, 60),
+            );
+            payloads.push(
+                SwimPayload::new_receiver(&OWNER, true, 1_000, 1, 5_000, vec![3u8; 10], memo)
+                    .with_relayer_exclusivity(&RELAYER, 60),
+            );
+        }
+        payloads
+    }
+
+    #[test]
+    fn test_round_trip_all_versions() {
+        for swim_payload in all_payloads() {
+            let encoded = swim_payload.try_to_vec().unwrap();
+            assert_eq!(SwimPayload::try_from_slice(&encoded).unwrap(), swim_payload);
+        }
+    }
+
+    #[test]
+    fn test_max_len() {
+        let swim_payload = SwimPayload::new_propeller(&OWNER, true, 1_000, 1, 5_000, Some(MEMO));
+        assert_eq!(swim_payload.try_to_vec().unwrap().len(), SwimPayload::LEN);
+        let swim_payload = SwimPayload::new_cross_chain(&OWNER);
+        assert_eq!(swim_payload.try_to_vec().unwrap().len(), 1 + 32);
+        assert!(!swim_payload.propeller_enabled());
+        assert_eq!(swim_payload.memo(), None);
+    }
+
+    #[test]
+    fn test_encode_lowest_version() {
+        let encoded = SwimPayload::new_cross_chain(&OWNER).try_to_vec().unwrap();
+        assert_eq!(encoded[0], SwimPayloadVersion::V1 as u8);
+
+        // without a min_output_amount the payload is encoded as V1
+        let swim_payload = SwimPayload::new_propeller(&OWNER, true, 1_000, 7, 0, Some(MEMO));
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V1);
+        let mut expected_encoded = vec![1u8];
+        expected_encoded.extend_from_slice(&OWNER);
+        expected_encoded.extend_from_slice(&[1, 1]);
+        expected_encoded.extend_from_slice(&1_000u64.to_be_bytes());
+        expected_encoded.extend_from_slice(&7u16.to_be_bytes());
+        expected_encoded.extend_from_slice(&MEMO);
+        assert_eq!(swim_payload.try_to_vec().unwrap(), expected_encoded);
+
+        let swim_payload = SwimPayload::new_propeller(&OWNER, true, 1_000, 7, 5_000, None);
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V2);
+        let mut expected_encoded = vec![2u8];
+        expected_encoded.extend_from_slice(&OWNER);
+        expected_encoded.extend_from_slice(&[1, 1]);
+        expected_encoded.extend_from_slice(&1_000u64.to_be_bytes());
+        expected_encoded.extend_from_slice(&7u16.to_be_bytes());
+        expected_encoded.extend_from_slice(&5_000u64.to_be_bytes());
+        assert_eq!(swim_payload.try_to_vec().unwrap(), expected_encoded);
+
+        let swim_payload = SwimPayload::new_receiver(&OWNER, true, 1_000, 7, 0, vec![4u8], None);
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V3);
+        assert_eq!(swim_payload.try_to_vec().unwrap()[0], SwimPayloadVersion::V3 as u8);
+        let swim_payload =
+            SwimPayload::new_propeller(&OWNER, true, 1_000, 7, 0, None).with_relayer_exclusivity(&RELAYER, 1);
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V4);
+        assert_eq!(swim_payload.try_to_vec().unwrap()[0], SwimPayloadVersion::V4 as u8);
+    }
+
+    #[test]
+    fn test_decode_v1_bytes() {
+        // encoded by a V1 routing contract
+        let mut encoded = vec![1u8];
+        encoded.extend_from_slice(&OWNER);
+        encoded.extend_from_slice(&[1, 0]);
+        encoded.extend_from_slice(&1_000u64.to_be_bytes());
+        encoded.extend_from_slice(&7u16.to_be_bytes());
+        encoded.extend_from_slice(&MEMO);
+        let swim_payload = SwimPayload::try_from_slice(&encoded).unwrap();
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V1);
+        assert!(swim_payload.propeller_enabled());
+        assert!(!swim_payload.gas_kickstart());
+        assert_eq!(swim_payload.max_fee(), 1_000);
+        assert_eq!(swim_payload.target_token_id(), 7);
+        assert_eq!(swim_payload.min_output_amount(), 0);
+        assert_eq!(swim_payload.memo(), Some(MEMO));
+    }
+
+    #[test]
+    fn test_decode_v2_bytes() {
+        let mut encoded = vec![2u8];
+        encoded.extend_from_slice(&OWNER);
+        encoded.extend_from_slice(&[1, 1]);
+        encoded.extend_from_slice(&1_000u64.to_be_bytes());
+        encoded.extend_from_slice(&7u16.to_be_bytes());
+        encoded.extend_from_slice(&5_000u64.to_be_bytes());
+        let swim_payload = SwimPayload::try_from_slice(&encoded).unwrap();
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V2);
+        assert!(swim_payload.gas_kickstart());
+        assert_eq!(swim_payload.min_output_amount(), 5_000);
+        assert_eq!(swim_payload.memo(), None);
+    }
+
+    #[test]
+    fn test_decode_v3_bytes() {
+        let mut encoded = vec![3u8];
+        encoded.extend_from_slice(&OWNER);
+        encoded.extend_from_slice(&[1, 0]);
+        encoded.extend_from_slice(&1_000u64.to_be_bytes());
+        encoded.extend_from_slice(&7u16.to_be_bytes());
+        encoded.extend_from_slice(&5_000u64.to_be_bytes());
+        encoded.push(1);
+        encoded.extend_from_slice(&3u16.to_be_bytes());
+        encoded.extend_from_slice(&[4, 5, 6]);
+        encoded.extend_from_slice(&MEMO);
+        let swim_payload = SwimPayload::try_from_slice(&encoded).unwrap();
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V3);
+        assert_eq!(swim_payload.min_output_amount(), 5_000);
+        assert_eq!(swim_payload.receiver_data(), Some(&vec![4, 5, 6]));
+        assert_eq!(swim_payload.memo(), Some(MEMO));
+
+        // no receiver data
+        let mut encoded = encoded[..1 + 32 + 12 + 8].to_vec();
+        encoded.push(0);
+        let swim_payload = SwimPayload::try_from_slice(&encoded).unwrap();
+        assert_eq!(swim_payload.receiver_data(), None);
+        assert_eq!(swim_payload.memo(), None);
+    }
+
+    #[test]
+    fn test_decode_v4_bytes() {
+        let mut encoded = vec![4u8];
+        encoded.extend_from_slice(&OWNER);
+        encoded.extend_from_slice(&[1, 0]);
+        encoded.extend_from_slice(&1_000u64.to_be_bytes());
+        encoded.extend_from_slice(&7u16.to_be_bytes());
+        encoded.extend_from_slice(&5_000u64.to_be_bytes());
+        encoded.push(0);
+        encoded.push(1);
+        encoded.extend_from_slice(&RELAYER);
+        encoded.extend_from_slice(&120u32.to_be_bytes());
+        let swim_payload = SwimPayload::try_from_slice(&encoded).unwrap();
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V4);
+        assert_eq!(swim_payload.receiver_data(), None);
+        assert_eq!(swim_payload.relayer_exclusivity(), Some(RelayerExclusivity { relayer: RELAYER, duration: 120 }));
+        assert_eq!(swim_payload.memo(), None);
+        assert_eq!(
+            swim_payload,
+            SwimPayload::new_propeller(&OWNER, false, 1_000, 7, 5_000, None).with_relayer_exclusivity(&RELAYER, 120)
+        );
+        // exclusivity is ignored without propeller params
+        assert_eq!(
+            SwimPayload::new_cross_chain(&OWNER).with_relayer_exclusivity(&RELAYER, 120).version,
+            SwimPayloadVersion::V1
+        );
+    }
+
+    #[test]
+    fn test_cross_version() {
+        // a V2 payload re-encoded as V1 drops the min_output_amount
+        let v2_payload = SwimPayload {
+            version: SwimPayloadVersion::V2,
+            owner: OWNER,
+            propeller: Some(propeller_params(SwimPayloadVersion::V1, Some(MEMO))),
+        };
+        let v1_payload = SwimPayload { version: SwimPayloadVersion::V1, ..v2_payload.clone() };
+        let v1_encoded = v1_payload.try_to_vec().unwrap();
+        let v2_encoded = v2_payload.try_to_vec().unwrap();
+        let mut expected_v1_encoded = v2_encoded.clone();
+        expected_v1_encoded[0] = SwimPayloadVersion::V1 as u8;
+        expected_v1_encoded.drain(1 + 32 + 12..1 + 32 + 12 + 8);
+        assert_eq!(v1_encoded, expected_v1_encoded);
+        assert_eq!(SwimPayload::try_from_slice(&v1_encoded).unwrap(), v1_payload);
+
+        let invalid_v1_payload = SwimPayload {
+            version: SwimPayloadVersion::V1,
+            propeller: Some(propeller_params(SwimPayloadVersion::V2, None)),
+            ..v1_payload
+        };
+        assert!(invalid_v1_payload.try_to_vec().is_err());
+
+        let mut receiver_payload = SwimPayload::new_receiver(&OWNER, false, 1, 1, 1, vec![1u8], None);
+        receiver_payload.version = SwimPayloadVersion::V2;
+        assert!(receiver_payload.try_to_vec().is_err());
+
+        let mut exclusive_payload =
+            SwimPayload::new_propeller(&OWNER, false, 1, 1, 1, None).with_relayer_exclusivity(&RELAYER, 1);
+        exclusive_payload.version = SwimPayloadVersion::V3;
+        assert!(exclusive_payload.try_to_vec().is_err());
+    }
+
+    #[test]
+    fn test_encode_outbound_propeller() {
+        let swim_payload =
+            SwimPayload::new_outbound_propeller(&OWNER, true, 1_000, 7, 0, Some(MEMO), None, None).unwrap();
+        assert_eq!(swim_payload, SwimPayload::new_propeller(&OWNER, true, 1_000, 7, 0, Some(MEMO)));
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V1);
+
+        // exclusivity is only encoded if set
+        let relayer_exclusivity = RelayerExclusivity { relayer: RELAYER, duration: 60 };
+        let swim_payload =
+            SwimPayload::new_outbound_propeller(&OWNER, true, 1_000, 7, 5_000, None, None, Some(relayer_exclusivity))
+                .unwrap();
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V4);
+        let encoded = swim_payload.try_to_vec().unwrap();
+        assert_eq!(encoded.len(), SwimPayload::LEN - 16 + 1 + 1 + 32 + 4);
+        assert_eq!(SwimPayload::try_from_slice(&encoded).unwrap().relayer_exclusivity(), Some(relayer_exclusivity));
+
+        let receiver_data = vec![4u8; MAX_RECEIVER_DATA_LEN];
+        let swim_payload = SwimPayload::new_outbound_propeller(
+            &OWNER,
+            true,
+            1_000,
+            7,
+            0,
+            Some(MEMO),
+            Some(receiver_data.clone()),
+            None,
+        )
+        .unwrap();
+        assert_eq!(swim_payload.version, SwimPayloadVersion::V3);
+        let encoded = swim_payload.try_to_vec().unwrap();
+        assert_eq!(encoded.len(), SwimPayload::LEN + 1 + 2 + MAX_RECEIVER_DATA_LEN);
+        assert_eq!(SwimPayload::try_from_slice(&encoded).unwrap().receiver_data(), Some(&receiver_data));
+
+        let too_long_receiver_data = vec![4u8; MAX_RECEIVER_DATA_LEN + 1];
+        assert!(SwimPayload::new_outbound_propeller(
+            &OWNER,
+            true,
+            1_000,
+            7,
+            0,
+            None,
+            Some(too_long_receiver_data),
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_invalid_payloads() {
+        let mut encoded = SwimPayload::new_cross_chain(&OWNER).try_to_vec().unwrap();
+        encoded[0] = 0;
+        assert!(SwimPayload::try_from_slice(&encoded).is_err());
+        encoded[0] = 5;
+        assert!(SwimPayload::try_from_slice(&encoded).is_err());
+
+        let encoded = SwimPayload::new_propeller(&OWNER, false, 1, 1, 1, Some(MEMO)).try_to_vec().unwrap();
+        // truncated memo
+        assert!(SwimPayload::try_from_slice(&encoded[..encoded.len() - 1]).is_err());
+        // truncated min_output_amount
+        assert!(SwimPayload::try_from_slice(&encoded[..1 + 32 + 12 + 4]).is_err());
+
+        let encoded = SwimPayload::new_receiver(&OWNER, false, 1, 1, 1, vec![1u8; 4], None).try_to_vec().unwrap();
+        // truncated receiver_data
+        assert!(SwimPayload::try_from_slice(&encoded[..encoded.len() - 1]).is_err());
+        // invalid has_receiver_data flag
+        let mut invalid_encoded = encoded.clone();
+        invalid_encoded[1 + 32 + 12 + 8] = 2;
+        assert!(SwimPayload::try_from_slice(&invalid_encoded).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use {
+    crate::{error::PropellerError, events::InvokeSwimReceiverEvent, state::SwimPayloadMessage},
+    anchor_lang::{
+        prelude::*,
+        solana_program::{
+            hash::hash,
+            instruction::{AccountMeta, Instruction},
+            program::invoke_signed,
+        },
+    },
+    anchor_spl::token::TokenAccount,
+};
+
+/// Seed of the PDA (derived with the receiver program) that is the authority of the
+/// receiver's token accounts & the recipient of the gas kickstart.
+pub const SWIM_RECEIVER_AUTHORITY_SEED: &[u8] = b"swim_receiver";
+/// Seed of the propeller PDA that signs the `swim_receive` callback so that receivers
+/// can verify they were invoked by the propeller. It is not the authority of any account.
+pub const SWIM_RECEIVER_CALLER_SEED: &[u8] = b"swim_receiver_caller";
+/// Max length of the `receiver_data` of a swim payload that can be completed on Solana.
+/// Also enforced on the swim payloads of outbound transfers
+pub const MAX_RECEIVER_DATA_LEN: usize = 512;
+
+/// Args of the `swim_receive` ix a receiver program has to implement.
+///
+/// The ix data is the anchor sighash of `global:swim_receive` followed by the borsh
+/// serialized `SwimReceiveParams` so that anchor programs can implement it as
+/// `pub fn swim_receive(ctx: Context<SwimReceive>, params: SwimReceiveParams)`.
+///
+/// Accounts:
+/// 0. `[signer]` swim receiver caller. PDA of the propeller derived from `[SWIM_RECEIVER_CALLER_SEED]`
+/// 1. `[writable]` receiver token account that received `amount` of `token_mint`
+/// 2. .. accounts passed to the propeller ix after the receiver program & the swim receiver caller.
+///    They're forwarded as non-signers, so the swim receiver caller is the only signer of the callback
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Clone)]
+pub struct SwimReceiveParams {
+    /// wormhole chain id of the source chain
+    pub source_chain: u16,
+    /// address that initiated the token bridge transfer on the source chain
+    pub sender: [u8; 32],
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub data: Vec<u8>,
+}
+
+impl SwimReceiveParams {
+    pub fn to_ix_data(&self) -> Result<Vec<u8>> {
+        let mut ix_data = hash(b"global:swim_receive").to_bytes()[..8].to_vec();
+        self.serialize(&mut ix_data)?;
+        Ok(ix_data)
+    }
+}
+
+pub fn get_swim_receiver_authority(receiver: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SWIM_RECEIVER_AUTHORITY_SEED], receiver).0
+}
+
+/// Invokes `swim_receive` on the receiver program (i.e. the swim payload owner) after
+/// `amount` of the output token has been transferred to `receiver_token_account`.
+///
+/// The callback runs in the same transaction as the token delivery, so if the receiver
+/// program fails (or isn't executable) the whole delivery is reverted. The escape hatch is
+/// `propeller_process_expired_swim_payload`: once the swim payload expired it delivers the
+/// swimUSD to the receiver authority's ATA without invoking the callback. Receivers that
+/// need to react to every delivery should therefore also watch their authority's ATA.
+///
+/// `remaining_accounts` = `[receiver program, swim receiver caller, ..accounts forwarded to the receiver]`
+///
+/// Only the swim receiver caller PDA signs the callback. The forwarded accounts are passed as non-signers
+/// even if they signed the propeller ix (e.g. the relayer paying for the txn) so that a receiver program
+/// can't use their signatures.
+pub fn invoke_swim_receiver<'info>(
+    propeller: &Pubkey,
+    swim_payload_message: &Account<'info, SwimPayloadMessage>,
+    receiver_token_account: &Account<'info, TokenAccount>,
+    amount: u64,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let receiver_data = match &swim_payload_message.receiver_data {
+        Some(receiver_data) => receiver_data,
+        None => return Ok(()),
+    };
+    require_gte!(remaining_accounts.len(), 2, PropellerError::MissingSwimReceiverAccounts);
+    let receiver_program = &remaining_accounts[0];
+    let swim_receiver_caller = &remaining_accounts[1];
+    let forwarded_accounts = &remaining_accounts[2..];
+    require_keys_eq!(receiver_program.key(), swim_payload_message.owner, PropellerError::InvalidSwimReceiver);
+    require!(receiver_program.executable, PropellerError::InvalidSwimReceiver);
+    let (swim_receiver_caller_key, swim_receiver_caller_bump) =
+        Pubkey::find_program_address(&[SWIM_RECEIVER_CALLER_SEED], &crate::ID);
+    require_keys_eq!(swim_receiver_caller.key(), swim_receiver_caller_key, PropellerError::InvalidSwimReceiverCaller);
+
+    let params = SwimReceiveParams {
+        source_chain: swim_payload_message.vaa_emitter_chain,
+        sender: swim_payload_message.sender,
+        token_mint: receiver_token_account.mint,
+        amount,
+        data: receiver_data.clone(),
+    };
+    let mut accounts = vec![
+        AccountMeta::new_readonly(swim_receiver_caller_key, true),
+        AccountMeta::new(receiver_token_account.key(), false),
+    ];
+    accounts.extend(get_forwarded_account_metas(forwarded_accounts));
+    let ix = Instruction { program_id: receiver_program.key(), accounts, data: params.to_ix_data()? };
+    let mut account_infos = vec![swim_receiver_caller.clone(), receiver_token_account.to_account_info()];
+    account_infos.extend_from_slice(forwarded_accounts);
+    account_infos.push(receiver_program.clone());
+    invoke_signed(&ix, &account_infos, &[&[SWIM_RECEIVER_CALLER_SEED, &[swim_receiver_caller_bump]]])?;
+
+    emit!(InvokeSwimReceiverEvent {
+        propeller: *propeller,
+        swim_payload_message: swim_payload_message.key(),
+        receiver: receiver_program.key(),
+        receiver_token_account: receiver_token_account.key(),
+        token_mint: params.token_mint,
+        amount,
+    });
+    Ok(())
+}
+
+/// `AccountMeta`s of the accounts forwarded to the receiver program. Never signers
+fn get_forwarded_account_metas(forwarded_accounts: &[AccountInfo]) -> Vec<AccountMeta> {
+    forwarded_accounts
+        .iter()
+        .map(|account| {
+            if account.is_writable {
+                AccountMeta::new(account.key(), false)
+            } else {
+                AccountMeta::new_readonly(account.key(), false)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forwarded_accounts_are_not_signers() {
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let owner = Pubkey::new_unique();
+        let mut lamports = [0u64; 2];
+        let mut data = [vec![], vec![]];
+        let [lamports_0, lamports_1] = &mut lamports;
+        let [data_0, data_1] = &mut data;
+        let forwarded_accounts = [
+            AccountInfo::new(&keys[0], true, true, lamports_0, data_0, &owner, false, 0),
+            AccountInfo::new(&keys[1], true, false, lamports_1, data_1, &owner, false, 0),
+        ];
+        assert_eq!(
+            get_forwarded_account_metas(&forwarded_accounts),
+            vec![AccountMeta::new(keys[0], false), AccountMeta::new_readonly(keys[1], false)]
+        );
+    }
+
+    #[test]
+    fn test_swim_receive_ix_data() {
+        let params = SwimReceiveParams {
+            source_chain: 2,
+            sender: [1u8; 32],
+            token_mint: Pubkey::new_unique(),
+            amount: 1_000,
+            data: vec![4, 5, 6],
+        };
+        let ix_data = params.to_ix_data().unwrap();
+        assert_eq!(ix_data[..8], hash(b"global:swim_receive").to_bytes()[..8]);
+        assert_eq!(SwimReceiveParams::try_from_slice(&ix_data[8..]).unwrap(), params);
+    }
+}
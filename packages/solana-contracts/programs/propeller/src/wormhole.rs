@@ -1,5 +1,5 @@
 use {
-    crate::{Propeller, PropellerError, RawSwimPayload},
+    crate::{Propeller, PropellerError, SwimPayload},
     anchor_lang::{prelude::*, solana_program::pubkey},
     borsh::{BorshDeserialize, BorshSerialize},
     byteorder::{BigEndian, ReadBytesExt, WriteBytesExt},
@@ -177,7 +177,7 @@ pub struct PayloadTransferWithPayload {
     pub from_address: Address,
     /// Arbitrary payload
     // pub payload: Vec<u8>,
-    pub payload: RawSwimPayload,
+    pub payload: SwimPayload,
 }
 
 impl AnchorDeserialize for PayloadTransferWithPayload {
@@ -210,7 +210,7 @@ impl AnchorDeserialize for PayloadTransferWithPayload {
 
         let mut payload = vec![];
         v.read_to_end(&mut payload)?;
-        let swim_payload = RawSwimPayload::deserialize(&mut payload.as_slice())?;
+        let swim_payload = SwimPayload::deserialize(&mut payload.as_slice())?;
 
         Ok(PayloadTransferWithPayload {
             message_type,
@@ -227,6 +227,11 @@ impl AnchorDeserialize for PayloadTransferWithPayload {
 
 //TODO: probably not needed since we shouldn't be serializing any payload directly.
 //  this would be handled in CPI
+impl PayloadTransferWithPayload {
+    /// Length of the serialized fields preceding the swim payload
+    pub const HEADER_LEN: usize = 1 + 32 + 32 + 2 + 32 + 2 + 32;
+}
+
 impl AnchorSerialize for PayloadTransferWithPayload {
     fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
         // Payload ID
@@ -278,8 +283,8 @@ pub fn deserialize_message_payload<T: AnchorDeserialize>(buf: &mut &[u8]) -> Res
     Ok(T::deserialize(buf)?)
 }
 
-pub fn deserialize_swim_payload(buf: &mut &[u8]) -> Result<RawSwimPayload> {
-    Ok(RawSwimPayload::deserialize(buf)?)
+pub fn deserialize_swim_payload(buf: &mut &[u8]) -> Result<SwimPayload> {
+    Ok(SwimPayload::deserialize(buf)?)
 }
 /** Adding PostedVAA version here for parity */
 
@@ -354,6 +359,13 @@ impl DerefMut for PostedVAAData {
     }
 }
 
+impl PostedVAAData {
+    /// Data length of a posted VAA account ("vaa" magic + `MessageData`) with a `payload_len` bytes long payload
+    pub fn data_len(payload_len: usize) -> usize {
+        3 + 1 + 1 + 4 + 32 + 4 + 4 + 8 + 2 + 32 + 4 + payload_len
+    }
+}
+
 impl anchor_lang::Owner for PostedVAAData {
     fn owner() -> Pubkey {
         Wormhole::id()
@@ -370,6 +382,10 @@ pub struct ClaimData {
     pub claimed: bool,
 }
 
+impl ClaimData {
+    pub const LEN: usize = 1;
+}
+
 /*
 This is created by the wasm post_vaa_ix() function.
 It takes a full vaa (AnchorSwimPayloadVAA) and strips out the signatures
@@ -1,7 +1,7 @@
 use {
-    crate::{decimal::DecimalU64, error::PoolError, DecimalU64Anchor},
+    crate::{decimal::DecimalU64, error::PoolError, BorshDecimal, DecimalU64Anchor},
     anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp},
-    rust_decimal::prelude::*,
+    rust_decimal::{prelude::*, Decimal},
     rust_decimal_macros::dec,
     std::ops::{Add, Sub},
 };
@@ -35,6 +35,20 @@ pub const MAX_RELATIVE_ADJUSTMENT: ValueT = ValueT::const_from(10);
 // };
 // pub const MAX_RELATIVE_ADJUSTMENT: ValueT = Decimal::TEN;
 
+/// How the [`AmpFactor`] moves from its initial to its target value during an adjustment
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum AmpFactorInterpolation {
+    Linear,
+    /// constant relative change per unit of time (see [`AmpFactor::get`])
+    Geometric,
+}
+
+impl Default for AmpFactorInterpolation {
+    fn default() -> Self {
+        AmpFactorInterpolation::Linear
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug)]
 pub struct AmpFactor {
     //invariants:
@@ -52,10 +66,30 @@ pub struct AmpFactor {
     initial_ts: i64,
     target_value: DecimalU64Anchor,
     target_ts: i64,
+    interpolation: AmpFactorInterpolation,
+    // `(target_value / initial_value) ^ (1 / total_adjustment_time)` of a geometric adjustment.
+    // precomputed by `set_target` so that `get` doesn't have to raise to a fractional power
+    per_second_ratio: BorshDecimal,
 }
 
 impl AmpFactor {
-    pub const LEN: usize = 2 * DecimalU64Anchor::LEN + 2 * 8;
+    pub const LEN: usize = 2 * DecimalU64Anchor::LEN + 2 * 8 + 1 + (16 + 4);
+}
+
+/// `base ^ exp` by repeated squaring, i.e. with O(log(exp)) multiplications
+/// (rust_decimal's `powu` does O(exp) multiplications)
+fn pow_by_squaring(mut base: Decimal, mut exp: u64) -> Decimal {
+    let mut result = Decimal::ONE;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result *= base;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base *= base;
+        }
+    }
+    result
 }
 
 // impl From<DecimalU64Anchor> for DecimalU64 {
@@ -196,13 +230,16 @@ impl AmpFactor {
                 initial_ts: 0,
                 target_value: amp_factor.into(),
                 target_ts: 0,
+                interpolation: AmpFactorInterpolation::default(),
+                per_second_ratio: BorshDecimal::default(),
             })
         }
     }
 
     /// Returns the current [`AmpFactor`] value
     ///
-    /// [`AmpFactor`] will be linearly interpolated while in the adjustment window
+    /// [`AmpFactor`] will be interpolated while in the adjustment window
+    /// using the [`AmpFactorInterpolation`] of the adjustment
     ///
     /// mathematically speaking we ought to use exponential interpolation
     /// to see why, assume an amp factor adjustment from 1 to 4:
@@ -212,9 +249,14 @@ impl AmpFactor {
     ///
     /// ultimately however, it's only important that the adjustment happens gradually
     /// to prevent [exploitation](https://medium.com/@peter_4205/curve-vulnerability-report-a1d7630140ec)
-    /// and so for simplicity's sake we use linear interpolation and restrict
+    /// and so for simplicity's sake linear interpolation is the default and we restrict
     /// the maximum _relative_ change to a factor of 10 (i.e. amp_factor at most do
     /// a 10x over a day (not +10, but potentially much more))
+    ///
+    /// with [`AmpFactorInterpolation::Geometric`] the value is
+    /// `initial_value * (target_value / initial_value) ^ (time_since_initial / total_adjustment_time)`,
+    /// computed as `initial_value * per_second_ratio ^ time_since_initial` so that only integer powers
+    /// are taken while swapping
     pub fn get(&self, current_ts: TimestampT) -> ValueT {
         let target_value: ValueT = self.target_value.into();
         let initial_value: ValueT = self.initial_value.into();
@@ -225,6 +267,13 @@ impl AmpFactor {
         } else {
             assert!(current_ts >= self.initial_ts);
 
+            if self.interpolation == AmpFactorInterpolation::Geometric {
+                let per_second_ratio =
+                    Decimal::from_i128_with_scale(self.per_second_ratio.mantissa, self.per_second_ratio.scale);
+                let time_since_initial = (current_ts - self.initial_ts) as u64;
+                return (Decimal::from(initial_value) * pow_by_squaring(per_second_ratio, time_since_initial)).into();
+            }
+
             let is_increase = target_value > initial_value;
             let value_diff = if is_increase { target_value - initial_value } else { initial_value - target_value };
             let time_since_initial: ValueT = ((current_ts - self.initial_ts) as u64).into();
@@ -238,9 +287,15 @@ impl AmpFactor {
 
     /// Sets the target value and timestamp for the [`AmpFactor`] to adjust to
     ///
-    /// [`AmpFactor`] will be linearly interpolated while in the adjustment window
+    /// [`AmpFactor`] will be interpolated using `interpolation` while in the adjustment window
     /// see [`AmpFactor::get`] for more details
-    pub fn set_target(&mut self, current_ts: TimestampT, target_value: ValueT, target_ts: TimestampT) -> Result<()> {
+    pub fn set_target(
+        &mut self,
+        current_ts: TimestampT,
+        target_value: ValueT,
+        target_ts: TimestampT,
+        interpolation: AmpFactorInterpolation,
+    ) -> Result<()> {
         if !(MIN_AMP_VALUE..=MAX_AMP_VALUE).contains(&target_value) {
             return err!(PoolError::InvalidAmpFactorValue);
         }
@@ -260,9 +315,38 @@ impl AmpFactor {
         self.initial_ts = current_ts;
         self.target_value = target_value.into();
         self.target_ts = target_ts;
+        self.interpolation = interpolation;
+        self.per_second_ratio = if interpolation == AmpFactorInterpolation::Geometric {
+            let total_adjustment_time = (target_ts - current_ts) as u64;
+            let ratio = Decimal::from(target_value) / Decimal::from(initial_value);
+            let per_second_ratio = ratio
+                .checked_powd(Decimal::ONE / Decimal::from(total_adjustment_time))
+                .ok_or_else(|| error!(PoolError::InvalidAmpFactorValue))?;
+            //one newton step on `per_second_ratio ^ total_adjustment_time = ratio` to get rid of the
+            // error of `powd`, which would otherwise be amplified by `total_adjustment_time` in `get`
+            let ratio_estimate = pow_by_squaring(per_second_ratio, total_adjustment_time);
+            let per_second_ratio = per_second_ratio
+                * (Decimal::ONE - (ratio_estimate - ratio) / (ratio_estimate * Decimal::from(total_adjustment_time)));
+            BorshDecimal { mantissa: per_second_ratio.mantissa(), scale: per_second_ratio.scale() }
+        } else {
+            BorshDecimal::default()
+        };
 
         Ok(())
     }
+
+    /// Ends the ongoing adjustment, keeping the [`AmpFactor`] at its current value
+    pub fn stop_adjustment(&mut self, current_ts: TimestampT) -> Result<ValueT> {
+        if current_ts >= self.target_ts {
+            return err!(PoolError::AmpFactorNotAdjusting);
+        }
+        let current_value = self.get(current_ts);
+        self.initial_value = current_value.into();
+        self.initial_ts = current_ts;
+        self.target_value = current_value.into();
+        self.target_ts = current_ts;
+        Ok(current_value)
+    }
 }
 
 #[cfg(test)]
@@ -291,7 +375,7 @@ mod tests {
         let mut amp = AmpFactor::new(new_u64(10000, 0)).unwrap();
         assert_eq!(amp.get(1), 10000);
 
-        amp.set_target(20000, new_u64(20000, 0), 106400).unwrap();
+        amp.set_target(20000, new_u64(20000, 0), 106400, AmpFactorInterpolation::Linear).unwrap();
 
         assert_eq!(amp.get(20000), 10000);
         assert_eq!(
@@ -318,7 +402,7 @@ mod tests {
         let mut amp = AmpFactor::new(ValueT::from(20000)).unwrap();
         assert_eq!(amp.get(1), 20000);
 
-        amp.set_target(20000, ValueT::from(10000), 106400).unwrap();
+        amp.set_target(20000, ValueT::from(10000), 106400, AmpFactorInterpolation::Linear).unwrap();
 
         assert_eq!(amp.get(20000), 20000);
         assert_eq!(amp.get(36400), new_u64(18101851851851851852, 15));
@@ -333,14 +417,86 @@ mod tests {
     fn invalid_set_target() {
         //Target value set to 20x initial value
         let mut amp = AmpFactor::new(ValueT::from(1000)).unwrap();
-        amp.set_target(20000, ValueT::from(20000), 106400).unwrap();
+        amp.set_target(20000, ValueT::from(20000), 106400, AmpFactorInterpolation::Linear).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn invalid_adjustment_window() {
         let mut amp = AmpFactor::new(ValueT::from(10000)).unwrap();
-        amp.set_target(20000, ValueT::from(20000), 50000).unwrap();
+        amp.set_target(20000, ValueT::from(20000), 50000, AmpFactorInterpolation::Linear).unwrap();
+    }
+
+    #[test]
+    fn valid_set_target_geometric() {
+        let mut amp = AmpFactor::new(ValueT::from(1000)).unwrap();
+        amp.set_target(20000, ValueT::from(4000), 106400, AmpFactorInterpolation::Geometric).unwrap();
+
+        assert_eq!(amp.get(20000), 1000);
+        // going from 1000 to 2000 takes as long as going from 2000 to 4000
+        let halfway = amp.get(63200);
+        assert!(halfway > new_u64(1999999, 3) && halfway < new_u64(2000001, 3));
+        let quarter = amp.get(41600);
+        assert!(quarter > new_u64(1414213, 3) && quarter < new_u64(1414214, 3));
+        assert_eq!(amp.get(106400), 4000);
+    }
+
+    #[test]
+    fn geometric_adjustment_matches_fractional_power() {
+        for (initial_value, target_value) in [(1000, 10000), (10000, 1000), (1, 10), (MAX_AMP_VALUE.trunc(), 100000)] {
+            let mut amp = AmpFactor::new(ValueT::from(initial_value)).unwrap();
+            amp.set_target(
+                20000,
+                ValueT::from(target_value),
+                20000 + MIN_ADJUSTMENT_WINDOW,
+                AmpFactorInterpolation::Geometric,
+            )
+            .unwrap();
+            let ratio = Decimal::from(target_value) / Decimal::from(initial_value);
+            //powd itself is only accurate to ~1e-9
+            for time_since_initial in [1, 7, 3600, 43199, MIN_ADJUSTMENT_WINDOW - 1] {
+                let expected = Decimal::from(initial_value)
+                    * ratio.powd(Decimal::from(time_since_initial) / Decimal::from(MIN_ADJUSTMENT_WINDOW));
+                let actual = Decimal::from(amp.get(20000 + time_since_initial));
+                assert!(((actual - expected) / expected).abs() < dec!(0.00000001), "{} != {}", actual, expected);
+            }
+            //the last step of the adjustment lands on the target value
+            let per_second_ratio =
+                Decimal::from_i128_with_scale(amp.per_second_ratio.mantissa, amp.per_second_ratio.scale);
+            let last_step = Decimal::from(amp.get(20000 + MIN_ADJUSTMENT_WINDOW - 1)) * per_second_ratio;
+            let target_value = Decimal::from(target_value);
+            assert!(((last_step - target_value) / target_value).abs() < dec!(0.000000000000001));
+        }
+
+        let mut amp = AmpFactor::new(ValueT::from(1000)).unwrap();
+        amp.set_target(0, ValueT::from(10000), MIN_ADJUSTMENT_WINDOW, AmpFactorInterpolation::Geometric).unwrap();
+        //1000 * 10 ^ (43199 / 86400)
+        let expected = dec!(3162.1933856732961977826);
+        let actual = Decimal::from(amp.get(43199));
+        assert!(((actual - expected) / expected).abs() < dec!(0.000000000000001), "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn test_pow_by_squaring() {
+        assert_eq!(pow_by_squaring(dec!(3), 0), Decimal::ONE);
+        assert_eq!(pow_by_squaring(dec!(3), 1), dec!(3));
+        assert_eq!(pow_by_squaring(dec!(3), 13), dec!(1594323));
+        assert_eq!(pow_by_squaring(dec!(0.5), 10), dec!(0.0009765625));
+        assert_eq!(pow_by_squaring(dec!(1.1), 5), dec!(1.61051));
+    }
+
+    #[test]
+    fn stop_adjustment() {
+        let mut amp = AmpFactor::new(ValueT::from(10000)).unwrap();
+        assert!(amp.stop_adjustment(20000).is_err());
+
+        amp.set_target(20000, ValueT::from(20000), 106400, AmpFactorInterpolation::Linear).unwrap();
+        let current_value = amp.get(50000);
+        assert_eq!(amp.stop_adjustment(50000).unwrap(), current_value);
+        assert_eq!(amp.get(50000), current_value);
+        assert_eq!(amp.get(106400), current_value);
+        assert_eq!(amp.get(200000), current_value);
+        assert!(amp.stop_adjustment(50001).is_err());
     }
 
     // #[test]
@@ -84,6 +84,22 @@ pub enum PoolError {
     MaxDecimalsExceeded,
     #[msg("Conversion error")]
     ConversionError,
+    #[msg("Invalid token count")]
+    InvalidTokenCount,
+    #[msg("Invalid TWAP window")]
+    InvalidTwapWindow,
+    #[msg("Nothing to cancel")]
+    InvalidCancel,
+    #[msg("Invalid enact delay")]
+    InvalidEnactDelay,
+    #[msg("Amp factor is not being adjusted")]
+    AmpFactorNotAdjusting,
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+    #[msg("Pool has no liquidity")]
+    PoolHasNoLiquidity,
+    #[msg("Virtual price was checkpointed too recently")]
+    VirtualPriceCheckpointTooEarly,
 }
 
 // impl From<PoolError> for ProgramError {
@@ -0,0 +1,256 @@
+use {
+    crate::{amp_factor::AmpFactorInterpolation, DecimalU64Anchor},
+    anchor_lang::prelude::*,
+};
+
+// Structured events emitted by every pool & governance ix so that indexers
+// don't have to reconstruct state transitions from `msg!` logs.
+// Amounts are in the atomic units of the respective token / lp mint.
+
+#[event]
+pub struct InitializeEvent {
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_mints: Vec<Pubkey>,
+    pub governance: Pubkey,
+    pub governance_fee: Pubkey,
+    pub pause_key: Pubkey,
+    pub amp_factor: DecimalU64Anchor,
+    pub lp_fee: DecimalU64Anchor,
+    pub governance_fee_rate: DecimalU64Anchor,
+}
+
+#[event]
+pub struct AddEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub input_amounts: Vec<u64>,
+    pub mint_amount: u64,
+    pub governance_mint_amount: u64,
+    pub latest_depth: u128,
+}
+
+#[event]
+pub struct SwapExactInputEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub exact_input_amounts: Vec<u64>,
+    pub output_token_index: u8,
+    pub output_amount: u64,
+    pub governance_mint_amount: u64,
+    pub latest_depth: u128,
+}
+
+#[event]
+pub struct SwapExactOutputEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub input_token_index: u8,
+    pub input_amount: u64,
+    pub exact_output_amounts: Vec<u64>,
+    pub governance_mint_amount: u64,
+    pub latest_depth: u128,
+}
+
+#[event]
+pub struct RemoveUniformEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub exact_burn_amount: u64,
+    pub output_amounts: Vec<u64>,
+    pub latest_depth: u128,
+}
+
+#[event]
+pub struct RemoveExactBurnEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub exact_burn_amount: u64,
+    pub output_token_index: u8,
+    pub output_amount: u64,
+    pub governance_mint_amount: u64,
+    pub latest_depth: u128,
+}
+
+#[event]
+pub struct RemoveExactOutputEvent {
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub burn_amount: u64,
+    pub exact_output_amounts: Vec<u64>,
+    pub governance_mint_amount: u64,
+    pub latest_depth: u128,
+}
+
+/** Governance Events **/
+#[event]
+pub struct PrepareGovernanceTransitionEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub upcoming_governance_key: Pubkey,
+    pub governance_transition_ts: i64,
+}
+
+#[event]
+pub struct EnactGovernanceTransitionEvent {
+    pub pool: Pubkey,
+    pub previous_governance_key: Pubkey,
+    pub new_governance_key: Pubkey,
+}
+
+#[event]
+pub struct PrepareFeeChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub lp_fee: DecimalU64Anchor,
+    pub governance_fee: DecimalU64Anchor,
+    pub fee_transition_ts: i64,
+}
+
+#[event]
+pub struct EnactFeeChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub lp_fee: DecimalU64Anchor,
+    pub governance_fee: DecimalU64Anchor,
+}
+
+#[event]
+pub struct CancelGovernanceTransitionEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub cancelled_governance_key: Pubkey,
+}
+
+#[event]
+pub struct CancelFeeChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub cancelled_lp_fee: DecimalU64Anchor,
+    pub cancelled_governance_fee: DecimalU64Anchor,
+}
+
+#[event]
+pub struct PrepareEnactDelayChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub enact_delay: i64,
+    pub enact_delay_transition_ts: i64,
+}
+
+#[event]
+pub struct EnactEnactDelayChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub previous_enact_delay: i64,
+    pub new_enact_delay: i64,
+}
+
+#[event]
+pub struct CancelEnactDelayChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub cancelled_enact_delay: i64,
+}
+
+#[event]
+pub struct PrepareGovernanceFeeAccountChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub upcoming_governance_fee_key: Pubkey,
+    pub governance_fee_key_transition_ts: i64,
+}
+
+#[event]
+pub struct EnactGovernanceFeeAccountChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub previous_governance_fee_key: Pubkey,
+    pub new_governance_fee_key: Pubkey,
+}
+
+#[event]
+pub struct CancelGovernanceFeeAccountChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub cancelled_governance_fee_key: Pubkey,
+}
+
+#[event]
+pub struct PreparePauseKeyChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub upcoming_pause_key: Pubkey,
+    pub pause_key_transition_ts: i64,
+}
+
+#[event]
+pub struct EnactPauseKeyChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub previous_pause_key: Pubkey,
+    pub new_pause_key: Pubkey,
+}
+
+#[event]
+pub struct CancelPauseKeyChangeEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub cancelled_pause_key: Pubkey,
+}
+
+#[event]
+pub struct AdjustAmpFactorEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub current_ts: i64,
+    pub target_value: DecimalU64Anchor,
+    pub target_ts: i64,
+    pub interpolation: AmpFactorInterpolation,
+}
+
+#[event]
+pub struct StopAmpFactorAdjustmentEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub current_ts: i64,
+    pub value: DecimalU64Anchor,
+}
+
+#[event]
+pub struct SetPausedEvent {
+    pub pool: Pubkey,
+    pub pause_key: Pubkey,
+    pub pause_flags: u8,
+}
+
+#[event]
+pub struct ChangePauseKeyEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub previous_pause_key: Pubkey,
+    pub new_pause_key: Pubkey,
+}
+
+#[event]
+pub struct CreateLpMetadataEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub lp_mint: Pubkey,
+    pub metadata: Pubkey,
+}
+
+#[event]
+pub struct UpdateLpMetadataEvent {
+    pub pool: Pubkey,
+    pub governance: Pubkey,
+    pub lp_mint: Pubkey,
+    pub metadata: Pubkey,
+}
+
+#[event]
+pub struct CheckpointVirtualPriceEvent {
+    pub pool: Pubkey,
+    pub current_ts: i64,
+    pub virtual_price: DecimalU64Anchor,
+}
@@ -1,5 +1,15 @@
 use {
-    crate::{decimal::U128, error::*, gen_pool_signer_seeds, invariant::Invariant, TwoPool, TOKEN_COUNT},
+    crate::{
+        common::create_array,
+        decimal::{DecimalU64, U128},
+        error::*,
+        events::AddEvent,
+        gen_pool_signer_seeds,
+        invariant::Invariant,
+        load_pool_token_accounts, load_user_token_accounts,
+        state::pause_flags,
+        update_price_oracle, with_token_count, TwoPool,
+    },
     anchor_lang::{
         prelude::*,
         solana_program::{
@@ -86,14 +96,14 @@ pub struct Add<'info> {
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct AddParams {
-    pub input_amounts: [u64; TOKEN_COUNT],
+    pub input_amounts: Vec<u64>,
     pub minimum_mint_amount: u64,
 }
 
 impl<'info> Add<'info> {
     pub fn accounts(ctx: &Context<Add>) -> Result<()> {
         let pool_state = &ctx.accounts.pool;
-        require!(!pool_state.is_paused, PoolError::PoolIsPaused);
+        pool_state.require_not_paused(pause_flags::ADD)?;
         require_keys_eq!(
             ctx.accounts.pool_token_account_0.key(),
             pool_state.token_keys[0],
@@ -121,54 +131,46 @@ impl<'info> Add<'info> {
 }
 
 //
-pub fn handle_add(
-    ctx: Context<Add>,
+pub fn handle_add<'info>(
+    ctx: Context<'_, '_, '_, 'info, Add<'info>>,
     params: AddParams,
     // input_amounts: [u64; TOKEN_COUNT],
     // minimum_mint_amount: u64,
 ) -> Result<u64> {
     let input_amounts = params.input_amounts;
     let minimum_mint_amount = params.minimum_mint_amount;
-    require!(input_amounts.iter().any(|&x| x > 0), PoolError::AddRequiresAtLeastOneToken);
+    let pool = &ctx.accounts.pool;
+    let token_count = pool.get_token_count();
     let lp_total_supply = ctx.accounts.lp_mint.supply;
-    //initial add to pool must add all tokens
-    if lp_total_supply == 0 {
-        for i in 0..TOKEN_COUNT {
-            require_gt!(input_amounts[i], 0u64, PoolError::InitialAddRequiresAllTokens);
-        }
-    }
+    validate_add(pool, &input_amounts, lp_total_supply)?;
 
-    let pool = &ctx.accounts.pool;
-    let user_token_accounts = [&ctx.accounts.user_token_account_0, &ctx.accounts.user_token_account_1];
-    let pool_token_accounts = [&ctx.accounts.pool_token_account_0, &ctx.accounts.pool_token_account_1];
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    let pool_token_accounts = load_pool_token_accounts(
+        pool,
+        &ctx.accounts.pool_token_account_0,
+        &ctx.accounts.pool_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let user_token_accounts = load_user_token_accounts(
+        pool,
+        &ctx.accounts.user_token_account_0,
+        &ctx.accounts.user_token_account_1,
+        &mut remaining_accounts,
+    )?;
     // let pool_balances = pool_token_accounts
     //   .iter()
     //   .map(|account| account.amount)
     //   .collect::<Vec<_>>()
     //   .as_slice()
     //   .try_into().unwrap();
-    let pool_balances = [ctx.accounts.pool_token_account_0.amount, ctx.accounts.pool_token_account_1.amount];
+    let pool_balances = pool_token_accounts.iter().map(|account| account.amount).collect::<Vec<_>>();
     let current_ts = Clock::get()?.unix_timestamp;
     require_gt!(current_ts, 0i64, PoolError::InvalidTimestamp);
-    let (user_amount, governance_mint_amount, latest_depth) = Invariant::<TOKEN_COUNT>::add(
-        &array_equalize(input_amounts, pool.token_decimal_equalizers),
-        &array_equalize(pool_balances, pool.token_decimal_equalizers),
-        pool.amp_factor.get(current_ts),
-        pool.lp_fee.get(),
-        pool.governance_fee.get(),
-        to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
-        pool.previous_depth.into(),
-    )?;
-    let (mint_amount, governance_mint_amount, latest_depth) = result_from_equalized(
-        user_amount,
-        pool.lp_decimal_equalizer,
-        governance_mint_amount,
-        pool.lp_decimal_equalizer,
-        latest_depth,
-    );
+    let (mint_amount, governance_mint_amount, latest_depth) =
+        calculate_add(pool, &input_amounts, &pool_balances, lp_total_supply, current_ts)?;
     require_gte!(mint_amount, minimum_mint_amount, PoolError::OutsideSpecifiedLimits);
-    let mut token_accounts = zip(user_token_accounts.into_iter(), pool_token_accounts.into_iter());
-    for i in 0..TOKEN_COUNT {
+    let mut token_accounts = zip(user_token_accounts.iter(), pool_token_accounts.iter());
+    for i in 0..token_count {
         let (user_token_account, pool_token_account) = token_accounts.next().unwrap();
         if input_amounts[i] > 0 {
             token::transfer(
@@ -218,6 +220,18 @@ pub fn handle_add(
 
     let pool_state = &mut ctx.accounts.pool;
     pool_state.previous_depth = latest_depth;
+    let pool_balances =
+        pool_balances.iter().zip(&input_amounts).map(|(balance, amount)| balance + amount).collect::<Vec<_>>();
+    let lp_total_supply = lp_total_supply + mint_amount + governance_mint_amount;
+    update_price_oracle(pool_state, &pool_balances, lp_total_supply, current_ts)?;
+    emit!(AddEvent {
+        pool: pool_state.key(),
+        user: ctx.accounts.user_transfer_authority.key(),
+        input_amounts,
+        mint_amount,
+        governance_mint_amount,
+        latest_depth,
+    });
     Ok(mint_amount)
     // msg!("add_and_wormhole_transfer");
     // handle_approve(&ctx, &pool_add_params)?;
@@ -230,6 +244,67 @@ pub fn handle_add(
     // // Ok(mint_amount)
 }
 
+/// Checks the `input_amounts` of an add. Shared by `add` & `quote_add`
+pub fn validate_add(pool: &TwoPool, input_amounts: &[u64], lp_total_supply: u64) -> Result<()> {
+    require_eq!(input_amounts.len(), pool.get_token_count(), PoolError::InvalidTokenCount);
+    require!(input_amounts.iter().any(|&x| x > 0), PoolError::AddRequiresAtLeastOneToken);
+    //initial add to pool must add all tokens
+    if lp_total_supply == 0 {
+        require!(input_amounts.iter().all(|&x| x > 0), PoolError::InitialAddRequiresAllTokens);
+    }
+    Ok(())
+}
+
+/// Returns `(mint_amount, governance_mint_amount, latest_depth)` of adding `input_amounts`
+/// without executing the add
+pub fn calculate_add(
+    pool: &TwoPool,
+    input_amounts: &[u64],
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    calculate_add_with_fees(
+        pool,
+        pool.lp_fee.get(),
+        pool.governance_fee.get(),
+        input_amounts,
+        pool_balances,
+        lp_total_supply,
+        current_ts,
+    )
+}
+
+/// Same as [calculate_add] but charges `lp_fee` & `governance_fee` instead of the pool's fees
+pub fn calculate_add_with_fees(
+    pool: &TwoPool,
+    lp_fee: DecimalU64,
+    governance_fee: DecimalU64,
+    input_amounts: &[u64],
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    let (user_amount, governance_mint_amount, latest_depth) = with_token_count!(pool.get_token_count(), N => {
+        Invariant::<N>::add(
+            &array_equalize(input_amounts, &pool.token_decimal_equalizers),
+            &array_equalize(pool_balances, &pool.token_decimal_equalizers),
+            pool.amp_factor.get(current_ts),
+            lp_fee,
+            governance_fee,
+            to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
+            pool.previous_depth.into(),
+        )
+    })?;
+    Ok(result_from_equalized(
+        user_amount,
+        pool.lp_decimal_equalizer,
+        governance_mint_amount,
+        pool.lp_decimal_equalizer,
+        latest_depth,
+    ))
+}
+
 pub fn to_equalized(value: u64, equalizer: u8) -> U128 {
     if equalizer > 0 {
         U128::from(value) * U128::ten_to_the(equalizer)
@@ -246,15 +321,9 @@ pub fn from_equalized(value: U128, equalizer: u8) -> u64 {
     }
 }
 
-pub fn array_equalize(amounts: [u64; TOKEN_COUNT], equalizers: [u8; TOKEN_COUNT]) -> [U128; TOKEN_COUNT] {
-    amounts
-        .iter()
-        .zip(equalizers.iter())
-        .map(|(&amount, &equalizer)| to_equalized(amount, equalizer))
-        .collect::<Vec<_>>()
-        .as_slice()
-        .try_into()
-        .unwrap()
+/// Equalizes the first `SIZE` entries of `amounts` using the corresponding `equalizers`
+pub fn array_equalize<const SIZE: usize>(amounts: &[u64], equalizers: &[u8]) -> [U128; SIZE] {
+    create_array(|i| to_equalized(amounts[i], equalizers[i]))
 }
 
 /// `result_from_equalized` takes in a user's amount, the user's equalizer, the governance mint amount,
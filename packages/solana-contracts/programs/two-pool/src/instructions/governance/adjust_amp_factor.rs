@@ -1,7 +1,7 @@
 use {
     crate::{
-        common_governance::*, error::*, get_current_ts, governance::ENACT_DELAY, DecimalU64, DecimalU64Anchor,
-        UnixTimestamp,
+        amp_factor::AmpFactorInterpolation, common_governance::*, error::*, events::AdjustAmpFactorEvent,
+        get_current_ts, governance::ENACT_DELAY, DecimalU64, DecimalU64Anchor, UnixTimestamp,
     },
     anchor_lang::prelude::*,
 };
@@ -15,6 +15,7 @@ pub struct AdjustAmpFactor<'info> {
 pub struct AdjustAmpFactorParams {
     pub target_ts: i64,
     pub target_value: DecimalU64Anchor,
+    pub interpolation: AmpFactorInterpolation,
 }
 
 impl<'info> AdjustAmpFactor<'info> {
@@ -27,6 +28,14 @@ impl<'info> AdjustAmpFactor<'info> {
 pub fn handle_adjust_amp_factor(ctx: Context<AdjustAmpFactor>, params: AdjustAmpFactorParams) -> Result<()> {
     let pool = &mut ctx.accounts.common_governance.pool;
     let current_ts = get_current_ts()?;
-    pool.amp_factor.set_target(current_ts, params.target_value.into(), params.target_ts)?;
+    pool.amp_factor.set_target(current_ts, params.target_value.into(), params.target_ts, params.interpolation)?;
+    emit!(AdjustAmpFactorEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        current_ts,
+        target_value: params.target_value,
+        target_ts: params.target_ts,
+        interpolation: params.interpolation,
+    });
     Ok(())
 }
@@ -0,0 +1,31 @@
+use {
+    crate::{common_governance::*, error::*, events::CancelEnactDelayChangeEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CancelEnactDelayChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> CancelEnactDelayChange<'info> {
+    pub fn accounts(ctx: &Context<CancelEnactDelayChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_cancel_enact_delay_change(ctx: Context<CancelEnactDelayChange>) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    require_neq!(pool.enact_delay_transition_ts, 0i64, PoolError::InvalidCancel);
+
+    let cancelled_enact_delay = pool.prepared_enact_delay;
+    pool.prepared_enact_delay = 0i64;
+    pool.enact_delay_transition_ts = 0i64;
+    emit!(CancelEnactDelayChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        cancelled_enact_delay,
+    });
+    Ok(())
+}
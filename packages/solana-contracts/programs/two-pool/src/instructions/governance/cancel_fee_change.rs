@@ -0,0 +1,34 @@
+use {
+    crate::{common_governance::*, error::*, events::CancelFeeChangeEvent, PoolFee},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CancelFeeChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> CancelFeeChange<'info> {
+    pub fn accounts(ctx: &Context<CancelFeeChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_cancel_fee_change(ctx: Context<CancelFeeChange>) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    require_neq!(pool.fee_transition_ts, 0i64, PoolError::InvalidCancel);
+
+    let cancelled_lp_fee = pool.prepared_lp_fee.get();
+    let cancelled_governance_fee = pool.prepared_governance_fee.get();
+    pool.prepared_lp_fee = PoolFee::default();
+    pool.prepared_governance_fee = PoolFee::default();
+    pool.fee_transition_ts = 0i64;
+    emit!(CancelFeeChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        cancelled_lp_fee: cancelled_lp_fee.into(),
+        cancelled_governance_fee: cancelled_governance_fee.into(),
+    });
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use {
+    crate::{common_governance::*, error::*, events::CancelGovernanceFeeAccountChangeEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CancelGovernanceFeeAccountChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> CancelGovernanceFeeAccountChange<'info> {
+    pub fn accounts(ctx: &Context<CancelGovernanceFeeAccountChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_cancel_governance_fee_account_change(ctx: Context<CancelGovernanceFeeAccountChange>) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    require_neq!(pool.governance_fee_key_transition_ts, 0i64, PoolError::InvalidCancel);
+
+    let cancelled_governance_fee_key = pool.prepared_governance_fee_key;
+    pool.prepared_governance_fee_key = Pubkey::default();
+    pool.governance_fee_key_transition_ts = 0i64;
+    emit!(CancelGovernanceFeeAccountChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        cancelled_governance_fee_key,
+    });
+    Ok(())
+}
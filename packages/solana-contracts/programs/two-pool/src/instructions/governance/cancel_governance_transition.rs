@@ -0,0 +1,31 @@
+use {
+    crate::{common_governance::*, error::*, events::CancelGovernanceTransitionEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CancelGovernanceTransition<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> CancelGovernanceTransition<'info> {
+    pub fn accounts(ctx: &Context<CancelGovernanceTransition>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_cancel_governance_transition(ctx: Context<CancelGovernanceTransition>) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    require_neq!(pool.governance_transition_ts, 0i64, PoolError::InvalidCancel);
+
+    let cancelled_governance_key = pool.prepared_governance_key;
+    pool.prepared_governance_key = Pubkey::default();
+    pool.governance_transition_ts = 0i64;
+    emit!(CancelGovernanceTransitionEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        cancelled_governance_key,
+    });
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use {
+    crate::{common_governance::*, error::*, events::CancelPauseKeyChangeEvent},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CancelPauseKeyChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> CancelPauseKeyChange<'info> {
+    pub fn accounts(ctx: &Context<CancelPauseKeyChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_cancel_pause_key_change(ctx: Context<CancelPauseKeyChange>) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    require_neq!(pool.pause_key_transition_ts, 0i64, PoolError::InvalidCancel);
+
+    let cancelled_pause_key = pool.prepared_pause_key;
+    pool.prepared_pause_key = Pubkey::default();
+    pool.pause_key_transition_ts = 0i64;
+    emit!(CancelPauseKeyChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        cancelled_pause_key,
+    });
+    Ok(())
+}
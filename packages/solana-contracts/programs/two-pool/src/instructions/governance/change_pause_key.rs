@@ -1,4 +1,7 @@
-use {crate::common_governance::*, anchor_lang::prelude::*};
+use {
+    crate::{common_governance::*, events::ChangePauseKeyEvent},
+    anchor_lang::prelude::*,
+};
 
 #[derive(Accounts)]
 pub struct ChangePauseKey<'info> {
@@ -14,6 +17,13 @@ impl<'info> ChangePauseKey<'info> {
 
 pub fn handle_change_pause_key(ctx: Context<ChangePauseKey>, new_pause_key: Pubkey) -> Result<()> {
     let pool = &mut ctx.accounts.common_governance.pool;
+    let previous_pause_key = pool.pause_key;
     pool.pause_key = new_pause_key;
+    emit!(ChangePauseKeyEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        previous_pause_key,
+        new_pause_key,
+    });
     Ok(())
 }
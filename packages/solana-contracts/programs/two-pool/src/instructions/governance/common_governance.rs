@@ -15,7 +15,7 @@ pub struct CommonGovernance<'info> {
     ],
     bump = pool.bump
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
 
     pub governance: Signer<'info>,
 }
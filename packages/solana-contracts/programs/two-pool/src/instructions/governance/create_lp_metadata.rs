@@ -1,5 +1,7 @@
 use {
-    crate::{common_governance::*, error::*, gen_pool_signer_seeds, AnchorDataV2, TwoPool},
+    crate::{
+        common_governance::*, error::*, events::CreateLpMetadataEvent, gen_pool_signer_seeds, AnchorDataV2, TwoPool,
+    },
     anchor_lang::prelude::*,
     anchor_spl::{
         metadata::{create_metadata_accounts_v2, CreateMetadataAccountsV2, Metadata},
@@ -125,5 +127,11 @@ pub fn handle_create_lp_metadata(ctx: Context<CreateLpMetadata>, params: CreateL
         params.is_mutable,
         params.update_authority_is_signer,
     )?;
+    emit!(CreateLpMetadataEvent {
+        pool: ctx.accounts.common_governance.pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        lp_mint: ctx.accounts.create_metadata_accounts.mint.key(),
+        metadata: ctx.accounts.create_metadata_accounts.metadata.key(),
+    });
     Ok(())
 }
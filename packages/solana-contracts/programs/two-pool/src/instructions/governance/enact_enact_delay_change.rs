@@ -0,0 +1,35 @@
+use {
+    crate::{common_governance::*, error::*, events::EnactEnactDelayChangeEvent, get_current_ts},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct EnactEnactDelayChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> EnactEnactDelayChange<'info> {
+    pub fn accounts(ctx: &Context<EnactEnactDelayChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_enact_enact_delay_change(ctx: Context<EnactEnactDelayChange>) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    require_neq!(pool.enact_delay_transition_ts, 0i64, PoolError::InvalidEnact);
+    let current_ts = get_current_ts()?;
+    require_gte!(current_ts, pool.enact_delay_transition_ts, PoolError::InsufficientDelay);
+
+    let previous_enact_delay = pool.enact_delay;
+    pool.enact_delay = pool.prepared_enact_delay;
+    pool.prepared_enact_delay = 0i64;
+    pool.enact_delay_transition_ts = 0i64;
+    emit!(EnactEnactDelayChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        previous_enact_delay,
+        new_enact_delay: pool.enact_delay,
+    });
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use {
     crate::{
-        common_governance::*, error::*, get_current_ts, governance::ENACT_DELAY, DecimalU64, DecimalU64Anchor, PoolFee,
-        UnixTimestamp,
+        common_governance::*, error::*, events::EnactFeeChangeEvent, get_current_ts, governance::ENACT_DELAY,
+        DecimalU64, DecimalU64Anchor, PoolFee, UnixTimestamp,
     },
     anchor_lang::prelude::*,
 };
@@ -32,5 +32,11 @@ pub fn handle_enact_fee_change(ctx: Context<EnactFeeChange>) -> Result<()> {
     pool.prepared_lp_fee = PoolFee::default();
     pool.prepared_governance_fee = PoolFee::default();
     pool.fee_transition_ts = 0i64;
+    emit!(EnactFeeChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        lp_fee: pool.lp_fee.get().into(),
+        governance_fee: pool.governance_fee.get().into(),
+    });
     Ok(())
 }
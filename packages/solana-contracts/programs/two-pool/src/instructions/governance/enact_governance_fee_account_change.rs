@@ -0,0 +1,41 @@
+use {
+    crate::{common_governance::*, error::*, events::EnactGovernanceFeeAccountChangeEvent, get_current_ts},
+    anchor_lang::prelude::*,
+    anchor_spl::token::TokenAccount,
+};
+
+#[derive(Accounts)]
+pub struct EnactGovernanceFeeAccountChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+    #[account(
+    address = common_governance.pool.prepared_governance_fee_key @ PoolError::InvalidGovernanceFeeAccount,
+    token::mint = common_governance.pool.lp_mint_key
+    )]
+    pub new_governance_fee: Account<'info, TokenAccount>,
+}
+
+impl<'info> EnactGovernanceFeeAccountChange<'info> {
+    pub fn accounts(ctx: &Context<EnactGovernanceFeeAccountChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_enact_governance_fee_account_change(ctx: Context<EnactGovernanceFeeAccountChange>) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    require_neq!(pool.governance_fee_key_transition_ts, 0i64, PoolError::InvalidEnact);
+    let current_ts = get_current_ts()?;
+    require_gte!(current_ts, pool.governance_fee_key_transition_ts, PoolError::InsufficientDelay);
+
+    let previous_governance_fee_key = pool.governance_fee_key;
+    pool.governance_fee_key = pool.prepared_governance_fee_key;
+    pool.prepared_governance_fee_key = Pubkey::default();
+    pool.governance_fee_key_transition_ts = 0i64;
+    emit!(EnactGovernanceFeeAccountChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        previous_governance_fee_key,
+        new_governance_fee_key: pool.governance_fee_key,
+    });
+    Ok(())
+}
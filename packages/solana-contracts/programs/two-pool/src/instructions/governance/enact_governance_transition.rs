@@ -1,7 +1,7 @@
 use {
     crate::{
-        common_governance::*, error::*, get_current_ts, governance::ENACT_DELAY, DecimalU64, DecimalU64Anchor, PoolFee,
-        UnixTimestamp,
+        common_governance::*, error::*, events::EnactGovernanceTransitionEvent, get_current_ts,
+        governance::ENACT_DELAY, DecimalU64, DecimalU64Anchor, PoolFee, UnixTimestamp,
     },
     anchor_lang::prelude::*,
 };
@@ -26,8 +26,14 @@ pub fn handle_enact_governance_transition(ctx: Context<EnactGovernanceTransition
 
     require_gte!(current_ts, pool.governance_transition_ts, PoolError::InsufficientDelay);
 
+    let previous_governance_key = pool.governance_key;
     pool.governance_key = pool.prepared_governance_key;
     pool.prepared_governance_key = Pubkey::default();
     pool.governance_transition_ts = 0i64;
+    emit!(EnactGovernanceTransitionEvent {
+        pool: pool.key(),
+        previous_governance_key,
+        new_governance_key: pool.governance_key,
+    });
     Ok(())
 }
@@ -0,0 +1,35 @@
+use {
+    crate::{common_governance::*, error::*, events::EnactPauseKeyChangeEvent, get_current_ts},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct EnactPauseKeyChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> EnactPauseKeyChange<'info> {
+    pub fn accounts(ctx: &Context<EnactPauseKeyChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_enact_pause_key_change(ctx: Context<EnactPauseKeyChange>) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    require_neq!(pool.pause_key_transition_ts, 0i64, PoolError::InvalidEnact);
+    let current_ts = get_current_ts()?;
+    require_gte!(current_ts, pool.pause_key_transition_ts, PoolError::InsufficientDelay);
+
+    let previous_pause_key = pool.pause_key;
+    pool.pause_key = pool.prepared_pause_key;
+    pool.prepared_pause_key = Pubkey::default();
+    pool.pause_key_transition_ts = 0i64;
+    emit!(EnactPauseKeyChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        previous_pause_key,
+        new_pause_key: pool.pause_key,
+    });
+    Ok(())
+}
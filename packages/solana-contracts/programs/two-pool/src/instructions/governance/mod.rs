@@ -1,20 +1,37 @@
 pub use {
-    adjust_amp_factor::*, change_governance_fee_account::*, change_pause_key::*, common_governance::*,
-    create_lp_metadata::*, enact_fee_change::*, enact_governance_transition::*, mpl::*, prepare_fee_change::*,
-    prepare_governance_transition::*, set_paused::*, update_lp_metadata::*,
+    adjust_amp_factor::*, cancel_enact_delay_change::*, cancel_fee_change::*, cancel_governance_fee_account_change::*,
+    cancel_governance_transition::*, cancel_pause_key_change::*, change_pause_key::*, common_governance::*,
+    create_lp_metadata::*, enact_enact_delay_change::*, enact_fee_change::*, enact_governance_fee_account_change::*,
+    enact_governance_transition::*, enact_pause_key_change::*, mpl::*, prepare_enact_delay_change::*,
+    prepare_fee_change::*, prepare_governance_fee_account_change::*, prepare_governance_transition::*,
+    prepare_pause_key_change::*, set_paused::*, stop_amp_factor_adjustment::*, update_lp_metadata::*,
 };
 
 pub mod adjust_amp_factor;
-pub mod change_governance_fee_account;
+pub mod cancel_enact_delay_change;
+pub mod cancel_fee_change;
+pub mod cancel_governance_fee_account_change;
+pub mod cancel_governance_transition;
+pub mod cancel_pause_key_change;
 pub mod change_pause_key;
 pub mod common_governance;
 pub mod create_lp_metadata;
+pub mod enact_enact_delay_change;
 pub mod enact_fee_change;
+pub mod enact_governance_fee_account_change;
 pub mod enact_governance_transition;
+pub mod enact_pause_key_change;
 pub mod mpl;
+pub mod prepare_enact_delay_change;
 pub mod prepare_fee_change;
+pub mod prepare_governance_fee_account_change;
 pub mod prepare_governance_transition;
+pub mod prepare_pause_key_change;
 pub mod set_paused;
+pub mod stop_amp_factor_adjustment;
 pub mod update_lp_metadata;
 
+/// default enact delay of a pool. see `TwoPool.enact_delay`
 pub const ENACT_DELAY: i64 = 3 * 86400;
+pub const MIN_ENACT_DELAY: i64 = 86400;
+pub const MAX_ENACT_DELAY: i64 = 30 * 86400;
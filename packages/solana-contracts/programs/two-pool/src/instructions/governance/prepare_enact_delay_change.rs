@@ -0,0 +1,41 @@
+use {
+    crate::{
+        common_governance::*,
+        error::*,
+        events::PrepareEnactDelayChangeEvent,
+        get_current_ts,
+        governance::{MAX_ENACT_DELAY, MIN_ENACT_DELAY},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct PrepareEnactDelayChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> PrepareEnactDelayChange<'info> {
+    pub fn accounts(ctx: &Context<PrepareEnactDelayChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+/// The enact delay can only be changed through the current enact delay
+///
+/// A delay below `MIN_ENACT_DELAY` is rejected since it would turn every timelocked change into an instant one
+pub fn handle_prepare_enact_delay_change(ctx: Context<PrepareEnactDelayChange>, enact_delay: i64) -> Result<()> {
+    require!((MIN_ENACT_DELAY..=MAX_ENACT_DELAY).contains(&enact_delay), PoolError::InvalidEnactDelay);
+    let pool = &mut ctx.accounts.common_governance.pool;
+    pool.prepared_enact_delay = enact_delay;
+
+    let current_ts = get_current_ts()?;
+    pool.enact_delay_transition_ts = pool.get_transition_ts(current_ts)?;
+    emit!(PrepareEnactDelayChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        enact_delay,
+        enact_delay_transition_ts: pool.enact_delay_transition_ts,
+    });
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use {
     crate::{
-        common_governance::*, error::*, get_current_ts, governance::ENACT_DELAY, DecimalU64, DecimalU64Anchor, PoolFee,
+        common_governance::*, error::*, events::PrepareFeeChangeEvent, get_current_ts, DecimalU64, DecimalU64Anchor,
+        PoolFee,
     },
     anchor_lang::prelude::*,
 };
@@ -38,6 +39,13 @@ pub fn handle_prepare_fee_change(
 
     let current_ts = get_current_ts()?;
 
-    pool.fee_transition_ts = current_ts + ENACT_DELAY;
+    pool.fee_transition_ts = pool.get_transition_ts(current_ts)?;
+    emit!(PrepareFeeChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        lp_fee: lp_fee.into(),
+        governance_fee: governance_fee.into(),
+        fee_transition_ts: pool.fee_transition_ts,
+    });
     Ok(())
 }
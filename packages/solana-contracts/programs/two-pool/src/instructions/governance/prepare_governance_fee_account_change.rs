@@ -0,0 +1,44 @@
+use {
+    crate::{common_governance::*, error::*, events::PrepareGovernanceFeeAccountChangeEvent, get_current_ts},
+    anchor_lang::prelude::*,
+    anchor_spl::token::TokenAccount,
+};
+
+#[derive(Accounts)]
+pub struct PrepareGovernanceFeeAccountChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+    #[account(
+    token::mint = common_governance.pool.lp_mint_key
+    )]
+    pub new_governance_fee: Account<'info, TokenAccount>,
+}
+
+impl<'info> PrepareGovernanceFeeAccountChange<'info> {
+    pub fn accounts(ctx: &Context<PrepareGovernanceFeeAccountChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_prepare_governance_fee_account_change(
+    ctx: Context<PrepareGovernanceFeeAccountChange>,
+    upcoming_governance_fee_key: Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.new_governance_fee.key(),
+        upcoming_governance_fee_key,
+        PoolError::InvalidGovernanceFeeAccount
+    );
+    let pool = &mut ctx.accounts.common_governance.pool;
+    pool.prepared_governance_fee_key = upcoming_governance_fee_key;
+
+    let current_ts = get_current_ts()?;
+    pool.governance_fee_key_transition_ts = pool.get_transition_ts(current_ts)?;
+    emit!(PrepareGovernanceFeeAccountChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        upcoming_governance_fee_key,
+        governance_fee_key_transition_ts: pool.governance_fee_key_transition_ts,
+    });
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use {
     crate::{
-        common_governance::*, get_current_ts, governance::ENACT_DELAY, DecimalU64, DecimalU64Anchor, PoolFee,
-        UnixTimestamp,
+        common_governance::*, events::PrepareGovernanceTransitionEvent, get_current_ts, DecimalU64, DecimalU64Anchor,
+        PoolFee, UnixTimestamp,
     },
     anchor_lang::prelude::*,
 };
@@ -26,7 +26,13 @@ pub fn handle_prepare_governance_transition(
     pool.prepared_governance_key = upcoming_governance_key;
 
     let current_ts = get_current_ts()?;
-    pool.governance_transition_ts = current_ts + ENACT_DELAY;
+    pool.governance_transition_ts = pool.get_transition_ts(current_ts)?;
+    emit!(PrepareGovernanceTransitionEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        upcoming_governance_key,
+        governance_transition_ts: pool.governance_transition_ts,
+    });
 
     Ok(())
 }
@@ -0,0 +1,31 @@
+use {
+    crate::{common_governance::*, events::PreparePauseKeyChangeEvent, get_current_ts},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct PreparePauseKeyChange<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> PreparePauseKeyChange<'info> {
+    pub fn accounts(ctx: &Context<PreparePauseKeyChange>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+pub fn handle_prepare_pause_key_change(ctx: Context<PreparePauseKeyChange>, upcoming_pause_key: Pubkey) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    pool.prepared_pause_key = upcoming_pause_key;
+
+    let current_ts = get_current_ts()?;
+    pool.pause_key_transition_ts = pool.get_transition_ts(current_ts)?;
+    emit!(PreparePauseKeyChangeEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        upcoming_pause_key,
+        pause_key_transition_ts: pool.pause_key_transition_ts,
+    });
+    Ok(())
+}
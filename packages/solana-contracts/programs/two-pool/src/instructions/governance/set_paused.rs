@@ -1,7 +1,7 @@
 use {
     crate::{
-        common_governance::*, error::*, get_current_ts, governance::ENACT_DELAY, DecimalU64, DecimalU64Anchor, PoolFee,
-        TwoPool, UnixTimestamp,
+        common_governance::*, error::*, events::SetPausedEvent, get_current_ts, governance::ENACT_DELAY,
+        state::pause_flags, DecimalU64, DecimalU64Anchor, PoolFee, TwoPool, UnixTimestamp,
     },
     anchor_lang::prelude::*,
 };
@@ -18,7 +18,7 @@ pub struct SetPaused<'info> {
     ],
     bump = pool.bump
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     pub pause_key: Signer<'info>,
 }
 
@@ -29,8 +29,16 @@ impl<'info> SetPaused<'info> {
     }
 }
 
+/// Pauses everything except `remove_uniform` & the price views or unpauses everything
 pub fn handle_set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    handle_set_pause_flags(ctx, if paused { pause_flags::DEFAULT_PAUSE } else { 0 })
+}
+
+/// Pauses the operations flagged in `pause_flags` & unpauses the others
+pub fn handle_set_pause_flags(ctx: Context<SetPaused>, pause_flags: u8) -> Result<()> {
+    require!(pause_flags & !pause_flags::ALL == 0, PoolError::InvalidPauseFlags);
     let pool = &mut ctx.accounts.pool;
-    pool.is_paused = paused;
+    pool.pause_flags = pause_flags;
+    emit!(SetPausedEvent { pool: pool.key(), pause_key: ctx.accounts.pause_key.key(), pause_flags });
     Ok(())
 }
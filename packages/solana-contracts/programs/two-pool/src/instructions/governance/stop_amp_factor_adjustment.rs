@@ -0,0 +1,30 @@
+use {
+    crate::{common_governance::*, events::StopAmpFactorAdjustmentEvent, get_current_ts},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct StopAmpFactorAdjustment<'info> {
+    pub common_governance: CommonGovernance<'info>,
+}
+
+impl<'info> StopAmpFactorAdjustment<'info> {
+    pub fn accounts(ctx: &Context<StopAmpFactorAdjustment>) -> Result<()> {
+        CommonGovernance::accounts(&ctx.accounts.common_governance)?;
+        Ok(())
+    }
+}
+
+/// Freezes the amp factor at its current value. Not timelocked since it's meant to abort a harmful adjustment
+pub fn handle_stop_amp_factor_adjustment(ctx: Context<StopAmpFactorAdjustment>) -> Result<()> {
+    let pool = &mut ctx.accounts.common_governance.pool;
+    let current_ts = get_current_ts()?;
+    let value = pool.amp_factor.stop_adjustment(current_ts)?;
+    emit!(StopAmpFactorAdjustmentEvent {
+        pool: pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        current_ts,
+        value: value.into(),
+    });
+    Ok(())
+}
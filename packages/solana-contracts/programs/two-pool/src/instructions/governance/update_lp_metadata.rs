@@ -1,5 +1,7 @@
 use {
-    crate::{common_governance::*, error::*, gen_pool_signer_seeds, AnchorDataV2, TwoPool},
+    crate::{
+        common_governance::*, error::*, events::UpdateLpMetadataEvent, gen_pool_signer_seeds, AnchorDataV2, TwoPool,
+    },
     anchor_lang::prelude::*,
     anchor_spl::{
         metadata::{
@@ -67,5 +69,12 @@ pub fn handle_update_lp_metadata(ctx: Context<UpdateLpMetadata>, params: UpdateL
         params.data.map(|data| data.into()),
         params.primary_sale_happened,
         params.is_mutable,
-    )
+    )?;
+    emit!(UpdateLpMetadataEvent {
+        pool: ctx.accounts.common_governance.pool.key(),
+        governance: ctx.accounts.common_governance.governance.key(),
+        lp_mint: ctx.accounts.common_governance.pool.lp_mint_key,
+        metadata: ctx.accounts.update_metadata_accounts.metadata.key(),
+    });
+    Ok(())
 }
@@ -1,13 +1,29 @@
 use {
-    crate::{decimal::DecimalU64, error::PoolError::*, AmpFactor, DecimalU64Anchor, PoolError, PoolFee, TwoPool},
+    crate::{
+        decimal::DecimalU64, error::PoolError::*, events::InitializeEvent, get_current_ts, governance::ENACT_DELAY,
+        price_oracle::PriceOracle, AmpFactor, DecimalU64Anchor, PoolError, PoolFee, TwoPool, MAX_TOKEN_COUNT,
+        MIN_TOKEN_COUNT,
+    },
     anchor_lang::prelude::*,
     anchor_spl::{
-        associated_token::{get_associated_token_address, AssociatedToken},
+        associated_token::{self, get_associated_token_address, AssociatedToken},
         token::{Mint, Token, TokenAccount},
     },
-    std::cmp::{max, min},
+    std::{
+        cmp::{max, min},
+        iter::zip,
+    },
 };
 
+/// Initializes a pool with 2 to MAX_TOKEN_COUNT tokens.
+///
+/// The pool's PDA is always derived from the first two mints and the lp mint (which is
+/// unique to the pool) so the seeds stay the same regardless of the number of tokens.
+///
+/// Mints and pool token accounts for tokens 2..token_count are passed in through
+/// `remaining_accounts` (the pool token accounts are created by this instruction):
+///     0. ..token_count - 2 `[]` pool mints for tokens 2..token_count
+///     1. ..token_count - 2 `[w]` pool token accounts (associated token accounts of the pool)
 #[derive(Accounts)]
 pub struct Initialize<'info> {
     // Not sure what the best way of specifying payer is here since we derive it
@@ -24,7 +40,7 @@ pub struct Initialize<'info> {
     payer = payer,
     space = 8 + TwoPool::LEN
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -106,17 +122,52 @@ pub struct InitializeParams {
     pub governance_fee: DecimalU64Anchor,
 }
 
-pub fn handle_initialize(
-    ctx: Context<Initialize>,
+pub fn handle_initialize<'info>(
+    ctx: Context<'_, '_, '_, 'info, Initialize<'info>>,
     amp_factor: DecimalU64Anchor,
     lp_fee: DecimalU64Anchor,
     governance_fee: DecimalU64Anchor,
 ) -> Result<()> {
     let fee_sum = DecimalU64::from(lp_fee) + DecimalU64::from(governance_fee);
     require_gt!(DecimalU64::const_from(1), fee_sum, PoolError::InvalidFeeInput);
+
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(remaining_accounts.len() % 2 == 0, PoolError::InvalidTokenCount);
+    let token_count = MIN_TOKEN_COUNT + remaining_accounts.len() / 2;
+    require_gte!(MAX_TOKEN_COUNT, token_count, PoolError::InvalidTokenCount);
+    let (extra_pool_mints, extra_pool_token_accounts) = remaining_accounts.split_at(token_count - MIN_TOKEN_COUNT);
+
+    let mut pool_mints = vec![(*ctx.accounts.pool_mint_0).clone(), (*ctx.accounts.pool_mint_1).clone()];
+    for pool_mint in extra_pool_mints {
+        pool_mints.push(Account::<Mint>::try_from(pool_mint)?);
+    }
+    let mut pool_token_account_keys =
+        vec![ctx.accounts.pool_token_account_0.key(), ctx.accounts.pool_token_account_1.key()];
+    let pool_key = ctx.accounts.pool.key();
+    for (pool_mint, pool_token_account) in zip(&pool_mints[MIN_TOKEN_COUNT..], extra_pool_token_accounts) {
+        require_keys_eq!(
+            pool_token_account.key(),
+            get_associated_token_address(&pool_key, &pool_mint.key()),
+            PoolError::PoolTokenAccountExpected
+        );
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: ctx.accounts.payer.to_account_info(),
+                associated_token: pool_token_account.clone(),
+                authority: ctx.accounts.pool.to_account_info(),
+                mint: pool_mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+        ))?;
+        pool_token_account_keys.push(pool_token_account.key());
+    }
+
     let two_pool = &mut ctx.accounts.pool;
     two_pool.bump = *ctx.bumps.get("pool").unwrap();
-    two_pool.is_paused = false;
+    two_pool.pause_flags = 0;
     // two_pool.amp_factor = AmpFactor::new(params.amp_factor.into())?;
     // two_pool.lp_fee = PoolFee::new(params.lp_fee.into())?;
     // two_pool.governance_fee = PoolFee::new(params.governance_fee.into())?;
@@ -127,18 +178,20 @@ pub fn handle_initialize(
 
     let mut decimal_range_min = ctx.accounts.lp_mint.decimals;
     let mut decimal_range_max = decimal_range_min;
-    decimal_range_min =
-        min(decimal_range_min, min(ctx.accounts.pool_mint_0.decimals, ctx.accounts.pool_mint_1.decimals));
-    decimal_range_max =
-        max(decimal_range_max, max(ctx.accounts.pool_mint_0.decimals, ctx.accounts.pool_mint_1.decimals));
+    for pool_mint in &pool_mints {
+        decimal_range_min = min(decimal_range_min, pool_mint.decimals);
+        decimal_range_max = max(decimal_range_max, pool_mint.decimals);
+    }
 
     require_gte!(8u8, decimal_range_max - decimal_range_min, PoolError::MaxDecimalDifferenceExceeded);
 
     two_pool.lp_decimal_equalizer = decimal_range_max - ctx.accounts.lp_mint.decimals;
-    two_pool.token_mint_keys = [ctx.accounts.pool_mint_0.key(), ctx.accounts.pool_mint_1.key()];
-    two_pool.token_decimal_equalizers =
-        [decimal_range_max - ctx.accounts.pool_mint_0.decimals, decimal_range_max - ctx.accounts.pool_mint_1.decimals];
-    two_pool.token_keys = [ctx.accounts.pool_token_account_0.key(), ctx.accounts.pool_token_account_1.key()];
+    two_pool.token_count = token_count as u8;
+    for (i, pool_mint) in pool_mints.iter().enumerate() {
+        two_pool.token_mint_keys[i] = pool_mint.key();
+        two_pool.token_decimal_equalizers[i] = decimal_range_max - pool_mint.decimals;
+        two_pool.token_keys[i] = pool_token_account_keys[i];
+    }
     two_pool.pause_key = ctx.accounts.pause_key.key();
     two_pool.governance_key = ctx.accounts.governance_account.key();
     two_pool.governance_fee_key = ctx.accounts.governance_fee_account.key();
@@ -148,6 +201,26 @@ pub fn handle_initialize(
     two_pool.prepared_governance_fee = PoolFee::default();
     two_pool.fee_transition_ts = 0;
     two_pool.previous_depth = 0;
+    two_pool.price_oracle = PriceOracle::new(get_current_ts()?);
+    two_pool.enact_delay = ENACT_DELAY;
+    two_pool.prepared_enact_delay = 0;
+    two_pool.enact_delay_transition_ts = 0;
+    two_pool.prepared_governance_fee_key = Pubkey::default();
+    two_pool.governance_fee_key_transition_ts = 0;
+    two_pool.prepared_pause_key = Pubkey::default();
+    two_pool.pause_key_transition_ts = 0;
+
+    emit!(InitializeEvent {
+        pool: two_pool.key(),
+        lp_mint: two_pool.lp_mint_key,
+        token_mints: pool_mints.iter().map(|pool_mint| pool_mint.key()).collect(),
+        governance: two_pool.governance_key,
+        governance_fee: two_pool.governance_fee_key,
+        pause_key: two_pool.pause_key,
+        amp_factor,
+        lp_fee,
+        governance_fee_rate: governance_fee,
+    });
 
     /**
       &PoolState {
@@ -1,7 +1,12 @@
 use {
     crate::{
-        array_equalize, common::create_array, decimal::U128, error::*, invariant::Invariant, to_equalized, AmpFactor,
-        BorshDecimal, DecimalU64Anchor, TwoPool, TOKEN_COUNT,
+        array_equalize,
+        decimal::{DecimalU64, U128},
+        error::*,
+        invariant::Invariant,
+        load_pool_token_accounts,
+        state::pause_flags,
+        to_equalized, with_token_count, AmpFactor, BorshDecimal, DecimalU64Anchor, TwoPool,
     },
     anchor_lang::prelude::*,
     anchor_spl::{
@@ -23,7 +28,7 @@ pub struct MarginalPrices<'info> {
     ],
     bump = pool.bump
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
 
     #[account(
     token::mint = pool.token_mint_keys[0],
@@ -43,7 +48,7 @@ pub struct MarginalPrices<'info> {
 impl<'info> MarginalPrices<'info> {
     pub fn accounts(ctx: &Context<MarginalPrices>) -> Result<()> {
         let pool_state = &ctx.accounts.pool;
-        require!(!pool_state.is_paused, PoolError::PoolIsPaused);
+        pool_state.require_not_paused(pause_flags::PRICES)?;
         require_keys_eq!(
             ctx.accounts.pool_token_account_0.key(),
             pool_state.token_keys[0],
@@ -60,20 +65,61 @@ impl<'info> MarginalPrices<'info> {
     }
 }
 
-pub fn handle_marginal_prices(ctx: Context<MarginalPrices>) -> Result<[BorshDecimal; TOKEN_COUNT]> {
+pub fn handle_marginal_prices<'info>(
+    ctx: Context<'_, '_, '_, 'info, MarginalPrices<'info>>,
+) -> Result<Vec<BorshDecimal>> {
     let pool = &ctx.accounts.pool;
     let lp_total_supply = ctx.accounts.lp_mint.supply;
-    let pool_balances = [ctx.accounts.pool_token_account_0.amount, ctx.accounts.pool_token_account_1.amount];
+    let pool_token_accounts = load_pool_token_accounts(
+        pool,
+        &ctx.accounts.pool_token_account_0,
+        &ctx.accounts.pool_token_account_1,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+    let pool_balances = pool_token_accounts.iter().map(|account| account.amount).collect::<Vec<_>>();
     let current_ts = Clock::get()?.unix_timestamp;
     require_gt!(current_ts, 0i64, PoolError::InvalidTimestamp);
     msg!("pool_balances: {:?}, lp_total_supply: {:?}", pool_balances, lp_total_supply);
 
-    let marginal_prices = Invariant::<TOKEN_COUNT>::marginal_prices(
-        &array_equalize(pool_balances, pool.token_decimal_equalizers),
-        pool.amp_factor.get(current_ts),
-        to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
-        pool.previous_depth.into(),
-    )?;
+    let marginal_prices = calculate_marginal_prices(pool, &pool_balances, lp_total_supply, current_ts)?;
+
+    Ok(marginal_prices.into_iter().map(|marginal_price| marginal_price.try_into().unwrap()).collect())
+}
+
+/// Returns the marginal prices of the pool's tokens (denominated in lp tokens)
+pub fn calculate_marginal_prices(
+    pool: &TwoPool,
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<Vec<DecimalU64>> {
+    let marginal_prices = with_token_count!(pool.get_token_count(), N => {
+        Invariant::<N>::marginal_prices(
+            &array_equalize(pool_balances, &pool.token_decimal_equalizers),
+            pool.amp_factor.get(current_ts),
+            to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
+            pool.previous_depth.into(),
+        )
+        .map(|marginal_prices| marginal_prices.to_vec())
+    })?;
+    Ok(marginal_prices)
+}
 
-    Ok(create_array(|i| marginal_prices[i].try_into().unwrap()))
+/// Accumulates the prices recorded by the previous ix into the pool's [`crate::price_oracle::PriceOracle`]
+/// and records the pool's new marginal prices
+///
+/// Must be called with the balances, lp supply and depth from *after* the ix
+/// changes them (i.e. after `pool.previous_depth` is updated).
+pub fn update_price_oracle(
+    pool: &mut TwoPool,
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<()> {
+    let marginal_prices = if lp_total_supply > 0 {
+        Some(calculate_marginal_prices(pool, pool_balances, lp_total_supply, current_ts)?)
+    } else {
+        None
+    };
+    pool.price_oracle.update(current_ts, marginal_prices.as_deref())
 }
@@ -3,18 +3,23 @@ use {
     anchor_lang::{prelude::*, require_gt, solana_program::clock::UnixTimestamp},
 };
 pub use {
-    add::*, governance::*, initialize::*, marginal_prices::*, remove_exact_burn::*, remove_exact_output::*,
-    remove_uniform::*, swap_exact_input::*, swap_exact_output::*,
+    add::*, governance::*, initialize::*, marginal_prices::*, observe::*, pool_token_accounts::*, quote::*,
+    remove_exact_burn::*, remove_exact_output::*, remove_uniform::*, swap_exact_input::*, swap_exact_output::*,
+    virtual_price::*,
 };
 
 pub mod add;
 pub mod initialize;
 pub mod marginal_prices;
+pub mod observe;
+pub mod pool_token_accounts;
+pub mod quote;
 pub mod remove_exact_burn;
 pub mod remove_exact_output;
 pub mod remove_uniform;
 pub mod swap_exact_input;
 pub mod swap_exact_output;
+pub mod virtual_price;
 
 pub mod governance;
 
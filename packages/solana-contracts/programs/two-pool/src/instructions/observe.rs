@@ -0,0 +1,21 @@
+use {
+    crate::{error::*, get_current_ts, BorshDecimal, MarginalPrices},
+    anchor_lang::prelude::*,
+};
+
+/// Uses the same accounts as [`MarginalPrices`]. The cumulative prices are extrapolated from
+/// the last update of the oracle to now using the prices recorded by that update (never the
+/// pool's live balances, see [`crate::price_oracle::PriceOracle`]).
+pub fn handle_observe<'info>(
+    ctx: Context<'_, '_, '_, 'info, MarginalPrices<'info>>,
+    window: i64,
+) -> Result<Vec<BorshDecimal>> {
+    let pool = &ctx.accounts.pool;
+    require_gt!(ctx.accounts.lp_mint.supply, 0u64, PoolError::PoolHasNoLiquidity);
+    let current_ts = get_current_ts()?;
+
+    let twap_prices = pool.price_oracle.twap(current_ts, window, pool.get_token_count())?;
+    msg!("window: {:?}, twap_prices: {:?}", window, twap_prices);
+
+    twap_prices.into_iter().map(|twap_price| twap_price.try_into()).collect()
+}
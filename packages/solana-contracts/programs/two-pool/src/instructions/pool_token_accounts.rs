@@ -0,0 +1,49 @@
+//! Every pool has at least two tokens so the token accounts for tokens 0 and 1 are
+//! part of the instruction's `#[derive(Accounts)]` struct. The token accounts of any
+//! additional tokens are passed in through `remaining_accounts` in the following order:
+//!     0. ..token_count - 2 `[w]` pool's token accounts for tokens 2..token_count
+//!     1. ..token_count - 2 `[w]` user token accounts for tokens 2..token_count
+//!
+//! `marginal_prices` only expects the pool's token accounts.
+
+use {
+    crate::{error::*, TwoPool},
+    anchor_lang::prelude::*,
+    anchor_spl::token::TokenAccount,
+    std::slice::Iter,
+};
+
+/// Returns all `pool.token_count` pool token accounts, consuming the ones
+/// for tokens 2..token_count from `remaining_accounts`
+pub fn load_pool_token_accounts<'info>(
+    pool: &TwoPool,
+    pool_token_account_0: &Account<'info, TokenAccount>,
+    pool_token_account_1: &Account<'info, TokenAccount>,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<Vec<Account<'info, TokenAccount>>> {
+    let mut pool_token_accounts = vec![pool_token_account_0.clone(), pool_token_account_1.clone()];
+    for i in 2..pool.get_token_count() {
+        let account_info = remaining_accounts.next().ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        require_keys_eq!(account_info.key(), pool.token_keys[i], PoolError::PoolTokenAccountExpected);
+        pool_token_accounts.push(Account::try_from(account_info)?);
+    }
+    Ok(pool_token_accounts)
+}
+
+/// Returns all `pool.token_count` user token accounts, consuming the ones
+/// for tokens 2..token_count from `remaining_accounts`
+pub fn load_user_token_accounts<'info>(
+    pool: &TwoPool,
+    user_token_account_0: &Account<'info, TokenAccount>,
+    user_token_account_1: &Account<'info, TokenAccount>,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<Vec<Account<'info, TokenAccount>>> {
+    let mut user_token_accounts = vec![user_token_account_0.clone(), user_token_account_1.clone()];
+    for i in 2..pool.get_token_count() {
+        let account_info = remaining_accounts.next().ok_or(ErrorCode::AccountNotEnoughKeys)?;
+        let user_token_account: Account<'info, TokenAccount> = Account::try_from(account_info)?;
+        require_keys_eq!(user_token_account.mint, pool.token_mint_keys[i], PoolError::InvalidMintAccount);
+        user_token_accounts.push(user_token_account);
+    }
+    Ok(user_token_accounts)
+}
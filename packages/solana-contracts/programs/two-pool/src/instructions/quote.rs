@@ -0,0 +1,295 @@
+use {
+    crate::{
+        calculate_add, calculate_add_with_fees, calculate_remove_exact_burn, calculate_remove_exact_burn_with_fees,
+        calculate_remove_exact_output, calculate_remove_exact_output_with_fees, calculate_remove_uniform,
+        calculate_swap_exact_input, calculate_swap_exact_input_with_fees, calculate_swap_exact_output,
+        calculate_swap_exact_output_with_fees, decimal::DecimalU64, error::*, get_current_ts, load_pool_token_accounts,
+        state::pause_flags, validate_add, validate_remove_exact_burn, validate_remove_exact_output,
+        validate_remove_uniform, validate_swap_exact_input, validate_swap_exact_output, AddParams, DecimalU64Anchor,
+        RemoveExactBurnParams, RemoveExactOutputParams, RemoveUniformParams, SwapExactInputParams,
+        SwapExactOutputParams, TwoPool,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, TokenAccount},
+    rust_decimal::{prelude::*, Decimal},
+};
+
+/// Read-only accounts needed to quote any pool operation.
+///
+/// Token accounts for tokens 2..token_count are passed in through `remaining_accounts`
+/// (see [crate::instructions::pool_token_accounts])
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    #[account(
+    seeds = [
+    b"two_pool".as_ref(),
+    pool_token_account_0.mint.as_ref(),
+    pool_token_account_1.mint.as_ref(),
+    lp_mint.key().as_ref(),
+    ],
+    bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, TwoPool>>,
+
+    #[account(
+    token::mint = pool.token_mint_keys[0],
+    token::authority = pool,
+    )]
+    pub pool_token_account_0: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+    token::mint = pool.token_mint_keys[1],
+    token::authority = pool,
+    )]
+    pub pool_token_account_1: Box<Account<'info, TokenAccount>>,
+
+    pub lp_mint: Box<Account<'info, Mint>>,
+}
+
+impl<'info> Quote<'info> {
+    pub fn accounts(ctx: &Context<Quote>) -> Result<()> {
+        let pool_state = &ctx.accounts.pool;
+        require_keys_eq!(
+            ctx.accounts.pool_token_account_0.key(),
+            pool_state.token_keys[0],
+            PoolError::PoolTokenAccountExpected
+        );
+        require_keys_eq!(
+            ctx.accounts.pool_token_account_1.key(),
+            pool_state.token_keys[1],
+            PoolError::PoolTokenAccountExpected
+        );
+        require_keys_eq!(ctx.accounts.lp_mint.key(), pool_state.lp_mint_key, PoolError::InvalidMintAccount);
+        Ok(())
+    }
+
+    fn pool_balances(ctx: &Context<'_, '_, '_, 'info, Quote<'info>>) -> Result<Vec<u64>> {
+        let pool_token_accounts = load_pool_token_accounts(
+            &ctx.accounts.pool,
+            &ctx.accounts.pool_token_account_0,
+            &ctx.accounts.pool_token_account_1,
+            &mut ctx.remaining_accounts.iter(),
+        )?;
+        Ok(pool_token_accounts.iter().map(|account| account.amount).collect())
+    }
+}
+
+/// Result of a quote ix. All amounts are in atomic units.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolQuote {
+    /// output amount of `add`, `swap_exact_input` & `remove_exact_burn`.
+    /// input amount of `swap_exact_output` & `remove_exact_output`
+    pub amount: u64,
+    /// lp tokens minted to the governance fee account
+    pub governance_mint_amount: u64,
+    /// share of the fees (denominated in the same token as `amount`) kept by the pool
+    pub lp_fee_amount: u64,
+    /// share of the fees (denominated in the same token as `amount`) paid out via `governance_mint_amount`
+    pub governance_fee_amount: u64,
+    pub lp_fee: DecimalU64Anchor,
+    pub governance_fee: DecimalU64Anchor,
+}
+
+impl PoolQuote {
+    /// `fee_amount` is the difference between `amount` with & without fees
+    fn new(pool: &TwoPool, amount: u64, governance_mint_amount: u64, fee_amount: u64) -> Self {
+        let lp_fee = Decimal::from(pool.lp_fee.get());
+        let governance_fee = Decimal::from(pool.governance_fee.get());
+        let total_fee = lp_fee + governance_fee;
+        let governance_fee_amount = if total_fee.is_zero() {
+            0
+        } else {
+            (Decimal::from(fee_amount) * governance_fee / total_fee).to_u64().unwrap_or(0).min(fee_amount)
+        };
+        Self {
+            amount,
+            governance_mint_amount,
+            lp_fee_amount: fee_amount - governance_fee_amount,
+            governance_fee_amount,
+            lp_fee: pool.lp_fee.get().into(),
+            governance_fee: pool.governance_fee.get().into(),
+        }
+    }
+}
+
+/// Fee passed to the `calculate_*_with_fees` fns to compute the fee amounts of a quote
+const NO_FEE: DecimalU64 = DecimalU64::const_from(0);
+
+pub fn handle_quote_add<'info>(ctx: Context<'_, '_, '_, 'info, Quote<'info>>, params: AddParams) -> Result<PoolQuote> {
+    let pool = &ctx.accounts.pool;
+    pool.require_not_paused(pause_flags::ADD)?;
+    let input_amounts = params.input_amounts;
+    let lp_total_supply = ctx.accounts.lp_mint.supply;
+    validate_add(pool, &input_amounts, lp_total_supply)?;
+    let pool_balances = Quote::pool_balances(&ctx)?;
+    let current_ts = get_current_ts()?;
+
+    let (mint_amount, governance_mint_amount, _) =
+        calculate_add(pool, &input_amounts, &pool_balances, lp_total_supply, current_ts)?;
+    require_gte!(mint_amount, params.minimum_mint_amount, PoolError::OutsideSpecifiedLimits);
+    let (fee_free_mint_amount, _, _) =
+        calculate_add_with_fees(pool, NO_FEE, NO_FEE, &input_amounts, &pool_balances, lp_total_supply, current_ts)?;
+    Ok(PoolQuote::new(pool, mint_amount, governance_mint_amount, fee_free_mint_amount.saturating_sub(mint_amount)))
+}
+
+pub fn handle_quote_swap_exact_input<'info>(
+    ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+    params: SwapExactInputParams,
+) -> Result<PoolQuote> {
+    let pool = &ctx.accounts.pool;
+    pool.require_not_paused(pause_flags::SWAP)?;
+    let exact_input_amounts = params.exact_input_amounts;
+    let output_token_index = params.output_token_index as usize;
+    validate_swap_exact_input(pool, &exact_input_amounts, output_token_index)?;
+    let lp_total_supply = ctx.accounts.lp_mint.supply;
+    let pool_balances = Quote::pool_balances(&ctx)?;
+    let current_ts = get_current_ts()?;
+
+    let (output_amount, governance_mint_amount, _) = calculate_swap_exact_input(
+        pool,
+        &exact_input_amounts,
+        output_token_index,
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
+    )?;
+    require_gte!(output_amount, params.minimum_output_amount, PoolError::OutsideSpecifiedLimits);
+    let (fee_free_output_amount, _, _) = calculate_swap_exact_input_with_fees(
+        pool,
+        NO_FEE,
+        NO_FEE,
+        &exact_input_amounts,
+        output_token_index,
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
+    )?;
+    Ok(PoolQuote::new(
+        pool,
+        output_amount,
+        governance_mint_amount,
+        fee_free_output_amount.saturating_sub(output_amount),
+    ))
+}
+
+pub fn handle_quote_swap_exact_output<'info>(
+    ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+    params: SwapExactOutputParams,
+) -> Result<PoolQuote> {
+    let pool = &ctx.accounts.pool;
+    pool.require_not_paused(pause_flags::SWAP)?;
+    let exact_output_amounts = params.exact_output_amounts;
+    let input_token_index = params.input_token_index as usize;
+    let pool_balances = Quote::pool_balances(&ctx)?;
+    validate_swap_exact_output(pool, input_token_index, &exact_output_amounts, &pool_balances)?;
+    let lp_total_supply = ctx.accounts.lp_mint.supply;
+    let current_ts = get_current_ts()?;
+
+    let (input_amount, governance_mint_amount, _) = calculate_swap_exact_output(
+        pool,
+        input_token_index,
+        &exact_output_amounts,
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
+    )?;
+    require_gte!(params.maximum_input_amount, input_amount, PoolError::OutsideSpecifiedLimits);
+    let (fee_free_input_amount, _, _) = calculate_swap_exact_output_with_fees(
+        pool,
+        NO_FEE,
+        NO_FEE,
+        input_token_index,
+        &exact_output_amounts,
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
+    )?;
+    Ok(PoolQuote::new(pool, input_amount, governance_mint_amount, input_amount.saturating_sub(fee_free_input_amount)))
+}
+
+/// `remove_uniform` charges no fees so only the output amounts are returned
+pub fn handle_quote_remove_uniform<'info>(
+    ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+    params: RemoveUniformParams,
+) -> Result<Vec<u64>> {
+    let pool = &ctx.accounts.pool;
+    pool.require_not_paused(pause_flags::REMOVE_UNIFORM)?;
+    let exact_burn_amount = params.exact_burn_amount;
+    let minimum_output_amounts = params.minimum_output_amounts;
+    let lp_total_supply = ctx.accounts.lp_mint.supply;
+    validate_remove_uniform(pool, exact_burn_amount, &minimum_output_amounts, lp_total_supply)?;
+    let pool_balances = Quote::pool_balances(&ctx)?;
+
+    let output_amounts = calculate_remove_uniform(exact_burn_amount, &pool_balances, lp_total_supply);
+    for (output_amount, minimum_output_amount) in output_amounts.iter().zip(minimum_output_amounts.iter()) {
+        require_gte!(*output_amount, *minimum_output_amount, PoolError::OutsideSpecifiedLimits);
+    }
+    Ok(output_amounts)
+}
+
+pub fn handle_quote_remove_exact_burn<'info>(
+    ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+    params: RemoveExactBurnParams,
+) -> Result<PoolQuote> {
+    let pool = &ctx.accounts.pool;
+    pool.require_not_paused(pause_flags::REMOVE_IMBALANCED)?;
+    let exact_burn_amount = params.exact_burn_amount;
+    let output_token_index = params.output_token_index as usize;
+    let lp_total_supply = ctx.accounts.lp_mint.supply;
+    validate_remove_exact_burn(pool, exact_burn_amount, output_token_index, lp_total_supply)?;
+    let pool_balances = Quote::pool_balances(&ctx)?;
+    let current_ts = get_current_ts()?;
+
+    let (output_amount, governance_mint_amount, _) = calculate_remove_exact_burn(
+        pool,
+        exact_burn_amount,
+        output_token_index,
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
+    )?;
+    require_gte!(output_amount, params.minimum_output_amount, PoolError::OutsideSpecifiedLimits);
+    let (fee_free_output_amount, _, _) = calculate_remove_exact_burn_with_fees(
+        pool,
+        NO_FEE,
+        NO_FEE,
+        exact_burn_amount,
+        output_token_index,
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
+    )?;
+    Ok(PoolQuote::new(
+        pool,
+        output_amount,
+        governance_mint_amount,
+        fee_free_output_amount.saturating_sub(output_amount),
+    ))
+}
+
+pub fn handle_quote_remove_exact_output<'info>(
+    ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+    params: RemoveExactOutputParams,
+) -> Result<PoolQuote> {
+    let pool = &ctx.accounts.pool;
+    pool.require_not_paused(pause_flags::REMOVE_IMBALANCED)?;
+    let exact_output_amounts = params.exact_output_amounts;
+    let pool_balances = Quote::pool_balances(&ctx)?;
+    validate_remove_exact_output(pool, &exact_output_amounts, params.maximum_burn_amount, &pool_balances)?;
+    let lp_total_supply = ctx.accounts.lp_mint.supply;
+    let current_ts = get_current_ts()?;
+
+    let (burn_amount, governance_mint_amount, _) =
+        calculate_remove_exact_output(pool, &exact_output_amounts, &pool_balances, lp_total_supply, current_ts)?;
+    require_gte!(params.maximum_burn_amount, burn_amount, PoolError::OutsideSpecifiedLimits);
+    let (fee_free_burn_amount, _, _) = calculate_remove_exact_output_with_fees(
+        pool,
+        NO_FEE,
+        NO_FEE,
+        &exact_output_amounts,
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
+    )?;
+    Ok(PoolQuote::new(pool, burn_amount, governance_mint_amount, burn_amount.saturating_sub(fee_free_burn_amount)))
+}
@@ -1,14 +1,14 @@
 use {
     crate::{
-        array_equalize, error::*, gen_pool_signer_seeds, invariant::Invariant, result_from_equalized, to_equalized,
-        TwoPool, TOKEN_COUNT,
+        array_equalize, decimal::DecimalU64, error::*, events::RemoveExactBurnEvent, gen_pool_signer_seeds,
+        invariant::Invariant, load_pool_token_accounts, load_user_token_accounts, result_from_equalized,
+        state::pause_flags, to_equalized, update_price_oracle, with_token_count, TwoPool,
     },
     anchor_lang::prelude::*,
     anchor_spl::{
         token,
         token::{Mint, Token, TokenAccount},
     },
-    std::iter::zip,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -30,7 +30,7 @@ pub struct RemoveExactBurn<'info> {
     ],
     bump = pool.bump
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     #[account(
     mut,
     token::mint = pool.token_mint_keys[0],
@@ -81,7 +81,7 @@ pub struct RemoveExactBurn<'info> {
 impl<'info> RemoveExactBurn<'info> {
     pub fn accounts(ctx: &Context<RemoveExactBurn>) -> Result<()> {
         let pool_state = &ctx.accounts.pool;
-        require!(!pool_state.is_paused, PoolError::PoolIsPaused);
+        pool_state.require_not_paused(pause_flags::REMOVE_IMBALANCED)?;
         require_keys_eq!(
             ctx.accounts.pool_token_account_0.key(),
             pool_state.token_keys[0],
@@ -108,53 +108,44 @@ impl<'info> RemoveExactBurn<'info> {
     }
 }
 
-pub fn handle_remove_exact_burn(
-    ctx: Context<RemoveExactBurn>,
+pub fn handle_remove_exact_burn<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveExactBurn<'info>>,
     remove_exact_burn_params: RemoveExactBurnParams,
 ) -> Result<u64> {
     let output_token_index = remove_exact_burn_params.output_token_index as usize;
     let exact_burn_amount = remove_exact_burn_params.exact_burn_amount;
     let lp_total_supply = ctx.accounts.lp_mint.supply;
-    require!(output_token_index < TOKEN_COUNT, PoolError::InvalidRemoveExactBurnParameters);
-    require_gt!(exact_burn_amount, 0, PoolError::InvalidRemoveExactBurnParameters);
-    require_gt!(lp_total_supply, exact_burn_amount, PoolError::InvalidRemoveExactBurnParameters);
-
     let pool = &ctx.accounts.pool;
-    let pool_token_account_0 = &ctx.accounts.pool_token_account_0;
-    let pool_token_account_1 = &ctx.accounts.pool_token_account_1;
-    let pool_balances = [ctx.accounts.pool_token_account_0.amount, ctx.accounts.pool_token_account_1.amount];
-    let lp_mint = &ctx.accounts.lp_mint;
-    let governance_fee = &ctx.accounts.governance_fee;
-    // let user_transfer_auth = &ctx.accounts.user_transfer_authority;
-    let user_token_account_0 = &ctx.accounts.user_token_account_0;
-    let user_token_account_1 = &ctx.accounts.user_token_account_1;
-    let user_token_accounts = [user_token_account_0, user_token_account_1];
-
-    let pool_token_accounts = [pool_token_account_0, pool_token_account_1];
+    validate_remove_exact_burn(pool, exact_burn_amount, output_token_index, lp_total_supply)?;
+
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    let pool_token_accounts = load_pool_token_accounts(
+        pool,
+        &ctx.accounts.pool_token_account_0,
+        &ctx.accounts.pool_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let user_token_accounts = load_user_token_accounts(
+        pool,
+        &ctx.accounts.user_token_account_0,
+        &ctx.accounts.user_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let pool_balances = pool_token_accounts.iter().map(|account| account.amount).collect::<Vec<_>>();
 
     let current_ts = Clock::get()?.unix_timestamp;
     require_gt!(current_ts, 0i64, PoolError::InvalidTimestamp);
-    let (user_amount, governance_mint_amount, latest_depth) = Invariant::<TOKEN_COUNT>::remove_exact_burn(
-        to_equalized(exact_burn_amount, pool.lp_decimal_equalizer),
+    let (output_amount, governance_mint_amount, latest_depth) = calculate_remove_exact_burn(
+        pool,
+        exact_burn_amount,
         output_token_index,
-        &array_equalize(pool_balances, pool.token_decimal_equalizers),
-        pool.amp_factor.get(current_ts),
-        pool.lp_fee.get(),
-        pool.governance_fee.get(),
-        to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
-        pool.previous_depth.into(),
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
     )?;
-    let (output_amount, governance_mint_amount, latest_depth) = result_from_equalized(
-        user_amount,
-        pool.token_decimal_equalizers[output_token_index],
-        governance_mint_amount,
-        pool.lp_decimal_equalizer,
-        latest_depth,
-    );
     let minimum_output_amount = remove_exact_burn_params.minimum_output_amount;
     require_gte!(output_amount, minimum_output_amount, PoolError::OutsideSpecifiedLimits);
 
-    let mut token_accounts = zip(user_token_accounts.into_iter(), pool_token_accounts.into_iter());
     token::burn(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -167,8 +158,8 @@ pub fn handle_remove_exact_burn(
         exact_burn_amount,
     )?;
 
-    let user_output_token_account = user_token_accounts[output_token_index];
-    let pool_output_token_account = pool_token_accounts[output_token_index];
+    let user_output_token_account = &user_token_accounts[output_token_index];
+    let pool_output_token_account = &pool_token_accounts[output_token_index];
     token::transfer(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
@@ -200,5 +191,86 @@ pub fn handle_remove_exact_burn(
     }
     let pool = &mut ctx.accounts.pool;
     pool.previous_depth = latest_depth;
+    let mut pool_balances = pool_balances;
+    pool_balances[output_token_index] -= output_amount;
+    let lp_total_supply = lp_total_supply - exact_burn_amount + governance_mint_amount;
+    update_price_oracle(pool, &pool_balances, lp_total_supply, current_ts)?;
+    emit!(RemoveExactBurnEvent {
+        pool: pool.key(),
+        user: ctx.accounts.user_transfer_authority.key(),
+        exact_burn_amount,
+        output_token_index: output_token_index as u8,
+        output_amount,
+        governance_mint_amount,
+        latest_depth,
+    });
     Ok(output_amount)
 }
+
+/// Checks the `exact_burn_amount` & `output_token_index` of a remove. Shared by `remove_exact_burn` &
+/// `quote_remove_exact_burn`
+pub fn validate_remove_exact_burn(
+    pool: &TwoPool,
+    exact_burn_amount: u64,
+    output_token_index: usize,
+    lp_total_supply: u64,
+) -> Result<()> {
+    require!(output_token_index < pool.get_token_count(), PoolError::InvalidRemoveExactBurnParameters);
+    require_gt!(exact_burn_amount, 0, PoolError::InvalidRemoveExactBurnParameters);
+    require_gt!(lp_total_supply, exact_burn_amount, PoolError::InvalidRemoveExactBurnParameters);
+    Ok(())
+}
+
+/// Returns `(output_amount, governance_mint_amount, latest_depth)` of burning `exact_burn_amount`
+/// lp tokens for the token at `output_token_index` without executing the removal
+pub fn calculate_remove_exact_burn(
+    pool: &TwoPool,
+    exact_burn_amount: u64,
+    output_token_index: usize,
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    calculate_remove_exact_burn_with_fees(
+        pool,
+        pool.lp_fee.get(),
+        pool.governance_fee.get(),
+        exact_burn_amount,
+        output_token_index,
+        pool_balances,
+        lp_total_supply,
+        current_ts,
+    )
+}
+
+/// Same as [calculate_remove_exact_burn] but charges `lp_fee` & `governance_fee` instead of the pool's fees
+pub fn calculate_remove_exact_burn_with_fees(
+    pool: &TwoPool,
+    lp_fee: DecimalU64,
+    governance_fee: DecimalU64,
+    exact_burn_amount: u64,
+    output_token_index: usize,
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    let (user_amount, governance_mint_amount, latest_depth) = with_token_count!(pool.get_token_count(), N => {
+        Invariant::<N>::remove_exact_burn(
+            to_equalized(exact_burn_amount, pool.lp_decimal_equalizer),
+            output_token_index,
+            &array_equalize(pool_balances, &pool.token_decimal_equalizers),
+            pool.amp_factor.get(current_ts),
+            lp_fee,
+            governance_fee,
+            to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
+            pool.previous_depth.into(),
+        )
+    })?;
+    Ok(result_from_equalized(
+        user_amount,
+        pool.token_decimal_equalizers[output_token_index],
+        governance_mint_amount,
+        pool.lp_decimal_equalizer,
+        latest_depth,
+    ))
+}
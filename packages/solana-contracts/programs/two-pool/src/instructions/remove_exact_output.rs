@@ -1,7 +1,8 @@
 use {
     crate::{
-        array_equalize, error::*, gen_pool_signer_seeds, get_current_ts, invariant::Invariant, result_from_equalized,
-        to_equalized, TwoPool, TOKEN_COUNT,
+        array_equalize, decimal::DecimalU64, error::*, events::RemoveExactOutputEvent, gen_pool_signer_seeds,
+        get_current_ts, invariant::Invariant, load_pool_token_accounts, load_user_token_accounts,
+        result_from_equalized, state::pause_flags, to_equalized, update_price_oracle, with_token_count, TwoPool,
     },
     anchor_lang::prelude::*,
     anchor_spl::{
@@ -14,7 +15,7 @@ use {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct RemoveExactOutputParams {
     pub maximum_burn_amount: u64,
-    pub exact_output_amounts: [u64; TOKEN_COUNT],
+    pub exact_output_amounts: Vec<u64>,
 }
 
 #[derive(Accounts)]
@@ -29,7 +30,7 @@ pub struct RemoveExactOutput<'info> {
     ],
     bump = pool.bump
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     #[account(
     mut,
     token::mint = pool.token_mint_keys[0],
@@ -80,7 +81,7 @@ pub struct RemoveExactOutput<'info> {
 impl<'info> RemoveExactOutput<'info> {
     pub fn accounts(ctx: &Context<RemoveExactOutput>) -> Result<()> {
         let pool_state = &ctx.accounts.pool;
-        require!(!pool_state.is_paused, PoolError::PoolIsPaused);
+        pool_state.require_not_paused(pause_flags::REMOVE_IMBALANCED)?;
         require_keys_eq!(
             ctx.accounts.pool_token_account_0.key(),
             pool_state.token_keys[0],
@@ -107,58 +108,40 @@ impl<'info> RemoveExactOutput<'info> {
     }
 }
 
-pub fn handle_remove_exact_output(
-    ctx: Context<RemoveExactOutput>,
+pub fn handle_remove_exact_output<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveExactOutput<'info>>,
     remove_exact_output_params: RemoveExactOutputParams,
 ) -> Result<Vec<u64>> {
     let maximum_burn_amount = remove_exact_output_params.maximum_burn_amount;
     let exact_output_amounts = remove_exact_output_params.exact_output_amounts;
     let lp_total_supply = ctx.accounts.lp_mint.supply;
-    let pool_balances = [ctx.accounts.pool_token_account_0.amount, ctx.accounts.pool_token_account_1.amount];
-
-    require!(exact_output_amounts.iter().any(|amount| *amount > 0), PoolError::InvalidRemoveExactOutputParameters);
-    require_gt!(maximum_burn_amount, 0u64, PoolError::InvalidRemoveExactOutputParameters);
-    let are_output_amounts_valid = exact_output_amounts
-        .iter()
-        .zip(pool_balances.iter())
-        .all(|(output_amount, pool_balance)| *output_amount < *pool_balance);
-    require!(are_output_amounts_valid, PoolError::InvalidRemoveExactOutputParameters);
-
     let pool = &ctx.accounts.pool;
-    let pool_token_account_0 = &ctx.accounts.pool_token_account_0;
-    let pool_token_account_1 = &ctx.accounts.pool_token_account_1;
-    let lp_mint = &ctx.accounts.lp_mint;
-    let governance_fee = &ctx.accounts.governance_fee;
-    // let user_transfer_auth = &ctx.accounts.user_transfer_authority;
-    let user_token_account_0 = &ctx.accounts.user_token_account_0;
-    let user_token_account_1 = &ctx.accounts.user_token_account_1;
-    let user_token_accounts = [user_token_account_0, user_token_account_1];
-
-    let pool_token_accounts = [pool_token_account_0, pool_token_account_1];
+    let token_count = pool.get_token_count();
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    let pool_token_accounts = load_pool_token_accounts(
+        pool,
+        &ctx.accounts.pool_token_account_0,
+        &ctx.accounts.pool_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let user_token_accounts = load_user_token_accounts(
+        pool,
+        &ctx.accounts.user_token_account_0,
+        &ctx.accounts.user_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let pool_balances = pool_token_accounts.iter().map(|account| account.amount).collect::<Vec<_>>();
+    validate_remove_exact_output(pool, &exact_output_amounts, maximum_burn_amount, &pool_balances)?;
 
     let current_ts = get_current_ts()?;
 
-    let (burn_amount, governance_mint_amount, latest_depth) = Invariant::<TOKEN_COUNT>::remove_exact_output(
-        &array_equalize(exact_output_amounts, pool.token_decimal_equalizers),
-        &array_equalize(pool_balances, pool.token_decimal_equalizers),
-        pool.amp_factor.get(current_ts),
-        pool.lp_fee.get(),
-        pool.governance_fee.get(),
-        to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
-        pool.previous_depth.into(),
-    )?;
-    let (burn_amount, governance_mint_amount, latest_depth) = result_from_equalized(
-        burn_amount,
-        pool.lp_decimal_equalizer,
-        governance_mint_amount,
-        pool.lp_decimal_equalizer,
-        latest_depth,
-    );
+    let (burn_amount, governance_mint_amount, latest_depth) =
+        calculate_remove_exact_output(pool, &exact_output_amounts, &pool_balances, lp_total_supply, current_ts)?;
 
     let maximum_burn_amount = remove_exact_output_params.maximum_burn_amount;
     require_gte!(maximum_burn_amount, burn_amount, PoolError::OutsideSpecifiedLimits);
 
-    let mut token_accounts = zip(user_token_accounts.into_iter(), pool_token_accounts.into_iter());
+    let mut token_accounts = zip(user_token_accounts.iter(), pool_token_accounts.iter());
     token::burn(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -171,7 +154,7 @@ pub fn handle_remove_exact_output(
         burn_amount,
     )?;
 
-    for i in 0..TOKEN_COUNT {
+    for i in 0..token_count {
         let (user_token_account, pool_token_account) = token_accounts.next().unwrap();
         if exact_output_amounts[i] > 0 {
             token::transfer(
@@ -207,5 +190,86 @@ pub fn handle_remove_exact_output(
     }
     let pool = &mut ctx.accounts.pool;
     pool.previous_depth = latest_depth;
-    Ok(exact_output_amounts.into())
+    let pool_balances =
+        pool_balances.iter().zip(&exact_output_amounts).map(|(balance, amount)| balance - amount).collect::<Vec<_>>();
+    let lp_total_supply = lp_total_supply - burn_amount + governance_mint_amount;
+    update_price_oracle(pool, &pool_balances, lp_total_supply, current_ts)?;
+    emit!(RemoveExactOutputEvent {
+        pool: pool.key(),
+        user: ctx.accounts.user_transfer_authority.key(),
+        burn_amount,
+        exact_output_amounts: exact_output_amounts.clone(),
+        governance_mint_amount,
+        latest_depth,
+    });
+    Ok(exact_output_amounts)
+}
+
+/// Checks the `exact_output_amounts` & `maximum_burn_amount` of a remove against the `pool_balances`.
+/// Shared by `remove_exact_output` & `quote_remove_exact_output`
+pub fn validate_remove_exact_output(
+    pool: &TwoPool,
+    exact_output_amounts: &[u64],
+    maximum_burn_amount: u64,
+    pool_balances: &[u64],
+) -> Result<()> {
+    require_eq!(exact_output_amounts.len(), pool.get_token_count(), PoolError::InvalidTokenCount);
+    require!(exact_output_amounts.iter().any(|amount| *amount > 0), PoolError::InvalidRemoveExactOutputParameters);
+    require_gt!(maximum_burn_amount, 0u64, PoolError::InvalidRemoveExactOutputParameters);
+    let are_output_amounts_valid = exact_output_amounts
+        .iter()
+        .zip(pool_balances.iter())
+        .all(|(output_amount, pool_balance)| *output_amount < *pool_balance);
+    require!(are_output_amounts_valid, PoolError::InvalidRemoveExactOutputParameters);
+    Ok(())
+}
+
+/// Returns `(burn_amount, governance_mint_amount, latest_depth)` of removing `exact_output_amounts`
+/// without executing the removal
+pub fn calculate_remove_exact_output(
+    pool: &TwoPool,
+    exact_output_amounts: &[u64],
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    calculate_remove_exact_output_with_fees(
+        pool,
+        pool.lp_fee.get(),
+        pool.governance_fee.get(),
+        exact_output_amounts,
+        pool_balances,
+        lp_total_supply,
+        current_ts,
+    )
+}
+
+/// Same as [calculate_remove_exact_output] but charges `lp_fee` & `governance_fee` instead of the pool's fees
+pub fn calculate_remove_exact_output_with_fees(
+    pool: &TwoPool,
+    lp_fee: DecimalU64,
+    governance_fee: DecimalU64,
+    exact_output_amounts: &[u64],
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    let (burn_amount, governance_mint_amount, latest_depth) = with_token_count!(pool.get_token_count(), N => {
+        Invariant::<N>::remove_exact_output(
+            &array_equalize(exact_output_amounts, &pool.token_decimal_equalizers),
+            &array_equalize(pool_balances, &pool.token_decimal_equalizers),
+            pool.amp_factor.get(current_ts),
+            lp_fee,
+            governance_fee,
+            to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
+            pool.previous_depth.into(),
+        )
+    })?;
+    Ok(result_from_equalized(
+        burn_amount,
+        pool.lp_decimal_equalizer,
+        governance_mint_amount,
+        pool.lp_decimal_equalizer,
+        latest_depth,
+    ))
 }
@@ -1,7 +1,8 @@
 use {
     crate::{
-        array_equalize, error::*, gen_pool_signer_seeds, invariant::Invariant, result_from_equalized, to_equalized,
-        DecimalU64, TwoPool, TOKEN_COUNT,
+        array_equalize, calculate_marginal_prices, error::*, events::RemoveUniformEvent, gen_pool_signer_seeds,
+        get_current_ts, invariant::Invariant, load_pool_token_accounts, load_user_token_accounts,
+        result_from_equalized, state::pause_flags, to_equalized, DecimalU64, TwoPool,
     },
     anchor_lang::prelude::*,
     anchor_spl::{
@@ -14,7 +15,7 @@ use {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct RemoveUniformParams {
     pub exact_burn_amount: u64,
-    pub minimum_output_amounts: [u64; TOKEN_COUNT],
+    pub minimum_output_amounts: Vec<u64>,
 }
 
 #[derive(Accounts)]
@@ -29,7 +30,7 @@ pub struct RemoveUniform<'info> {
     ],
     bump = pool.bump
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     #[account(
     mut,
     token::mint = pool.token_mint_keys[0],
@@ -81,7 +82,7 @@ pub struct RemoveUniform<'info> {
 impl<'info> RemoveUniform<'info> {
     pub fn accounts(ctx: &Context<RemoveUniform>) -> Result<()> {
         let pool_state = &ctx.accounts.pool;
-        require!(!pool_state.is_paused, PoolError::PoolIsPaused);
+        pool_state.require_not_paused(pause_flags::REMOVE_UNIFORM)?;
         require_keys_eq!(
             ctx.accounts.pool_token_account_0.key(),
             pool_state.token_keys[0],
@@ -108,20 +109,31 @@ impl<'info> RemoveUniform<'info> {
     }
 }
 
-pub fn handle_remove_uniform(
-    ctx: Context<RemoveUniform>,
+pub fn handle_remove_uniform<'info>(
+    ctx: Context<'_, '_, '_, 'info, RemoveUniform<'info>>,
     remove_uniform_params: RemoveUniformParams,
 ) -> Result<Vec<u64>> {
     let exact_burn_amount = remove_uniform_params.exact_burn_amount;
     let minimum_output_amounts = remove_uniform_params.minimum_output_amounts;
     let lp_total_supply = ctx.accounts.lp_mint.supply;
-    require_gt!(exact_burn_amount, 0u64, PoolError::InvalidRemoveUniformParameters);
-    require_gte!(lp_total_supply, exact_burn_amount, PoolError::InvalidRemoveUniformParameters);
-
     let pool = &ctx.accounts.pool;
-    let pool_token_account_0 = &ctx.accounts.pool_token_account_0;
-    let pool_token_account_1 = &ctx.accounts.pool_token_account_1;
-    let pool_balances = [ctx.accounts.pool_token_account_0.amount, ctx.accounts.pool_token_account_1.amount];
+    let token_count = pool.get_token_count();
+    validate_remove_uniform(pool, exact_burn_amount, &minimum_output_amounts, lp_total_supply)?;
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    let pool_token_accounts = load_pool_token_accounts(
+        pool,
+        &ctx.accounts.pool_token_account_0,
+        &ctx.accounts.pool_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let user_token_accounts = load_user_token_accounts(
+        pool,
+        &ctx.accounts.user_token_account_0,
+        &ctx.accounts.user_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let pool_balances = pool_token_accounts.iter().map(|account| account.amount).collect::<Vec<_>>();
+    let current_ts = get_current_ts()?;
 
     let user_share = DecimalU64::from(exact_burn_amount) / lp_total_supply;
     //u64 can store 19 decimals, previous_depth can theoretically go up to TOKEN_COUNT * u64::MAX
@@ -133,19 +145,12 @@ pub fn handle_remove_uniform(
         / 10u128.pow(DECIMAL_UPSHIFT);
     let latest_depth = pool.previous_depth - user_depth;
 
-    let user_token_account_0 = &ctx.accounts.user_token_account_0;
-    let user_token_account_1 = &ctx.accounts.user_token_account_1;
-    let user_token_accounts = [user_token_account_0, user_token_account_1];
-
-    let pool_token_accounts = [pool_token_account_0, pool_token_account_1];
+    let mut token_accounts = zip(user_token_accounts.iter(), pool_token_accounts.iter());
 
-    let mut token_accounts = zip(user_token_accounts.into_iter(), pool_token_accounts.into_iter());
-
-    let mut output_amounts = vec![];
-    for i in 0..TOKEN_COUNT {
+    let output_amounts = calculate_remove_uniform(exact_burn_amount, &pool_balances, lp_total_supply);
+    for i in 0..token_count {
         let (user_token_account, pool_token_account) = token_accounts.next().unwrap();
-        let output_amount = (pool_balances[i] * user_share).trunc();
-        output_amounts.push(output_amount);
+        let output_amount = output_amounts[i];
         require_gte!(output_amount, minimum_output_amounts[i], PoolError::OutsideSpecifiedLimits);
         token::transfer(
             CpiContext::new_with_signer(
@@ -176,6 +181,50 @@ pub fn handle_remove_uniform(
 
     let pool = &mut ctx.accounts.pool;
     pool.previous_depth = latest_depth;
+    let pool_balances =
+        pool_balances.iter().zip(&output_amounts).map(|(balance, amount)| balance - amount).collect::<Vec<_>>();
+    //remove_uniform is the only way to exit a pool whose marginal prices can't be calculated (e.g. because
+    //one of its balances is 0), hence the oracle update is best-effort and must never fail the ix.
+    //if the new prices can't be calculated, none are recorded until the next ix that mutates the pool
+    let lp_total_supply = lp_total_supply - exact_burn_amount;
+    let marginal_prices = if lp_total_supply > 0 && !pool_balances.contains(&0) {
+        calculate_marginal_prices(pool, &pool_balances, lp_total_supply, current_ts)
+            .map_err(|err| msg!("failed to calculate marginal prices: {:?}", err))
+            .ok()
+    } else {
+        None
+    };
+    if let Err(err) = pool.price_oracle.update(current_ts, marginal_prices.as_deref()) {
+        msg!("failed to update price oracle: {:?}", err);
+    }
+    emit!(RemoveUniformEvent {
+        pool: pool.key(),
+        user: ctx.accounts.user_transfer_authority.key(),
+        exact_burn_amount,
+        output_amounts: output_amounts.clone(),
+        latest_depth,
+    });
 
     Ok(output_amounts)
 }
+
+/// Checks the `exact_burn_amount` & `minimum_output_amounts` of a uniform remove.
+/// Shared by `remove_uniform` & `quote_remove_uniform`
+pub fn validate_remove_uniform(
+    pool: &TwoPool,
+    exact_burn_amount: u64,
+    minimum_output_amounts: &[u64],
+    lp_total_supply: u64,
+) -> Result<()> {
+    require_gt!(exact_burn_amount, 0u64, PoolError::InvalidRemoveUniformParameters);
+    require_gte!(lp_total_supply, exact_burn_amount, PoolError::InvalidRemoveUniformParameters);
+    require_eq!(minimum_output_amounts.len(), pool.get_token_count(), PoolError::InvalidTokenCount);
+    Ok(())
+}
+
+/// Returns the amounts of each pool token paid out for burning `exact_burn_amount` lp tokens
+/// (i.e. the burnt share of the lp supply of every pool balance)
+pub fn calculate_remove_uniform(exact_burn_amount: u64, pool_balances: &[u64], lp_total_supply: u64) -> Vec<u64> {
+    let user_share = DecimalU64::from(exact_burn_amount) / lp_total_supply;
+    pool_balances.iter().map(|&pool_balance| (pool_balance * user_share).trunc()).collect()
+}
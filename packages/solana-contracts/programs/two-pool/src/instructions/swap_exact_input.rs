@@ -1,7 +1,8 @@
 use {
     crate::{
-        array_equalize, error::*, gen_pool_signer_seeds, invariant::Invariant, result_from_equalized, to_equalized,
-        TwoPool, TOKEN_COUNT,
+        array_equalize, decimal::DecimalU64, error::*, events::SwapExactInputEvent, gen_pool_signer_seeds,
+        invariant::Invariant, load_pool_token_accounts, load_user_token_accounts, result_from_equalized,
+        state::pause_flags, to_equalized, update_price_oracle, with_token_count, TwoPool,
     },
     anchor_lang::prelude::*,
     anchor_spl::{
@@ -23,6 +24,9 @@ use {
 ///     4. ..4 + TOKEN_COUNT `[w]` governance_fee_account
 ///     5. ..5 + TOKEN_COUNT `[s]` user transfer authority account
 ///     6. ..6 + TOKEN_COUNT `[w]` user token accounts
+///
+/// Token accounts for tokens 2..token_count are passed in through `remaining_accounts`
+/// (see [crate::instructions::pool_token_accounts])
 #[derive(Accounts)]
 pub struct SwapExactInput<'info> {
     #[account(
@@ -35,7 +39,7 @@ pub struct SwapExactInput<'info> {
     ],
     bump = pool.bump
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     #[account(
     mut,
     token::mint = pool.token_mint_keys[0],
@@ -81,7 +85,7 @@ pub struct SwapExactInput<'info> {
 impl<'info> SwapExactInput<'info> {
     pub fn accounts(ctx: &Context<SwapExactInput>) -> Result<()> {
         let pool_state = &ctx.accounts.pool;
-        require!(!pool_state.is_paused, PoolError::PoolIsPaused);
+        pool_state.require_not_paused(pause_flags::SWAP)?;
         require_keys_eq!(
             ctx.accounts.pool_token_account_0.key(),
             pool_state.token_keys[0],
@@ -110,13 +114,13 @@ impl<'info> SwapExactInput<'info> {
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct SwapExactInputParams {
-    pub exact_input_amounts: [u64; TOKEN_COUNT],
+    pub exact_input_amounts: Vec<u64>,
     pub output_token_index: u8,
     pub minimum_output_amount: u64,
 }
 
-pub fn handle_swap_exact_input(
-    ctx: Context<SwapExactInput>,
+pub fn handle_swap_exact_input<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapExactInput<'info>>,
     swap_exact_input_params: SwapExactInputParams,
     // exact_input_amounts: [u64; TOKEN_COUNT],
     // output_token_index: u8,
@@ -126,51 +130,41 @@ pub fn handle_swap_exact_input(
     let output_token_index = swap_exact_input_params.output_token_index as usize;
     let exact_input_amounts = swap_exact_input_params.exact_input_amounts;
     let minimum_output_amount = swap_exact_input_params.minimum_output_amount;
-    if exact_input_amounts.iter().all(|amount| *amount == 0)
-        || output_token_index >= TOKEN_COUNT
-        || exact_input_amounts[output_token_index] != 0
-    {
-        return err!(PoolError::InvalidSwapExactInputParameters);
-    }
-
     let pool = &ctx.accounts.pool;
-    let pool_token_account_0 = &ctx.accounts.pool_token_account_0;
-    let pool_token_account_1 = &ctx.accounts.pool_token_account_1;
-    let pool_balances = [ctx.accounts.pool_token_account_0.amount, ctx.accounts.pool_token_account_1.amount];
-    let lp_mint = &ctx.accounts.lp_mint;
-    let governance_fee = &ctx.accounts.governance_fee;
-    // let user_transfer_auth = &ctx.accounts.user_transfer_authority;
-    let user_token_account_0 = &ctx.accounts.user_token_account_0;
-    let user_token_account_1 = &ctx.accounts.user_token_account_1;
-    let user_token_accounts = [user_token_account_0, user_token_account_1];
-
-    let pool_token_accounts = [pool_token_account_0, pool_token_account_1];
+    let token_count = pool.get_token_count();
+    validate_swap_exact_input(pool, &exact_input_amounts, output_token_index)?;
+
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    let pool_token_accounts = load_pool_token_accounts(
+        pool,
+        &ctx.accounts.pool_token_account_0,
+        &ctx.accounts.pool_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let user_token_accounts = load_user_token_accounts(
+        pool,
+        &ctx.accounts.user_token_account_0,
+        &ctx.accounts.user_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let pool_balances = pool_token_accounts.iter().map(|account| account.amount).collect::<Vec<_>>();
 
     let lp_total_supply = ctx.accounts.lp_mint.supply;
     let current_ts = Clock::get()?.unix_timestamp;
     require_gt!(current_ts, 0i64, PoolError::InvalidTimestamp);
-    let (user_amount, governance_mint_amount, latest_depth) = Invariant::<TOKEN_COUNT>::swap_exact_input(
-        &array_equalize(exact_input_amounts, pool.token_decimal_equalizers),
+    let (output_amount, governance_mint_amount, latest_depth) = calculate_swap_exact_input(
+        pool,
+        &exact_input_amounts,
         output_token_index,
-        &array_equalize(pool_balances, pool.token_decimal_equalizers),
-        pool.amp_factor.get(current_ts),
-        pool.lp_fee.get(),
-        pool.governance_fee.get(),
-        to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
-        pool.previous_depth.into(),
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
     )?;
-    let (output_amount, governance_mint_amount, latest_depth) = result_from_equalized(
-        user_amount,
-        pool.token_decimal_equalizers[output_token_index],
-        governance_mint_amount,
-        pool.lp_decimal_equalizer,
-        latest_depth,
-    );
 
     require_gte!(output_amount, minimum_output_amount, PoolError::OutsideSpecifiedLimits);
 
-    let mut token_accounts = zip(user_token_accounts.into_iter(), pool_token_accounts.into_iter());
-    for i in 0..TOKEN_COUNT {
+    let mut token_accounts = zip(user_token_accounts.iter(), pool_token_accounts.iter());
+    for i in 0..token_count {
         let (user_token_account, pool_token_account) = token_accounts.next().unwrap();
         if exact_input_amounts[i] > 0 {
             token::transfer(
@@ -187,8 +181,8 @@ pub fn handle_swap_exact_input(
             )?;
         }
     }
-    let user_output_token_account = user_token_accounts[output_token_index];
-    let pool_output_token_account = pool_token_accounts[output_token_index];
+    let user_output_token_account = &user_token_accounts[output_token_index];
+    let pool_output_token_account = &pool_token_accounts[output_token_index];
     token::transfer(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
@@ -220,5 +214,86 @@ pub fn handle_swap_exact_input(
     }
     let pool = &mut ctx.accounts.pool;
     pool.previous_depth = latest_depth;
+    let mut pool_balances =
+        pool_balances.iter().zip(&exact_input_amounts).map(|(balance, amount)| balance + amount).collect::<Vec<_>>();
+    pool_balances[output_token_index] -= output_amount;
+    update_price_oracle(pool, &pool_balances, lp_total_supply + governance_mint_amount, current_ts)?;
+    emit!(SwapExactInputEvent {
+        pool: pool.key(),
+        user: ctx.accounts.user_transfer_authority.key(),
+        exact_input_amounts,
+        output_token_index: output_token_index as u8,
+        output_amount,
+        governance_mint_amount,
+        latest_depth,
+    });
     Ok(output_amount)
 }
+
+/// Checks the `exact_input_amounts` & `output_token_index` of a swap. Shared by `swap_exact_input` &
+/// `quote_swap_exact_input`
+pub fn validate_swap_exact_input(pool: &TwoPool, exact_input_amounts: &[u64], output_token_index: usize) -> Result<()> {
+    let token_count = pool.get_token_count();
+    require_eq!(exact_input_amounts.len(), token_count, PoolError::InvalidTokenCount);
+    if exact_input_amounts.iter().all(|amount| *amount == 0)
+        || output_token_index >= token_count
+        || exact_input_amounts[output_token_index] != 0
+    {
+        return err!(PoolError::InvalidSwapExactInputParameters);
+    }
+    Ok(())
+}
+
+/// Returns `(output_amount, governance_mint_amount, latest_depth)` of swapping in `exact_input_amounts`
+/// for the token at `output_token_index` without executing the swap
+pub fn calculate_swap_exact_input(
+    pool: &TwoPool,
+    exact_input_amounts: &[u64],
+    output_token_index: usize,
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    calculate_swap_exact_input_with_fees(
+        pool,
+        pool.lp_fee.get(),
+        pool.governance_fee.get(),
+        exact_input_amounts,
+        output_token_index,
+        pool_balances,
+        lp_total_supply,
+        current_ts,
+    )
+}
+
+/// Same as [calculate_swap_exact_input] but charges `lp_fee` & `governance_fee` instead of the pool's fees
+pub fn calculate_swap_exact_input_with_fees(
+    pool: &TwoPool,
+    lp_fee: DecimalU64,
+    governance_fee: DecimalU64,
+    exact_input_amounts: &[u64],
+    output_token_index: usize,
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    let (user_amount, governance_mint_amount, latest_depth) = with_token_count!(pool.get_token_count(), N => {
+        Invariant::<N>::swap_exact_input(
+            &array_equalize(exact_input_amounts, &pool.token_decimal_equalizers),
+            output_token_index,
+            &array_equalize(pool_balances, &pool.token_decimal_equalizers),
+            pool.amp_factor.get(current_ts),
+            lp_fee,
+            governance_fee,
+            to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
+            pool.previous_depth.into(),
+        )
+    })?;
+    Ok(result_from_equalized(
+        user_amount,
+        pool.token_decimal_equalizers[output_token_index],
+        governance_mint_amount,
+        pool.lp_decimal_equalizer,
+        latest_depth,
+    ))
+}
@@ -1,7 +1,8 @@
 use {
     crate::{
-        array_equalize, error::*, gen_pool_signer_seeds, invariant::Invariant, result_from_equalized, to_equalized,
-        TwoPool, TOKEN_COUNT,
+        array_equalize, decimal::DecimalU64, error::*, events::SwapExactOutputEvent, gen_pool_signer_seeds,
+        invariant::Invariant, load_pool_token_accounts, load_user_token_accounts, result_from_equalized,
+        state::pause_flags, to_equalized, update_price_oracle, with_token_count, TwoPool,
     },
     anchor_lang::{
         prelude::*,
@@ -33,6 +34,9 @@ use {
 ///     4. ..4 + TOKEN_COUNT `[w]` governance_fee_account
 ///     5. ..5 + TOKEN_COUNT `[s]` user transfer authority account
 ///     6. ..6 + TOKEN_COUNT `[w]` user token accounts
+///
+/// Token accounts for tokens 2..token_count are passed in through `remaining_accounts`
+/// (see [crate::instructions::pool_token_accounts])
 #[derive(Accounts)]
 pub struct SwapExactOutput<'info> {
     #[account(
@@ -45,7 +49,7 @@ pub struct SwapExactOutput<'info> {
     ],
     bump = pool.bump
     )]
-    pub pool: Account<'info, TwoPool>,
+    pub pool: Box<Account<'info, TwoPool>>,
     #[account(
     mut,
     token::mint = pool.token_mint_keys[0],
@@ -91,7 +95,7 @@ pub struct SwapExactOutput<'info> {
 impl<'info> SwapExactOutput<'info> {
     pub fn accounts(ctx: &Context<SwapExactOutput>) -> Result<()> {
         let pool_state = &ctx.accounts.pool;
-        require!(!pool_state.is_paused, PoolError::PoolIsPaused);
+        pool_state.require_not_paused(pause_flags::SWAP)?;
         require_keys_eq!(
             ctx.accounts.pool_token_account_0.key(),
             pool_state.token_keys[0],
@@ -117,72 +121,50 @@ impl<'info> SwapExactOutput<'info> {
 pub struct SwapExactOutputParams {
     pub maximum_input_amount: u64,
     pub input_token_index: u8,
-    pub exact_output_amounts: [u64; TOKEN_COUNT],
+    pub exact_output_amounts: Vec<u64>,
 }
 
-pub fn handle_swap_exact_output(
-    ctx: Context<SwapExactOutput>,
+pub fn handle_swap_exact_output<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapExactOutput<'info>>,
     swap_exact_output_params: SwapExactOutputParams,
 ) -> Result<Vec<u64>> {
     let input_token_index = swap_exact_output_params.input_token_index as usize;
     let exact_output_amounts = swap_exact_output_params.exact_output_amounts;
-    let pool_balances = [ctx.accounts.pool_token_account_0.amount, ctx.accounts.pool_token_account_1.amount];
-    let are_output_amounts_valid = exact_output_amounts.iter().any(|amount| *amount == 0);
-    require!(are_output_amounts_valid, PoolError::InvalidSwapExactOutputParameters);
-    require!(input_token_index < TOKEN_COUNT, PoolError::InvalidSwapExactOutputParameters);
-    require!(exact_output_amounts[input_token_index] == 0, PoolError::InvalidSwapExactOutputParameters);
-    // || exact_output_amounts[input_token_index] != 0
-    // if exact_output_amounts.iter().all(|amount| *amount == 0)
-    //   || input_token_index >= TOKEN_COUNT
-    //   || exact_output_amounts[input_token_index] != 0
-    // {
-    //   return err!(PoolError::InvalidSwapExactOutputParameters);
-    // }
-    let are_pool_balances_sufficient = exact_output_amounts
-        .iter()
-        .zip(pool_balances.iter())
-        .all(|(output_amount, pool_balance)| *output_amount < *pool_balance);
-    require!(are_pool_balances_sufficient, PoolError::InsufficientPoolTokenAccountBalance);
-
     let pool = &ctx.accounts.pool;
-    let pool_token_account_0 = &ctx.accounts.pool_token_account_0;
-    let pool_token_account_1 = &ctx.accounts.pool_token_account_1;
-    let pool_balances = [ctx.accounts.pool_token_account_0.amount, ctx.accounts.pool_token_account_1.amount];
-    let lp_mint = &ctx.accounts.lp_mint;
-    let governance_fee = &ctx.accounts.governance_fee;
-    // let user_transfer_auth = &ctx.accounts.user_transfer_authority;
-    let user_token_account_0 = &ctx.accounts.user_token_account_0;
-    let user_token_account_1 = &ctx.accounts.user_token_account_1;
-    let user_token_accounts = [user_token_account_0, user_token_account_1];
-
-    let pool_token_accounts = [pool_token_account_0, pool_token_account_1];
+    let token_count = pool.get_token_count();
+    let mut remaining_accounts = ctx.remaining_accounts.iter();
+    let pool_token_accounts = load_pool_token_accounts(
+        pool,
+        &ctx.accounts.pool_token_account_0,
+        &ctx.accounts.pool_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let user_token_accounts = load_user_token_accounts(
+        pool,
+        &ctx.accounts.user_token_account_0,
+        &ctx.accounts.user_token_account_1,
+        &mut remaining_accounts,
+    )?;
+    let pool_balances = pool_token_accounts.iter().map(|account| account.amount).collect::<Vec<_>>();
+    validate_swap_exact_output(pool, input_token_index, &exact_output_amounts, &pool_balances)?;
 
     let lp_total_supply = ctx.accounts.lp_mint.supply;
     let current_ts = Clock::get()?.unix_timestamp;
     require_gt!(current_ts, 0i64, PoolError::InvalidTimestamp);
-    let (user_amount, governance_mint_amount, latest_depth) = Invariant::<TOKEN_COUNT>::swap_exact_output(
+    let (input_amount, governance_mint_amount, latest_depth) = calculate_swap_exact_output(
+        pool,
         input_token_index,
-        &array_equalize(exact_output_amounts, pool.token_decimal_equalizers),
-        &array_equalize(pool_balances, pool.token_decimal_equalizers),
-        pool.amp_factor.get(current_ts),
-        pool.lp_fee.get(),
-        pool.governance_fee.get(),
-        to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
-        pool.previous_depth.into(),
+        &exact_output_amounts,
+        &pool_balances,
+        lp_total_supply,
+        current_ts,
     )?;
-    let (input_amount, governance_mint_amount, latest_depth) = result_from_equalized(
-        user_amount,
-        pool.token_decimal_equalizers[input_token_index],
-        governance_mint_amount,
-        pool.lp_decimal_equalizer,
-        latest_depth,
-    );
 
     let maximum_input_amount = swap_exact_output_params.maximum_input_amount;
     require_gte!(maximum_input_amount, input_amount, PoolError::OutsideSpecifiedLimits);
 
-    let user_input_token_account = user_token_accounts[input_token_index];
-    let pool_input_token_account = pool_token_accounts[input_token_index];
+    let user_input_token_account = &user_token_accounts[input_token_index];
+    let pool_input_token_account = &pool_token_accounts[input_token_index];
     token::transfer(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -196,9 +178,9 @@ pub fn handle_swap_exact_output(
         input_amount,
     )?;
 
-    let mut token_accounts = zip(user_token_accounts.into_iter(), pool_token_accounts.into_iter());
+    let mut token_accounts = zip(user_token_accounts.iter(), pool_token_accounts.iter());
 
-    for i in 0..TOKEN_COUNT {
+    for i in 0..token_count {
         let (user_token_account, pool_token_account) = token_accounts.next().unwrap();
         if exact_output_amounts[i] > 0 {
             token::transfer(
@@ -234,5 +216,93 @@ pub fn handle_swap_exact_output(
     }
     let pool = &mut ctx.accounts.pool;
     pool.previous_depth = latest_depth;
-    Ok(exact_output_amounts.into())
+    let mut pool_balances =
+        pool_balances.iter().zip(&exact_output_amounts).map(|(balance, amount)| balance - amount).collect::<Vec<_>>();
+    pool_balances[input_token_index] += input_amount;
+    update_price_oracle(pool, &pool_balances, lp_total_supply + governance_mint_amount, current_ts)?;
+    emit!(SwapExactOutputEvent {
+        pool: pool.key(),
+        user: ctx.accounts.user_transfer_authority.key(),
+        input_token_index: input_token_index as u8,
+        input_amount,
+        exact_output_amounts: exact_output_amounts.clone(),
+        governance_mint_amount,
+        latest_depth,
+    });
+    Ok(exact_output_amounts)
+}
+
+/// Checks the `input_token_index` & `exact_output_amounts` of a swap against the `pool_balances`.
+/// Shared by `swap_exact_output` & `quote_swap_exact_output`
+pub fn validate_swap_exact_output(
+    pool: &TwoPool,
+    input_token_index: usize,
+    exact_output_amounts: &[u64],
+    pool_balances: &[u64],
+) -> Result<()> {
+    let token_count = pool.get_token_count();
+    require_eq!(exact_output_amounts.len(), token_count, PoolError::InvalidTokenCount);
+    require!(exact_output_amounts.iter().any(|amount| *amount == 0), PoolError::InvalidSwapExactOutputParameters);
+    require!(input_token_index < token_count, PoolError::InvalidSwapExactOutputParameters);
+    require!(exact_output_amounts[input_token_index] == 0, PoolError::InvalidSwapExactOutputParameters);
+    let are_pool_balances_sufficient = exact_output_amounts
+        .iter()
+        .zip(pool_balances.iter())
+        .all(|(output_amount, pool_balance)| *output_amount < *pool_balance);
+    require!(are_pool_balances_sufficient, PoolError::InsufficientPoolTokenAccountBalance);
+    Ok(())
+}
+
+/// Returns `(input_amount, governance_mint_amount, latest_depth)` of swapping the token at `input_token_index`
+/// for `exact_output_amounts` without executing the swap
+pub fn calculate_swap_exact_output(
+    pool: &TwoPool,
+    input_token_index: usize,
+    exact_output_amounts: &[u64],
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    calculate_swap_exact_output_with_fees(
+        pool,
+        pool.lp_fee.get(),
+        pool.governance_fee.get(),
+        input_token_index,
+        exact_output_amounts,
+        pool_balances,
+        lp_total_supply,
+        current_ts,
+    )
+}
+
+/// Same as [calculate_swap_exact_output] but charges `lp_fee` & `governance_fee` instead of the pool's fees
+pub fn calculate_swap_exact_output_with_fees(
+    pool: &TwoPool,
+    lp_fee: DecimalU64,
+    governance_fee: DecimalU64,
+    input_token_index: usize,
+    exact_output_amounts: &[u64],
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<(u64, u64, u128)> {
+    let (user_amount, governance_mint_amount, latest_depth) = with_token_count!(pool.get_token_count(), N => {
+        Invariant::<N>::swap_exact_output(
+            input_token_index,
+            &array_equalize(exact_output_amounts, &pool.token_decimal_equalizers),
+            &array_equalize(pool_balances, &pool.token_decimal_equalizers),
+            pool.amp_factor.get(current_ts),
+            lp_fee,
+            governance_fee,
+            to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
+            pool.previous_depth.into(),
+        )
+    })?;
+    Ok(result_from_equalized(
+        user_amount,
+        pool.token_decimal_equalizers[input_token_index],
+        governance_mint_amount,
+        pool.lp_decimal_equalizer,
+        latest_depth,
+    ))
 }
@@ -0,0 +1,154 @@
+use {
+    crate::{
+        array_equalize, decimal::DecimalU64, error::*, events::CheckpointVirtualPriceEvent, get_current_ts,
+        invariant::Invariant, load_pool_token_accounts, state::pause_flags, to_equalized,
+        virtual_price_checkpoints::VirtualPriceCheckpoints, with_token_count, BorshDecimal, MarginalPrices, TwoPool,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, TokenAccount},
+};
+
+/// Uses the same accounts as [`MarginalPrices`]. Returns the pool's depth per lp token.
+pub fn handle_virtual_price<'info>(ctx: Context<'_, '_, '_, 'info, MarginalPrices<'info>>) -> Result<BorshDecimal> {
+    let pool = &ctx.accounts.pool;
+    let lp_total_supply = ctx.accounts.lp_mint.supply;
+    let pool_token_accounts = load_pool_token_accounts(
+        pool,
+        &ctx.accounts.pool_token_account_0,
+        &ctx.accounts.pool_token_account_1,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+    let pool_balances = pool_token_accounts.iter().map(|account| account.amount).collect::<Vec<_>>();
+    let current_ts = get_current_ts()?;
+
+    let virtual_price = calculate_virtual_price(pool, &pool_balances, lp_total_supply, current_ts)?;
+    msg!("virtual_price: {:?}", virtual_price);
+    virtual_price.try_into()
+}
+
+/// Returns the pool's depth divided by the lp token supply (both equalized)
+pub fn calculate_virtual_price(
+    pool: &TwoPool,
+    pool_balances: &[u64],
+    lp_total_supply: u64,
+    current_ts: i64,
+) -> Result<DecimalU64> {
+    require_gt!(lp_total_supply, 0u64, PoolError::PoolHasNoLiquidity);
+    let virtual_price = with_token_count!(pool.get_token_count(), N => {
+        Invariant::<N>::virtual_price(
+            &array_equalize(pool_balances, &pool.token_decimal_equalizers),
+            pool.amp_factor.get(current_ts),
+            to_equalized(lp_total_supply, pool.lp_decimal_equalizer),
+            pool.previous_depth.into(),
+        )
+    })?;
+    Ok(virtual_price)
+}
+
+#[derive(Accounts)]
+pub struct InitializeVirtualPriceCheckpoints<'info> {
+    pub pool: Box<Account<'info, TwoPool>>,
+
+    #[account(
+    init,
+    payer = payer,
+    seeds = [b"two_pool".as_ref(), b"virtual_price_checkpoints".as_ref(), pool.key().as_ref()],
+    bump,
+    space = 8 + VirtualPriceCheckpoints::LEN
+    )]
+    pub virtual_price_checkpoints: Box<Account<'info, VirtualPriceCheckpoints>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_virtual_price_checkpoints(ctx: Context<InitializeVirtualPriceCheckpoints>) -> Result<()> {
+    let bump = *ctx.bumps.get("virtual_price_checkpoints").unwrap();
+    ctx.accounts.virtual_price_checkpoints.set_inner(VirtualPriceCheckpoints::new(bump, ctx.accounts.pool.key()));
+    Ok(())
+}
+
+/// Permissionless crank that appends the pool's current virtual price to its checkpoints
+///
+/// Token accounts for tokens 2..token_count are passed in through `remaining_accounts`
+/// (see [crate::instructions::pool_token_accounts])
+#[derive(Accounts)]
+pub struct CheckpointVirtualPrice<'info> {
+    #[account(
+    seeds = [
+    b"two_pool".as_ref(),
+    pool_token_account_0.mint.as_ref(),
+    pool_token_account_1.mint.as_ref(),
+    lp_mint.key().as_ref(),
+    ],
+    bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, TwoPool>>,
+
+    #[account(
+    token::mint = pool.token_mint_keys[0],
+    token::authority = pool,
+    )]
+    pub pool_token_account_0: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+    token::mint = pool.token_mint_keys[1],
+    token::authority = pool,
+    )]
+    pub pool_token_account_1: Box<Account<'info, TokenAccount>>,
+
+    pub lp_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+    mut,
+    seeds = [b"two_pool".as_ref(), b"virtual_price_checkpoints".as_ref(), pool.key().as_ref()],
+    bump = virtual_price_checkpoints.bump,
+    has_one = pool
+    )]
+    pub virtual_price_checkpoints: Box<Account<'info, VirtualPriceCheckpoints>>,
+}
+
+impl<'info> CheckpointVirtualPrice<'info> {
+    pub fn accounts(ctx: &Context<CheckpointVirtualPrice>) -> Result<()> {
+        let pool_state = &ctx.accounts.pool;
+        pool_state.require_not_paused(pause_flags::PRICES)?;
+        require_keys_eq!(
+            ctx.accounts.pool_token_account_0.key(),
+            pool_state.token_keys[0],
+            PoolError::PoolTokenAccountExpected
+        );
+        require_keys_eq!(
+            ctx.accounts.pool_token_account_1.key(),
+            pool_state.token_keys[1],
+            PoolError::PoolTokenAccountExpected
+        );
+        require_keys_eq!(ctx.accounts.lp_mint.key(), pool_state.lp_mint_key, PoolError::InvalidMintAccount);
+        Ok(())
+    }
+}
+
+pub fn handle_checkpoint_virtual_price<'info>(
+    ctx: Context<'_, '_, '_, 'info, CheckpointVirtualPrice<'info>>,
+) -> Result<()> {
+    let current_ts = get_current_ts()?;
+    require!(
+        ctx.accounts.virtual_price_checkpoints.needs_update(current_ts),
+        PoolError::VirtualPriceCheckpointTooEarly
+    );
+    let pool = &ctx.accounts.pool;
+    let lp_total_supply = ctx.accounts.lp_mint.supply;
+    let pool_token_accounts = load_pool_token_accounts(
+        pool,
+        &ctx.accounts.pool_token_account_0,
+        &ctx.accounts.pool_token_account_1,
+        &mut ctx.remaining_accounts.iter(),
+    )?;
+    let pool_balances = pool_token_accounts.iter().map(|account| account.amount).collect::<Vec<_>>();
+
+    let virtual_price = calculate_virtual_price(pool, &pool_balances, lp_total_supply, current_ts)?;
+    ctx.accounts.virtual_price_checkpoints.update(current_ts, virtual_price);
+    emit!(CheckpointVirtualPriceEvent { pool: pool.key(), current_ts, virtual_price: virtual_price.into() });
+    Ok(())
+}
@@ -48,7 +48,7 @@ impl U192 {
 
 impl From<DecT> for Decimal {
     fn from(value: DecT) -> Self {
-        Self::new(value.get_raw() as i64, value.get_decimals() as u32)
+        Self::from_i128_with_scale(value.get_raw() as i128, value.get_decimals() as u32)
     }
 }
 
@@ -308,6 +308,17 @@ impl<const TOKEN_COUNT: usize> Invariant<TOKEN_COUNT> {
         }))
     }
 
+    /// Returns the pool's depth per lp token (both in equalized units)
+    pub fn virtual_price(
+        pool_balances: &[AmountT; TOKEN_COUNT],
+        amp_factor: DecT,
+        lp_total_supply: AmountT,
+        previous_depth: AmountT,
+    ) -> Result<DecT, PoolError> {
+        let depth = Self::calculate_depth(pool_balances, Decimal::from(amp_factor), previous_depth.into())?;
+        Ok((depth / Decimal::from(lp_total_supply)).into())
+    }
+
     fn swap(
         is_exact_input: bool, //false => exact output
         amounts: &[AmountT; TOKEN_COUNT],
@@ -995,7 +1006,7 @@ mod tests {
         let lp_decimal_equalizer = 0u8;
         let previous_depth = 197600076u128;
         let marginal_prices = Invariant::<TOKEN_COUNT>::marginal_prices(
-            &array_equalize(pool_balances, token_decimal_equalizers),
+            &array_equalize(&pool_balances, &token_decimal_equalizers),
             amp_factor,
             to_equalized(lp_total_supply, lp_decimal_equalizer),
             previous_depth.into(),
@@ -1003,4 +1014,46 @@ mod tests {
         .unwrap();
         println!(">>> marginal_prices: {:?}", marginal_prices);
     }
+
+    #[test]
+    fn marginal_prices_with_runtime_token_count() {
+        let amp_factor = DecT::new(300, 0).unwrap();
+        let pool_balances = vec![100_000_000u64; 3];
+        let token_decimal_equalizers = [0u8; crate::MAX_TOKEN_COUNT];
+        let lp_total_supply = 300_000_000u64;
+        let previous_depth = 300_000_000u128;
+        let marginal_prices = crate::with_token_count!(pool_balances.len(), N => {
+            Invariant::<N>::marginal_prices(
+                &array_equalize(&pool_balances, &token_decimal_equalizers),
+                amp_factor,
+                to_equalized(lp_total_supply, 0),
+                previous_depth.into(),
+            )
+            .map(|marginal_prices| marginal_prices.to_vec())
+        })
+        .unwrap();
+        assert_eq!(marginal_prices.len(), 3);
+        //balanced pool => every token is worth exactly one lp token
+        for marginal_price in marginal_prices {
+            assert_eq!(marginal_price, DecT::from(1));
+        }
+        assert_eq!(crate::with_token_count!(7usize, N => Ok::<_, PoolError>(N)), Err(PoolError::InvalidTokenCount));
+    }
+
+    #[test]
+    fn virtual_price() {
+        let amp_factor = DecT::new(300, 0).unwrap();
+        let lp_total_supply = AmountT::from(200_000_000u64);
+        let pool_balances = [AmountT::from(100_000_000u64); 2];
+        //balanced pool => depth == sum of balances
+        let virtual_price =
+            Invariant::<2>::virtual_price(&pool_balances, amp_factor, lp_total_supply, lp_total_supply).unwrap();
+        assert_eq!(virtual_price, DecT::from(1));
+
+        //fees accrued to the pool increase the depth without minting lp tokens
+        let pool_balances = [AmountT::from(101_000_000u64); 2];
+        let virtual_price =
+            Invariant::<2>::virtual_price(&pool_balances, amp_factor, lp_total_supply, lp_total_supply).unwrap();
+        assert_eq!(virtual_price, DecT::new(101, 2).unwrap());
+    }
 }
@@ -1,7 +1,7 @@
 use rust_decimal::Decimal;
 use {
     crate::{
-        amp_factor::AmpFactor,
+        amp_factor::{AmpFactor, AmpFactorInterpolation},
         // decimal::{DecimalError, DecimalU64},
         decimal::DecimalU64,
         error::PoolError,
@@ -17,10 +17,13 @@ pub mod amp_factor;
 mod common;
 pub mod decimal;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod invariant;
 pub mod pool_fee;
+pub mod price_oracle;
 pub mod state;
+pub mod virtual_price_checkpoints;
 
 // #[macro_use]
 mod macros;
@@ -33,7 +36,11 @@ mod macros;
 // type AtomicT = u64;
 // type DecT = DecimalU64;
 
-pub const TOKEN_COUNT: usize = 2;
+// The pool is initialized with anywhere between MIN_TOKEN_COUNT and MAX_TOKEN_COUNT tokens.
+// Token accounts for tokens beyond the first two are passed in through `remaining_accounts`
+// (see [crate::instructions::pool_token_accounts]).
+pub const MIN_TOKEN_COUNT: usize = 2;
+pub const MAX_TOKEN_COUNT: usize = 6;
 
 //TODO: option to have separate programIds depending on cluster. probably not needed and should keep the same
 //  programId for all environments
@@ -64,8 +71,8 @@ pub mod two_pool {
     use super::*;
 
     #[access_control(Initialize::accounts(&ctx))]
-    pub fn initialize(
-        ctx: Context<Initialize>,
+    pub fn initialize<'info>(
+        ctx: Context<'_, '_, '_, 'info, Initialize<'info>>,
         amp_factor: DecimalU64Anchor,
         lp_fee: DecimalU64Anchor,
         governance_fee: DecimalU64Anchor,
@@ -74,9 +81,9 @@ pub mod two_pool {
     }
 
     #[access_control(Add::accounts(&ctx))]
-    pub fn add(
-        ctx: Context<Add>,
-        input_amounts: [u64; TOKEN_COUNT],
+    pub fn add<'info>(
+        ctx: Context<'_, '_, '_, 'info, Add<'info>>,
+        input_amounts: Vec<u64>,
         minimum_mint_amount: u64,
         // params: AddParams
     ) -> Result<u64> {
@@ -86,9 +93,9 @@ pub mod two_pool {
     }
 
     #[access_control(SwapExactInput::accounts(&ctx))]
-    pub fn swap_exact_input(
-        ctx: Context<SwapExactInput>,
-        exact_input_amounts: [u64; TOKEN_COUNT],
+    pub fn swap_exact_input<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapExactInput<'info>>,
+        exact_input_amounts: Vec<u64>,
         output_token_index: u8,
         minimum_output_amount: u64,
         // params: SwapExactInputParams,
@@ -109,23 +116,23 @@ pub mod two_pool {
 
     //returning cpi data from this is a little redundant since it's already passed in the params
     //but keeping for parity with other ixs
-    // note using Vec<u64> instead of [u64; TOKEN_COUNT] since anchor can't handle it properly.
+    // note using Vec<u64> since the number of tokens is only known at runtime.
     #[access_control(SwapExactOutput::accounts(&ctx))]
-    pub fn swap_exact_output(
-        ctx: Context<SwapExactOutput>,
+    pub fn swap_exact_output<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapExactOutput<'info>>,
         maximum_input_amount: u64,
         input_token_index: u8,
-        exact_output_amounts: [u64; TOKEN_COUNT], // params: SwapExactOutputParams,
+        exact_output_amounts: Vec<u64>, // params: SwapExactOutputParams,
     ) -> Result<Vec<u64>> {
         let params = SwapExactOutputParams { maximum_input_amount, input_token_index, exact_output_amounts };
         handle_swap_exact_output(ctx, params)
     }
 
     #[access_control(RemoveUniform::accounts(&ctx))]
-    pub fn remove_uniform(
-        ctx: Context<RemoveUniform>,
+    pub fn remove_uniform<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveUniform<'info>>,
         exact_burn_amount: u64,
-        minimum_output_amounts: [u64; TOKEN_COUNT],
+        minimum_output_amounts: Vec<u64>,
         // params: RemoveUniformParams,
     ) -> Result<Vec<u64>> {
         let params = RemoveUniformParams { exact_burn_amount, minimum_output_amounts };
@@ -133,8 +140,8 @@ pub mod two_pool {
     }
 
     #[access_control(RemoveExactBurn::accounts(&ctx))]
-    pub fn remove_exact_burn(
-        ctx: Context<RemoveExactBurn>,
+    pub fn remove_exact_burn<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveExactBurn<'info>>,
         exact_burn_amount: u64,
         output_token_index: u8,
         minimum_output_amount: u64,
@@ -145,23 +152,114 @@ pub mod two_pool {
     }
 
     #[access_control(RemoveExactOutput::accounts(&ctx))]
-    pub fn remove_exact_output(
-        ctx: Context<RemoveExactOutput>,
+    pub fn remove_exact_output<'info>(
+        ctx: Context<'_, '_, '_, 'info, RemoveExactOutput<'info>>,
         maximum_burn_amount: u64,
-        exact_output_amounts: [u64; TOKEN_COUNT],
+        exact_output_amounts: Vec<u64>,
         // params: RemoveExactOutputParams,
     ) -> Result<Vec<u64>> {
         let params = RemoveExactOutputParams { maximum_burn_amount, exact_output_amounts };
         handle_remove_exact_output(ctx, params)
     }
 
-    //TODO: using 2 instead of TOKEN_COUNT const since anchor can't handle it properly.
+    /** Quote Ixs **/
+    /// Same checks & calculations as `add` without moving any tokens
+    #[access_control(Quote::accounts(&ctx))]
+    pub fn quote_add<'info>(
+        ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+        input_amounts: Vec<u64>,
+        minimum_mint_amount: u64,
+    ) -> Result<PoolQuote> {
+        let params = AddParams { input_amounts, minimum_mint_amount };
+        handle_quote_add(ctx, params)
+    }
+
+    #[access_control(Quote::accounts(&ctx))]
+    pub fn quote_swap_exact_input<'info>(
+        ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+        exact_input_amounts: Vec<u64>,
+        output_token_index: u8,
+        minimum_output_amount: u64,
+    ) -> Result<PoolQuote> {
+        let params = SwapExactInputParams { exact_input_amounts, output_token_index, minimum_output_amount };
+        handle_quote_swap_exact_input(ctx, params)
+    }
+
+    #[access_control(Quote::accounts(&ctx))]
+    pub fn quote_swap_exact_output<'info>(
+        ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+        maximum_input_amount: u64,
+        input_token_index: u8,
+        exact_output_amounts: Vec<u64>,
+    ) -> Result<PoolQuote> {
+        let params = SwapExactOutputParams { maximum_input_amount, input_token_index, exact_output_amounts };
+        handle_quote_swap_exact_output(ctx, params)
+    }
+
+    #[access_control(Quote::accounts(&ctx))]
+    pub fn quote_remove_uniform<'info>(
+        ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+        exact_burn_amount: u64,
+        minimum_output_amounts: Vec<u64>,
+    ) -> Result<Vec<u64>> {
+        let params = RemoveUniformParams { exact_burn_amount, minimum_output_amounts };
+        handle_quote_remove_uniform(ctx, params)
+    }
+
+    #[access_control(Quote::accounts(&ctx))]
+    pub fn quote_remove_exact_burn<'info>(
+        ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+        exact_burn_amount: u64,
+        output_token_index: u8,
+        minimum_output_amount: u64,
+    ) -> Result<PoolQuote> {
+        let params = RemoveExactBurnParams { exact_burn_amount, output_token_index, minimum_output_amount };
+        handle_quote_remove_exact_burn(ctx, params)
+    }
+
+    #[access_control(Quote::accounts(&ctx))]
+    pub fn quote_remove_exact_output<'info>(
+        ctx: Context<'_, '_, '_, 'info, Quote<'info>>,
+        maximum_burn_amount: u64,
+        exact_output_amounts: Vec<u64>,
+    ) -> Result<PoolQuote> {
+        let params = RemoveExactOutputParams { maximum_burn_amount, exact_output_amounts };
+        handle_quote_remove_exact_output(ctx, params)
+    }
+
+    // note using Vec<BorshDecimal> since the number of tokens is only known at runtime.
     #[access_control(MarginalPrices::accounts(&ctx))]
     // pub fn marginal_prices(ctx: Context<MarginalPrices>) -> Result<MarginalPricesResult> {
-    pub fn marginal_prices(ctx: Context<MarginalPrices>) -> Result<[BorshDecimal; 2]> {
+    pub fn marginal_prices<'info>(ctx: Context<'_, '_, '_, 'info, MarginalPrices<'info>>) -> Result<Vec<BorshDecimal>> {
         handle_marginal_prices(ctx)
     }
 
+    /// Returns the time weighted average marginal prices over the last `window` seconds
+    #[access_control(MarginalPrices::accounts(&ctx))]
+    pub fn observe<'info>(
+        ctx: Context<'_, '_, '_, 'info, MarginalPrices<'info>>,
+        window: i64,
+    ) -> Result<Vec<BorshDecimal>> {
+        handle_observe(ctx, window)
+    }
+
+    /// Returns the pool's depth per lp token
+    #[access_control(MarginalPrices::accounts(&ctx))]
+    pub fn virtual_price<'info>(ctx: Context<'_, '_, '_, 'info, MarginalPrices<'info>>) -> Result<BorshDecimal> {
+        handle_virtual_price(ctx)
+    }
+
+    pub fn initialize_virtual_price_checkpoints(ctx: Context<InitializeVirtualPriceCheckpoints>) -> Result<()> {
+        handle_initialize_virtual_price_checkpoints(ctx)
+    }
+
+    #[access_control(CheckpointVirtualPrice::accounts(&ctx))]
+    pub fn checkpoint_virtual_price<'info>(
+        ctx: Context<'_, '_, '_, 'info, CheckpointVirtualPrice<'info>>,
+    ) -> Result<()> {
+        handle_checkpoint_virtual_price(ctx)
+    }
+
     /** Governance Ixs **/
     #[access_control(PrepareGovernanceTransition::accounts(&ctx))]
     pub fn prepare_governance_transition(
@@ -176,6 +274,11 @@ pub mod two_pool {
         handle_enact_governance_transition(ctx)
     }
 
+    #[access_control(CancelGovernanceTransition::accounts(&ctx))]
+    pub fn cancel_governance_transition(ctx: Context<CancelGovernanceTransition>) -> Result<()> {
+        handle_cancel_governance_transition(ctx)
+    }
+
     #[access_control(PrepareFeeChange::accounts(&ctx))]
     pub fn prepare_fee_change(
         ctx: Context<PrepareFeeChange>,
@@ -192,35 +295,96 @@ pub mod two_pool {
         handle_enact_fee_change(ctx)
     }
 
-    #[access_control(ChangeGovernanceFeeAccount::accounts(&ctx))]
-    pub fn change_governance_fee_account(
-        ctx: Context<ChangeGovernanceFeeAccount>,
-        new_governance_fee_key: Pubkey,
+    #[access_control(CancelFeeChange::accounts(&ctx))]
+    pub fn cancel_fee_change(ctx: Context<CancelFeeChange>) -> Result<()> {
+        handle_cancel_fee_change(ctx)
+    }
+
+    #[access_control(PrepareEnactDelayChange::accounts(&ctx))]
+    pub fn prepare_enact_delay_change(ctx: Context<PrepareEnactDelayChange>, enact_delay: i64) -> Result<()> {
+        handle_prepare_enact_delay_change(ctx, enact_delay)
+    }
+
+    #[access_control(EnactEnactDelayChange::accounts(&ctx))]
+    pub fn enact_enact_delay_change(ctx: Context<EnactEnactDelayChange>) -> Result<()> {
+        handle_enact_enact_delay_change(ctx)
+    }
+
+    #[access_control(CancelEnactDelayChange::accounts(&ctx))]
+    pub fn cancel_enact_delay_change(ctx: Context<CancelEnactDelayChange>) -> Result<()> {
+        handle_cancel_enact_delay_change(ctx)
+    }
+
+    /// There is no instant governance fee account change since the account receives the governance fees
+    #[access_control(PrepareGovernanceFeeAccountChange::accounts(&ctx))]
+    pub fn prepare_governance_fee_account_change(
+        ctx: Context<PrepareGovernanceFeeAccountChange>,
+        upcoming_governance_fee_key: Pubkey,
     ) -> Result<()> {
-        handle_change_governance_fee_account(ctx, new_governance_fee_key)
+        handle_prepare_governance_fee_account_change(ctx, upcoming_governance_fee_key)
+    }
+
+    #[access_control(EnactGovernanceFeeAccountChange::accounts(&ctx))]
+    pub fn enact_governance_fee_account_change(ctx: Context<EnactGovernanceFeeAccountChange>) -> Result<()> {
+        handle_enact_governance_fee_account_change(ctx)
+    }
+
+    #[access_control(CancelGovernanceFeeAccountChange::accounts(&ctx))]
+    pub fn cancel_governance_fee_account_change(ctx: Context<CancelGovernanceFeeAccountChange>) -> Result<()> {
+        handle_cancel_governance_fee_account_change(ctx)
     }
 
+    /// `interpolation` is a required arg: callers built against the previous
+    /// `adjust_amp_factor(target_ts, target_value)` layout have to pass it (`Linear` keeps the old behavior)
     #[access_control(AdjustAmpFactor::accounts(&ctx))]
     pub fn adjust_amp_factor(
         ctx: Context<AdjustAmpFactor>,
         target_ts: i64,
         target_value: DecimalU64Anchor,
+        interpolation: AmpFactorInterpolation,
         // params: AdjustAmpFactorParams,
     ) -> Result<()> {
-        let params = AdjustAmpFactorParams { target_ts, target_value };
+        let params = AdjustAmpFactorParams { target_ts, target_value, interpolation };
         handle_adjust_amp_factor(ctx, params)
     }
 
+    #[access_control(StopAmpFactorAdjustment::accounts(&ctx))]
+    pub fn stop_amp_factor_adjustment(ctx: Context<StopAmpFactorAdjustment>) -> Result<()> {
+        handle_stop_amp_factor_adjustment(ctx)
+    }
+
     #[access_control(SetPaused::accounts(&ctx))]
     pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
         handle_set_paused(ctx, paused)
     }
 
+    #[access_control(SetPaused::accounts(&ctx))]
+    pub fn set_pause_flags(ctx: Context<SetPaused>, pause_flags: u8) -> Result<()> {
+        handle_set_pause_flags(ctx, pause_flags)
+    }
+
+    /// Changes the pause key without the enact delay so that a compromised pause key can be replaced
+    /// immediately. The pause key can only pause & unpause the pool, it can't move any funds
     #[access_control(ChangePauseKey::accounts(&ctx))]
     pub fn change_pause_key(ctx: Context<ChangePauseKey>, new_pause_key: Pubkey) -> Result<()> {
         handle_change_pause_key(ctx, new_pause_key)
     }
 
+    #[access_control(PreparePauseKeyChange::accounts(&ctx))]
+    pub fn prepare_pause_key_change(ctx: Context<PreparePauseKeyChange>, upcoming_pause_key: Pubkey) -> Result<()> {
+        handle_prepare_pause_key_change(ctx, upcoming_pause_key)
+    }
+
+    #[access_control(EnactPauseKeyChange::accounts(&ctx))]
+    pub fn enact_pause_key_change(ctx: Context<EnactPauseKeyChange>) -> Result<()> {
+        handle_enact_pause_key_change(ctx)
+    }
+
+    #[access_control(CancelPauseKeyChange::accounts(&ctx))]
+    pub fn cancel_pause_key_change(ctx: Context<CancelPauseKeyChange>) -> Result<()> {
+        handle_cancel_pause_key_change(ctx)
+    }
+
     #[access_control(CreateLpMetadata::accounts(&ctx))]
     pub fn create_lp_metadata(
         ctx: Context<CreateLpMetadata>,
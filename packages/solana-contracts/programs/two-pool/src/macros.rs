@@ -12,3 +12,34 @@ macro_rules! gen_pool_signer_seeds {
         ]
     };
 }
+
+/// Binds `$n` to `$token_count` as a const so that `$body` can call into
+/// [crate::invariant::Invariant] for a pool whose token count is only known at runtime.
+#[macro_export]
+macro_rules! with_token_count {
+    ($token_count:expr, $n:ident => $body:expr) => {
+        match $token_count {
+            2 => {
+                const $n: usize = 2;
+                $body
+            }
+            3 => {
+                const $n: usize = 3;
+                $body
+            }
+            4 => {
+                const $n: usize = 4;
+                $body
+            }
+            5 => {
+                const $n: usize = 5;
+                $body
+            }
+            6 => {
+                const $n: usize = 6;
+                $body
+            }
+            _ => Err($crate::error::PoolError::InvalidTokenCount),
+        }
+    };
+}
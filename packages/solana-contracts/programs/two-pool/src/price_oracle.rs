@@ -0,0 +1,246 @@
+use {
+    crate::{decimal::DecimalU64, error::PoolError, MAX_TOKEN_COUNT},
+    anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp},
+};
+
+pub type TimestampT = UnixTimestamp;
+pub type ValueT = DecimalU64;
+
+//cumulative prices are stored as fixed point numbers with PRICE_DECIMALS decimals
+//(i.e. sum of price * 10^PRICE_DECIMALS * seconds) and are allowed to wrap around
+//(only differences between two cumulative prices are meaningful)
+pub const PRICE_DECIMALS: u8 = 18;
+//number of historical observations kept in the ring buffer
+pub const OBSERVATION_COUNT: usize = 8;
+//minimum time between two observations. together with OBSERVATION_COUNT this
+//determines how far back a twap can be calculated (at least 7 * 15 minutes)
+pub const OBSERVATION_INTERVAL: TimestampT = 15 * 60;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, Default)]
+pub struct PriceObservation {
+    pub ts: i64,
+    pub price_cumulatives: [u128; MAX_TOKEN_COUNT],
+}
+
+impl PriceObservation {
+    pub const LEN: usize = 8 + 16 * MAX_TOKEN_COUNT;
+}
+
+/// Time weighted average price (TWAP) oracle for the pool's marginal prices
+///
+/// Every ix that mutates the pool's balances accumulates the `last_marginal_prices` recorded
+/// by the previous such ix (i.e. the prices that were in effect since `last_update_ts`) and
+/// then records the prices resulting from its own balance changes. Prices are never derived
+/// from the pool's live token balances, so tokens sent to the pool through a direct transfer
+/// don't affect the twap until an ix of the pool records them. Skewing the pool within a
+/// transaction doesn't affect the twap either since no time elapses between the manipulation
+/// and its reversal.
+///
+/// Every `OBSERVATION_INTERVAL` seconds a snapshot of the cumulative prices is written
+/// to `observations` so that twaps can be calculated for windows chosen by the caller.
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, Default)]
+pub struct PriceOracle {
+    pub price_cumulatives: [u128; MAX_TOKEN_COUNT],
+    pub last_update_ts: i64,
+    /// marginal prices (as fixed point numbers) recorded by the last ix that mutated the pool
+    pub last_marginal_prices: [u128; MAX_TOKEN_COUNT],
+    pub observation_index: u8,
+    pub observations: [PriceObservation; OBSERVATION_COUNT],
+}
+
+impl PriceOracle {
+    pub const LEN: usize =
+        16 * MAX_TOKEN_COUNT + 8 + 16 * MAX_TOKEN_COUNT + 1 + PriceObservation::LEN * OBSERVATION_COUNT;
+
+    pub fn new(current_ts: TimestampT) -> Self {
+        let mut oracle = Self::default();
+        oracle.last_update_ts = current_ts;
+        oracle.observations[0].ts = current_ts;
+        oracle
+    }
+
+    /// Returns `true` if time has passed since the last update
+    pub fn needs_update(&self, current_ts: TimestampT) -> bool {
+        current_ts > self.last_update_ts
+    }
+
+    /// Accumulates `last_marginal_prices` for the time elapsed since the last update and
+    /// records `marginal_prices` as the prices in effect from `current_ts` onwards
+    ///
+    /// `marginal_prices` should be the prices *after* the current ix changed the pool's
+    /// balances. `None` if the pool has no liquidity (in which case there are no prices
+    /// to accumulate until the next update).
+    pub fn update(&mut self, current_ts: TimestampT, marginal_prices: Option<&[ValueT]>) -> Result<()> {
+        if self.needs_update(current_ts) {
+            self.price_cumulatives = self.get_price_cumulatives(current_ts);
+            self.last_update_ts = current_ts;
+
+            let index = self.observation_index as usize;
+            if current_ts - self.observations[index].ts >= OBSERVATION_INTERVAL {
+                let index = (index + 1) % OBSERVATION_COUNT;
+                self.observations[index] =
+                    PriceObservation { ts: current_ts, price_cumulatives: self.price_cumulatives };
+                self.observation_index = index as u8;
+            }
+        }
+        self.last_marginal_prices = [0; MAX_TOKEN_COUNT];
+        for (last_marginal_price, &marginal_price) in
+            self.last_marginal_prices.iter_mut().zip(marginal_prices.unwrap_or_default())
+        {
+            *last_marginal_price = to_fixed_point(marginal_price);
+        }
+        Ok(())
+    }
+
+    /// Returns the time weighted average of the first `token_count` marginal prices over the
+    /// last `window` seconds
+    ///
+    /// `last_marginal_prices` are used to extrapolate the cumulative prices from
+    /// `last_update_ts` to `current_ts`.
+    pub fn twap(&self, current_ts: TimestampT, window: TimestampT, token_count: usize) -> Result<Vec<ValueT>> {
+        require_gt!(window, 0i64, PoolError::InvalidTwapWindow);
+        let target_ts = current_ts.checked_sub(window).ok_or(error!(PoolError::InvalidTwapWindow))?;
+
+        let mut points = self
+            .observations
+            .iter()
+            .filter(|observation| observation.ts != 0)
+            .map(|observation| (observation.ts, observation.price_cumulatives))
+            .collect::<Vec<_>>();
+        points.push((self.last_update_ts, self.price_cumulatives));
+        points.push((current_ts, self.get_price_cumulatives(current_ts)));
+        points.sort_by_key(|(ts, _)| *ts);
+        points.dedup_by_key(|(ts, _)| *ts);
+
+        //find the two points surrounding target_ts and interpolate between them
+        let i = points.iter().rposition(|(ts, _)| *ts <= target_ts).ok_or(error!(PoolError::InvalidTwapWindow))?;
+        let (ts_before, cumulatives_before) = points[i];
+        let (ts_after, cumulatives_after) = points[i + 1];
+        let (_, cumulatives_now) = points[points.len() - 1];
+
+        (0..token_count)
+            .map(|j| {
+                let delta = cumulatives_after[j].wrapping_sub(cumulatives_before[j]);
+                let target_cumulative = cumulatives_before[j].wrapping_add(
+                    delta.checked_mul((target_ts - ts_before) as u128).ok_or(error!(PoolError::ConversionError))?
+                        / (ts_after - ts_before) as u128,
+                );
+                let twap = cumulatives_now[j].wrapping_sub(target_cumulative) / window as u128;
+                from_fixed_point(twap)
+            })
+            .collect()
+    }
+
+    /// Returns the cumulative prices extrapolated from `last_update_ts` to `current_ts`
+    /// using `last_marginal_prices` (wrapping around like the stored cumulative prices)
+    pub fn get_price_cumulatives(&self, current_ts: TimestampT) -> [u128; MAX_TOKEN_COUNT] {
+        let elapsed = (current_ts - self.last_update_ts) as u128;
+        let mut price_cumulatives = self.price_cumulatives;
+        for (i, &marginal_price) in self.last_marginal_prices.iter().enumerate() {
+            price_cumulatives[i] = price_cumulatives[i].wrapping_add(marginal_price.wrapping_mul(elapsed));
+        }
+        price_cumulatives
+    }
+}
+
+pub(crate) fn to_fixed_point(value: ValueT) -> u128 {
+    let decimals = value.get_decimals();
+    if decimals <= PRICE_DECIMALS {
+        value.get_raw() as u128 * 10u128.pow((PRICE_DECIMALS - decimals) as u32)
+    } else {
+        value.get_raw() as u128 / 10u128.pow((decimals - PRICE_DECIMALS) as u32)
+    }
+}
+
+pub(crate) fn from_fixed_point(mut value: u128) -> Result<ValueT> {
+    let mut decimals = PRICE_DECIMALS;
+    while value > u64::MAX as u128 {
+        require_gt!(decimals, 0u8, PoolError::ConversionError);
+        value /= 10;
+        decimals -= 1;
+    }
+    Ok(ValueT::new(value as u64, decimals)?.normalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_u64(value: u64, decimals: u8) -> ValueT {
+        ValueT::new(value, decimals).unwrap()
+    }
+
+    #[test]
+    fn constant_price_twap() {
+        let prices = [new_u64(1, 0), new_u64(2, 0)];
+        let mut oracle = PriceOracle::new(1000);
+        oracle.update(1000, Some(&prices)).unwrap();
+        oracle.update(2000, Some(&prices)).unwrap();
+        oracle.update(3000, Some(&prices)).unwrap();
+
+        assert_eq!(oracle.twap(3000, 1000, 2).unwrap(), prices.to_vec());
+        assert_eq!(oracle.twap(3500, 2000, 2).unwrap(), prices.to_vec());
+        assert!(oracle.twap(3000, 2001, 2).is_err());
+        assert!(oracle.twap(3000, 0, 2).is_err());
+    }
+
+    #[test]
+    fn twap_with_wrapped_price_cumulatives() {
+        let prices = [new_u64(1, 0), new_u64(2, 0)];
+        let mut oracle = PriceOracle::new(1000);
+        oracle.price_cumulatives = [u128::MAX - 5; MAX_TOKEN_COUNT];
+        oracle.observations[0].price_cumulatives = oracle.price_cumulatives;
+        oracle.update(1000, Some(&prices)).unwrap();
+        oracle.update(2000, Some(&prices)).unwrap();
+
+        assert!(oracle.price_cumulatives[0] < u128::MAX - 5);
+        assert_eq!(oracle.twap(3000, 1500, 2).unwrap(), prices.to_vec());
+    }
+
+    #[test]
+    fn twap_ignores_manipulation_without_elapsed_time() {
+        let prices = [new_u64(1, 0), new_u64(1, 0)];
+        let skewed_prices = [new_u64(5, 1), new_u64(15, 1)];
+        let mut oracle = PriceOracle::new(1000);
+        oracle.update(1000, Some(&prices)).unwrap();
+        //price is skewed & reverted within the same timestamp
+        oracle.update(2000, Some(&skewed_prices)).unwrap();
+        oracle.update(2000, Some(&prices)).unwrap();
+
+        assert_eq!(oracle.twap(2000, 1000, 2).unwrap(), prices.to_vec());
+        assert_eq!(oracle.twap(3000, 2000, 2).unwrap(), prices.to_vec());
+    }
+
+    #[test]
+    fn twap_weighs_prices_by_time() {
+        let prices = [new_u64(1, 0), new_u64(1, 0)];
+        let new_prices = [new_u64(2, 0), new_u64(5, 1)];
+        let mut oracle = PriceOracle::new(1000);
+        oracle.update(1000, Some(&prices)).unwrap();
+        oracle.update(2000, Some(&prices)).unwrap();
+        //prices were `prices` from 1000 to 4000 and `new_prices` from 4000 onwards
+        oracle.update(4000, Some(&new_prices)).unwrap();
+
+        assert_eq!(oracle.twap(5000, 2000, 2).unwrap(), vec![new_u64(15, 1), new_u64(75, 2)]);
+        //window starts between two observations
+        assert_eq!(
+            oracle.twap(5000, 3500, 2).unwrap(),
+            vec![new_u64(1285714285714285714, 18), new_u64(857142857142857142, 18)]
+        );
+    }
+
+    #[test]
+    fn twap_ignores_donations() {
+        //tokens transferred directly to the pool don't change the recorded prices so the twap
+        //only moves once an ix of the pool records the resulting prices
+        let prices = [new_u64(1, 0), new_u64(1, 0)];
+        let donated_prices = [new_u64(5, 1), new_u64(15, 1)];
+        let mut oracle = PriceOracle::new(1000);
+        oracle.update(1000, Some(&prices)).unwrap();
+        assert_eq!(oracle.twap(5000, 2000, 2).unwrap(), prices.to_vec());
+
+        oracle.update(5000, Some(&donated_prices)).unwrap();
+        assert_eq!(oracle.twap(5000, 2000, 2).unwrap(), prices.to_vec());
+        assert_eq!(oracle.twap(6000, 2000, 2).unwrap(), vec![new_u64(75, 2), new_u64(125, 2)]);
+    }
+}
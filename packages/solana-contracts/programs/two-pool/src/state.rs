@@ -1,5 +1,5 @@
 use {
-    crate::{amp_factor::AmpFactor, error::*, pool_fee::PoolFee, TOKEN_COUNT},
+    crate::{amp_factor::AmpFactor, error::*, pool_fee::PoolFee, price_oracle::PriceOracle, MAX_TOKEN_COUNT},
     anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp},
 };
 
@@ -9,6 +9,22 @@ use {
 
 // use solana_program::{clock::UnixTimestamp, pubkey::Pubkey};
 
+/// Operations of the pool that can be paused independently
+pub mod pause_flags {
+    pub const ADD: u8 = 1 << 0;
+    pub const SWAP: u8 = 1 << 1;
+    /// `remove_exact_burn` & `remove_exact_output`
+    pub const REMOVE_IMBALANCED: u8 = 1 << 2;
+    /// `marginal_prices` & `observe`
+    pub const PRICES: u8 = 1 << 3;
+    pub const REMOVE_UNIFORM: u8 = 1 << 4;
+    /// set by `set_paused`. `remove_uniform` stays open so that LPs can always exit the pool
+    /// and prices stay available for integrations (e.g. the propeller's fee conversion).
+    /// `PRICES` is only paused when explicitly set through `set_pause_flags`
+    pub const DEFAULT_PAUSE: u8 = ADD | SWAP | REMOVE_IMBALANCED;
+    pub const ALL: u8 = DEFAULT_PAUSE | PRICES | REMOVE_UNIFORM;
+}
+
 //arguably, various fields should be Options (e.g. all the prepared_* fields)
 //the advantage of taking a special value approach is that serialized data
 //always has the same size (otherwise we'll have to figure out the maximum
@@ -18,7 +34,8 @@ use {
 #[account]
 pub struct TwoPool {
     pub bump: u8,
-    pub is_paused: bool,
+    /// bitflags of the operations disabled by the pause key. see [`pause_flags`]
+    pub pause_flags: u8,
     pub amp_factor: AmpFactor,
     pub lp_fee: PoolFee,
     pub governance_fee: PoolFee,
@@ -26,9 +43,12 @@ pub struct TwoPool {
     pub lp_mint_key: Pubkey,
     pub lp_decimal_equalizer: u8,
 
-    pub token_mint_keys: [Pubkey; TOKEN_COUNT],
-    pub token_decimal_equalizers: [u8; TOKEN_COUNT],
-    pub token_keys: [Pubkey; TOKEN_COUNT],
+    //only the first `token_count` entries of the token arrays are in use,
+    //the rest are left as default values
+    pub token_count: u8,
+    pub token_mint_keys: [Pubkey; MAX_TOKEN_COUNT],
+    pub token_decimal_equalizers: [u8; MAX_TOKEN_COUNT],
+    pub token_keys: [Pubkey; MAX_TOKEN_COUNT],
     pub pause_key: Pubkey,
     pub governance_key: Pubkey,
     pub governance_fee_key: Pubkey,
@@ -38,6 +58,15 @@ pub struct TwoPool {
     pub prepared_governance_fee: PoolFee,
     pub fee_transition_ts: i64,
     pub previous_depth: u128,
+    pub price_oracle: PriceOracle,
+    /// delay (in seconds) between preparing & enacting a governance change
+    pub enact_delay: i64,
+    pub prepared_enact_delay: i64,
+    pub enact_delay_transition_ts: i64,
+    pub prepared_governance_fee_key: Pubkey,
+    pub governance_fee_key_transition_ts: i64,
+    pub prepared_pause_key: Pubkey,
+    pub pause_key_transition_ts: i64,
 }
 
 impl TwoPool {
@@ -48,7 +77,7 @@ impl TwoPool {
     pub const LEN: usize =
         // nonce
         1 +
-      // is_paused
+      // pause_flags
       1 +
       // amp_factor
       AmpFactor::LEN +
@@ -60,12 +89,14 @@ impl TwoPool {
       32 +
       // lp_decimal_equalizer
       1 +
+      // token_count
+      1 +
       // token_mint_keys
-      32 * TOKEN_COUNT +
+      32 * MAX_TOKEN_COUNT +
       // token_decimal_equalizers
-      1 * TOKEN_COUNT +
+      1 * MAX_TOKEN_COUNT +
       // token_keys
-      32 * TOKEN_COUNT +
+      32 * MAX_TOKEN_COUNT +
       // pause_key
       32 +
       // governance_key
@@ -83,7 +114,27 @@ impl TwoPool {
       // fee_transition_ts
       8 +
       // previous_depth
-      16;
+      16 +
+      // price_oracle
+      PriceOracle::LEN +
+      // enact_delay
+      8 +
+      // prepared_enact_delay
+      8 +
+      // enact_delay_transition_ts
+      8 +
+      // prepared_governance_fee_key
+      32 +
+      // governance_fee_key_transition_ts
+      8 +
+      // prepared_pause_key
+      32 +
+      // pause_key_transition_ts
+      8;
+
+    pub fn get_token_count(&self) -> usize {
+        self.token_count as usize
+    }
 
     // Note: this is a workaround for to be able to declare the seeds in the
     //  governance ix. anchor does not handle using `pool.token_mint_keys[0]` directly
@@ -95,4 +146,14 @@ impl TwoPool {
     pub fn get_token_mint_1(&self) -> Result<Pubkey> {
         Ok(self.token_mint_keys[1])
     }
+
+    pub fn require_not_paused(&self, pause_flag: u8) -> Result<()> {
+        require!(self.pause_flags & pause_flag == 0, PoolError::PoolIsPaused);
+        Ok(())
+    }
+
+    /// Timestamp from which a change prepared at `current_ts` can be enacted
+    pub fn get_transition_ts(&self, current_ts: UnixTimestamp) -> Result<UnixTimestamp> {
+        current_ts.checked_add(self.enact_delay).ok_or_else(|| error!(PoolError::InvalidTimestamp))
+    }
 }
@@ -0,0 +1,135 @@
+use {
+    crate::{
+        decimal::DecimalU64,
+        price_oracle::{from_fixed_point, to_fixed_point},
+    },
+    anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp},
+};
+
+pub type TimestampT = UnixTimestamp;
+pub type ValueT = DecimalU64;
+
+//virtual prices are stored as fixed point numbers with PRICE_DECIMALS decimals
+//(see [crate::price_oracle::PRICE_DECIMALS])
+//number of checkpoints kept in the ring buffer
+pub const CHECKPOINT_COUNT: usize = 256;
+//minimum time between two checkpoints. together with CHECKPOINT_COUNT this
+//determines how far back yields can be calculated (at least 255 * 3 hours ~ 32 days)
+pub const CHECKPOINT_INTERVAL: TimestampT = 3 * 60 * 60;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, Default)]
+pub struct VirtualPriceCheckpoint {
+    pub ts: i64,
+    pub virtual_price: u128,
+}
+
+impl VirtualPriceCheckpoint {
+    pub const LEN: usize = 8 + 16;
+
+    pub fn get_virtual_price(&self) -> Result<ValueT> {
+        from_fixed_point(self.virtual_price)
+    }
+}
+
+/// Ring buffer of `(timestamp, virtual_price)` samples of a pool
+///
+/// Appended to by the permissionless `checkpoint_virtual_price` crank at most once every
+/// `CHECKPOINT_INTERVAL` seconds. The yield of the pool's lp token between two checkpoints
+/// is the ratio of their virtual prices. Unused checkpoints have a `ts` of 0.
+#[account]
+pub struct VirtualPriceCheckpoints {
+    pub bump: u8,
+    pub pool: Pubkey,
+    /// index of the latest checkpoint
+    pub checkpoint_index: u16,
+    /// always contains `CHECKPOINT_COUNT` entries
+    pub checkpoints: Vec<VirtualPriceCheckpoint>,
+}
+
+impl VirtualPriceCheckpoints {
+    pub const LEN: usize = 1 + 32 + 2 + 4 + VirtualPriceCheckpoint::LEN * CHECKPOINT_COUNT;
+
+    pub fn new(bump: u8, pool: Pubkey) -> Self {
+        Self {
+            bump,
+            pool,
+            checkpoint_index: (CHECKPOINT_COUNT - 1) as u16,
+            checkpoints: vec![VirtualPriceCheckpoint::default(); CHECKPOINT_COUNT],
+        }
+    }
+
+    pub fn latest(&self) -> Option<&VirtualPriceCheckpoint> {
+        Some(&self.checkpoints[self.checkpoint_index as usize]).filter(|checkpoint| checkpoint.ts != 0)
+    }
+
+    /// Returns `true` if at least `CHECKPOINT_INTERVAL` seconds passed since the latest checkpoint
+    pub fn needs_update(&self, current_ts: TimestampT) -> bool {
+        self.latest().map_or(true, |latest| current_ts - latest.ts >= CHECKPOINT_INTERVAL)
+    }
+
+    /// Overwrites the oldest checkpoint with `(current_ts, virtual_price)`
+    ///
+    /// Callers are expected to check `needs_update` first.
+    pub fn update(&mut self, current_ts: TimestampT, virtual_price: ValueT) {
+        let index = (self.checkpoint_index as usize + 1) % CHECKPOINT_COUNT;
+        self.checkpoints[index] =
+            VirtualPriceCheckpoint { ts: current_ts, virtual_price: to_fixed_point(virtual_price) };
+        self.checkpoint_index = index as u16;
+    }
+
+    /// Returns the latest checkpoint that is at least `min_age` seconds old
+    pub fn at_least(&self, current_ts: TimestampT, min_age: TimestampT) -> Option<&VirtualPriceCheckpoint> {
+        (0..CHECKPOINT_COUNT)
+            .map(|i| &self.checkpoints[(self.checkpoint_index as usize + CHECKPOINT_COUNT - i) % CHECKPOINT_COUNT])
+            .take_while(|checkpoint| checkpoint.ts != 0)
+            .find(|checkpoint| current_ts - checkpoint.ts >= min_age)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_u64(value: u64, decimals: u8) -> ValueT {
+        ValueT::new(value, decimals).unwrap()
+    }
+
+    #[test]
+    fn empty_checkpoints() {
+        let checkpoints = VirtualPriceCheckpoints::new(255, Pubkey::default());
+        assert_eq!(checkpoints.latest(), None);
+        assert!(checkpoints.needs_update(1));
+        assert_eq!(checkpoints.at_least(1000, 0), None);
+    }
+
+    #[test]
+    fn update_respects_interval() {
+        let mut checkpoints = VirtualPriceCheckpoints::new(255, Pubkey::default());
+        checkpoints.update(1000, new_u64(1, 0));
+        assert_eq!(checkpoints.checkpoint_index, 0);
+        assert!(!checkpoints.needs_update(1000 + CHECKPOINT_INTERVAL - 1));
+        assert!(checkpoints.needs_update(1000 + CHECKPOINT_INTERVAL));
+        assert_eq!(checkpoints.latest().unwrap().get_virtual_price().unwrap(), new_u64(1, 0));
+    }
+
+    #[test]
+    fn ring_buffer_wraps_around() {
+        let mut checkpoints = VirtualPriceCheckpoints::new(255, Pubkey::default());
+        for i in 0..(CHECKPOINT_COUNT as i64 + 2) {
+            checkpoints.update(1 + i * CHECKPOINT_INTERVAL, new_u64(1000 + i as u64, 3));
+        }
+        assert_eq!(checkpoints.checkpoint_index, 1);
+        assert_eq!(checkpoints.checkpoints.len(), CHECKPOINT_COUNT);
+        let now = 1 + (CHECKPOINT_COUNT as i64 + 1) * CHECKPOINT_INTERVAL;
+        let latest = checkpoints.latest().unwrap();
+        assert_eq!(latest.ts, now);
+        assert_eq!(latest.get_virtual_price().unwrap(), new_u64(1000 + CHECKPOINT_COUNT as u64 + 1, 3));
+
+        let day_old = checkpoints.at_least(now, 86400).unwrap();
+        assert_eq!(day_old.ts, now - 8 * CHECKPOINT_INTERVAL);
+        //oldest remaining checkpoint
+        let oldest = checkpoints.at_least(now, (CHECKPOINT_COUNT as i64 - 1) * CHECKPOINT_INTERVAL).unwrap();
+        assert_eq!(oldest.ts, 1 + 2 * CHECKPOINT_INTERVAL);
+        assert_eq!(checkpoints.at_least(now, CHECKPOINT_COUNT as i64 * CHECKPOINT_INTERVAL), None);
+    }
+}
@@ -10,7 +10,7 @@ use {
             clock::Clock,
             commitment_config::{CommitmentConfig, CommitmentLevel},
             hash::Hash,
-            instruction::{Instruction, InstructionError},
+            instruction::{AccountMeta, Instruction, InstructionError},
             pubkey::Pubkey,
             rent::*,
             signature::{Keypair, Signer},
@@ -29,7 +29,8 @@ use {
     spl_associated_token_account::instruction as associated_token_instruction,
     std::rc::Rc,
     two_pool::{
-        amp_factor::AmpFactor,
+        amp_factor::{AmpFactor, AmpFactorInterpolation},
+        decimal::DecimalU64,
         instructions::{PrepareFeeChangeParams, PrepareGovernanceTransition, ENACT_DELAY},
         pool_fee::PoolFee,
         state::TwoPool,
@@ -264,6 +265,369 @@ async fn test_fee_change() {
     assert_eq!(pool_state.fee_transition_ts, 0i64);
 }
 
+#[tokio::test]
+async fn test_enact_delay_and_pause_key_change() {
+    let mut pt = ProgramTest::new("two_pool", two_pool::id(), None);
+
+    let pt_ctxt = &mut DeployedPoolProgramTestContext::new(pt).await;
+
+    let client = Client::new_with_options(
+        Cluster::Debug,
+        Rc::new(copy_keypair(pt_ctxt.get_payer())),
+        CommitmentConfig::finalized(),
+    );
+    let program = client.program(two_pool::id());
+    pt_ctxt.initialize_pool(&program).await.unwrap();
+
+    let pool_state_account = pt_ctxt.get_pool_state_data(pt_ctxt.pool_key).await;
+    let pool_state: TwoPool = TwoPool::try_deserialize(&mut pool_state_account.data.as_slice()).unwrap();
+    assert_eq!(pool_state.enact_delay, ENACT_DELAY);
+
+    let (pool_key, governance_key) = (pt_ctxt.pool_key, pt_ctxt.get_governance().pubkey());
+    let common_governance = || two_pool::accounts::CommonGovernance { pool: pool_key, governance: governance_key };
+    let zero_enact_delay_change_ix = program
+        .request()
+        .accounts(two_pool::accounts::PrepareEnactDelayChange { common_governance: common_governance() })
+        .args(two_pool::instruction::PrepareEnactDelayChange { enact_delay: 0 })
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt
+        .process_governance_ix(zero_enact_delay_change_ix)
+        .await
+        .expect_err("prepare enact delay change to 0 should fail");
+
+    let new_enact_delay = 86400i64;
+    let prepare_enact_delay_change_ix = program
+        .request()
+        .accounts(two_pool::accounts::PrepareEnactDelayChange { common_governance: common_governance() })
+        .args(two_pool::instruction::PrepareEnactDelayChange { enact_delay: new_enact_delay })
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt.process_governance_ix(prepare_enact_delay_change_ix).await.unwrap();
+
+    let enact_enact_delay_change_ix = program
+        .request()
+        .accounts(two_pool::accounts::EnactEnactDelayChange { common_governance: common_governance() })
+        .args(two_pool::instruction::EnactEnactDelayChange {})
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt
+        .process_governance_ix(enact_enact_delay_change_ix.clone())
+        .await
+        .expect_err("enact enact delay change should fail");
+
+    pt_ctxt.time_travel(ENACT_DELAY + 1).await;
+    pt_ctxt.process_governance_ix(enact_enact_delay_change_ix).await.unwrap();
+
+    let pool_state_account = pt_ctxt.get_pool_state_data(pt_ctxt.pool_key).await;
+    let pool_state: TwoPool = TwoPool::try_deserialize(&mut pool_state_account.data.as_slice()).unwrap();
+    assert_eq!(pool_state.enact_delay, new_enact_delay);
+    assert_eq!(pool_state.enact_delay_transition_ts, 0i64);
+
+    let new_pause_key = Keypair::new();
+    let prepare_pause_key_change_ix = program
+        .request()
+        .accounts(two_pool::accounts::PreparePauseKeyChange { common_governance: common_governance() })
+        .args(two_pool::instruction::PreparePauseKeyChange { upcoming_pause_key: new_pause_key.pubkey() })
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt.process_governance_ix(prepare_pause_key_change_ix.clone()).await.unwrap();
+
+    let cancel_pause_key_change_ix = program
+        .request()
+        .accounts(two_pool::accounts::CancelPauseKeyChange { common_governance: common_governance() })
+        .args(two_pool::instruction::CancelPauseKeyChange {})
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt.process_governance_ix(cancel_pause_key_change_ix).await.unwrap();
+
+    let pool_state_account = pt_ctxt.get_pool_state_data(pt_ctxt.pool_key).await;
+    let pool_state: TwoPool = TwoPool::try_deserialize(&mut pool_state_account.data.as_slice()).unwrap();
+    assert_eq!(pool_state.prepared_pause_key, Pubkey::default());
+    assert_eq!(pool_state.pause_key_transition_ts, 0i64);
+
+    let enact_pause_key_change_ix = program
+        .request()
+        .accounts(two_pool::accounts::EnactPauseKeyChange { common_governance: common_governance() })
+        .args(two_pool::instruction::EnactPauseKeyChange {})
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt.time_travel(new_enact_delay + 1).await;
+    pt_ctxt
+        .process_governance_ix(enact_pause_key_change_ix.clone())
+        .await
+        .expect_err("enact cancelled pause key change should fail");
+
+    pt_ctxt.process_governance_ix(prepare_pause_key_change_ix).await.unwrap();
+    pt_ctxt.time_travel(new_enact_delay + 1).await;
+    pt_ctxt.process_governance_ix(enact_pause_key_change_ix).await.unwrap();
+
+    let pool_state_account = pt_ctxt.get_pool_state_data(pt_ctxt.pool_key).await;
+    let pool_state: TwoPool = TwoPool::try_deserialize(&mut pool_state_account.data.as_slice()).unwrap();
+    assert_eq!(pool_state.pause_key, new_pause_key.pubkey());
+    assert_eq!(pool_state.prepared_pause_key, Pubkey::default());
+    assert_eq!(pool_state.pause_key_transition_ts, 0i64);
+}
+
+#[tokio::test]
+async fn test_stop_amp_factor_adjustment() {
+    let mut pt = ProgramTest::new("two_pool", two_pool::id(), None);
+
+    let pt_ctxt = &mut DeployedPoolProgramTestContext::new(pt).await;
+
+    let client = Client::new_with_options(
+        Cluster::Debug,
+        Rc::new(copy_keypair(pt_ctxt.get_payer())),
+        CommitmentConfig::finalized(),
+    );
+    let program = client.program(two_pool::id());
+    pt_ctxt.initialize_pool(&program).await.unwrap();
+
+    let (pool_key, governance_key) = (pt_ctxt.pool_key, pt_ctxt.get_governance().pubkey());
+    let common_governance = || two_pool::accounts::CommonGovernance { pool: pool_key, governance: governance_key };
+    let current_ts = pt_ctxt.pt_ctxt.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let adjust_amp_factor_ix = program
+        .request()
+        .accounts(two_pool::accounts::AdjustAmpFactor { common_governance: common_governance() })
+        .args(two_pool::instruction::AdjustAmpFactor {
+            target_ts: current_ts + 2 * 86400,
+            target_value: DecimalU64Anchor { value: 1200u64, decimals: 0u8 },
+            interpolation: AmpFactorInterpolation::Geometric,
+        })
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt.process_governance_ix(adjust_amp_factor_ix).await.unwrap();
+
+    let stop_amp_factor_adjustment_ix = program
+        .request()
+        .accounts(two_pool::accounts::StopAmpFactorAdjustment { common_governance: common_governance() })
+        .args(two_pool::instruction::StopAmpFactorAdjustment {})
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt.time_travel(86400).await;
+    pt_ctxt.process_governance_ix(stop_amp_factor_adjustment_ix.clone()).await.unwrap();
+
+    // halfway through a geometric adjustment from 300 to 1200
+    let pool_state_account = pt_ctxt.get_pool_state_data(pt_ctxt.pool_key).await;
+    let pool_state: TwoPool = TwoPool::try_deserialize(&mut pool_state_account.data.as_slice()).unwrap();
+    let stopped_value = pool_state.amp_factor.get(i64::MAX);
+    assert!(stopped_value > DecimalU64::from(550) && stopped_value < DecimalU64::from(650));
+
+    pt_ctxt.time_travel(2 * 86400).await;
+    let pool_state_account = pt_ctxt.get_pool_state_data(pt_ctxt.pool_key).await;
+    let pool_state: TwoPool = TwoPool::try_deserialize(&mut pool_state_account.data.as_slice()).unwrap();
+    assert_eq!(pool_state.amp_factor.get(i64::MAX), stopped_value);
+    pt_ctxt
+        .process_governance_ix(stop_amp_factor_adjustment_ix)
+        .await
+        .expect_err("stop amp factor adjustment without an ongoing adjustment should fail");
+}
+
+#[tokio::test]
+async fn test_three_token_pool() {
+    let pt = ProgramTest::new("two_pool", two_pool::id(), None);
+
+    let pt_ctxt = &mut DeployedPoolProgramTestContext::new(pt).await;
+
+    let client = Client::new_with_options(
+        Cluster::Debug,
+        Rc::new(copy_keypair(pt_ctxt.get_payer())),
+        CommitmentConfig::finalized(),
+    );
+    let program = client.program(two_pool::id());
+    let extra_mint = create_mint(&mut pt_ctxt.pt_ctxt, 6u8).await.pubkey();
+    pt_ctxt.initialize_pool_with_extra_mints(&program, &[extra_mint]).await.unwrap();
+
+    let pool_key = pt_ctxt.pool_key;
+    let pool_state_account = pt_ctxt.get_pool_state_data(pool_key).await;
+    let pool_state: TwoPool = TwoPool::try_deserialize(&mut pool_state_account.data.as_slice()).unwrap();
+    assert_eq!(pool_state.get_token_count(), 3);
+    assert_eq!(pool_state.token_mint_keys[2], extra_mint);
+    let pool_token_accounts = [0, 1, 2].map(|i| pool_state.token_keys[i]);
+    assert_eq!(pool_token_accounts[2], get_associated_token_address(&pool_key, &extra_mint));
+
+    let user = pt_ctxt.get_payer().pubkey();
+    let mints = [pt_ctxt.pool_mints[0].pubkey(), pt_ctxt.pool_mints[1].pubkey(), extra_mint];
+    let lp_mint = pt_ctxt.lp_mint.pubkey();
+    let mut user_token_accounts = vec![];
+    for mint in &mints {
+        let user_token_account = create_associated_token_account(&mut pt_ctxt.pt_ctxt, &user, mint).await;
+        let mint_to_ix =
+            instruction::mint_to(&spl_token::id(), mint, &user_token_account, &user, &[], 1_000_000_000).unwrap();
+        pt_ctxt.process_payer_ixs(&[mint_to_ix]).await.unwrap();
+        user_token_accounts.push(user_token_account);
+    }
+    let user_lp_token_account = create_associated_token_account(&mut pt_ctxt.pt_ctxt, &user, &lp_mint).await;
+    //token accounts of token 2 are passed in through the remaining accounts
+    let remaining_accounts =
+        vec![AccountMeta::new(pool_token_accounts[2], false), AccountMeta::new(user_token_accounts[2], false)];
+
+    let mut add_ix = program
+        .request()
+        .accounts(two_pool::accounts::Add {
+            pool: pool_key,
+            pool_token_account_0: pool_token_accounts[0],
+            pool_token_account_1: pool_token_accounts[1],
+            lp_mint,
+            governance_fee: pool_state.governance_fee_key,
+            user_transfer_authority: user,
+            user_token_account_0: user_token_accounts[0],
+            user_token_account_1: user_token_accounts[1],
+            user_lp_token_account,
+            token_program: spl_token::id(),
+        })
+        .args(two_pool::instruction::Add {
+            input_amounts: vec![100_000_000, 100_000_000, 100_000_000],
+            minimum_mint_amount: 0,
+        })
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    add_ix.accounts.extend(remaining_accounts.clone());
+    pt_ctxt.process_payer_ixs(&[add_ix]).await.unwrap();
+    assert_eq!(pt_ctxt.get_token_balance(pool_token_accounts[2]).await, 100_000_000);
+    assert!(pt_ctxt.get_token_balance(user_lp_token_account).await > 0);
+
+    let mut swap_ix = program
+        .request()
+        .accounts(two_pool::accounts::SwapExactInput {
+            pool: pool_key,
+            pool_token_account_0: pool_token_accounts[0],
+            pool_token_account_1: pool_token_accounts[1],
+            lp_mint,
+            governance_fee: pool_state.governance_fee_key,
+            user_transfer_authority: user,
+            user_token_account_0: user_token_accounts[0],
+            user_token_account_1: user_token_accounts[1],
+            token_program: spl_token::id(),
+        })
+        .args(two_pool::instruction::SwapExactInput {
+            exact_input_amounts: vec![1_000_000, 0, 0],
+            output_token_index: 2,
+            minimum_output_amount: 990_000,
+        })
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    swap_ix.accounts.extend(remaining_accounts);
+    pt_ctxt.process_payer_ixs(&[swap_ix]).await.unwrap();
+    assert_eq!(pt_ctxt.get_token_balance(user_token_accounts[0]).await, 899_000_000);
+    let output_amount = pt_ctxt.get_token_balance(user_token_accounts[2]).await - 900_000_000;
+    assert!(output_amount >= 990_000);
+    assert_eq!(pt_ctxt.get_token_balance(pool_token_accounts[1]).await, 100_000_000);
+}
+
+#[tokio::test]
+async fn test_remove_uniform_with_zero_balance() {
+    let pt = ProgramTest::new("two_pool", two_pool::id(), None);
+
+    let pt_ctxt = &mut DeployedPoolProgramTestContext::new(pt).await;
+
+    let client = Client::new_with_options(
+        Cluster::Debug,
+        Rc::new(copy_keypair(pt_ctxt.get_payer())),
+        CommitmentConfig::finalized(),
+    );
+    let program = client.program(two_pool::id());
+    pt_ctxt.initialize_pool(&program).await.unwrap();
+
+    let pool_key = pt_ctxt.pool_key;
+    let pool_state_account = pt_ctxt.get_pool_state_data(pool_key).await;
+    let pool_state: TwoPool = TwoPool::try_deserialize(&mut pool_state_account.data.as_slice()).unwrap();
+    let pool_token_accounts = [pool_state.token_keys[0], pool_state.token_keys[1]];
+
+    let user = pt_ctxt.get_payer().pubkey();
+    let mints = [pt_ctxt.pool_mints[0].pubkey(), pt_ctxt.pool_mints[1].pubkey()];
+    let lp_mint = pt_ctxt.lp_mint.pubkey();
+    let mut user_token_accounts = vec![];
+    for mint in &mints {
+        let user_token_account = create_associated_token_account(&mut pt_ctxt.pt_ctxt, &user, mint).await;
+        let mint_to_ix =
+            instruction::mint_to(&spl_token::id(), mint, &user_token_account, &user, &[], 1_000_000_000).unwrap();
+        pt_ctxt.process_payer_ixs(&[mint_to_ix]).await.unwrap();
+        user_token_accounts.push(user_token_account);
+    }
+    let user_lp_token_account = create_associated_token_account(&mut pt_ctxt.pt_ctxt, &user, &lp_mint).await;
+
+    let add_ix = program
+        .request()
+        .accounts(two_pool::accounts::Add {
+            pool: pool_key,
+            pool_token_account_0: pool_token_accounts[0],
+            pool_token_account_1: pool_token_accounts[1],
+            lp_mint,
+            governance_fee: pool_state.governance_fee_key,
+            user_transfer_authority: user,
+            user_token_account_0: user_token_accounts[0],
+            user_token_account_1: user_token_accounts[1],
+            user_lp_token_account,
+            token_program: spl_token::id(),
+        })
+        .args(two_pool::instruction::Add { input_amounts: vec![100_000_000, 100_000_000], minimum_mint_amount: 0 })
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt.process_payer_ixs(&[add_ix]).await.unwrap();
+    let lp_balance = pt_ctxt.get_token_balance(user_lp_token_account).await;
+    assert!(lp_balance > 0);
+
+    //drain the pool's first token account so that the pool's marginal prices can't be calculated
+    let mut pool_token_account_0 =
+        pt_ctxt.pt_ctxt.banks_client.get_account(pool_token_accounts[0]).await.unwrap().unwrap();
+    let mut token_account_state = spl_token::state::Account::unpack(&pool_token_account_0.data).unwrap();
+    token_account_state.amount = 0;
+    spl_token::state::Account::pack(token_account_state, &mut pool_token_account_0.data).unwrap();
+    pt_ctxt.pt_ctxt.set_account(&pool_token_accounts[0], &pool_token_account_0.into());
+    pt_ctxt.time_travel(60).await;
+
+    let remove_uniform_ix = program
+        .request()
+        .accounts(two_pool::accounts::RemoveUniform {
+            pool: pool_key,
+            pool_token_account_0: pool_token_accounts[0],
+            pool_token_account_1: pool_token_accounts[1],
+            lp_mint,
+            governance_fee: pool_state.governance_fee_key,
+            user_transfer_authority: user,
+            user_token_account_0: user_token_accounts[0],
+            user_token_account_1: user_token_accounts[1],
+            user_lp_token_account,
+            token_program: spl_token::id(),
+        })
+        .args(two_pool::instruction::RemoveUniform {
+            exact_burn_amount: lp_balance / 2,
+            minimum_output_amounts: vec![0, 0],
+        })
+        .instructions()
+        .unwrap()
+        .pop()
+        .unwrap();
+    pt_ctxt.process_payer_ixs(&[remove_uniform_ix]).await.unwrap();
+    assert_eq!(pt_ctxt.get_token_balance(pool_token_accounts[0]).await, 0);
+    assert_eq!(pt_ctxt.get_token_balance(user_token_accounts[0]).await, 900_000_000);
+    assert!(pt_ctxt.get_token_balance(user_token_accounts[1]).await > 900_000_000);
+    assert_eq!(pt_ctxt.get_token_balance(user_lp_token_account).await, lp_balance - lp_balance / 2);
+}
+
 async fn create_mint(context: &mut ProgramTestContext, decimals: u8) -> Keypair {
     let mint_account = Keypair::new();
     let token_mint_address = mint_account.pubkey();
@@ -353,6 +717,15 @@ impl DeployedPoolProgramTestContext {
     }
 
     pub async fn initialize_pool(&mut self, program: &Program) -> Result<(), BanksClientError> {
+        self.initialize_pool_with_extra_mints(program, &[]).await
+    }
+
+    /// Initializes a pool with `2 + extra_mints.len()` tokens
+    pub async fn initialize_pool_with_extra_mints(
+        &mut self,
+        program: &Program,
+        extra_mints: &[Pubkey],
+    ) -> Result<(), BanksClientError> {
         // let governance = Keypair::new();
         let usdc_mint_keypair = copy_keypair(&self.pool_mints[0]);
         let usdt_mint_keypair = copy_keypair(&self.pool_mints[1]);
@@ -375,7 +748,7 @@ impl DeployedPoolProgramTestContext {
         let lp_fee = DecimalU64Anchor { value: 300u64, decimals: 6u8 };
         let governance_fee = DecimalU64Anchor { value: 100u64, decimals: 6u8 };
 
-        let init_ix = program
+        let mut init_ix = program
             .request()
             .accounts(two_pool::accounts::Initialize {
                 pool: pool_key,
@@ -407,6 +780,10 @@ impl DeployedPoolProgramTestContext {
             .unwrap()
             .pop()
             .unwrap();
+        init_ix.accounts.extend(extra_mints.iter().map(|mint| AccountMeta::new_readonly(*mint, false)));
+        init_ix.accounts.extend(
+            extra_mints.iter().map(|mint| AccountMeta::new(get_associated_token_address(&pool_key, mint), false)),
+        );
 
         let recent_blockhash = self.get_latest_blockhash().await;
 
@@ -436,6 +813,11 @@ impl DeployedPoolProgramTestContext {
         self.pt_ctxt.banks_client.get_account(pool_key).await.unwrap().unwrap()
     }
 
+    pub async fn get_token_balance(&mut self, token_account: Pubkey) -> u64 {
+        let account = self.pt_ctxt.banks_client.get_account(token_account).await.unwrap().unwrap();
+        spl_token::state::Account::unpack(&account.data).unwrap().amount
+    }
+
     async fn get_latest_blockhash(&mut self) -> Hash {
         self.pt_ctxt.banks_client.get_latest_blockhash().await.unwrap()
     }
@@ -444,6 +826,30 @@ impl DeployedPoolProgramTestContext {
         self.pt_ctxt.banks_client.process_transaction(transaction).await
     }
 
+    /// Sends `ixs` in a txn signed by the payer
+    pub async fn process_payer_ixs(&mut self, ixs: &[Instruction]) -> Result<(), BanksClientError> {
+        let recent_blockhash = self.get_latest_blockhash().await;
+        let txn = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&self.get_payer().pubkey()),
+            &[self.get_payer()],
+            recent_blockhash,
+        );
+        self.process_transaction(txn).await
+    }
+
+    /// Sends `ix` in a txn signed by the payer & the governance key
+    pub async fn process_governance_ix(&mut self, ix: Instruction) -> Result<(), BanksClientError> {
+        let recent_blockhash = self.get_latest_blockhash().await;
+        let txn = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.get_payer().pubkey()),
+            &[self.get_payer(), self.get_governance()],
+            recent_blockhash,
+        );
+        self.process_transaction(txn).await
+    }
+
     // Time-travel
     // Note: in order to "time-travel", must also warp to future slot.
     //  not 100% sure why might be something to do with the blockhash being the same.
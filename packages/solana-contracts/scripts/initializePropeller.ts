@@ -71,7 +71,12 @@ type InitParameters = {
   readonly completeWithPayloadFee: BN;
   readonly initAtaFee: BN;
   readonly processSwimPayloadFee: BN;
+  readonly swimPayloadExpiry: BN;
+  readonly feePriceCacheMaxAge: BN;
   readonly marginalPricePoolTokenIndex: number;
+  readonly marginalPriceTwapWindow: number;
+  readonly maxStaleness: BN;
+  readonly maxConfidenceInterval: { readonly mantissa: BN; readonly scale: number };
 };
 const DEFAULT_INIT_PROPELLER_PARAMS: InitParameters = {
   gasKickstartAmount: new BN(0.25 * web3.LAMPORTS_PER_SOL),
@@ -81,7 +86,13 @@ const DEFAULT_INIT_PROPELLER_PARAMS: InitParameters = {
   completeWithPayloadFee: new BN(0.0000055 * web3.LAMPORTS_PER_SOL),
   initAtaFee: new BN(0.25 * web3.LAMPORTS_PER_SOL),
   processSwimPayloadFee: new BN(0.00001 * web3.LAMPORTS_PER_SOL),
+  swimPayloadExpiry: new BN(7 * 24 * 60 * 60),
+  feePriceCacheMaxAge: new BN(60),
   marginalPricePoolTokenIndex: 0,
+  marginalPriceTwapWindow: 0,
+  maxStaleness: new BN(300),
+  // 1 USD per SOL
+  maxConfidenceInterval: { mantissa: new BN(1), scale: 0 },
 };
 
 type PropellerConfig = {
@@ -301,6 +312,7 @@ async function initializePropellerState(): Promise<PropellerInfo> {
         propellerRedeemerEscrow,
         propellerFeeVault,
         admin: propellerAdmin.publicKey,
+        pauseKey: propellerAdmin.publicKey,
         swimUsdMint,
         payer: payer.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -377,6 +389,7 @@ async function createTargetTokenIdMaps(): Promise<
             tokenIdMap.poolTokenIndex,
             tokenIdMap.poolTokenMint,
             tokenIdMap.poolIx,
+            [],
           )
           .accounts({
             propeller: propellerInfo.address,
@@ -632,7 +645,10 @@ async function transferTokens() {
             gasKickstart,
             maxFee,
             evmTargetTokenId,
+            new BN(0),
             memoBuffer2,
+            null,
+            null,
           )
           .accounts({
             propeller: propellerInfo.address,
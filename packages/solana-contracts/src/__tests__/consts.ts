@@ -61,7 +61,7 @@ export const SWIM_USD_TO_TOKEN_NUMBER = 0;
 export const USDC_TO_TOKEN_NUMBER = 256;
 export const USDT_TO_TOKEN_NUMBER = 257;
 export const marginalPricePoolTokenIndex = 0;
-export const swimPayloadVersion = 1;
+export const swimPayloadVersion = 2;
 export const usdcPoolTokenIndex = 0;
 export const usdtPoolTokenIndex = 1;
 export const metapoolMint1OutputTokenIndex = 3;
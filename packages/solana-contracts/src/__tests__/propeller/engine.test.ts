@@ -85,6 +85,8 @@ import {
   encodeSwimPayload,
   generatePropellerEngineTxns,
   getOwnerTokenAccountsForPool,
+  getPropellerFeePriceCacheAddr,
+  getTargetChainIdMapAddr,
   getPropellerPda,
   getPropellerRedeemerPda,
   getSwimClaimPda,
@@ -249,6 +251,8 @@ const propellerEnginePropellerProgram = new Program(
 console.info(`propellerEngine: ${propellerEngineKeypair.publicKey.toBase58()}`);
 
 let propellerEngineFeeTracker: web3.PublicKey;
+let propellerFeePriceCache: web3.PublicKey;
+let ethTargetChainMap: web3.PublicKey;
 
 let propellerEngineSwimUsdFeeAccount: web3.PublicKey;
 
@@ -798,6 +802,60 @@ describe("propeller", () => {
       expect(feeTrackerAccount.feesOwed.eq(new BN(0))).toBeTruthy();
     });
 
+    it("Initializes and refreshes the fee price cache", async () => {
+      const initFeePriceCache = propellerProgram.methods
+        .initializeFeePriceCache()
+        .accounts({
+          propeller,
+          payer: propellerEngineKeypair.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        });
+      const initFeePriceCachePubkeys = await initFeePriceCache.pubkeys();
+      if (!initFeePriceCachePubkeys.feePriceCache) {
+        throw new Error("feePriceCache is undefined");
+      }
+      const [expectedFeePriceCache, bump] = await getPropellerFeePriceCacheAddr(
+        propeller,
+        propellerProgram.programId,
+      );
+      expect(initFeePriceCachePubkeys.feePriceCache.toBase58()).toEqual(
+        expectedFeePriceCache.toBase58(),
+      );
+      await provider.sendAndConfirm(await initFeePriceCache.transaction(), [
+        propellerEngineKeypair,
+      ]);
+      propellerFeePriceCache = expectedFeePriceCache;
+
+      await propellerProgram.methods
+        .refreshFeePriceCache()
+        .accounts({
+          propeller,
+          feePriceCache: propellerFeePriceCache,
+          aggregator,
+          fallbackOracle: web3.SystemProgram.programId,
+          solUsdPool: web3.SystemProgram.programId,
+          marginalPricePool,
+          marginalPricePoolToken0Account,
+          marginalPricePoolToken1Account,
+          marginalPricePoolLpMint,
+          twoPoolProgram: twoPoolProgram.programId,
+        })
+        .rpc();
+
+      const feePriceCacheAccount =
+        await propellerProgram.account.feePriceCache.fetch(
+          propellerFeePriceCache,
+        );
+      expect(feePriceCacheAccount.bump).toEqual(bump);
+      expect(feePriceCacheAccount.propeller.toBase58()).toEqual(
+        propeller.toBase58(),
+      );
+      expect(
+        feePriceCacheAccount.swimUsdAtomicPerLamport.mantissa.gt(new BN(0)),
+      ).toBeTruthy();
+      expect(feePriceCacheAccount.lastUpdateTs.gt(new BN(0))).toBeTruthy();
+    });
+
     const propellerEnabled = true;
     describe("without gas kickstart", () => {
       const gasKickstart = false;
@@ -938,6 +996,7 @@ describe("propeller", () => {
                     claim: wormholeClaim,
                     swimPayloadMessage: expectedSwimPayloadMessage,
                     endpoint: ethEndpointAccount,
+                    sourceChainMap: ethTargetChainMap,
                     to: propellerRedeemerEscrowAccount,
                     redeemer: propellerRedeemer,
                     feeRecipient: propellerFeeVault,
@@ -953,7 +1012,7 @@ describe("propeller", () => {
                     tokenBridge,
                   },
                   feeTracker: propellerEngineFeeTracker,
-                  aggregator,
+                  feePriceCache: propellerFeePriceCache,
                   // marginalPricePool: {
                   //   pool: marginalPricePool,
                   //   poolToken0Account: marginalPricePoolToken0Account,
@@ -961,13 +1020,6 @@ describe("propeller", () => {
                   //   lpMint: marginalPricePoolLpMint,
                   // },
                   // twoPoolProgram: twoPoolProgram.programId,
-                  marginalPricePool: marginalPricePool,
-                  marginalPricePoolToken0Account:
-                    marginalPricePoolToken0Account,
-                  marginalPricePoolToken1Account:
-                    marginalPricePoolToken1Account,
-                  marginalPricePoolLpMint: marginalPricePoolLpMint,
-                  twoPoolProgram: twoPoolProgram.programId,
                   memo: MEMO_PROGRAM_ID,
                 })
                 .preInstructions([setComputeUnitLimitIx])
@@ -1222,11 +1274,7 @@ describe("propeller", () => {
                 systemProgram: web3.SystemProgram.programId,
                 tokenProgram: splToken.programId,
                 memo: MEMO_PROGRAM_ID,
-                aggregator,
-                marginalPricePool: marginalPricePool,
-                marginalPricePoolToken0Account: marginalPricePoolToken0Account,
-                marginalPricePoolToken1Account: marginalPricePoolToken1Account,
-                marginalPricePoolLpMint: marginalPricePoolLpMint,
+                feePriceCache: propellerFeePriceCache,
                 twoPoolProgram: twoPoolProgram.programId,
               })
               .preInstructions([setComputeUnitLimitIx])
@@ -1340,13 +1388,7 @@ describe("propeller", () => {
                   processSwimPayload: processSwimPayloadPubkeys,
                   feeVault: propellerFeeVault,
                   feeTracker: propellerEngineFeeTracker,
-                  aggregator,
-                  marginalPricePool: marginalPricePool,
-                  marginalPricePoolToken0Account:
-                    marginalPricePoolToken0Account,
-                  marginalPricePoolToken1Account:
-                    marginalPricePoolToken1Account,
-                  marginalPricePoolLpMint: marginalPricePoolLpMint,
+                  feePriceCache: propellerFeePriceCache,
                   owner,
                   memo: MEMO_PROGRAM_ID,
                 })
@@ -1631,6 +1673,7 @@ describe("propeller", () => {
                     claim: wormholeClaim,
                     swimPayloadMessage: expectedSwimPayloadMessage,
                     endpoint: ethEndpointAccount,
+                    sourceChainMap: ethTargetChainMap,
                     to: propellerRedeemerEscrowAccount,
                     redeemer: propellerRedeemer,
                     feeRecipient: propellerFeeVault,
@@ -1647,7 +1690,7 @@ describe("propeller", () => {
                     tokenBridge,
                   },
                   feeTracker: propellerEngineFeeTracker,
-                  aggregator,
+                  feePriceCache: propellerFeePriceCache,
                   // marginalPricePool: {
                   //   pool: marginalPricePool,
                   //   poolToken0Account: marginalPricePoolToken0Account,
@@ -1655,13 +1698,6 @@ describe("propeller", () => {
                   //   lpMint: marginalPricePoolLpMint,
                   // },
                   // twoPoolProgram: twoPoolProgram.programId,
-                  marginalPricePool: marginalPricePool,
-                  marginalPricePoolToken0Account:
-                    marginalPricePoolToken0Account,
-                  marginalPricePoolToken1Account:
-                    marginalPricePoolToken1Account,
-                  marginalPricePoolLpMint: marginalPricePoolLpMint,
-                  twoPoolProgram: twoPoolProgram.programId,
                   memo: MEMO_PROGRAM_ID,
                 })
                 .preInstructions([setComputeUnitLimitIx])
@@ -1917,11 +1953,7 @@ describe("propeller", () => {
                 systemProgram: web3.SystemProgram.programId,
                 tokenProgram: splToken.programId,
                 memo: MEMO_PROGRAM_ID,
-                aggregator,
-                marginalPricePool: marginalPricePool,
-                marginalPricePoolToken0Account: marginalPricePoolToken0Account,
-                marginalPricePoolToken1Account: marginalPricePoolToken1Account,
-                marginalPricePoolLpMint: marginalPricePoolLpMint,
+                feePriceCache: propellerFeePriceCache,
                 twoPoolProgram: twoPoolProgram.programId,
               })
               .preInstructions([setComputeUnitLimitIx])
@@ -2109,13 +2141,7 @@ describe("propeller", () => {
                   processSwimPayload: processSwimPayloadPubkeys,
                   feeVault: propellerFeeVault,
                   feeTracker: propellerEngineFeeTracker,
-                  aggregator,
-                  marginalPricePool: marginalPricePool,
-                  marginalPricePoolToken0Account:
-                    marginalPricePoolToken0Account,
-                  marginalPricePoolToken1Account:
-                    marginalPricePoolToken1Account,
-                  marginalPricePoolLpMint: marginalPricePoolLpMint,
+                  feePriceCache: propellerFeePriceCache,
                   owner,
                   memo: MEMO_PROGRAM_ID,
                 })
@@ -2445,6 +2471,7 @@ describe("propeller", () => {
                   claim: wormholeClaim,
                   swimPayloadMessage: expectedSwimPayloadMessage,
                   endpoint: ethEndpointAccount,
+                  sourceChainMap: ethTargetChainMap,
                   to: propellerRedeemerEscrowAccount,
                   redeemer: propellerRedeemer,
                   feeRecipient: propellerFeeVault,
@@ -2460,13 +2487,8 @@ describe("propeller", () => {
                   tokenBridge,
                 },
                 feeTracker: propellerEngineFeeTracker,
-                aggregator,
+                feePriceCache: propellerFeePriceCache,
 
-                marginalPricePool: marginalPricePool,
-                marginalPricePoolToken0Account: marginalPricePoolToken0Account,
-                marginalPricePoolToken1Account: marginalPricePoolToken1Account,
-                marginalPricePoolLpMint: marginalPricePoolLpMint,
-                twoPoolProgram: twoPoolProgram.programId,
                 memo: MEMO_PROGRAM_ID,
               })
               .preInstructions([setComputeUnitLimitIx])
@@ -2691,12 +2713,7 @@ describe("propeller", () => {
               systemProgram: web3.SystemProgram.programId,
               tokenProgram: splToken.programId,
               memo: MEMO_PROGRAM_ID,
-              aggregator,
-              marginalPricePool: marginalPricePool,
-              marginalPricePoolToken0Account: marginalPricePoolToken0Account,
-              marginalPricePoolToken1Account: marginalPricePoolToken1Account,
-              marginalPricePoolLpMint: marginalPricePoolLpMint,
-              twoPoolProgram: twoPoolProgram.programId,
+              feePriceCache: propellerFeePriceCache,
               rent: web3.SYSVAR_RENT_PUBKEY,
             })
             .preInstructions([setComputeUnitLimitIx])
@@ -2772,15 +2789,10 @@ describe("propeller", () => {
                 userSwimUsdAta: ownerSwimUsdAta,
                 tokenProgram: splToken.programId,
                 memo: MEMO_PROGRAM_ID,
-                twoPoolProgram: twoPoolProgram.programId,
                 systemProgram: web3.SystemProgram.programId,
                 feeVault: propellerFeeVault,
                 feeTracker: propellerEngineFeeTracker,
-                aggregator,
-                marginalPricePool: marginalPricePool,
-                marginalPricePoolToken0Account: marginalPricePoolToken0Account,
-                marginalPricePoolToken1Account: marginalPricePoolToken1Account,
-                marginalPricePoolLpMint: marginalPricePoolLpMint,
+                feePriceCache: propellerFeePriceCache,
                 owner,
               })
               .preInstructions([setComputeUnitLimitIx])
@@ -2935,6 +2947,250 @@ describe("propeller", () => {
 
         //TODO: add min_output_amount test cases
       });
+
+      describe("for expired swim payload to a swim receiver that reverts", () => {
+        let wormholeClaim: web3.PublicKey;
+        let swimPayloadMessage: web3.PublicKey;
+        let invalidTokenIdMapAddr: web3.PublicKey;
+        let receiverAuthoritySwimUsdAta: web3.PublicKey;
+        // the token program fails on the unknown `swim_receive` ix data
+        const receiver = TOKEN_PROGRAM_ID;
+        const [receiverAuthority] = PublicKey.findProgramAddressSync(
+          [Buffer.from("swim_receiver")],
+          receiver,
+        );
+        const [swimReceiverCaller] = PublicKey.findProgramAddressSync(
+          [Buffer.from("swim_receiver_caller")],
+          propellerProgram.programId,
+        );
+        const swimReceiverAccounts = [
+          { pubkey: receiver, isSigner: false, isWritable: false },
+          { pubkey: swimReceiverCaller, isSigner: false, isWritable: false },
+        ];
+        const targetTokenId = 98;
+        const memoBuffer = createMemoId();
+
+        const processSwimPayloadFallbackAccounts = async () => {
+          const swimPayloadMessageAccount =
+            await propellerProgram.account.swimPayloadMessage.fetch(
+              swimPayloadMessage,
+            );
+          const [swimClaim] = await getSwimClaimPda(
+            wormholeClaim,
+            propellerProgram.programId,
+          );
+          return {
+            propeller,
+            payer: propellerEngineKeypair.publicKey,
+            claim: wormholeClaim,
+            swimClaim,
+            swimPayloadMessage,
+            swimPayloadMessagePayer:
+              swimPayloadMessageAccount.swimPayloadMessagePayer,
+            redeemer: propellerRedeemer,
+            redeemerEscrow: propellerRedeemerEscrowAccount,
+            tokenIdMap: invalidTokenIdMapAddr,
+            userSwimUsdAta: receiverAuthoritySwimUsdAta,
+            tokenProgram: splToken.programId,
+            memo: MEMO_PROGRAM_ID,
+            systemProgram: web3.SystemProgram.programId,
+            feeVault: propellerFeeVault,
+            feeTracker: propellerEngineFeeTracker,
+            feePriceCache: propellerFeePriceCache,
+            owner: receiverAuthority,
+          };
+        };
+
+        it("mocks token transfer with payload then verifySig & postVaa then executes CompleteWithPayload", async () => {
+          const swimPayload = {
+            version: 3,
+            owner: receiver.toBuffer(),
+            propellerEnabled,
+            gasKickstart,
+            maxFee: new BN(1000000000),
+            targetTokenId,
+            receiverData: Buffer.from([1, 2, 3]),
+            memo: memoBuffer,
+          };
+          const amount = parseUnits("100", mintDecimal);
+          const nonce = createNonce().readUInt32LE(0);
+          const tokenTransferWithPayloadSignedVaa = signAndEncodeVaa(
+            0,
+            nonce,
+            CHAIN_ID_ETH as number,
+            ethTokenBridge,
+            BigInt(++ethTokenBridgeSequence),
+            encodeTokenTransferWithPayload(
+              amount.toString(),
+              swimUsdKeypair.publicKey.toBuffer(),
+              CHAIN_ID_SOLANA,
+              propellerProgram.programId,
+              ethRoutingContract,
+              encodeSwimPayload(swimPayload),
+            ),
+          );
+          await postVaaSolanaWithRetry(
+            connection,
+            // eslint-disable-next-line @typescript-eslint/require-await
+            async (tx) => {
+              tx.partialSign(userKeypair);
+              return tx;
+            },
+            WORMHOLE_CORE_BRIDGE.toBase58(),
+            userKeypair.publicKey.toBase58(),
+            tokenTransferWithPayloadSignedVaa,
+            10,
+          );
+          const [wormholeMessage] = await deriveMessagePda(
+            tokenTransferWithPayloadSignedVaa,
+            WORMHOLE_CORE_BRIDGE,
+          );
+          wormholeClaim = await getClaimAddressSolana(
+            WORMHOLE_TOKEN_BRIDGE.toBase58(),
+            tokenTransferWithPayloadSignedVaa,
+          );
+          [swimPayloadMessage] = await getSwimPayloadMessagePda(
+            wormholeClaim,
+            propellerProgram.programId,
+          );
+          [invalidTokenIdMapAddr] = await getTargetTokenIdMapAddr(
+            propeller,
+            targetTokenId,
+            propellerEnginePropellerProgram.programId,
+          );
+
+          await propellerEnginePropellerProgram.methods
+            .propellerCompleteNativeWithPayload()
+            .accounts({
+              completeNativeWithPayload: {
+                propeller,
+                payer: propellerEngineKeypair.publicKey,
+                tokenBridgeConfig,
+                message: wormholeMessage,
+                claim: wormholeClaim,
+                swimPayloadMessage,
+                endpoint: ethEndpointAccount,
+                sourceChainMap: ethTargetChainMap,
+                to: propellerRedeemerEscrowAccount,
+                redeemer: propellerRedeemer,
+                feeRecipient: propellerFeeVault,
+                custody: custody,
+                swimUsdMint: swimUsdMint,
+                custodySigner,
+                rent: web3.SYSVAR_RENT_PUBKEY,
+                systemProgram: web3.SystemProgram.programId,
+                wormhole,
+                tokenProgram: splToken.programId,
+                tokenBridge,
+              },
+              feeTracker: propellerEngineFeeTracker,
+              feePriceCache: propellerFeePriceCache,
+              memo: MEMO_PROGRAM_ID,
+            })
+            .preInstructions([setComputeUnitLimitIx])
+            .signers([propellerEngineKeypair])
+            .rpc();
+
+          const swimPayloadMessageAccount =
+            await propellerProgram.account.swimPayloadMessage.fetch(
+              swimPayloadMessage,
+            );
+          expect(new PublicKey(swimPayloadMessageAccount.owner)).toEqual(
+            receiver,
+          );
+          expect(swimPayloadMessageAccount.receiverData).toEqual(
+            swimPayload.receiverData,
+          );
+          receiverAuthoritySwimUsdAta = (
+            await getOrCreateAssociatedTokenAccount(
+              provider.connection,
+              userKeypair,
+              swimUsdMint,
+              receiverAuthority,
+              true,
+            )
+          ).address;
+        });
+
+        it("fails the swimUSD fallback because the swim receiver reverts", async () => {
+          const userTransferAuthority = web3.Keypair.generate();
+          await expect(
+            propellerEnginePropellerProgram.methods
+              .propellerProcessSwimPayloadFallback()
+              .accounts({
+                ...(await processSwimPayloadFallbackAccounts()),
+                userTransferAuthority: userTransferAuthority.publicKey,
+              })
+              .remainingAccounts(swimReceiverAccounts)
+              .preInstructions([setComputeUnitLimitIx])
+              .signers([userTransferAuthority, propellerEngineKeypair])
+              .rpc(),
+          ).rejects.toThrow();
+        });
+
+        it("processes the expired swim payload without invoking the swim receiver", async () => {
+          const userTransferAuthority = web3.Keypair.generate();
+          const updateSwimPayloadExpiry = (swimPayloadExpiry: BN) =>
+            propellerProgram.methods
+              .updateSwimPayloadExpiry(swimPayloadExpiry)
+              .accounts({
+                commonAdmin: {
+                  propeller,
+                  admin: propellerAdmin.publicKey,
+                },
+              })
+              .signers([propellerAdmin])
+              .rpc();
+          await updateSwimPayloadExpiry(new BN(1));
+          await new Promise((resolve) => setTimeout(resolve, 3000));
+
+          const swimPayloadMessageAccount =
+            await propellerProgram.account.swimPayloadMessage.fetch(
+              swimPayloadMessage,
+            );
+          const receiverAuthoritySwimUsdAtaBalanceBefore = (
+            await splToken.account.token.fetch(receiverAuthoritySwimUsdAta)
+          ).amount;
+          const accounts = await processSwimPayloadFallbackAccounts();
+          try {
+            await propellerEnginePropellerProgram.methods
+              .propellerProcessExpiredSwimPayload()
+              .accounts({
+                ...accounts,
+                userTransferAuthority: userTransferAuthority.publicKey,
+              })
+              .remainingAccounts(swimReceiverAccounts)
+              .preInstructions([setComputeUnitLimitIx])
+              .signers([userTransferAuthority, propellerEngineKeypair])
+              .rpc();
+          } finally {
+            await updateSwimPayloadExpiry(new BN(7 * 24 * 60 * 60));
+          }
+
+          const receiverAuthoritySwimUsdAtaBalanceAfter = (
+            await splToken.account.token.fetch(receiverAuthoritySwimUsdAta)
+          ).amount;
+          expect(
+            receiverAuthoritySwimUsdAtaBalanceAfter.gt(
+              receiverAuthoritySwimUsdAtaBalanceBefore,
+            ),
+          ).toBeTruthy();
+          expect(
+            receiverAuthoritySwimUsdAtaBalanceAfter.lte(
+              receiverAuthoritySwimUsdAtaBalanceBefore.add(
+                swimPayloadMessageAccount.transferAmount,
+              ),
+            ),
+          ).toBeTruthy();
+          const [swimClaim] = await getSwimClaimPda(
+            wormholeClaim,
+            propellerProgram.programId,
+          );
+          const swimClaimAccount =
+            await propellerProgram.account.swimClaim.fetch(swimClaim);
+          expect(swimClaimAccount.claimed).toBeTruthy();
+        });
+      });
       // eslint-disable-next-line jest/no-commented-out-tests
       // describe.skip("for swimUSD as output token", () => {
       //   let wormholeClaim: web3.PublicKey;
@@ -3907,6 +4163,7 @@ describe("propeller", () => {
                     claim: wormholeClaim,
                     swimPayloadMessage: expectedSwimPayloadMessage,
                     endpoint: ethEndpointAccount,
+                    sourceChainMap: ethTargetChainMap,
                     to: propellerRedeemerEscrowAccount,
                     redeemer: propellerRedeemer,
                     feeRecipient: propellerFeeVault,
@@ -3922,7 +4179,7 @@ describe("propeller", () => {
                     tokenBridge,
                   },
                   feeTracker: propellerEngineFeeTracker,
-                  aggregator,
+                  feePriceCache: propellerFeePriceCache,
                   // marginalPricePool: {
                   //   pool: marginalPricePool,
                   //   poolToken0Account: marginalPricePoolToken0Account,
@@ -3930,13 +4187,6 @@ describe("propeller", () => {
                   //   lpMint: marginalPricePoolLpMint,
                   // },
                   // twoPoolProgram: twoPoolProgram.programId,
-                  marginalPricePool: marginalPricePool,
-                  marginalPricePoolToken0Account:
-                    marginalPricePoolToken0Account,
-                  marginalPricePoolToken1Account:
-                    marginalPricePoolToken1Account,
-                  marginalPricePoolLpMint: marginalPricePoolLpMint,
-                  twoPoolProgram: twoPoolProgram.programId,
                   memo: MEMO_PROGRAM_ID,
                 })
                 .preInstructions([setComputeUnitLimitIx])
@@ -4194,11 +4444,7 @@ describe("propeller", () => {
                 systemProgram: web3.SystemProgram.programId,
                 tokenProgram: splToken.programId,
                 memo: MEMO_PROGRAM_ID,
-                aggregator,
-                marginalPricePool: marginalPricePool,
-                marginalPricePoolToken0Account: marginalPricePoolToken0Account,
-                marginalPricePoolToken1Account: marginalPricePoolToken1Account,
-                marginalPricePoolLpMint: marginalPricePoolLpMint,
+                feePriceCache: propellerFeePriceCache,
                 twoPoolProgram: twoPoolProgram.programId,
               })
               .preInstructions([setComputeUnitLimitIx])
@@ -4328,13 +4574,7 @@ describe("propeller", () => {
                   processSwimPayload: processSwimPayloadPubkeys,
                   feeVault: propellerFeeVault,
                   feeTracker: propellerEngineFeeTracker,
-                  aggregator,
-                  marginalPricePool: marginalPricePool,
-                  marginalPricePoolToken0Account:
-                    marginalPricePoolToken0Account,
-                  marginalPricePoolToken1Account:
-                    marginalPricePoolToken1Account,
-                  marginalPricePoolLpMint: marginalPricePoolLpMint,
+                  feePriceCache: propellerFeePriceCache,
                   owner,
                   memo: MEMO_PROGRAM_ID,
                 })
@@ -4714,6 +4954,11 @@ describe("propeller", () => {
             marginalPricePoolToken1Account: ${marginalPricePoolToken1Account.toBase58()}
           `);
 
+          const refreshFeePriceCacheTxn = txns[txnIdx++];
+          await propellerEngineAnchorProvider.sendAndConfirm(
+            refreshFeePriceCacheTxn,
+          );
+
           const providerBalanceBefore = await provider.connection.getBalance(
             provider.wallet.publicKey,
           );
@@ -5268,6 +5513,11 @@ describe("propeller", () => {
             marginalPricePoolToken1Account: ${marginalPricePoolToken1Account.toBase58()}
           `);
 
+          const refreshFeePriceCacheTxn = txns[txnIdx++];
+          await propellerEngineAnchorProvider.sendAndConfirm(
+            refreshFeePriceCacheTxn,
+          );
+
           const providerBalanceBefore = await provider.connection.getBalance(
             provider.wallet.publicKey,
           );
@@ -5811,6 +6061,7 @@ describe("propeller", () => {
                     claim: wormholeClaim,
                     swimPayloadMessage: expectedSwimPayloadMessage,
                     endpoint: ethEndpointAccount,
+                    sourceChainMap: ethTargetChainMap,
                     to: propellerRedeemerEscrowAccount,
                     redeemer: propellerRedeemer,
                     feeRecipient: propellerFeeVault,
@@ -5826,15 +6077,8 @@ describe("propeller", () => {
                     tokenBridge,
                   },
                   feeTracker: propellerEngineFeeTracker,
-                  aggregator,
-
-                  marginalPricePool: marginalPricePool,
-                  marginalPricePoolToken0Account:
-                    marginalPricePoolToken0Account,
-                  marginalPricePoolToken1Account:
-                    marginalPricePoolToken1Account,
-                  marginalPricePoolLpMint: marginalPricePoolLpMint,
-                  twoPoolProgram: twoPoolProgram.programId,
+                  feePriceCache: propellerFeePriceCache,
+
                   memo: MEMO_PROGRAM_ID,
                 })
                 .preInstructions([setComputeUnitLimitIx])
@@ -6082,13 +6326,7 @@ describe("propeller", () => {
                   systemProgram: web3.SystemProgram.programId,
                   tokenProgram: splToken.programId,
                   memo: MEMO_PROGRAM_ID,
-                  aggregator,
-                  marginalPricePool: marginalPricePool,
-                  marginalPricePoolToken0Account:
-                    marginalPricePoolToken0Account,
-                  marginalPricePoolToken1Account:
-                    marginalPricePoolToken1Account,
-                  marginalPricePoolLpMint: marginalPricePoolLpMint,
+                  feePriceCache: propellerFeePriceCache,
                   twoPoolProgram: twoPoolProgram.programId,
                 })
                 .preInstructions([setComputeUnitLimitIx])
@@ -6221,13 +6459,7 @@ describe("propeller", () => {
                   processSwimPayload: processSwimPayloadPubkeys,
                   feeVault: propellerFeeVault,
                   feeTracker: propellerEngineFeeTracker,
-                  aggregator,
-                  marginalPricePool: marginalPricePool,
-                  marginalPricePoolToken0Account:
-                    marginalPricePoolToken0Account,
-                  marginalPricePoolToken1Account:
-                    marginalPricePoolToken1Account,
-                  marginalPricePoolLpMint: marginalPricePoolLpMint,
+                  feePriceCache: propellerFeePriceCache,
                   owner,
                   memo: MEMO_PROGRAM_ID,
                 })
@@ -6921,11 +7153,18 @@ const initializePropeller = async () => {
     completeWithPayloadFee,
     initAtaFee,
     processSwimPayloadFee,
+    swimPayloadExpiry: new BN(7 * 24 * 60 * 60),
+    // the fee price cache is only refreshed once before the engine tests
+    feePriceCacheMaxAge: new BN(24 * 60 * 60),
     // propellerMinTransferAmount,
     // propellerEthMinTransferAmount,
     marginalPricePool,
     marginalPricePoolTokenIndex,
     marginalPricePoolTokenMint,
+    marginalPriceTwapWindow: 0,
+    // the SOL/USD feed is loaded from a fixture so its latest round never gets updated
+    maxStaleness: new BN("9223372036854775807"),
+    maxConfidenceInterval: { mantissa: new BN(10).pow(new BN(12)), scale: 0 },
     // evmRoutingContractAddress: ethRoutingContract,
     // evmRoutingContractAddress: ethRoutingContractEthUint8Arr
   };
@@ -6935,6 +7174,7 @@ const initializePropeller = async () => {
       propellerRedeemerEscrow: propellerRedeemerEscrowAddr,
       propellerFeeVault,
       admin: propellerAdmin.publicKey,
+      pauseKey: propellerAdmin.publicKey,
       swimUsdMint: swimUsdMint,
       payer: userKeypair.publicKey,
       tokenProgram: TOKEN_PROGRAM_ID,
@@ -7050,6 +7290,7 @@ const createTokenIdMaps = async () => {
           tokenIdMap.poolTokenIndex,
           tokenIdMap.poolTokenMint,
           tokenIdMap.poolIx,
+          [],
         )
         .accounts({
           propeller,
@@ -7140,6 +7381,11 @@ const createTargetChainMaps = async () => {
       );
     },
   );
+  [ethTargetChainMap] = await getTargetChainIdMapAddr(
+    propeller,
+    CHAIN_ID_ETH,
+    propellerProgram.programId,
+  );
 };
 
 const seedWormholeCustody = async () => {
@@ -83,6 +83,7 @@ import {
   getPropellerSenderPda,
   getSwimClaimPda,
   getSwimPayloadMessagePda,
+  getTargetChainIdMapAddr,
   parseTokenTransferWithSwimPayloadPostedMessage,
 } from "./propellerUtils";
 import {
@@ -245,6 +246,7 @@ let wormholeSequence: web3.PublicKey;
 let authoritySigner: web3.PublicKey;
 let tokenBridgeConfig: web3.PublicKey;
 let custodySigner: web3.PublicKey;
+let ethTargetChainMap: web3.PublicKey;
 const aggregator: web3.PublicKey = DEFAULT_SOL_USD_FEED;
 
 describe("propeller", () => {
@@ -586,11 +588,17 @@ describe("propeller", () => {
       postVaaFee,
       completeWithPayloadFee,
       processSwimPayloadFee,
+      swimPayloadExpiry: new BN(7 * 24 * 60 * 60),
+      feePriceCacheMaxAge: new BN(60),
       // propellerMinTransferAmount,
       // propellerEthMinTransferAmount,
       marginalPricePool,
       marginalPricePoolTokenIndex,
       marginalPricePoolTokenMint,
+      marginalPriceTwapWindow: 0,
+      // the SOL/USD feed is loaded from a fixture so its latest round never gets updated
+      maxStaleness: new BN("9223372036854775807"),
+      maxConfidenceInterval: { mantissa: new BN(10).pow(new BN(12)), scale: 0 },
       // evmRoutingContractAddress: ethRoutingContract,
       // evmRoutingContractAddress: ethRoutingContractEthUint8Arr
     };
@@ -600,6 +608,7 @@ describe("propeller", () => {
         propellerRedeemerEscrow: propellerRedeemerEscrowAddr,
         propellerFeeVault,
         admin: propellerAdmin.publicKey,
+        pauseKey: propellerAdmin.publicKey,
         swimUsdMint: swimUsdMint,
         payer: payer.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -743,6 +752,7 @@ describe("propeller", () => {
             tokenIdMap.poolTokenIndex,
             tokenIdMap.poolTokenMint,
             tokenIdMap.poolIx,
+            [],
           )
           .accounts({
             propeller,
@@ -850,6 +860,115 @@ describe("propeller", () => {
         );
       },
     );
+    [ethTargetChainMap] = await getTargetChainIdMapAddr(
+      propeller,
+      CHAIN_ID_ETH,
+      propellerProgram.programId,
+    );
+  });
+
+  it("Updates the global & target chain rate limits", async () => {
+    const inbound = {
+      maxAmount: new BN(1_000_000_000_000),
+      window: new BN(3600),
+    };
+    const outbound = {
+      maxAmount: new BN(500_000_000_000),
+      window: new BN(3600),
+    };
+    await propellerProgram.methods
+      .updateRateLimits({
+        inbound,
+        outbound,
+        rateLimitAction: { queue: {} },
+        largeTransferThreshold: new BN(0),
+        largeTransferDelay: new BN(0),
+      })
+      .accounts({
+        commonAdmin: {
+          propeller,
+          admin: propellerAdmin.publicKey,
+        },
+      })
+      .signers([propellerAdmin])
+      .rpc();
+    await propellerProgram.methods
+      .updateTargetChainRateLimits(inbound, outbound)
+      .accounts({
+        commonAdmin: {
+          propeller,
+          admin: propellerAdmin.publicKey,
+        },
+        targetChainMap: ethTargetChainMap,
+      })
+      .signers([propellerAdmin])
+      .rpc();
+
+    const propellerData = await propellerProgram.account.propeller.fetch(
+      propeller,
+    );
+    expect(
+      propellerData.inboundRateLimit.maxAmount.eq(inbound.maxAmount),
+    ).toBe(true);
+    expect(
+      propellerData.outboundRateLimit.maxAmount.eq(outbound.maxAmount),
+    ).toBe(true);
+    expect(propellerData.rateLimitAction).toEqual({ queue: {} });
+    const ethTargetChainMapData =
+      await propellerProgram.account.targetChainMap.fetch(ethTargetChainMap);
+    expect(
+      ethTargetChainMapData.inboundRateLimit.window.eq(inbound.window),
+    ).toBe(true);
+    expect(
+      ethTargetChainMapData.outboundRateLimit.maxAmount.eq(outbound.maxAmount),
+    ).toBe(true);
+
+    await expect(
+      propellerProgram.methods
+        .updateTargetChainRateLimits(
+          { maxAmount: new BN(1), window: new BN(0) },
+          outbound,
+        )
+        .accounts({
+          commonAdmin: {
+            propeller,
+            admin: propellerAdmin.publicKey,
+          },
+          targetChainMap: ethTargetChainMap,
+        })
+        .signers([propellerAdmin])
+        .rpc(),
+    ).rejects.toThrow("Invalid rate limit config");
+
+    // disable the limits again for the rest of the tests
+    const disabled = { maxAmount: new BN(0), window: new BN(0) };
+    await propellerProgram.methods
+      .updateRateLimits({
+        inbound: disabled,
+        outbound: disabled,
+        rateLimitAction: { reject: {} },
+        largeTransferThreshold: new BN(0),
+        largeTransferDelay: new BN(0),
+      })
+      .accounts({
+        commonAdmin: {
+          propeller,
+          admin: propellerAdmin.publicKey,
+        },
+      })
+      .signers([propellerAdmin])
+      .rpc();
+    await propellerProgram.methods
+      .updateTargetChainRateLimits(disabled, disabled)
+      .accounts({
+        commonAdmin: {
+          propeller,
+          admin: propellerAdmin.publicKey,
+        },
+        targetChainMap: ethTargetChainMap,
+      })
+      .signers([propellerAdmin])
+      .rpc();
   });
 
   describe("Propeller Pool Ixs", () => {
@@ -1728,6 +1847,7 @@ describe("propeller", () => {
       const wormholeMessage = web3.Keypair.generate();
       const gasKickstart = false;
       const maxFee = new BN(100_000);
+      const minOutputAmount = new BN(1_000);
       const nonceBefore = (
         await propellerProgram.account.propeller.fetch(propeller)
       ).nonce;
@@ -1739,7 +1859,10 @@ describe("propeller", () => {
           gasKickstart,
           maxFee,
           evmTargetTokenId,
+          minOutputAmount,
           Buffer.from(memo, "hex"),
+          null,
+          null,
         )
         .accounts({
           propeller,
@@ -1855,6 +1978,7 @@ describe("propeller", () => {
       expect(swimPayload.gasKickstart).toEqual(gasKickstart);
       expect(swimPayload.maxFee).toEqual(maxFee);
       expect(swimPayload.targetTokenId).toEqual(evmTargetTokenId);
+      expect(swimPayload.minOutputAmount).toEqual(minOutputAmount);
       expect(swimPayload.memo.toString("hex")).toEqual(memo.toString("hex"));
       await checkTxnLogsForMemo(
         propellerTransferNativeTxnSig,
@@ -1901,6 +2025,7 @@ describe("propeller", () => {
       const wormholeMessage = web3.Keypair.generate();
       const gasKickstart = false;
       const maxFee = new BN(100_000);
+      const minOutputAmount = new BN(1_000);
       const nonceBefore = (
         await propellerProgram.account.propeller.fetch(propeller)
       ).nonce;
@@ -1912,6 +2037,9 @@ describe("propeller", () => {
           gasKickstart,
           maxFee,
           evmTargetTokenId,
+          minOutputAmount,
+          null,
+          null,
           null,
         )
         .accounts({
@@ -2068,6 +2196,9 @@ describe("propeller", () => {
             gasKickstart,
             maxFee,
             evmTargetTokenId,
+            new BN(0),
+            null,
+            null,
             null,
           )
           .accounts({
@@ -2218,6 +2349,7 @@ describe("propeller", () => {
               message: wormholeMessage,
               claim: wormholeClaim,
               endpoint: ethEndpointAccount,
+              sourceChainMap: ethTargetChainMap,
               to: propellerRedeemerEscrowAccount,
               redeemer: propellerRedeemer,
               // this is only used in propellerCompleteNativeWithPayload
@@ -2513,7 +2645,162 @@ describe("propeller", () => {
           await checkTxnLogsForMemo(processSwimPayloadTxnSig, memoStr);
         });
 
-        //TODO: add min_output_amount test cases
+      });
+
+      describe("with a min output amount set on the source chain", () => {
+        let wormholeClaim: web3.PublicKey;
+        let swimPayloadMessage: web3.PublicKey;
+
+        const targetTokenId = USDC_TO_TOKEN_NUMBER;
+        const amount = parseUnits("1", mintDecimal);
+        // can never be reached by swapping `amount` swimUSD
+        const payloadMinOutputAmount = new BN(amount.toString()).muln(10);
+
+        it("mocks token transfer with payload then verifySig & postVaa then executes CompleteWithPayload", async () => {
+          const swimPayload = {
+            version: swimPayloadVersion,
+            owner: provider.publicKey.toBuffer(),
+            propellerEnabled,
+            gasKickstart,
+            maxFee: new BN(0),
+            targetTokenId,
+            minOutputAmount: payloadMinOutputAmount,
+          };
+          const nonce = createNonce().readUInt32LE(0);
+          const tokenTransferWithPayloadSignedVaa = signAndEncodeVaa(
+            0,
+            nonce,
+            CHAIN_ID_ETH as number,
+            ethTokenBridge,
+            BigInt(++ethTokenBridgeSequence),
+            encodeTokenTransferWithPayload(
+              amount.toString(),
+              swimUsdKeypair.publicKey.toBuffer(),
+              CHAIN_ID_SOLANA,
+              propellerProgram.programId,
+              ethRoutingContract,
+              encodeSwimPayload(swimPayload),
+            ),
+          );
+          await postVaaSolanaWithRetry(
+            connection,
+            // eslint-disable-next-line @typescript-eslint/require-await
+            async (tx) => {
+              tx.partialSign(payer);
+              return tx;
+            },
+            WORMHOLE_CORE_BRIDGE.toBase58(),
+            payer.publicKey.toBase58(),
+            tokenTransferWithPayloadSignedVaa,
+            10,
+          );
+          const [wormholeMessage] = await deriveMessagePda(
+            tokenTransferWithPayloadSignedVaa,
+            WORMHOLE_CORE_BRIDGE,
+          );
+          const [endpointAccount] = await deriveEndpointPda(
+            CHAIN_ID_ETH,
+            ethTokenBridge,
+            WORMHOLE_TOKEN_BRIDGE,
+          );
+          wormholeClaim = await getClaimAddressSolana(
+            WORMHOLE_TOKEN_BRIDGE.toBase58(),
+            tokenTransferWithPayloadSignedVaa,
+          );
+          [swimPayloadMessage] = await getSwimPayloadMessagePda(
+            wormholeClaim,
+            propellerProgram.programId,
+          );
+
+          const completeNativeWithPayloadTxn = await propellerProgram.methods
+            .completeNativeWithPayload()
+            .accounts({
+              propeller,
+              payer: payer.publicKey,
+              tokenBridgeConfig,
+              message: wormholeMessage,
+              claim: wormholeClaim,
+              endpoint: endpointAccount,
+              sourceChainMap: ethTargetChainMap,
+              to: propellerRedeemerEscrowAccount,
+              redeemer: propellerRedeemer,
+              feeRecipient: userSwimUsdAtaAddr,
+              custody: custody,
+              swimUsdMint: swimUsdMint,
+              custodySigner,
+              rent: web3.SYSVAR_RENT_PUBKEY,
+              systemProgram: web3.SystemProgram.programId,
+              wormhole,
+              tokenProgram: splToken.programId,
+              tokenBridge,
+            })
+            .preInstructions([setComputeUnitLimitIx])
+            .transaction();
+          await provider.sendAndConfirm(completeNativeWithPayloadTxn, [payer], {
+            skipPreflight: true,
+          });
+
+          const swimPayloadMessageAccount =
+            await propellerProgram.account.swimPayloadMessage.fetch(
+              swimPayloadMessage,
+            );
+          expect(
+            swimPayloadMessageAccount.minOutputAmount.eq(
+              payloadMinOutputAmount,
+            ),
+          ).toBeTruthy();
+        });
+
+        it("fails if a third party processes the swim payload with a min output amount of 0", async () => {
+          const thirdParty = web3.Keypair.generate();
+          const userTransferAuthority = web3.Keypair.generate();
+          const propellerRedeemerEscrowBalanceBefore = (
+            await splToken.account.token.fetch(propellerRedeemerEscrowAccount)
+          ).amount;
+          const fundThirdPartyIx = web3.SystemProgram.transfer({
+            fromPubkey: payer.publicKey,
+            toPubkey: thirdParty.publicKey,
+            lamports: web3.LAMPORTS_PER_SOL,
+          });
+
+          await expect(
+            propellerProgram.methods
+              .processSwimPayload(targetTokenId, new BN(0))
+              .accounts({
+                propeller,
+                payer: thirdParty.publicKey,
+                claim: wormholeClaim,
+                swimPayloadMessage,
+                swimPayloadMessagePayer: payer.publicKey,
+                redeemer: propellerRedeemer,
+                redeemerEscrow: propellerRedeemerEscrowAccount,
+                pool: flagshipPool,
+                poolTokenAccount0: poolUsdcAtaAddr,
+                poolTokenAccount1: poolUsdtAtaAddr,
+                lpMint: swimUsdMint,
+                governanceFee: flagshipPoolGovernanceFeeAcct,
+                userTransferAuthority: userTransferAuthority.publicKey,
+                userTokenAccount0: userUsdcAtaAddr,
+                userTokenAccount1: userUsdtAtaAddr,
+                userLpTokenAccount: userSwimUsdAtaAddr,
+                tokenProgram: splToken.programId,
+                twoPoolProgram: twoPoolProgram.programId,
+                systemProgram: web3.SystemProgram.programId,
+              })
+              .preInstructions([setComputeUnitLimitIx, fundThirdPartyIx])
+              .signers([thirdParty, userTransferAuthority])
+              .rpc(),
+          ).rejects.toThrow();
+
+          const propellerRedeemerEscrowBalanceAfter = (
+            await splToken.account.token.fetch(propellerRedeemerEscrowAccount)
+          ).amount;
+          expect(
+            propellerRedeemerEscrowBalanceAfter.eq(
+              propellerRedeemerEscrowBalanceBefore,
+            ),
+          ).toBeTruthy();
+        });
       });
 
       describe("for swimUSD as output token", () => {
@@ -2600,6 +2887,7 @@ describe("propeller", () => {
               message: wormholeMessage,
               claim: wormholeClaim,
               endpoint: endpointAccount,
+              sourceChainMap: ethTargetChainMap,
               to: propellerRedeemerEscrowAccount,
               redeemer: propellerRedeemer,
               // this is only used in propellerCompleteNativeWithPayload
@@ -2987,6 +3275,7 @@ describe("propeller", () => {
               message: wormholeMessage,
               claim: wormholeClaim,
               endpoint: endpointAccount,
+              sourceChainMap: ethTargetChainMap,
               to: propellerRedeemerEscrowAccount,
               redeemer: propellerRedeemer,
               // this is only used in propellerCompleteNativeWithPayload
@@ -3384,6 +3673,7 @@ describe("propeller", () => {
               message: wormholeMessage,
               claim: wormholeClaim,
               endpoint: endpointAccount,
+              sourceChainMap: ethTargetChainMap,
               to: propellerRedeemerEscrowAccount,
               redeemer: propellerRedeemer,
               // this is only used in propellerCompleteNativeWithPayload
@@ -3799,6 +4089,7 @@ describe("propeller", () => {
               message: wormholeMessage,
               claim: wormholeClaim,
               endpoint: endpointAccount,
+              sourceChainMap: ethTargetChainMap,
               to: propellerRedeemerEscrowAccount,
               redeemer: propellerRedeemer,
               // this is only used in propellerCompleteNativeWithPayload
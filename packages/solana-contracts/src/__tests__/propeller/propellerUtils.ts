@@ -209,6 +209,16 @@ export interface ParsedSwimPayload {
   readonly gasKickstart?: boolean;
   readonly maxFee?: BN;
   readonly targetTokenId?: number;
+  // only in version 2+
+  readonly minOutputAmount?: BN;
+  // only in version 3+. if set, owner is a program implementing `swim_receive`
+  readonly receiverData?: Buffer;
+  // only in version 4+. only `relayer` (or the owner) can run the propeller ixs
+  // until `duration` seconds after the VAA timestamp
+  readonly relayerExclusivity?: {
+    readonly relayer: Buffer;
+    readonly duration: number;
+  };
   readonly memo?: Buffer;
 }
 
@@ -216,6 +226,21 @@ export function encodeSwimPayload(swimPayload: ParsedSwimPayload): Buffer {
   let bufferSize = 1 + 32;
   if (swimPayload.propellerEnabled !== undefined) {
     bufferSize += 1 + 1 + 8 + 2;
+    if (swimPayload.version >= 2) {
+      bufferSize += 8;
+    }
+    if (swimPayload.version >= 3) {
+      bufferSize += 1;
+      if (swimPayload.receiverData !== undefined) {
+        bufferSize += 2 + swimPayload.receiverData.length;
+      }
+    }
+    if (swimPayload.version >= 4) {
+      bufferSize += 1;
+      if (swimPayload.relayerExclusivity !== undefined) {
+        bufferSize += 32 + 4;
+      }
+    }
     if (swimPayload.memo !== undefined) {
       bufferSize += 16;
     }
@@ -252,6 +277,32 @@ export function encodeSwimPayload(swimPayload: ParsedSwimPayload): Buffer {
     }
     encoded.writeUint16BE(swimPayload.targetTokenId, offset);
     offset += 2;
+    if (swimPayload.version >= 2) {
+      const minOutputAmount = swimPayload.minOutputAmount ?? new BN(0);
+      encoded.writeBigUint64BE(BigInt(minOutputAmount.toString()), offset);
+      offset += 8;
+    }
+    if (swimPayload.version >= 3) {
+      encoded.writeUint8(Number(swimPayload.receiverData !== undefined), offset);
+      offset++;
+      if (swimPayload.receiverData !== undefined) {
+        encoded.writeUint16BE(swimPayload.receiverData.length, offset);
+        offset += 2;
+        swimPayload.receiverData.copy(encoded, offset);
+        offset += swimPayload.receiverData.length;
+      }
+    }
+    if (swimPayload.version >= 4) {
+      const { relayerExclusivity } = swimPayload;
+      encoded.writeUint8(Number(relayerExclusivity !== undefined), offset);
+      offset++;
+      if (relayerExclusivity !== undefined) {
+        relayerExclusivity.relayer.copy(encoded, offset);
+        offset += 32;
+        encoded.writeUint32BE(relayerExclusivity.duration, offset);
+        offset += 4;
+      }
+    }
     if (swimPayload.memo !== undefined) {
       encoded.write(swimPayload.memo.toString("hex"), offset, "hex");
     }
@@ -279,6 +330,34 @@ export function parseSwimPayload(arr: Buffer): ParsedSwimPayload {
   offset += 8;
   const targetTokenId = arr.readUint16BE(offset);
   offset += 2;
+  let minOutputAmount: BN | undefined;
+  if (version >= 2) {
+    minOutputAmount = new BN(arr.readBigUint64BE(offset).toString());
+    offset += 8;
+  }
+  let receiverData: Buffer | undefined;
+  if (version >= 3) {
+    const hasReceiverData = arr.readUint8(offset) === 1;
+    offset++;
+    if (hasReceiverData) {
+      const receiverDataLen = arr.readUint16BE(offset);
+      offset += 2;
+      receiverData = arr.subarray(offset, offset + receiverDataLen);
+      offset += receiverDataLen;
+    }
+  }
+  let relayerExclusivity: ParsedSwimPayload["relayerExclusivity"];
+  if (version >= 4) {
+    const hasRelayerExclusivity = arr.readUint8(offset) === 1;
+    offset++;
+    if (hasRelayerExclusivity) {
+      relayerExclusivity = {
+        relayer: arr.subarray(offset, offset + 32),
+        duration: arr.readUint32BE(offset + 32),
+      };
+      offset += 32 + 4;
+    }
+  }
   if (offset == arr.length) {
     return {
       version,
@@ -287,6 +366,9 @@ export function parseSwimPayload(arr: Buffer): ParsedSwimPayload {
       gasKickstart,
       maxFee,
       targetTokenId,
+      minOutputAmount,
+      receiverData,
+      relayerExclusivity,
     };
   }
 
@@ -298,6 +380,9 @@ export function parseSwimPayload(arr: Buffer): ParsedSwimPayload {
     gasKickstart,
     maxFee,
     targetTokenId,
+    minOutputAmount,
+    receiverData,
+    relayerExclusivity,
     memo,
   };
   // return {
@@ -506,6 +591,20 @@ export const getPropellerFeeTrackerAddr = async (
   );
 };
 
+export const getPropellerFeePriceCacheAddr = async (
+  propeller: web3.PublicKey,
+  propellerProgramId: web3.PublicKey,
+) => {
+  return await web3.PublicKey.findProgramAddress(
+    [
+      Buffer.from("propeller"),
+      Buffer.from("fee_price_cache"),
+      propeller.toBuffer(),
+    ],
+    propellerProgramId,
+  );
+};
+
 export type WormholeAddresses = {
   readonly wormhole: web3.PublicKey;
   readonly tokenBridge: web3.PublicKey;
@@ -622,6 +721,28 @@ export const generatePropellerEngineTxns = async (
     )}
   `);
 
+  // the relay ixs only read the cached swimUSD per lamport rate so refresh it first
+  const [feePriceCache] = await getPropellerFeePriceCacheAddr(
+    propeller,
+    propellerProgram.programId,
+  );
+  const refreshFeePriceCacheTxn = await propellerProgram.methods
+    .refreshFeePriceCache()
+    .accounts({
+      propeller,
+      feePriceCache,
+      aggregator,
+      fallbackOracle: web3.SystemProgram.programId,
+      solUsdPool: web3.SystemProgram.programId,
+      marginalPricePool: marginalPricePoolInfo.pool,
+      marginalPricePoolToken0Account: marginalPricePoolInfo.token0Account,
+      marginalPricePoolToken1Account: marginalPricePoolInfo.token1Account,
+      marginalPricePoolLpMint: marginalPricePoolInfo.lpMint,
+      twoPoolProgram: twoPoolProgram.programId,
+    })
+    .transaction();
+  txns = [...txns, refreshFeePriceCacheTxn];
+
   const { swimPayload } = parseTokenTransferWithSwimPayloadSignedVaa(
     tokenTransferWithPayloadSignedVaa,
   );
@@ -647,6 +768,11 @@ export const generatePropellerEngineTxns = async (
     propellerData: ${JSON.stringify(propellerData, null, 2)}
   `);
 
+  const [sourceChainMap] = await getTargetChainIdMapAddr(
+    propeller,
+    CHAIN_ID_ETH,
+    propellerProgram.programId,
+  );
   const completePubkeys = await propellerProgram.methods
     .completeNativeWithPayload()
     .accounts({
@@ -657,6 +783,7 @@ export const generatePropellerEngineTxns = async (
       claim: wormholeClaim,
       swimPayloadMessage: swimPayloadMessage,
       endpoint: ethEndpointAccount,
+      sourceChainMap,
       to: propellerRedeemerEscrowAccount,
       redeemer: propellerRedeemer,
       feeRecipient: propellerFeeVault,
@@ -681,18 +808,13 @@ export const generatePropellerEngineTxns = async (
       // @ts-ignore
       completeNativeWithPayload: completePubkeys,
       feeTracker: propellerEngineFeeTracker,
-      aggregator,
+      feePriceCache,
       // marginalPricePool: {
       //   pool: marginalPricePoolInfo.pool,
       //   poolToken0Account: marginalPricePoolInfo.token0Account,
       //   poolToken1Account: marginalPricePoolInfo.token1Account,
       //   lpMint: marginalPricePoolInfo.lpMint,
       // },
-      marginalPricePool: marginalPricePoolInfo.pool,
-      marginalPricePoolToken0Account: marginalPricePoolInfo.token0Account,
-      marginalPricePoolToken1Account: marginalPricePoolInfo.token1Account,
-      marginalPricePoolLpMint: marginalPricePoolInfo.lpMint,
-      twoPoolProgram: twoPoolProgram.programId,
       memo: MEMO_PROGRAM_ID,
     })
     .preInstructions([requestUnitsIx])
@@ -761,12 +883,7 @@ export const generatePropellerEngineTxns = async (
           systemProgram: web3.SystemProgram.programId,
           tokenProgram: splToken.programId,
           memo: MEMO_PROGRAM_ID,
-          aggregator,
-          marginalPricePool: marginalPricePoolInfo.pool,
-          marginalPricePoolToken0Account: marginalPricePoolInfo.token0Account,
-          marginalPricePoolToken1Account: marginalPricePoolInfo.token1Account,
-          marginalPricePoolLpMint: marginalPricePoolInfo.lpMint,
-          twoPoolProgram: twoPoolProgram.programId,
+          feePriceCache,
           rent: web3.SYSVAR_RENT_PUBKEY,
         })
         .transaction();
@@ -790,15 +907,10 @@ export const generatePropellerEngineTxns = async (
           userSwimUsdAta: userSwimUsdAta,
           tokenProgram: splToken.programId,
           memo: MEMO_PROGRAM_ID,
-          twoPoolProgram: twoPoolProgram.programId,
           systemProgram: web3.SystemProgram.programId,
           feeVault: propellerFeeVault,
           feeTracker: propellerEngineFeeTracker,
-          aggregator,
-          marginalPricePool: marginalPricePoolInfo.pool,
-          marginalPricePoolToken0Account: marginalPricePoolInfo.token0Account,
-          marginalPricePoolToken1Account: marginalPricePoolInfo.token1Account,
-          marginalPricePoolLpMint: marginalPricePoolInfo.lpMint,
+          feePriceCache,
           owner,
         })
         .preInstructions([requestUnitsIx])
@@ -867,11 +979,7 @@ export const generatePropellerEngineTxns = async (
           systemProgram: web3.SystemProgram.programId,
           tokenProgram: splToken.programId,
           memo: MEMO_PROGRAM_ID,
-          aggregator,
-          marginalPricePool: marginalPricePoolInfo.pool,
-          marginalPricePoolToken0Account: marginalPricePoolInfo.token0Account,
-          marginalPricePoolToken1Account: marginalPricePoolInfo.token1Account,
-          marginalPricePoolLpMint: marginalPricePoolInfo.lpMint,
+          feePriceCache,
           twoPoolProgram: twoPoolProgram.programId,
         })
         .preInstructions([requestUnitsIx])
@@ -939,11 +1047,7 @@ export const generatePropellerEngineTxns = async (
         processSwimPayload: processSwimPayloadPubkeys,
         feeVault: propellerFeeVault,
         feeTracker: propellerEngineFeeTracker,
-        aggregator,
-        marginalPricePool: marginalPricePoolInfo.pool,
-        marginalPricePoolToken0Account: marginalPricePoolInfo.token0Account,
-        marginalPricePoolToken1Account: marginalPricePoolInfo.token1Account,
-        marginalPricePoolLpMint: marginalPricePoolInfo.lpMint,
+        feePriceCache,
         owner,
         memo: MEMO_PROGRAM_ID,
       })
@@ -243,6 +243,7 @@ describe("utils tests", () => {
         gasKickstart: false,
         maxFee: new BN(100),
         targetTokenId: 1,
+        minOutputAmount: new BN(1_000),
         memo: memoBuffer,
       };
       const encodedSwimPayload = encodeSwimPayload(swimPayload);
@@ -264,8 +265,61 @@ describe("utils tests", () => {
       expect(parsedSwimPayload.targetTokenId).toEqual(
         swimPayload.targetTokenId,
       );
+      expect(
+        parsedSwimPayload.minOutputAmount.eq(swimPayload.minOutputAmount),
+      ).toBeTruthy();
       expect(parsedSwimPayload.memo).toEqual(swimPayload.memo);
     });
+    it("encodes & parses V3 SwimPayload with receiver data", () => {
+      const memoBuffer = Buffer.alloc(16);
+      memoBuffer.write((++memoId).toString().padStart(16, "0"));
+      const swimPayload = {
+        version: 3,
+        owner: provider.publicKey.toBuffer(),
+        propellerEnabled: true,
+        gasKickstart: false,
+        maxFee: new BN(100),
+        targetTokenId: 1,
+        minOutputAmount: new BN(1_000),
+        receiverData: Buffer.from([4, 5, 6]),
+        memo: memoBuffer,
+      };
+      const encodedSwimPayload = encodeSwimPayload(swimPayload);
+      expect(encodedSwimPayload.length).toEqual(1 + 32 + 12 + 8 + 3 + 3 + 16);
+      const parsedSwimPayload = parseSwimPayload(encodedSwimPayload);
+
+      expect(parsedSwimPayload.version).toEqual(3);
+      expect(
+        parsedSwimPayload.minOutputAmount.eq(swimPayload.minOutputAmount),
+      ).toBeTruthy();
+      expect(parsedSwimPayload.receiverData).toEqual(swimPayload.receiverData);
+      expect(parsedSwimPayload.memo).toEqual(swimPayload.memo);
+    });
+    it("encodes & parses V4 SwimPayload with relayer exclusivity", () => {
+      const swimPayload = {
+        version: 4,
+        owner: provider.publicKey.toBuffer(),
+        propellerEnabled: true,
+        gasKickstart: false,
+        maxFee: new BN(100),
+        targetTokenId: 1,
+        minOutputAmount: new BN(1_000),
+        relayerExclusivity: {
+          relayer: web3.Keypair.generate().publicKey.toBuffer(),
+          duration: 120,
+        },
+      };
+      const encodedSwimPayload = encodeSwimPayload(swimPayload);
+      expect(encodedSwimPayload.length).toEqual(1 + 32 + 12 + 8 + 1 + 37);
+      const parsedSwimPayload = parseSwimPayload(encodedSwimPayload);
+
+      expect(parsedSwimPayload.version).toEqual(4);
+      expect(parsedSwimPayload.receiverData).toBeUndefined();
+      expect(parsedSwimPayload.relayerExclusivity).toEqual(
+        swimPayload.relayerExclusivity,
+      );
+      expect(parsedSwimPayload.memo).toBeUndefined();
+    });
     it("encodes & parses SwimPayload with only version & owner", () => {
       const swimPayload = {
         version: swimPayloadVersion,
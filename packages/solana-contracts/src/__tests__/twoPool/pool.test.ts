@@ -840,6 +840,100 @@ describe("TwoPool", () => {
       // }
       expect(true).toBeTruthy();
     });
+
+    it("Can quote a swap exact input", async () => {
+      const exactInputAmounts = [new BN(100_000), new BN(0)];
+      const outputTokenIndex = 1;
+      const minimumOutputAmount = new BN(0);
+      // eslint-disable-next-line @typescript-eslint/no-unsafe-assignment
+      const quote = await twoPoolProgram.methods
+        .quoteSwapExactInput(
+          exactInputAmounts,
+          outputTokenIndex,
+          minimumOutputAmount,
+        )
+        .accounts({
+          poolTokenAccount0: poolUsdcAtaAddr,
+          poolTokenAccount1: poolUsdtAtaAddr,
+          lpMint: swimUsdKeypair.publicKey,
+        })
+        .view();
+      console.info(`quote: ${JSON.stringify(quote)}`);
+      // eslint-disable-next-line @typescript-eslint/no-unsafe-member-access
+      expect(quote.amount.gt(new BN(0))).toBeTruthy();
+      // eslint-disable-next-line @typescript-eslint/no-unsafe-member-access
+      expect(quote.lpFeeAmount.gt(new BN(0))).toBeTruthy();
+      // eslint-disable-next-line @typescript-eslint/no-unsafe-member-access
+      expect(quote.governanceFeeAmount.gt(new BN(0))).toBeTruthy();
+
+      await expect(
+        twoPoolProgram.methods
+          .quoteSwapExactInput(
+            exactInputAmounts,
+            outputTokenIndex,
+            new BN(100_000_000),
+          )
+          .accounts({
+            poolTokenAccount0: poolUsdcAtaAddr,
+            poolTokenAccount1: poolUsdtAtaAddr,
+            lpMint: swimUsdKeypair.publicKey,
+          })
+          .view(),
+      ).rejects.toThrow();
+    });
+
+    it("Can checkpoint the virtual price", async () => {
+      const [virtualPriceCheckpoints] = await web3.PublicKey.findProgramAddress(
+        [
+          Buffer.from("two_pool"),
+          Buffer.from("virtual_price_checkpoints"),
+          flagshipPool.toBuffer(),
+        ],
+        twoPoolProgram.programId,
+      );
+      await twoPoolProgram.methods
+        .initializeVirtualPriceCheckpoints()
+        .accounts({
+          pool: flagshipPool,
+          virtualPriceCheckpoints,
+          payer: provider.publicKey,
+          systemProgram: web3.SystemProgram.programId,
+        })
+        .rpc();
+
+      // eslint-disable-next-line @typescript-eslint/no-unsafe-assignment
+      const virtualPrice = await twoPoolProgram.methods
+        .virtualPrice()
+        .accounts({
+          poolTokenAccount0: poolUsdcAtaAddr,
+          poolTokenAccount1: poolUsdtAtaAddr,
+          lpMint: swimUsdKeypair.publicKey,
+        })
+        .view();
+      console.info(`virtualPrice: ${JSON.stringify(virtualPrice)}`);
+
+      const checkpointVirtualPrice = () =>
+        twoPoolProgram.methods
+          .checkpointVirtualPrice()
+          .accounts({
+            pool: flagshipPool,
+            poolTokenAccount0: poolUsdcAtaAddr,
+            poolTokenAccount1: poolUsdtAtaAddr,
+            lpMint: swimUsdKeypair.publicKey,
+            virtualPriceCheckpoints,
+          })
+          .rpc();
+      await checkpointVirtualPrice();
+      const checkpoints =
+        await twoPoolProgram.account.virtualPriceCheckpoints.fetch(
+          virtualPriceCheckpoints,
+        );
+      const latest = checkpoints.checkpoints[checkpoints.checkpointIndex];
+      expect(latest.ts.gt(new BN(0))).toBeTruthy();
+      expect(latest.virtualPrice.gt(new BN(0))).toBeTruthy();
+      // checkpoints can only be added once every CHECKPOINT_INTERVAL
+      await expect(checkpointVirtualPrice()).rejects.toThrow();
+    });
   });
 
   // prepare + enact tests (with time-transitions) are rust functional tests
@@ -854,7 +948,7 @@ describe("TwoPool", () => {
       // };
       await twoPoolProgram.methods
         // .adjustAmpFactor(params)
-        .adjustAmpFactor(targetTs, targetValue)
+        .adjustAmpFactor(targetTs, targetValue, { linear: {} })
         .accounts({
           commonGovernance: {
             pool: flagshipPool,
@@ -872,9 +966,32 @@ describe("TwoPool", () => {
       expect(ampFactor1.targetValue.value.eq(new BN(400))).toBeTruthy();
     });
 
+    it("Can stop an amp factor adjustment", async () => {
+      await twoPoolProgram.methods
+        .stopAmpFactorAdjustment()
+        .accounts({
+          commonGovernance: {
+            pool: flagshipPool,
+            governance: governanceKeypair.publicKey,
+          },
+        })
+        .signers([governanceKeypair])
+        .rpc();
+
+      const { ampFactor } = await twoPoolProgram.account.twoPool.fetch(
+        flagshipPool,
+      );
+      expect(ampFactor.targetTs.eq(ampFactor.initialTs)).toBeTruthy();
+      expect(
+        // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+        // @ts-ignore
+        ampFactor.targetValue.value.eq(ampFactor.initialValue.value),
+      ).toBeTruthy();
+    });
+
     it("Can pause and unpause the pool", async () => {
       let poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
-      expect(!poolData.isPaused).toBe(true);
+      expect(poolData.pauseFlags).toEqual(0);
       let paused = true;
       console.info(`sending pauseTxn`);
       await twoPoolProgram.methods
@@ -887,7 +1004,19 @@ describe("TwoPool", () => {
         .rpc();
 
       poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
-      expect(poolData.isPaused).toBe(true);
+      expect(poolData.pauseFlags).not.toEqual(0);
+
+      console.info(`fetching marginal prices of paused pool`);
+      // eslint-disable-next-line @typescript-eslint/no-unsafe-assignment
+      const marginalPrices = await twoPoolProgram.methods
+        .marginalPrices()
+        .accounts({
+          poolTokenAccount0: poolUsdcAtaAddr,
+          poolTokenAccount1: poolUsdtAtaAddr,
+          lpMint: swimUsdKeypair.publicKey,
+        })
+        .view();
+      expect(marginalPrices).toHaveLength(2);
 
       const exactInputAmounts = [new BN(1_000), new BN(0)];
       const outputTokenIndex = 1;
@@ -937,6 +1066,54 @@ describe("TwoPool", () => {
       //   console.info(`successfully threw error: ${JSON.stringify(e)}`);
       // }
 
+      console.info(`removing uniform from paused pool`);
+      const exactBurnAmount = new BN(1_000);
+      const [removeApproveIxs, removeRevokeIxs] =
+        await getApproveAndRevokeIxs(
+          splToken,
+          [userSwimUsdAtaAddr],
+          [exactBurnAmount],
+          userTransferAuthority.publicKey,
+          payer,
+        );
+      // eslint-disable-next-line @typescript-eslint/no-unsafe-assignment
+      const removeUniformQuote: readonly BN[] = await twoPoolProgram.methods
+        .quoteRemoveUniform(exactBurnAmount, [new BN(0), new BN(0)])
+        .accounts({
+          poolTokenAccount0: poolUsdcAtaAddr,
+          poolTokenAccount1: poolUsdtAtaAddr,
+          lpMint: swimUsdKeypair.publicKey,
+        })
+        .view();
+      const userUsdcBalanceBefore = (
+        await splToken.account.token.fetch(userUsdcAtaAddr)
+      ).amount;
+      await twoPoolProgram.methods
+        .removeUniform(exactBurnAmount, [new BN(0), new BN(0)])
+        .accounts({
+          poolTokenAccount0: poolUsdcAtaAddr,
+          poolTokenAccount1: poolUsdtAtaAddr,
+          lpMint: swimUsdKeypair.publicKey,
+          governanceFee: governanceFeeAddr,
+          userTransferAuthority: userTransferAuthority.publicKey,
+          userTokenAccount0: userUsdcAtaAddr,
+          userTokenAccount1: userUsdtAtaAddr,
+          userLpTokenAccount: userSwimUsdAtaAddr,
+          tokenProgram: splToken.programId,
+        })
+        .preInstructions([...removeApproveIxs])
+        .postInstructions([...removeRevokeIxs])
+        .signers([userTransferAuthority])
+        .rpc();
+      const userUsdcBalanceAfter = (
+        await splToken.account.token.fetch(userUsdcAtaAddr)
+      ).amount;
+      expect(
+        userUsdcBalanceAfter
+          .sub(userUsdcBalanceBefore)
+          .eq(removeUniformQuote[0]),
+      ).toBeTruthy();
+
       console.info(`un-pausing pool`);
       paused = false;
       await twoPoolProgram.methods
@@ -949,7 +1126,7 @@ describe("TwoPool", () => {
         .rpc();
 
       poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
-      expect(!poolData.isPaused).toBe(true);
+      expect(poolData.pauseFlags).toEqual(0);
 
       console.info(`re-trying defi ix on un-paused pool`);
       await twoPoolProgram.methods
@@ -975,8 +1152,87 @@ describe("TwoPool", () => {
         .rpc();
     });
 
+    it("Can pause operations independently", async () => {
+      const swapPauseFlag = 1 << 1;
+      await twoPoolProgram.methods
+        .setPauseFlags(swapPauseFlag)
+        .accounts({
+          pool: flagshipPool,
+          pauseKey: pauseKeypair.publicKey,
+        })
+        .signers([pauseKeypair])
+        .rpc();
+      let poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
+      expect(poolData.pauseFlags).toEqual(swapPauseFlag);
+
+      await expect(() =>
+        twoPoolProgram.methods
+          .setPauseFlags(1 << 7)
+          .accounts({
+            pool: flagshipPool,
+            pauseKey: pauseKeypair.publicKey,
+          })
+          .signers([pauseKeypair])
+          .rpc(),
+      ).rejects.toThrow("Invalid pause flags");
+
+      await twoPoolProgram.methods
+        .setPauseFlags(0)
+        .accounts({
+          pool: flagshipPool,
+          pauseKey: pauseKeypair.publicKey,
+        })
+        .signers([pauseKeypair])
+        .rpc();
+      poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
+      expect(poolData.pauseFlags).toEqual(0);
+    });
+
+    it("Can prepare pool's pause key change", async () => {
+      const commonGovernance = {
+        pool: flagshipPool,
+        governance: governanceKeypair.publicKey,
+      };
+      await twoPoolProgram.methods
+        .preparePauseKeyChange(newPauseKeypair.publicKey)
+        .accounts({ commonGovernance })
+        .signers([governanceKeypair])
+        .rpc();
+
+      const poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
+      expect(
+        poolData.preparedPauseKey.equals(newPauseKeypair.publicKey),
+      ).toBeTruthy();
+      expect(poolData.pauseKey.equals(pauseKeypair.publicKey)).toBeTruthy();
+
+      await expect(() =>
+        twoPoolProgram.methods
+          .enactPauseKeyChange()
+          .accounts({ commonGovernance })
+          .signers([governanceKeypair])
+          .rpc(),
+      ).rejects.toThrow("Not enough time has passed since prepare instruction");
+
+      await expect(() =>
+        twoPoolProgram.methods
+          .setPaused(true)
+          .accounts({
+            pool: flagshipPool,
+            pauseKey: newPauseKeypair.publicKey,
+          })
+          .signers([newPauseKeypair])
+          .rpc(),
+      ).rejects.toThrow("Invalid Pause Key");
+
+      await twoPoolProgram.methods
+        .cancelPauseKeyChange()
+        .accounts({ commonGovernance })
+        .signers([governanceKeypair])
+        .rpc();
+    });
+
     it("Can update pool's pause key", async () => {
-      const changePauseKeyTxn = await twoPoolProgram.methods
+      await twoPoolProgram.methods
         .changePauseKey(newPauseKeypair.publicKey)
         .accounts({
           commonGovernance: {
@@ -987,14 +1243,12 @@ describe("TwoPool", () => {
         .signers([governanceKeypair])
         .rpc();
 
-      console.info(`changePauseKeyTxn: ${changePauseKeyTxn}`);
       let poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
       expect(poolData.pauseKey.equals(newPauseKeypair.publicKey)).toBeTruthy();
-      let paused = true;
 
       await expect(() =>
         twoPoolProgram.methods
-          .setPaused(paused)
+          .setPaused(true)
           .accounts({
             pool: flagshipPool,
             pauseKey: pauseKeypair.publicKey,
@@ -1002,47 +1256,28 @@ describe("TwoPool", () => {
           .signers([pauseKeypair])
           .rpc(),
       ).rejects.toThrow("Invalid Pause Key");
-      // try {
-      //   console.info(`sending pauseTxn expected to fail`);
-      //   await twoPoolProgram.methods
-      //     .setPaused(paused)
-      //     .accounts({
-      //       pool: flagshipPool,
-      //       pauseKey: pauseKeypair.publicKey,
-      //     })
-      //     .signers([pauseKeypair])
-      //     .rpc();
-      // } catch (_err) {
-      //   console.info(`successfully threw error: ${JSON.stringify(_err)}`);
-      //   expect(_err instanceof AnchorError).toBe(true);
-      //   const err: AnchorError = _err as unknown as AnchorError;
-      //   expect(err.error.errorMessage).toBe("Invalid Pause Key");
-      // }
 
       await twoPoolProgram.methods
-        .setPaused(paused)
+        .setPaused(true)
         .accounts({
           pool: flagshipPool,
           pauseKey: newPauseKeypair.publicKey,
         })
         .signers([newPauseKeypair])
         .rpc();
-
       poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
-      expect(poolData.isPaused).toEqual(true);
+      expect(poolData.pauseFlags).not.toEqual(0);
 
-      paused = false;
       await twoPoolProgram.methods
-        .setPaused(paused)
+        .setPaused(false)
         .accounts({
           pool: flagshipPool,
           pauseKey: newPauseKeypair.publicKey,
         })
         .signers([newPauseKeypair])
         .rpc();
-
       poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
-      expect(!poolData.isPaused).toEqual(true);
+      expect(poolData.pauseFlags).toEqual(0);
     });
 
     it("Can prepare fee changes", async () => {
@@ -1103,7 +1338,83 @@ describe("TwoPool", () => {
       ).toBeTruthy();
     });
 
-    it("Can change governance fee account", async () => {
+    it("Can cancel prepared governance changes", async () => {
+      const commonGovernance = {
+        pool: flagshipPool,
+        governance: governanceKeypair.publicKey,
+      };
+      const upcomingPauseKey = Keypair.generate().publicKey;
+      await twoPoolProgram.methods
+        .preparePauseKeyChange(upcomingPauseKey)
+        .accounts({ commonGovernance })
+        .signers([governanceKeypair])
+        .rpc();
+      await twoPoolProgram.methods
+        .prepareEnactDelayChange(new BN(86400))
+        .accounts({ commonGovernance })
+        .signers([governanceKeypair])
+        .rpc();
+      await expect(() =>
+        twoPoolProgram.methods
+          .prepareEnactDelayChange(new BN(0))
+          .accounts({ commonGovernance })
+          .signers([governanceKeypair])
+          .rpc(),
+      ).rejects.toThrow("Invalid enact delay");
+
+      let poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
+      expect(poolData.preparedPauseKey.equals(upcomingPauseKey)).toBeTruthy();
+      expect(poolData.preparedEnactDelay.toNumber()).toEqual(86400);
+      expect(poolData.enactDelay.toNumber()).toEqual(3 * 86400);
+
+      await expect(() =>
+        twoPoolProgram.methods
+          .enactPauseKeyChange()
+          .accounts({ commonGovernance })
+          .signers([governanceKeypair])
+          .rpc(),
+      ).rejects.toThrow("Not enough time has passed since prepare instruction");
+
+      await twoPoolProgram.methods
+        .cancelGovernanceTransition()
+        .accounts({ commonGovernance })
+        .postInstructions([
+          await twoPoolProgram.methods
+            .cancelFeeChange()
+            .accounts({ commonGovernance })
+            .instruction(),
+          await twoPoolProgram.methods
+            .cancelPauseKeyChange()
+            .accounts({ commonGovernance })
+            .instruction(),
+          await twoPoolProgram.methods
+            .cancelEnactDelayChange()
+            .accounts({ commonGovernance })
+            .instruction(),
+        ])
+        .signers([governanceKeypair])
+        .rpc();
+
+      poolData = await twoPoolProgram.account.twoPool.fetch(flagshipPool);
+      expect(poolData.governanceTransitionTs.toNumber()).toEqual(0);
+      expect(poolData.feeTransitionTs.toNumber()).toEqual(0);
+      expect(poolData.pauseKeyTransitionTs.toNumber()).toEqual(0);
+      expect(poolData.enactDelayTransitionTs.toNumber()).toEqual(0);
+      expect(
+        poolData.preparedGovernanceKey.equals(PublicKey.default),
+      ).toBeTruthy();
+      expect(poolData.preparedPauseKey.equals(PublicKey.default)).toBeTruthy();
+
+      await expect(() =>
+        twoPoolProgram.methods
+          .cancelPauseKeyChange()
+          .accounts({ commonGovernance })
+          .signers([governanceKeypair])
+          .rpc(),
+      ).rejects.toThrow("Nothing to cancel");
+    });
+
+    it("Can prepare governance fee account change", async () => {
       const newGovernanceFeeOwner = Keypair.generate().publicKey;
 
       const newGovernanceFeeKey: PublicKey = (
@@ -1114,8 +1425,8 @@ describe("TwoPool", () => {
           newGovernanceFeeOwner,
         )
       ).address;
-      const changeGovFeeTxn = await twoPoolProgram.methods
-        .changeGovernanceFeeAccount(newGovernanceFeeKey)
+      const prepareGovFeeTxn = await twoPoolProgram.methods
+        .prepareGovernanceFeeAccountChange(newGovernanceFeeKey)
         .accounts({
           commonGovernance: {
             pool: flagshipPool,
@@ -1125,22 +1436,24 @@ describe("TwoPool", () => {
         })
         .signers([governanceKeypair])
         .rpc();
-      console.info(`send changeGovFeeTxn: ${changeGovFeeTxn}`);
+      console.info(`send prepareGovFeeTxn: ${prepareGovFeeTxn}`);
 
       const poolDataAfter = await twoPoolProgram.account.twoPool.fetch(
         flagshipPool,
       );
       expect(
-        poolDataAfter.governanceFeeKey.equals(newGovernanceFeeKey),
+        poolDataAfter.preparedGovernanceFeeKey.equals(newGovernanceFeeKey),
+      ).toBeTruthy();
+      expect(
+        poolDataAfter.governanceFeeKey.equals(governanceFeeAddr),
       ).toBeTruthy();
-      governanceFeeAddr = newGovernanceFeeKey;
     });
 
-    it("Throws error when changing governance fee account to invalid token account", async () => {
+    it("Throws error when preparing governance fee account change to invalid token account", async () => {
       const newGovernanceFeeKey = userUsdcAtaAddr;
       await expect(() => {
         return twoPoolProgram.methods
-          .changeGovernanceFeeAccount(newGovernanceFeeKey)
+          .prepareGovernanceFeeAccountChange(newGovernanceFeeKey)
           .accounts({
             commonGovernance: {
               pool: flagshipPool,
@@ -1151,53 +1464,8 @@ describe("TwoPool", () => {
           .signers([governanceKeypair])
           .rpc();
       }).rejects.toThrow("A token mint constraint was violated");
-      // try {
-      //
-      //
-      //   await twoPoolProgram.methods
-      //     .changeGovernanceFeeAccount(newGovernanceFeeKey)
-      //     .accounts({
-      //       commonGovernance: {
-      //         pool: flagshipPool,
-      //         governance: governanceKeypair.publicKey,
-      //       },
-      //       newGovernanceFee: newGovernanceFeeKey,
-      //     })
-      //     .signers([governanceKeypair])
-      //     .rpc();
-      //   expect(false).toBeTruthy();
-      // } catch (_err) {
-      //   // console.info(`_err: ${JSON.stringify(_err)}`);
-      //   // console.info(`anchorErr: ${JSON.stringify(err)}`);
-      //   //anchorErr: {
-      //   // "errorLogs":[
-      //   //    "Program log: AnchorError occurred. Error Code: ConstraintTokenMint. Error Number: 2014. Error Message: A token mint constraint was violated."
-      //   //  ],
-      //   // "logs":[
-      //   //    "Program 8VNVtWUae4qMe535i4yL1gD3VTo8JhcfFEygaozBq8aM invoke [1]",
-      //   //    "Program log: Instruction: ChangeGovernanceFeeAccount",
-      //   //    "Program log: AnchorError occurred. Error Code: ConstraintTokenMint. Error Number: 2014. Error Message: A token mint constraint was violated.",
-      //   //    "Program 8VNVtWUae4qMe535i4yL1gD3VTo8JhcfFEygaozBq8aM consumed 7871 of 200000 compute units",
-      //   //    "Program 8VNVtWUae4qMe535i4yL1gD3VTo8JhcfFEygaozBq8aM failed: custom program error: 0x7de"
-      //   //  ],
-      //   // "error":{
-      //   //    "errorCode":{
-      //   //      "code":"ConstraintTokenMint",
-      //   //      "number":2014
-      //   //    },
-      //   //    "errorMessage":"A token mint constraint was violated"
-      //   //    },
-      //   //    "_programErrorStack":{
-      //   //      "stack":["8VNVtWUae4qMe535i4yL1gD3VTo8JhcfFEygaozBq8aM"]
-      //   //    }
-      //   //  }
-      //   expect(_err instanceof AnchorError).toBe(true);
-      //   const err: AnchorError = _err;
-      //   expect(err.error.errorMessage).toBe(
-      //     "A token mint constraint was violated",
-      //   );
-      // }
     });
+
     it("Can create mpl token metadata for lp token", async () => {
       const name = "swimUSD";
       const symbol = "swimUSD";
@@ -68,6 +68,11 @@ export type Propeller = {
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "pauseKey",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "swimUsdMint",
           "isMut": false,
@@ -300,11 +305,19 @@ export type Propeller = {
           "type": {
             "defined": "PoolInstruction"
           }
+        },
+        {
+          "name": "nextSteps",
+          "type": {
+            "vec": {
+              "defined": "PoolStep"
+            }
+          }
         }
       ]
     },
     {
-      "name": "createTargetChainMap",
+      "name": "updateTokenIdMap",
       "accounts": [
         {
           "name": "propeller",
@@ -332,12 +345,12 @@ export type Propeller = {
           "isSigner": true
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "targetChainMap",
+          "name": "tokenIdMap",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -347,6 +360,11 @@ export type Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
@@ -354,37 +372,46 @@ export type Propeller = {
                 "path": "propeller"
               },
               {
-                "kind": "arg",
+                "kind": "account",
                 "type": "u16",
-                "path": "target_chain"
+                "account": "TokenIdMap",
+                "path": "token_id_map.output_token_index"
               }
             ]
           }
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "targetChain",
-          "type": "u16"
+          "name": "pool",
+          "type": "publicKey"
         },
         {
-          "name": "targetAddress",
+          "name": "poolTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "poolTokenMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "poolIx",
           "type": {
-            "array": [
-              "u8",
-              32
-            ]
+            "defined": "PoolInstruction"
+          }
+        },
+        {
+          "name": "nextSteps",
+          "type": {
+            "vec": {
+              "defined": "PoolStep"
+            }
           }
         }
       ]
     },
     {
-      "name": "updateTargetChainMap",
+      "name": "closeTokenIdMap",
       "accounts": [
         {
           "name": "propeller",
@@ -412,12 +439,7 @@ export type Propeller = {
           "isSigner": true
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "targetChainMap",
+          "name": "tokenIdMap",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -427,6 +449,11 @@ export type Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
@@ -436,32 +463,22 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "u16",
-                "account": "TargetChainMap",
-                "path": "target_chain_map.target_chain"
+                "account": "TokenIdMap",
+                "path": "token_id_map.output_token_index"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "rentRecipient",
+          "isMut": true,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "routingContract",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initializeFeeTracker",
+      "name": "createTargetChainMap",
       "accounts": [
         {
           "name": "propeller",
@@ -477,14 +494,24 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "swim_usd_mint"
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
               }
             ]
           }
         },
         {
-          "name": "feeTracker",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "targetChainMap",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -494,45 +521,44 @@ export type Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "swim_usd_mint"
+                "account": "Propeller",
+                "path": "propeller"
               },
               {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "payer"
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_chain"
               }
             ]
           }
         },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "swimUsdMint",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "targetChain",
+          "type": "u16"
+        },
+        {
+          "name": "targetAddress",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
     },
     {
-      "name": "claimFees",
+      "name": "updateTargetChainMap",
       "accounts": [
         {
           "name": "propeller",
@@ -555,7 +581,17 @@ export type Propeller = {
           }
         },
         {
-          "name": "feeTracker",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "targetChainMap",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -565,51 +601,41 @@ export type Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "FeeTracker",
-                "path": "fee_tracker.fees_mint"
+                "account": "Propeller",
+                "path": "propeller"
               },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "FeeTracker",
-                "path": "fee_tracker.payer"
+                "type": "u16",
+                "account": "TargetChainMap",
+                "path": "target_chain_map.target_chain"
               }
             ]
           }
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "feeAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "routingContract",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
     },
     {
-      "name": "crossChainAdd",
+      "name": "closeTargetChainMap",
       "accounts": [
         {
           "name": "propeller",
@@ -625,14 +651,19 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
               }
             ]
           }
         },
         {
-          "name": "pool",
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "targetChainMap",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -640,108 +671,117 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
+                "value": "propeller"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
+                "account": "Propeller",
+                "path": "propeller"
               },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+                "type": "u16",
+                "account": "TargetChainMap",
+                "path": "target_chain_map.target_chain"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
-            }
+            ]
           }
         },
         {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
+          "name": "rentRecipient",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "prepareAdminTransition",
+      "docs": [
+        "Admin Ixs *"
+      ],
+      "accounts": [
         {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userLpTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         }
       ],
       "args": [
         {
-          "name": "inputAmounts",
-          "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          }
-        },
+          "name": "upcomingAdmin",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "enactAdminTransition",
+      "accounts": [
         {
-          "name": "minimumMintAmount",
-          "type": "u64"
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         }
       ],
-      "returns": "u64"
+      "args": []
     },
     {
-      "name": "propellerAdd",
+      "name": "setPaused",
       "accounts": [
         {
           "name": "propeller",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -753,370 +793,550 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
               }
             ]
           }
         },
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
-            }
-          }
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
+          "name": "pauseKey",
           "isMut": false,
           "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userLpTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "inputAmounts",
+          "name": "direction",
           "type": {
-            "array": [
-              "u64",
-              2
-            ]
+            "defined": "PauseDirection"
           }
         },
         {
-          "name": "maxFee",
-          "type": "u64"
+          "name": "paused",
+          "type": "bool"
         }
-      ],
-      "returns": "u64"
+      ]
     },
     {
-      "name": "crossChainSwapExactInput",
+      "name": "changePauseKey",
       "accounts": [
         {
-          "name": "propeller",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
               }
-            ]
-          }
-        },
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+          "name": "newPauseKey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "updateGasKickstartAmount",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
             }
-          }
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "gasKickstartAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateSwimPayloadExpiry",
+      "accounts": [
         {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "swimPayloadExpiry",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateFeePriceCacheMaxAge",
+      "accounts": [
         {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "feePriceCacheMaxAge",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateFees",
+      "accounts": [
         {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "params",
+          "type": {
+            "defined": "UpdateFeesParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMarginalPricePool",
+      "accounts": [
         {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         },
         {
-          "name": "swimUsdMint",
+          "name": "pool",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "exactInputAmount",
-          "type": "u64"
+          "name": "marginalPricePoolTokenIndex",
+          "type": "u8"
         },
         {
-          "name": "minimumOutputAmount",
-          "type": "u64"
+          "name": "marginalPriceTwapWindow",
+          "type": "u32"
         }
-      ],
-      "returns": "u64"
+      ]
     },
     {
-      "name": "propellerSwapExactInput",
+      "name": "updateFeeVault",
       "accounts": [
         {
-          "name": "propeller",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
             }
-          }
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
+          ]
         },
         {
-          "name": "poolTokenAccount1",
-          "isMut": true,
+          "name": "feeVault",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateAggregator",
+      "accounts": [
         {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         },
         {
-          "name": "governanceFee",
-          "isMut": true,
+          "name": "aggregator",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateAggregatorConfig",
+      "accounts": [
         {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
+          "name": "maxStaleness",
+          "type": "i64"
         },
         {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "maxConfidenceInterval",
+          "type": {
+            "defined": "BorshDecimal"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateOracleConfig",
+      "accounts": [
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         },
         {
-          "name": "twoPoolProgram",
+          "name": "fallbackOracle",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "swimUsdMint",
+          "name": "solUsdPool",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "exactInputAmount",
-          "type": "u64"
-        },
+          "name": "params",
+          "type": {
+            "defined": "UpdateOracleConfigParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateRateLimits",
+      "accounts": [
         {
-          "name": "maxFee",
-          "type": "u64"
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         }
       ],
-      "returns": "u64"
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "UpdateRateLimitsParams"
+          }
+        }
+      ]
     },
     {
-      "name": "crossChainTransferNativeWithPayload",
+      "name": "updateTargetChainRateLimits",
       "accounts": [
         {
-          "name": "propeller",
-          "isMut": true,
-          "isSigner": false,
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "targetChainMap",
+          "isMut": true,
+          "isSigner": false,
           "pda": {
             "seeds": [
               {
@@ -1126,79 +1346,103 @@ export type Propeller = {
               },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "swim_usd_mint"
+                "type": {
+                  "defined": "Box<Account<'info,Propeller>>"
+                },
+                "account": "CommonAdmin",
+                "path": "common_admin.propeller"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "TargetChainMap",
+                "path": "target_chain_map.target_chain"
               }
             ]
           }
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "tokenBridgeConfig",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "config"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
+          "name": "inbound",
+          "type": {
+            "defined": "RateLimitConfig"
           }
         },
         {
-          "name": "userSwimUsdAta",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "outbound",
+          "type": {
+            "defined": "RateLimitConfig"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseSwimPayload",
+      "accounts": [
         {
-          "name": "swimUsdMint",
-          "isMut": true,
-          "isSigner": false
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         },
         {
-          "name": "custody",
+          "name": "swimPayloadMessage",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenBridge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "custodySigner",
-          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "custody_signer"
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.claim"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "token_bridge"
-            }
+            ]
           }
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeFeePriceCache",
+      "accounts": [
         {
-          "name": "authoritySigner",
+          "name": "propeller",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -1206,18 +1450,19 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "authority_signer"
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "token_bridge"
-            }
+            ]
           }
         },
         {
-          "name": "wormholeConfig",
+          "name": "feePriceCache",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -1225,44 +1470,60 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "Bridge"
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
+            ]
           }
         },
         {
-          "name": "wormholeMessage",
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "wormholeEmitter",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refreshFeePriceCache",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "emitter"
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
+            ]
           }
         },
         {
-          "name": "wormholeSequence",
+          "name": "feePriceCache",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -1270,102 +1531,102 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "Sequence"
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "wormhole_emitter"
+                "account": "Propeller",
+                "path": "propeller"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
+            ]
           }
         },
         {
-          "name": "wormholeFeeCollector",
-          "isMut": true,
+          "name": "aggregator",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "fallbackOracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solUsdPool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "marginalPricePool",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "fee_collector"
-              }
-            ],
-            "programId": {
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "marginal_price_pool_token_0_account.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "marginal_price_pool_token_1_account.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "marginal_price_pool_lp_mint"
+              }
+            ],
+            "programId": {
               "kind": "account",
               "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
+              "path": "two_pool_program"
             }
           }
         },
         {
-          "name": "sender",
+          "name": "marginalPricePoolToken0Account",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Transfers with payload also include the address of the account or contract",
-            "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
-            "EVM chains, i.e. it is the address of the immediate caller of the token",
-            "bridge transaction.",
-            "Since on Solana, a transaction can have multiple different signers, getting",
-            "this information is not so straightforward.",
-            "The strategy we use to figure out the sender of the transaction is to",
-            "require an additional signer ([`SenderAccount`]) for the transaction.",
-            "If the transaction was sent by a user wallet directly, then this may just be",
-            "the wallet's pubkey. If, however, the transaction was initiated by a",
-            "program, then we require this to be a PDA derived from the sender program's",
-            "id and the string \"sender\". In this case, the sender program must also",
-            "attach its program id to the instruction data. If the PDA verification",
-            "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
-            "transaction), then the program's id is attached to the VAA as the sender,",
-            "otherwise the transaction is rejected.",
-            "",
-            "Note that a program may opt to forego the PDA derivation and instead just",
-            "pass on the original wallet as the wallet account (or any other signer, as",
-            "long as they don't provide their program_id in the instruction data). The",
-            "sender address is provided as a means for protocols to verify on the",
-            "receiving end that the message was emitted by a contract they trust, so",
-            "foregoing this check is not advised. If the receiving contract needs to know",
-            "the sender wallet's address too, then that information can be included in",
-            "the additional payload, along with any other data that the protocol needs to",
-            "send across. The legitimacy of the attached data can be verified by checking",
-            "that the sender contract is a trusted one.",
-            "",
-            "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
-            "[[`cpi_program_id`]] field will result in a successful transaction, but in",
-            "that case the PDA's address will directly be encoded into the payload",
-            "instead of the sender program's id."
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "sender"
-              }
-            ]
-          }
+          "isSigner": false
         },
         {
-          "name": "wormhole",
+          "name": "marginalPricePoolToken1Account",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "marginalPricePoolLpMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "targetChainMap",
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeFeeTracker",
+      "accounts": [
+        {
+          "name": "propeller",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -1378,54 +1639,66 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller"
+                "account": "Mint",
+                "path": "swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeTracker",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
               },
               {
-                "kind": "arg",
-                "type": "u16",
-                "path": "target_chain"
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "clock",
+          "name": "swimUsdMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "targetChain",
-          "type": "u16"
-        },
-        {
-          "name": "owner",
-          "type": "bytes"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "propellerTransferNativeWithPayload",
+      "name": "claimFees",
       "accounts": [
         {
           "name": "propeller",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -1437,19 +1710,14 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "swim_usd_mint"
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
               }
             ]
           }
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenBridgeConfig",
+          "name": "feeTracker",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -1457,39 +1725,56 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "config"
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "FeeTracker",
+                "path": "fee_tracker.fees_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "FeeTracker",
+                "path": "fee_tracker.payer"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
+            ]
           }
         },
         {
-          "name": "userSwimUsdAta",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "swimUsdMint",
+          "name": "feeAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "custody",
+          "name": "feeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenBridge",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "crossChainAdd",
+      "accounts": [
         {
-          "name": "custodySigner",
+          "name": "propeller",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -1497,264 +1782,124 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "custody_signer"
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "token_bridge"
-            }
+            ]
           }
         },
         {
-          "name": "authoritySigner",
-          "isMut": false,
+          "name": "pool",
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "authority_signer"
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
             ],
             "programId": {
               "kind": "account",
               "type": "publicKey",
-              "path": "token_bridge"
+              "path": "two_pool_program"
             }
           }
         },
         {
-          "name": "wormholeConfig",
+          "name": "poolTokenAccount0",
           "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "Bridge"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
-        },
-        {
-          "name": "wormholeMessage",
-          "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "wormholeEmitter",
+          "name": "poolTokenAccount1",
           "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "emitter"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
+          "isSigner": false
         },
         {
-          "name": "wormholeSequence",
+          "name": "lpMint",
           "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "Sequence"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "wormhole_emitter"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
+          "isSigner": false
         },
         {
-          "name": "wormholeFeeCollector",
+          "name": "governanceFee",
           "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee_collector"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
+          "isSigner": false
         },
         {
-          "name": "sender",
+          "name": "userTransferAuthority",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Transfers with payload also include the address of the account or contract",
-            "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
-            "EVM chains, i.e. it is the address of the immediate caller of the token",
-            "bridge transaction.",
-            "Since on Solana, a transaction can have multiple different signers, getting",
-            "this information is not so straightforward.",
-            "The strategy we use to figure out the sender of the transaction is to",
-            "require an additional signer ([`SenderAccount`]) for the transaction.",
-            "If the transaction was sent by a user wallet directly, then this may just be",
-            "the wallet's pubkey. If, however, the transaction was initiated by a",
-            "program, then we require this to be a PDA derived from the sender program's",
-            "id and the string \"sender\". In this case, the sender program must also",
-            "attach its program id to the instruction data. If the PDA verification",
-            "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
-            "transaction), then the program's id is attached to the VAA as the sender,",
-            "otherwise the transaction is rejected.",
-            "",
-            "Note that a program may opt to forego the PDA derivation and instead just",
-            "pass on the original wallet as the wallet account (or any other signer, as",
-            "long as they don't provide their program_id in the instruction data). The",
-            "sender address is provided as a means for protocols to verify on the",
-            "receiving end that the message was emitted by a contract they trust, so",
-            "foregoing this check is not advised. If the receiving contract needs to know",
-            "the sender wallet's address too, then that information can be included in",
-            "the additional payload, along with any other data that the protocol needs to",
-            "send across. The legitimacy of the attached data can be verified by checking",
-            "that the sender contract is a trusted one.",
-            "",
-            "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
-            "[[`cpi_program_id`]] field will result in a successful transaction, but in",
-            "that case the PDA's address will directly be encoded into the payload",
-            "instead of the sender program's id."
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "sender"
-              }
-            ]
-          }
+          "isSigner": true
         },
         {
-          "name": "wormhole",
-          "isMut": false,
+          "name": "userTokenAccount0",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
+          "name": "userTokenAccount1",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "targetChainMap",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller"
-              },
-              {
-                "kind": "arg",
-                "type": "u16",
-                "path": "target_chain"
-              }
-            ]
-          }
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "userLpTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "clock",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "twoPoolProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "targetChain",
-          "type": "u16"
-        },
-        {
-          "name": "owner",
-          "type": "bytes"
-        },
-        {
-          "name": "gasKickstart",
-          "type": "bool"
+          "name": "inputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          }
         },
         {
-          "name": "maxFee",
+          "name": "minimumMintAmount",
           "type": "u64"
-        },
-        {
-          "name": "targetTokenId",
-          "type": "u16"
-        },
-        {
-          "name": "memo",
-          "type": {
-            "option": {
-              "array": [
-                "u8",
-                16
-              ]
-            }
-          }
         }
-      ]
+      ],
+      "returns": "u64"
     },
     {
-      "name": "completeNativeWithPayload",
+      "name": "propellerAdd",
       "accounts": [
         {
           "name": "propeller",
@@ -1770,19 +1915,14 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
+                "account": "Mint",
+                "path": "lp_mint"
               }
             ]
           }
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenBridgeConfig",
+          "name": "pool",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -1790,114 +1930,72 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "config"
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
             ],
             "programId": {
               "kind": "account",
               "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
+              "path": "two_pool_program"
             }
           }
         },
         {
-          "name": "message",
+          "name": "poolTokenAccount0",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "contains the VAA",
-            "{",
-            "...MessageData:",
-            "payload: PayloadTransferWithPayload = {",
-            "pub amount: U256,",
-            "}",
-            "}"
-          ]
-        },
-        {
-          "name": "claim",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "seeds = [",
-            "vaa.emitter_address, vaa.emitter_chain, vaa.sequence",
-            "],",
-            "seeds::program = token_bridge"
-          ]
-        },
-        {
-          "name": "endpoint",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "to",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "owned by redeemer. \"redeemerEscrow\""
-          ]
-        },
-        {
-          "name": "redeemer",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-            "will have to be signed when it invokes complete_transfer_with_payload",
-            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)",
-            "TODO: this is a little weird. i think we can safely assume that the `vaa.to` will be this programId",
-            "and that the `redeemer` account will be the PDA derived from [\"redeemer\"], seeds::program = propeller::id()"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "redeemer"
-              }
-            ]
-          }
-        },
-        {
-          "name": "feeRecipient",
+          "name": "poolTokenAccount1",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "this is \"to_fees\"",
-            "recipient of fees for executing complete transfer (e.g. relayer)"
-          ]
+          "isSigner": false
         },
         {
-          "name": "custody",
+          "name": "lpMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "swimUsdMint",
-          "isMut": false,
+          "name": "governanceFee",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "custodySigner",
+          "name": "userTransferAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "rent",
-          "isMut": false,
+          "name": "userTokenAccount0",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "userTokenAccount1",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "wormhole",
-          "isMut": false,
+          "name": "userLpTokenAccount",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1906,39 +2004,30 @@ export type Propeller = {
           "isSigner": false
         },
         {
-          "name": "tokenBridge",
+          "name": "twoPoolProgram",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "swimPayloadMessage",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "swim_payload"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "claim"
-              }
+          "name": "inputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
             ]
           }
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
         }
       ],
-      "args": []
+      "returns": "u64"
     },
     {
-      "name": "processSwimPayload",
+      "name": "crossChainSwapExactInput",
       "accounts": [
         {
           "name": "propeller",
@@ -1954,58 +2043,118 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               }
             ]
           }
         },
         {
-          "name": "payer",
+          "name": "pool",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "claim",
-          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
               {
                 "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_address"
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               },
               {
                 "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_chain"
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
               },
               {
                 "kind": "account",
-                "type": "u64",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_sequence"
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
             ],
             "programId": {
               "kind": "account",
               "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
+              "path": "two_pool_program"
             }
           }
         },
         {
-          "name": "swimClaim",
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerSwapExactInput",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -2014,21 +2163,17 @@ export type Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "claim"
-              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "claim"
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               }
             ]
           }
         },
         {
-          "name": "swimPayloadMessage",
+          "name": "pool",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -2036,54 +2181,225 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "propeller"
+                "value": "two_pool"
               },
               {
-                "kind": "const",
-                "type": "string",
-                "value": "swim_payload"
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "claim"
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            ]
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
           }
         },
         {
-          "name": "swimPayloadMessagePayer",
+          "name": "poolTokenAccount0",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "redeemer",
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "crossChainSwapExactOutput",
+      "accounts": [
+        {
+          "name": "propeller",
           "isMut": false,
           "isSigner": false,
-          "docs": [
-            "this used to be \"to_owner\".",
-            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-            "will have to be signed when it invokes complete_transfer_with_payload",
-            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "redeemer"
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               }
             ]
           }
         },
         {
-          "name": "redeemerEscrow",
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenIdMap",
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerSwapExactOutput",
+      "accounts": [
+        {
+          "name": "propeller",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -2093,21 +2409,11 @@ export type Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "token_id"
-              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller"
-              },
-              {
-                "kind": "arg",
-                "type": "u16",
-                "path": "target_token_id"
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               }
             ]
           }
@@ -2184,11 +2490,6 @@ export type Propeller = {
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "userLpTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "tokenProgram",
           "isMut": false,
@@ -2200,251 +2501,52 @@ export type Propeller = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "swimUsdMint",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "targetTokenId",
-          "type": "u16"
+          "name": "maximumInputAmount",
+          "type": "u64"
         },
         {
-          "name": "minOutputAmount",
+          "name": "exactOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
           "type": "u64"
         }
       ],
       "returns": "u64"
     },
     {
-      "name": "propellerCompleteNativeWithPayload",
+      "name": "crossChainRemoveUniform",
       "accounts": [
         {
-          "name": "completeNativeWithPayload",
-          "accounts": [
-            {
-              "name": "propeller",
-              "isMut": false,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "propeller"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "Propeller",
-                    "path": "propeller.swim_usd_mint"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "payer",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "tokenBridgeConfig",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "config"
-                  }
-                ],
-                "programId": {
-                  "kind": "account",
-                  "type": "publicKey",
-                  "account": "Propeller",
-                  "path": "propeller"
-                }
-              }
-            },
-            {
-              "name": "message",
-              "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "contains the VAA",
-                "{",
-                "...MessageData:",
-                "payload: PayloadTransferWithPayload = {",
-                "pub amount: U256,",
-                "}",
-                "}"
-              ]
-            },
-            {
-              "name": "claim",
-              "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "seeds = [",
-                "vaa.emitter_address, vaa.emitter_chain, vaa.sequence",
-                "],",
-                "seeds::program = token_bridge"
-              ]
-            },
-            {
-              "name": "endpoint",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "to",
-              "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "owned by redeemer. \"redeemerEscrow\""
-              ]
-            },
-            {
-              "name": "redeemer",
-              "isMut": false,
-              "isSigner": false,
-              "docs": [
-                "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-                "will have to be signed when it invokes complete_transfer_with_payload",
-                "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-                "(NOT the `to` account)",
-                "TODO: this is a little weird. i think we can safely assume that the `vaa.to` will be this programId",
-                "and that the `redeemer` account will be the PDA derived from [\"redeemer\"], seeds::program = propeller::id()"
-              ],
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "redeemer"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "feeRecipient",
-              "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "this is \"to_fees\"",
-                "recipient of fees for executing complete transfer (e.g. relayer)"
-              ]
-            },
-            {
-              "name": "custody",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "swimUsdMint",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "custodySigner",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "wormhole",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "tokenBridge",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "swimPayloadMessage",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "propeller"
-                  },
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "swim_payload"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "path": "claim"
-                  }
-                ]
-              }
-            }
-          ]
-        },
-        {
-          "name": "feeTracker",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
-              {
-                "kind": "account",
-                "type": {
-                  "defined": "Box<Account<'info,Mint>>"
-                },
-                "account": "CompleteNativeWithPayload",
-                "path": "complete_native_with_payload.swim_usd_mint"
-              },
-              {
-                "kind": "account",
-                "type": {
-                  "defined": "Signer<'info>"
-                },
-                "account": "CompleteNativeWithPayload",
-                "path": "complete_native_with_payload.payer"
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               }
             ]
           }
         },
         {
-          "name": "aggregator",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePool",
+          "name": "pool",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -2458,19 +2560,19 @@ export type Propeller = {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
+                "path": "pool_token_account_0.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
+                "path": "pool_token_account_1.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
+                "path": "lp_mint"
               }
             ],
             "programId": {
@@ -2481,17 +2583,47 @@ export type Propeller = {
           }
         },
         {
-          "name": "marginalPricePoolToken0Account",
-          "isMut": false,
+          "name": "poolTokenAccount0",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "marginalPricePoolToken1Account",
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "marginalPricePoolLpMint",
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -2501,18 +2633,32 @@ export type Propeller = {
           "isSigner": false
         },
         {
-          "name": "memo",
+          "name": "swimUsdMint",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minimumOutputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          }
+        }
+      ],
+      "returns": {
+        "vec": "u64"
+      }
     },
     {
-      "name": "propellerCreateOwnerTokenAccounts",
-      "docs": [
-        "Valid target_token_id *"
-      ],
+      "name": "propellerRemoveUniform",
       "accounts": [
         {
           "name": "propeller",
@@ -2528,50 +2674,14 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "redeemer",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "this used to be \"to_owner\".",
-            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-            "will have to be signed when it invokes complete_transfer_with_payload",
-            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "redeemer"
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               }
             ]
           }
         },
         {
-          "name": "redeemerEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeTracker",
+          "name": "pool",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -2579,91 +2689,109 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
+                "value": "two_pool"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "payer"
-              }
-            ]
-          }
-        },
-        {
-          "name": "claim",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_address"
-              },
-              {
-                "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_chain"
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
               },
               {
                 "kind": "account",
-                "type": "u64",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_sequence"
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
             ],
             "programId": {
               "kind": "account",
               "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
+              "path": "two_pool_program"
             }
           }
         },
         {
-          "name": "swimPayloadMessage",
+          "name": "poolTokenAccount0",
           "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "swim_payload"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "claim"
-              }
-            ]
-          }
+          "isSigner": false
         },
         {
-          "name": "tokenIdMap",
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "vec": "u64"
+      }
+    },
+    {
+      "name": "crossChainRemoveExactBurn",
+      "accounts": [
+        {
+          "name": "propeller",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -2673,22 +2801,11 @@ export type Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "token_id"
-              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.target_token_id"
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               }
             ]
           }
@@ -2707,20 +2824,20 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "pool_token_0_mint"
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "pool_token_1_mint"
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Mint",
-                "path": "pool_lp_mint"
+                "path": "lp_mint"
               }
             ],
             "programId": {
@@ -2731,32 +2848,37 @@ export type Propeller = {
           }
         },
         {
-          "name": "poolToken0Mint",
-          "isMut": false,
+          "name": "poolTokenAccount0",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolToken1Mint",
-          "isMut": false,
+          "name": "poolTokenAccount1",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolLpMint",
-          "isMut": false,
+          "name": "lpMint",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "user",
-          "isMut": false,
+          "name": "governanceFee",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userPoolToken0Account",
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userPoolToken1Account",
+          "name": "userTokenAccount1",
           "isMut": true,
           "isSigner": false
         },
@@ -2766,53 +2888,84 @@ export type Propeller = {
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "twoPoolProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "swimUsdMint",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "aggregator",
-          "isMut": false,
-          "isSigner": false
+          "name": "exactBurnAmount",
+          "type": "u64"
         },
         {
-          "name": "marginalPricePool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerRemoveExactBurn",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
+                "path": "lp_mint"
               }
             ],
             "programId": {
@@ -2823,17 +2976,47 @@ export type Propeller = {
           }
         },
         {
-          "name": "marginalPricePoolToken0Account",
-          "isMut": false,
+          "name": "poolTokenAccount0",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "marginalPricePoolToken1Account",
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "marginalPricePoolLpMint",
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -2843,309 +3026,157 @@ export type Propeller = {
           "isSigner": false
         },
         {
-          "name": "memo",
+          "name": "swimUsdMint",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
     },
     {
-      "name": "propellerProcessSwimPayload",
-      "docs": [
-        "Note: passing in target_token_id here due to PDA seed derivation.",
-        "for propeller_process_swim_payload, require_eq!(target_token_id, propeller_message.target_token_id);"
-      ],
+      "name": "crossChainRemoveExactOutput",
       "accounts": [
         {
-          "name": "processSwimPayload",
-          "accounts": [
-            {
-              "name": "propeller",
-              "isMut": false,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "propeller"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "Propeller",
-                    "path": "propeller.swim_usd_mint"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "payer",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "claim",
-              "isMut": false,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "account",
-                    "type": {
-                      "array": [
-                        "u8",
-                        32
-                      ]
-                    },
-                    "account": "SwimPayloadMessage",
-                    "path": "swim_payload_message.vaa_emitter_address"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "u16",
-                    "account": "SwimPayloadMessage",
-                    "path": "swim_payload_message.vaa_emitter_chain"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "u64",
-                    "account": "SwimPayloadMessage",
-                    "path": "swim_payload_message.vaa_sequence"
-                  }
-                ],
-                "programId": {
-                  "kind": "account",
-                  "type": "publicKey",
-                  "account": "Propeller",
-                  "path": "propeller"
-                }
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               }
-            },
-            {
-              "name": "swimClaim",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "propeller"
-                  },
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "claim"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "path": "claim"
-                  }
-                ]
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            },
-            {
-              "name": "swimPayloadMessage",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "propeller"
-                  },
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "swim_payload"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "path": "claim"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "swimPayloadMessagePayer",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "redeemer",
-              "isMut": false,
-              "isSigner": false,
-              "docs": [
-                "this used to be \"to_owner\".",
-                "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-                "will have to be signed when it invokes complete_transfer_with_payload",
-                "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-                "(NOT the `to` account)"
-              ],
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "redeemer"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "redeemerEscrow",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenIdMap",
-              "isMut": false,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "propeller"
-                  },
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "token_id"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "Propeller",
-                    "path": "propeller"
-                  },
-                  {
-                    "kind": "arg",
-                    "type": "u16",
-                    "path": "target_token_id"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TokenAccount",
-                    "path": "pool_token_account_0.mint"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TokenAccount",
-                    "path": "pool_token_account_1.mint"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "Mint",
-                    "path": "lp_mint"
-                  }
-                ],
-                "programId": {
-                  "kind": "account",
-                  "type": "publicKey",
-                  "path": "two_pool_program"
-                }
-              }
-            },
-            {
-              "name": "poolTokenAccount0",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "poolTokenAccount1",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "lpMint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "governanceFee",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "userTransferAuthority",
-              "isMut": false,
-              "isSigner": true
-            },
-            {
-              "name": "userTokenAccount0",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "userTokenAccount1",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "userLpTokenAccount",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "twoPoolProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
             }
-          ]
+          }
         },
         {
-          "name": "aggregator",
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
           "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Assuming that USD:USDC 1:1"
-          ]
+          "isSigner": true
         },
         {
-          "name": "feeVault",
+          "name": "userTokenAccount0",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "this is \"to_fees\"",
-            "recipient of fees for executing complete transfer (e.g. relayer)"
-          ]
+          "isSigner": false
         },
         {
-          "name": "feeTracker",
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerRemoveExactOutput",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -3154,30 +3185,17 @@ export type Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "ProcessSwimPayload",
-                "path": "process_swim_payload"
-              },
-              {
-                "kind": "account",
-                "type": {
-                  "defined": "Signer<'info>"
-                },
-                "account": "ProcessSwimPayload",
-                "path": "process_swim_payload.payer"
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               }
             ]
           }
         },
         {
-          "name": "marginalPricePool",
+          "name": "pool",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -3191,79 +3209,106 @@ export type Propeller = {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
+                "path": "pool_token_account_0.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
+                "path": "pool_token_account_1.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
+                "path": "lp_mint"
               }
             ],
             "programId": {
               "kind": "account",
-              "type": {
-                "defined": "Program<'info,two_pool::program::TwoPool>"
-              },
-              "account": "ProcessSwimPayload",
-              "path": "process_swim_payload.two_pool_program"
+              "type": "publicKey",
+              "path": "two_pool_program"
             }
           }
         },
         {
-          "name": "marginalPricePoolToken0Account",
-          "isMut": false,
+          "name": "poolTokenAccount0",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "marginalPricePoolToken1Account",
-          "isMut": false,
+          "name": "poolTokenAccount1",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "marginalPricePoolLpMint",
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "owner",
+          "name": "userTokenAccount1",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "This is for transferring lamports for kickstart"
-          ]
+          "isSigner": false
         },
         {
-          "name": "memo",
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "targetTokenId",
-          "type": "u16"
+          "name": "maximumBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
         }
       ],
       "returns": "u64"
     },
     {
-      "name": "propellerCreateOwnerSwimUsdAta",
-      "docs": [
-        "This ix is used if a propeller engine detects (off-chain) that the target_token_id is not valid",
-        "\"Fallback\" behavior is to check/create just the token bridge mint (swimUSD) token account for the owner",
-        "then call propeller_process_swim_payload_fallback to finish transferring the swimUSD to the owner."
-      ],
+      "name": "crossChainTransferNativeWithPayload",
       "accounts": [
         {
           "name": "propeller",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -3275,8 +3320,8 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
+                "account": "Mint",
+                "path": "swim_usd_mint"
               }
             ]
           }
@@ -3287,94 +3332,93 @@ export type Propeller = {
           "isSigner": true
         },
         {
-          "name": "redeemer",
-          "isMut": false,
+          "name": "tokenBridgeConfig",
+          "isMut": true,
           "isSigner": false,
-          "docs": [
-            "this used to be \"to_owner\".",
-            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-            "will have to be signed when it invokes complete_transfer_with_payload",
-            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "redeemer"
+                "value": "config"
               }
-            ]
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
           }
         },
         {
-          "name": "redeemerEscrow",
+          "name": "userSwimUsdAta",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeVault",
+          "name": "swimUsdMint",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "feeTracker",
+          "name": "custody",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBridge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodySigner",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "propeller"
-              },
+                "value": "custody_signer"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "token_bridge"
+            }
+          }
+        },
+        {
+          "name": "authoritySigner",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "fee"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "payer"
+                "value": "authority_signer"
               }
-            ]
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "token_bridge"
+            }
           }
         },
         {
-          "name": "claim",
-          "isMut": false,
+          "name": "wormholeConfig",
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_address"
-              },
-              {
-                "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_chain"
-              },
-              {
-                "kind": "account",
-                "type": "u64",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_sequence"
+                "kind": "const",
+                "type": "string",
+                "value": "Bridge"
               }
             ],
             "programId": {
@@ -3386,7 +3430,12 @@ export type Propeller = {
           }
         },
         {
-          "name": "swimPayloadMessage",
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "wormholeEmitter",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -3394,56 +3443,112 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "propeller"
-              },
+                "value": "emitter"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "wormholeSequence",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "swim_payload"
+                "value": "Sequence"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "claim"
+                "path": "wormhole_emitter"
               }
-            ]
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
           }
         },
         {
-          "name": "tokenIdMap",
-          "isMut": false,
+          "name": "wormholeFeeCollector",
+          "isMut": true,
           "isSigner": false,
-          "docs": [
-            "deseraizlied as a `TokenIdMap`. if it does exist, then engine should have called",
-            "propeller_create_owner_token_accounts instead"
-          ]
-        },
-        {
-          "name": "swimUsdMint",
-          "isMut": false,
-          "isSigner": false
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_collector"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
         },
         {
-          "name": "owner",
+          "name": "sender",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "ownerSwimUsdAta",
-          "isMut": true,
           "isSigner": false,
           "docs": [
-            "Note: specifying TokenAccount type here since only one token account to initialize so no need to",
-            "\"guess and check\" which token accounts need to be initialized"
-          ]
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
+            "Transfers with payload also include the address of the account or contract",
+            "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+            "EVM chains, i.e. it is the address of the immediate caller of the token",
+            "bridge transaction.",
+            "Since on Solana, a transaction can have multiple different signers, getting",
+            "this information is not so straightforward.",
+            "The strategy we use to figure out the sender of the transaction is to",
+            "require an additional signer ([`SenderAccount`]) for the transaction.",
+            "If the transaction was sent by a user wallet directly, then this may just be",
+            "the wallet's pubkey. If, however, the transaction was initiated by a",
+            "program, then we require this to be a PDA derived from the sender program's",
+            "id and the string \"sender\". In this case, the sender program must also",
+            "attach its program id to the instruction data. If the PDA verification",
+            "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+            "transaction), then the program's id is attached to the VAA as the sender,",
+            "otherwise the transaction is rejected.",
+            "",
+            "Note that a program may opt to forego the PDA derivation and instead just",
+            "pass on the original wallet as the wallet account (or any other signer, as",
+            "long as they don't provide their program_id in the instruction data). The",
+            "sender address is provided as a means for protocols to verify on the",
+            "receiving end that the message was emitted by a contract they trust, so",
+            "foregoing this check is not advised. If the receiving contract needs to know",
+            "the sender wallet's address too, then that information can be included in",
+            "the additional payload, along with any other data that the protocol needs to",
+            "send across. The legitimacy of the attached data can be verified by checking",
+            "that the sender contract is a trusted one.",
+            "",
+            "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+            "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+            "that case the PDA's address will directly be encoded into the payload",
+            "instead of the sender program's id."
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "sender"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
+          "name": "wormhole",
           "isMut": false,
           "isSigner": false
         },
@@ -3453,12 +3558,7 @@ export type Propeller = {
           "isSigner": false
         },
         {
-          "name": "aggregator",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePool",
+          "name": "targetChainMap",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -3466,77 +3566,59 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
+                "value": "propeller"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
+                "account": "Propeller",
+                "path": "propeller"
               },
               {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_chain"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
-            }
+            ]
           }
         },
         {
-          "name": "marginalPricePoolToken0Account",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "marginalPricePoolToken1Account",
+          "name": "clock",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "marginalPricePoolLpMint",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64"
         },
         {
-          "name": "memo",
-          "isMut": false,
-          "isSigner": false
+          "name": "targetChain",
+          "type": "u16"
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "type": "bytes"
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "propellerProcessSwimPayloadFallback",
-      "docs": [
-        "This ix is used if a propeller engine detects (off-chain) that the payload.target_token_id is not valid",
-        "this will transfer the swimUSD to the owner (will still kickstart if requested)"
-      ],
+      "name": "propellerTransferNativeWithPayload",
       "accounts": [
         {
           "name": "propeller",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -3548,8 +3630,8 @@ export type Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
+                "account": "Mint",
+                "path": "swim_usd_mint"
               }
             ]
           }
@@ -3560,33 +3642,15 @@ export type Propeller = {
           "isSigner": true
         },
         {
-          "name": "claim",
-          "isMut": false,
+          "name": "tokenBridgeConfig",
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_address"
-              },
-              {
-                "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_chain"
-              },
-              {
-                "kind": "account",
-                "type": "u64",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_sequence"
+                "kind": "const",
+                "type": "string",
+                "value": "config"
               }
             ],
             "programId": {
@@ -3598,31 +3662,65 @@ export type Propeller = {
           }
         },
         {
-          "name": "swimClaim",
+          "name": "userSwimUsdAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBridge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodySigner",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "propeller"
-              },
+                "value": "custody_signer"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "token_bridge"
+            }
+          }
+        },
+        {
+          "name": "authoritySigner",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "claim"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "claim"
+                "value": "authority_signer"
               }
-            ]
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "token_bridge"
+            }
           }
         },
         {
-          "name": "swimPayloadMessage",
+          "name": "wormholeConfig",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -3630,69 +3728,138 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "swim_payload"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "claim"
+                "value": "Bridge"
               }
-            ]
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
           }
         },
         {
-          "name": "swimPayloadMessagePayer",
+          "name": "wormholeMessage",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "redeemer",
-          "isMut": false,
+          "name": "wormholeEmitter",
+          "isMut": true,
           "isSigner": false,
-          "docs": [
-            "this used to be \"to_owner\".",
-            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-            "will have to be signed when it invokes complete_transfer_with_payload",
-            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "redeemer"
+                "value": "emitter"
               }
-            ]
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
           }
         },
         {
-          "name": "redeemerEscrow",
+          "name": "wormholeSequence",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "Sequence"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "wormhole_emitter"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
         },
         {
-          "name": "tokenIdMap",
-          "isMut": false,
+          "name": "wormholeFeeCollector",
+          "isMut": true,
           "isSigner": false,
-          "docs": [
-            "deserialized as a `TokenIdMap`. if it does exist, then engine should have called",
-            "propeller_create_owner_token_accounts instead"
-          ]
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_collector"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
         },
         {
-          "name": "userTransferAuthority",
+          "name": "sender",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false,
+          "docs": [
+            "Transfers with payload also include the address of the account or contract",
+            "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+            "EVM chains, i.e. it is the address of the immediate caller of the token",
+            "bridge transaction.",
+            "Since on Solana, a transaction can have multiple different signers, getting",
+            "this information is not so straightforward.",
+            "The strategy we use to figure out the sender of the transaction is to",
+            "require an additional signer ([`SenderAccount`]) for the transaction.",
+            "If the transaction was sent by a user wallet directly, then this may just be",
+            "the wallet's pubkey. If, however, the transaction was initiated by a",
+            "program, then we require this to be a PDA derived from the sender program's",
+            "id and the string \"sender\". In this case, the sender program must also",
+            "attach its program id to the instruction data. If the PDA verification",
+            "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+            "transaction), then the program's id is attached to the VAA as the sender,",
+            "otherwise the transaction is rejected.",
+            "",
+            "Note that a program may opt to forego the PDA derivation and instead just",
+            "pass on the original wallet as the wallet account (or any other signer, as",
+            "long as they don't provide their program_id in the instruction data). The",
+            "sender address is provided as a means for protocols to verify on the",
+            "receiving end that the message was emitted by a contract they trust, so",
+            "foregoing this check is not advised. If the receiving contract needs to know",
+            "the sender wallet's address too, then that information can be included in",
+            "the additional payload, along with any other data that the protocol needs to",
+            "send across. The legitimacy of the attached data can be verified by checking",
+            "that the sender contract is a trusted one.",
+            "",
+            "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+            "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+            "that case the PDA's address will directly be encoded into the payload",
+            "instead of the sender program's id."
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "sender"
+              }
+            ]
+          }
         },
         {
-          "name": "userSwimUsdAta",
-          "isMut": true,
+          "name": "wormhole",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -3701,36 +3868,107 @@ export type Propeller = {
           "isSigner": false
         },
         {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "targetChainMap",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_chain"
+              }
+            ]
+          }
         },
         {
-          "name": "memo",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "clock",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "aggregator",
+          "name": "rent",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
         },
         {
-          "name": "feeVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "this is \"to_fees\"",
-            "recipient of fees for executing complete transfer (e.g. relayer)"
-          ]
+          "name": "targetChain",
+          "type": "u16"
         },
         {
-          "name": "feeTracker",
+          "name": "owner",
+          "type": "bytes"
+        },
+        {
+          "name": "gasKickstart",
+          "type": "bool"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        },
+        {
+          "name": "targetTokenId",
+          "type": "u16"
+        },
+        {
+          "name": "minOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        },
+        {
+          "name": "receiverData",
+          "type": {
+            "option": "bytes"
+          }
+        },
+        {
+          "name": "relayerExclusivity",
+          "type": {
+            "option": {
+              "defined": "RelayerExclusivity"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "completeNativeWithPayload",
+      "accounts": [
+        {
+          "name": "propeller",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -3740,27 +3978,22 @@ export type Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Propeller",
                 "path": "propeller.swim_usd_mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "payer"
               }
             ]
           }
         },
         {
-          "name": "marginalPricePool",
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenBridgeConfig",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -3768,1018 +4001,10276 @@ export type Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
+                "value": "config"
               }
             ],
             "programId": {
               "kind": "account",
               "type": "publicKey",
-              "path": "two_pool_program"
+              "account": "Propeller",
+              "path": "propeller"
             }
           }
         },
         {
-          "name": "marginalPricePoolToken0Account",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePoolToken1Account",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePoolLpMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
+          "name": "message",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "This is for transferring lamports for kickstart"
+            "contains the VAA",
+            "{",
+            "...MessageData:",
+            "payload: PayloadTransferWithPayload = {",
+            "pub amount: U256,",
+            "}",
+            "}"
           ]
-        }
-      ],
-      "args": [],
-      "returns": "u64"
-    }
-  ],
-  "accounts": [
-    {
-      "name": "feeTracker",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "payer",
-            "type": "publicKey"
-          },
-          {
-            "name": "feesOwed",
-            "type": "u64"
-          },
-          {
-            "name": "feesMint",
-            "type": "publicKey"
+        },
+        {
+          "name": "claim",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "seeds = [",
+            "vaa.emitter_address, vaa.emitter_chain, vaa.sequence",
+            "],",
+            "seeds::program = token_bridge"
+          ]
+        },
+        {
+          "name": "endpoint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "to",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "owned by redeemer. \"redeemerEscrow\""
+          ]
+        },
+        {
+          "name": "redeemer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)",
+            "TODO: this is a little weird. i think we can safely assume that the `vaa.to` will be this programId",
+            "and that the `redeemer` account will be the PDA derived from [\"redeemer\"], seeds::program = propeller::id()"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "targetChainMap",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "targetChain",
-            "type": "u16"
-          },
-          {
-            "name": "targetAddress",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+        },
+        {
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "this is \"to_fees\"",
+            "recipient of fees for executing complete transfer (e.g. relayer)"
+          ]
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodySigner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wormhole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBridge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "tokenIdMap",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "outputTokenIndex",
-            "type": "u16"
-          },
-          {
-            "name": "pool",
-            "type": "publicKey"
-          },
-          {
-            "name": "poolTokenIndex",
-            "type": "u8"
-          },
-          {
-            "name": "poolTokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "poolIx",
-            "type": {
-              "defined": "PoolInstruction"
-            }
-          },
-          {
-            "name": "bump",
-            "type": "u8"
+        },
+        {
+          "name": "sourceChainMap",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "`TargetChainMap` of the chain the transfer was sent from. Holds the chain's inbound rate limit"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "TargetChainMap",
+                "path": "source_chain_map.target_chain"
+              }
+            ]
           }
-        ]
-      }
+        }
+      ],
+      "args": []
     },
     {
-      "name": "propeller",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "nonce",
-            "type": "u32"
-          },
-          {
-            "name": "admin",
-            "type": "publicKey"
-          },
-          {
-            "name": "wormhole",
-            "type": "publicKey"
-          },
-          {
-            "name": "tokenBridge",
-            "type": "publicKey"
-          },
-          {
-            "name": "swimUsdMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "senderBump",
-            "type": "u8"
-          },
-          {
-            "name": "redeemerBump",
-            "type": "u8"
-          },
-          {
-            "name": "gasKickstartAmount",
-            "type": "u64"
-          },
-          {
-            "name": "secpVerifyInitFee",
-            "type": "u64"
-          },
-          {
-            "name": "secpVerifyFee",
-            "type": "u64"
-          },
-          {
-            "name": "postVaaFee",
-            "type": "u64"
-          },
-          {
-            "name": "initAtaFee",
-            "type": "u64"
-          },
-          {
-            "name": "completeWithPayloadFee",
-            "type": "u64"
-          },
-          {
-            "name": "processSwimPayloadFee",
-            "type": "u64"
-          },
-          {
-            "name": "marginalPricePool",
-            "type": "publicKey"
-          },
-          {
-            "name": "marginalPricePoolTokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "marginalPricePoolTokenIndex",
-            "type": "u8"
-          },
-          {
-            "name": "feeVault",
-            "type": "publicKey"
-          },
-          {
-            "name": "aggregator",
-            "type": "publicKey"
-          }
-        ]
-      }
-    },
-    {
-      "name": "swimClaim",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "claimed",
-            "type": "bool"
+      "name": "processSwimPayload",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "swimPayloadMessage",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "swimPayloadMessagePayer",
-            "docs": [
-              "payer of `CompleteWithPayload` that will get the lamports",
-              "when `SwimPayloadMessage` is closed after `ProcessSwimPayload`"
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "claim",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
+              }
             ],
-            "type": "publicKey"
-          },
-          {
-            "name": "claim",
-            "type": "publicKey"
-          },
-          {
-            "name": "vaaEmitterAddress",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "vaaEmitterChain",
-            "type": "u16"
-          },
-          {
-            "name": "vaaSequence",
-            "type": "u64"
-          },
-          {
-            "name": "transferAmount",
-            "type": "u64"
-          },
-          {
-            "name": "swimPayloadVersion",
-            "type": "u8"
-          },
-          {
-            "name": "owner",
-            "type": "publicKey"
-          },
-          {
-            "name": "propellerEnabled",
-            "type": "bool"
-          },
-          {
-            "name": "gasKickstart",
-            "type": "bool"
-          },
-          {
-            "name": "maxFee",
-            "type": "u64"
-          },
-          {
-            "name": "targetTokenId",
-            "type": "u16"
-          },
-          {
-            "name": "memo",
-            "type": {
-              "array": [
-                "u8",
-                16
-              ]
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
             }
           }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "InitializeParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "gasKickstartAmount",
-            "type": "u64"
-          },
-          {
-            "name": "secpVerifyInitFee",
-            "type": "u64"
-          },
-          {
-            "name": "secpVerifyFee",
-            "type": "u64"
-          },
-          {
-            "name": "postVaaFee",
-            "type": "u64"
-          },
-          {
-            "name": "completeWithPayloadFee",
-            "type": "u64"
-          },
-          {
-            "name": "initAtaFee",
-            "type": "u64"
-          },
-          {
-            "name": "processSwimPayloadFee",
-            "type": "u64"
-          },
-          {
-            "name": "marginalPricePool",
-            "type": "publicKey"
-          },
-          {
-            "name": "marginalPricePoolTokenIndex",
-            "type": "u8"
-          },
-          {
-            "name": "marginalPricePoolTokenMint",
-            "type": "publicKey"
+        },
+        {
+          "name": "swimClaim",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "claim"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "CompleteNativeWithPayloadData",
-      "type": {
-        "kind": "struct",
-        "fields": []
-      }
-    },
-    {
-      "name": "VerifySignaturesData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "signers",
-            "docs": [
-              "instruction indices of signers (-1 for missing)"
-            ],
-            "type": {
-              "array": [
-                "i8",
-                19
-              ]
-            }
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "TransferWithPayloadData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nonce",
-            "type": "u32"
-          },
-          {
-            "name": "amount",
-            "type": "u64"
-          },
-          {
-            "name": "targetAddress",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "targetChain",
-            "type": "u16"
-          },
-          {
-            "name": "payload",
-            "type": "bytes"
-          },
-          {
-            "name": "cpiProgramId",
-            "type": {
-              "option": "publicKey"
-            }
+        },
+        {
+          "name": "swimPayloadMessagePayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "redeemer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "this used to be \"to_owner\".",
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "PostMessageData",
-      "docs": [
-        "Data that goes into a [`wormhole::Instruction::PostMessage`]"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nonce",
-            "docs": [
-              "Unique nonce for this message"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "payload",
-            "docs": [
-              "Message payload"
-            ],
-            "type": "bytes"
-          },
-          {
-            "name": "consistencyLevel",
-            "docs": [
-              "Commitment Level required for an attestation to be produced"
+        },
+        {
+          "name": "redeemerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_token_id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
             ],
-            "type": {
-              "defined": "ConsistencyLevel"
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
             }
           }
-        ]
-      }
-    },
-    {
-      "name": "BridgeData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "guardianSetIndex",
-            "docs": [
-              "The current guardian set index, used to decide which signature sets to accept."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "lastLamports",
-            "docs": [
-              "Lamports in the collection account"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "config",
-            "docs": [
-              "Bridge configuration, which is set once upon initialization."
-            ],
-            "type": {
-              "defined": "BridgeConfig"
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "BridgeConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "guardianSetExpirationTime",
-            "docs": [
-              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
-              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
-              "this period we still trust the old guardian set."
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "fee",
-            "docs": [
-              "Amount of lamports that needs to be paid to the protocol to post a message"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "MessageData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "vaaVersion",
-            "docs": [
-              "Header of the posted VAA"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "consistencyLevel",
-            "docs": [
-              "Level of consistency requested by the emitter"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "vaaTime",
-            "docs": [
-              "Time the vaa was submitted"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "vaaSignatureAccount",
-            "docs": [
-              "Account where signatures are stored"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "submissionTime",
-            "docs": [
-              "Time the posted message was created"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "nonce",
-            "docs": [
-              "Unique nonce for this message"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "sequence",
-            "docs": [
-              "Sequence number of this message"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "emitterChain",
-            "docs": [
-              "Emitter of the message"
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "emitterAddress",
-            "docs": [
-              "Emitter of the message"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "payload",
-            "docs": [
-              "Message payload aka `PayloadTransferWithPayload`"
-            ],
-            "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "ClaimData",
-      "docs": [
-        "Wormhole Claim Account data"
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "claimed",
-            "type": "bool"
-          }
-        ]
-      }
-    },
-    {
-      "name": "PostVAAData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "version",
-            "type": "u8"
-          },
-          {
-            "name": "guardianSetIndex",
-            "type": "u32"
-          },
-          {
-            "name": "timestamp",
-            "type": "u32"
-          },
-          {
-            "name": "nonce",
-            "type": "u32"
-          },
-          {
-            "name": "emitterChain",
-            "type": "u16"
-          },
-          {
-            "name": "emitterAddress",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "sequence",
-            "type": "u64"
-          },
-          {
-            "name": "consistencyLevel",
-            "type": "u8"
-          },
-          {
-            "name": "payload",
-            "type": "bytes"
-          }
-        ]
-      }
+      "args": [
+        {
+          "name": "targetTokenId",
+          "type": "u16"
+        },
+        {
+          "name": "minOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
     },
     {
-      "name": "PoolInstruction",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Transfer"
+      "name": "propellerCompleteNativeWithPayload",
+      "accounts": [
+        {
+          "name": "completeNativeWithPayload",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "tokenBridgeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "config"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "message",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "contains the VAA",
+                "{",
+                "...MessageData:",
+                "payload: PayloadTransferWithPayload = {",
+                "pub amount: U256,",
+                "}",
+                "}"
+              ]
+            },
+            {
+              "name": "claim",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "seeds = [",
+                "vaa.emitter_address, vaa.emitter_chain, vaa.sequence",
+                "],",
+                "seeds::program = token_bridge"
+              ]
+            },
+            {
+              "name": "endpoint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "to",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "owned by redeemer. \"redeemerEscrow\""
+              ]
+            },
+            {
+              "name": "redeemer",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+                "will have to be signed when it invokes complete_transfer_with_payload",
+                "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+                "(NOT the `to` account)",
+                "TODO: this is a little weird. i think we can safely assume that the `vaa.to` will be this programId",
+                "and that the `redeemer` account will be the PDA derived from [\"redeemer\"], seeds::program = propeller::id()"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "redeemer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "this is \"to_fees\"",
+                "recipient of fees for executing complete transfer (e.g. relayer)"
+              ]
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custodySigner",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormhole",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swimPayloadMessage",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "swim_payload"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "claim"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "sourceChainMap",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "`TargetChainMap` of the chain the transfer was sent from. Holds the chain's inbound rate limit"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "u16",
+                    "account": "TargetChainMap",
+                    "path": "source_chain_map.target_chain"
+                  }
+                ]
+              }
+            }
+          ]
+        },
+        {
+          "name": "feeTracker",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "defined": "Box<Account<'info,Mint>>"
+                },
+                "account": "CompleteNativeWithPayload",
+                "path": "complete_native_with_payload.swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "defined": "Signer<'info>"
+                },
+                "account": "CompleteNativeWithPayload",
+                "path": "complete_native_with_payload.payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feePriceCache",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "defined": "Box<Account<'info,Propeller>>"
+                },
+                "account": "CompleteNativeWithPayload",
+                "path": "complete_native_with_payload.propeller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "memo",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "propellerCreateOwnerTokenAccounts",
+      "docs": [
+        "Valid target_token_id *"
+      ],
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "redeemer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "this used to be \"to_owner\".",
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redeemerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTracker",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "claim",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.target_token_id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_token_0_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_token_1_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolToken0Mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolToken1Mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolLpMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userPoolToken0Account",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userPoolToken1Account",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feePriceCache",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memo",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "propellerProcessSwimPayload",
+      "docs": [
+        "Note: passing in target_token_id here due to PDA seed derivation.",
+        "for propeller_process_swim_payload, require_eq!(target_token_id, propeller_message.target_token_id);"
+      ],
+      "accounts": [
+        {
+          "name": "processSwimPayload",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "claim",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "type": {
+                      "array": [
+                        "u8",
+                        32
+                      ]
+                    },
+                    "account": "SwimPayloadMessage",
+                    "path": "swim_payload_message.vaa_emitter_address"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "u16",
+                    "account": "SwimPayloadMessage",
+                    "path": "swim_payload_message.vaa_emitter_chain"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "u64",
+                    "account": "SwimPayloadMessage",
+                    "path": "swim_payload_message.vaa_sequence"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "swimClaim",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "claim"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "claim"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "swimPayloadMessage",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "swim_payload"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "claim"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "swimPayloadMessagePayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "redeemer",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "this used to be \"to_owner\".",
+                "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+                "will have to be signed when it invokes complete_transfer_with_payload",
+                "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+                "(NOT the `to` account)"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "redeemer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "redeemerEscrow",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenIdMap",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "token_id"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "arg",
+                    "type": "u16",
+                    "path": "target_token_id"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_1.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "two_pool_program"
+                }
+              }
+            },
+            {
+              "name": "poolTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lpMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "governanceFee",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTransferAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userLpTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "feePriceCache",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "defined": "Box<Account<'info,Propeller>>"
+                },
+                "account": "ProcessSwimPayload",
+                "path": "process_swim_payload.propeller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "this is \"to_fees\"",
+            "recipient of fees for executing complete transfer (e.g. relayer)"
+          ]
+        },
+        {
+          "name": "feeTracker",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "ProcessSwimPayload",
+                "path": "process_swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "defined": "Signer<'info>"
+                },
+                "account": "ProcessSwimPayload",
+                "path": "process_swim_payload.payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is for transferring lamports for kickstart"
+          ]
+        },
+        {
+          "name": "memo",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetTokenId",
+          "type": "u16"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerCreateOwnerSwimUsdAta",
+      "docs": [
+        "This ix is used if a propeller engine detects (off-chain) that the target_token_id is not valid",
+        "\"Fallback\" behavior is to check/create just the token bridge mint (swimUSD) token account for the owner",
+        "then call propeller_process_swim_payload_fallback to finish transferring the swimUSD to the owner."
+      ],
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "redeemer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "this used to be \"to_owner\".",
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redeemerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeTracker",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "claim",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "deseraizlied as a `TokenIdMap`. if it does exist, then engine should have called",
+            "propeller_create_owner_token_accounts instead"
+          ]
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerSwimUsdAta",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Note: specifying TokenAccount type here since only one token account to initialize so no need to",
+            "\"guess and check\" which token accounts need to be initialized"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feePriceCache",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "memo",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "propellerProcessSwimPayloadFallback",
+      "docs": [
+        "This ix is used if a propeller engine detects (off-chain) that the payload.target_token_id is not valid",
+        "this will transfer the swimUSD to the owner (will still kickstart if requested)"
+      ],
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "claim",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "swimClaim",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "claim"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "swimPayloadMessagePayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "redeemer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "this used to be \"to_owner\".",
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redeemerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "deserialized as a `TokenIdMap`. if it does exist, then engine should have called",
+            "propeller_create_owner_token_accounts instead"
+          ]
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userSwimUsdAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memo",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feePriceCache",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "this is \"to_fees\"",
+            "recipient of fees for executing complete transfer (e.g. relayer)"
+          ]
+        },
+        {
+          "name": "feeTracker",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is for transferring lamports for kickstart"
+          ]
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerProcessExpiredSwimPayload",
+      "docs": [
+        "Permissionless swimUSD fallback for swim payloads that weren't processed within",
+        "`propeller.swim_payload_expiry` seconds. Fees are capped at the swim payload's `max_fee`",
+        "& the `swim_receive` callback of swim receivers is skipped"
+      ],
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "claim",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "swimClaim",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "claim"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "swimPayloadMessagePayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "redeemer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "this used to be \"to_owner\".",
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redeemerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "deserialized as a `TokenIdMap`. if it does exist, then engine should have called",
+            "propeller_create_owner_token_accounts instead"
+          ]
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userSwimUsdAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memo",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feePriceCache",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "this is \"to_fees\"",
+            "recipient of fees for executing complete transfer (e.g. relayer)"
+          ]
+        },
+        {
+          "name": "feeTracker",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is for transferring lamports for kickstart"
+          ]
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "quotePropellerFees",
+      "docs": [
+        "Read-only. Returns (via return data) the swimUSD fees a propeller engine would currently charge",
+        "to deliver a swim payload of `params.swim_payload_len` bytes for `target_token_id`"
+      ],
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_token_id"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_token_0_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_token_1_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolToken0Mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolToken1Mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolLpMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "(i.e. `SwimPayloadMessage::token_account_owner()`). Only used to derive the ATAs."
+          ]
+        },
+        {
+          "name": "ownerPoolToken0Account",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerPoolToken1Account",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerLpTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feePriceCache",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetTokenId",
+          "type": "u16"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": "QuotePropellerFeesParams"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "PropellerFeesQuote"
+      }
+    },
+    {
+      "name": "crossChainAddAndTransfer",
+      "accounts": [
+        {
+          "name": "add",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_1.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "two_pool_program"
+                }
+              }
+            },
+            {
+              "name": "poolTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lpMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "governanceFee",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTransferAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userLpTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "transfer",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "tokenBridgeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "config"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "userSwimUsdAta",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custodySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "custody_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "authoritySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "authority_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "wormholeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Bridge"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeMessage",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "wormholeEmitter",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeSequence",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Sequence"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "wormhole_emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeFeeCollector",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "fee_collector"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "sender",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "Transfers with payload also include the address of the account or contract",
+                "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+                "EVM chains, i.e. it is the address of the immediate caller of the token",
+                "bridge transaction.",
+                "Since on Solana, a transaction can have multiple different signers, getting",
+                "this information is not so straightforward.",
+                "The strategy we use to figure out the sender of the transaction is to",
+                "require an additional signer ([`SenderAccount`]) for the transaction.",
+                "If the transaction was sent by a user wallet directly, then this may just be",
+                "the wallet's pubkey. If, however, the transaction was initiated by a",
+                "program, then we require this to be a PDA derived from the sender program's",
+                "id and the string \"sender\". In this case, the sender program must also",
+                "attach its program id to the instruction data. If the PDA verification",
+                "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+                "transaction), then the program's id is attached to the VAA as the sender,",
+                "otherwise the transaction is rejected.",
+                "",
+                "Note that a program may opt to forego the PDA derivation and instead just",
+                "pass on the original wallet as the wallet account (or any other signer, as",
+                "long as they don't provide their program_id in the instruction data). The",
+                "sender address is provided as a means for protocols to verify on the",
+                "receiving end that the message was emitted by a contract they trust, so",
+                "foregoing this check is not advised. If the receiving contract needs to know",
+                "the sender wallet's address too, then that information can be included in",
+                "the additional payload, along with any other data that the protocol needs to",
+                "send across. The legitimacy of the attached data can be verified by checking",
+                "that the sender contract is a trusted one.",
+                "",
+                "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+                "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+                "that case the PDA's address will directly be encoded into the payload",
+                "instead of the sender program's id."
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "sender"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "wormhole",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetChainMap",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "arg",
+                    "type": "u16",
+                    "path": "target_chain"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "targetChain",
+          "type": "u16"
+        },
+        {
+          "name": "inputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "type": "bytes"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerAddAndTransfer",
+      "accounts": [
+        {
+          "name": "add",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_1.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "two_pool_program"
+                }
+              }
+            },
+            {
+              "name": "poolTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lpMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "governanceFee",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTransferAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userLpTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "transfer",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "tokenBridgeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "config"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "userSwimUsdAta",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custodySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "custody_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "authoritySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "authority_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "wormholeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Bridge"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeMessage",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "wormholeEmitter",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeSequence",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Sequence"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "wormhole_emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeFeeCollector",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "fee_collector"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "sender",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "Transfers with payload also include the address of the account or contract",
+                "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+                "EVM chains, i.e. it is the address of the immediate caller of the token",
+                "bridge transaction.",
+                "Since on Solana, a transaction can have multiple different signers, getting",
+                "this information is not so straightforward.",
+                "The strategy we use to figure out the sender of the transaction is to",
+                "require an additional signer ([`SenderAccount`]) for the transaction.",
+                "If the transaction was sent by a user wallet directly, then this may just be",
+                "the wallet's pubkey. If, however, the transaction was initiated by a",
+                "program, then we require this to be a PDA derived from the sender program's",
+                "id and the string \"sender\". In this case, the sender program must also",
+                "attach its program id to the instruction data. If the PDA verification",
+                "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+                "transaction), then the program's id is attached to the VAA as the sender,",
+                "otherwise the transaction is rejected.",
+                "",
+                "Note that a program may opt to forego the PDA derivation and instead just",
+                "pass on the original wallet as the wallet account (or any other signer, as",
+                "long as they don't provide their program_id in the instruction data). The",
+                "sender address is provided as a means for protocols to verify on the",
+                "receiving end that the message was emitted by a contract they trust, so",
+                "foregoing this check is not advised. If the receiving contract needs to know",
+                "the sender wallet's address too, then that information can be included in",
+                "the additional payload, along with any other data that the protocol needs to",
+                "send across. The legitimacy of the attached data can be verified by checking",
+                "that the sender contract is a trusted one.",
+                "",
+                "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+                "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+                "that case the PDA's address will directly be encoded into the payload",
+                "instead of the sender program's id."
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "sender"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "wormhole",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetChainMap",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "arg",
+                    "type": "u16",
+                    "path": "target_chain"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "targetChain",
+          "type": "u16"
+        },
+        {
+          "name": "inputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          }
+        },
+        {
+          "name": "owner",
+          "type": "bytes"
+        },
+        {
+          "name": "gasKickstart",
+          "type": "bool"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        },
+        {
+          "name": "targetTokenId",
+          "type": "u16"
+        },
+        {
+          "name": "targetMinOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        },
+        {
+          "name": "receiverData",
+          "type": {
+            "option": "bytes"
+          }
+        },
+        {
+          "name": "relayerExclusivity",
+          "type": {
+            "option": {
+              "defined": "RelayerExclusivity"
+            }
+          }
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "crossChainSwapExactInputAndTransfer",
+      "accounts": [
+        {
+          "name": "swap",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_1.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "two_pool_program"
+                }
+              }
+            },
+            {
+              "name": "poolTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lpMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "governanceFee",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTransferAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "transfer",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "tokenBridgeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "config"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "userSwimUsdAta",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custodySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "custody_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "authoritySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "authority_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "wormholeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Bridge"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeMessage",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "wormholeEmitter",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeSequence",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Sequence"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "wormhole_emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeFeeCollector",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "fee_collector"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "sender",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "Transfers with payload also include the address of the account or contract",
+                "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+                "EVM chains, i.e. it is the address of the immediate caller of the token",
+                "bridge transaction.",
+                "Since on Solana, a transaction can have multiple different signers, getting",
+                "this information is not so straightforward.",
+                "The strategy we use to figure out the sender of the transaction is to",
+                "require an additional signer ([`SenderAccount`]) for the transaction.",
+                "If the transaction was sent by a user wallet directly, then this may just be",
+                "the wallet's pubkey. If, however, the transaction was initiated by a",
+                "program, then we require this to be a PDA derived from the sender program's",
+                "id and the string \"sender\". In this case, the sender program must also",
+                "attach its program id to the instruction data. If the PDA verification",
+                "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+                "transaction), then the program's id is attached to the VAA as the sender,",
+                "otherwise the transaction is rejected.",
+                "",
+                "Note that a program may opt to forego the PDA derivation and instead just",
+                "pass on the original wallet as the wallet account (or any other signer, as",
+                "long as they don't provide their program_id in the instruction data). The",
+                "sender address is provided as a means for protocols to verify on the",
+                "receiving end that the message was emitted by a contract they trust, so",
+                "foregoing this check is not advised. If the receiving contract needs to know",
+                "the sender wallet's address too, then that information can be included in",
+                "the additional payload, along with any other data that the protocol needs to",
+                "send across. The legitimacy of the attached data can be verified by checking",
+                "that the sender contract is a trusted one.",
+                "",
+                "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+                "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+                "that case the PDA's address will directly be encoded into the payload",
+                "instead of the sender program's id."
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "sender"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "wormhole",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetChainMap",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "arg",
+                    "type": "u16",
+                    "path": "target_chain"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "targetChain",
+          "type": "u16"
+        },
+        {
+          "name": "exactInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "owner",
+          "type": "bytes"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerSwapExactInputAndTransfer",
+      "accounts": [
+        {
+          "name": "swap",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_1.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "two_pool_program"
+                }
+              }
+            },
+            {
+              "name": "poolTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lpMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "governanceFee",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTransferAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "transfer",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "tokenBridgeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "config"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "userSwimUsdAta",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custodySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "custody_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "authoritySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "authority_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "wormholeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Bridge"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeMessage",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "wormholeEmitter",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeSequence",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Sequence"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "wormhole_emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeFeeCollector",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "fee_collector"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "sender",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "Transfers with payload also include the address of the account or contract",
+                "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+                "EVM chains, i.e. it is the address of the immediate caller of the token",
+                "bridge transaction.",
+                "Since on Solana, a transaction can have multiple different signers, getting",
+                "this information is not so straightforward.",
+                "The strategy we use to figure out the sender of the transaction is to",
+                "require an additional signer ([`SenderAccount`]) for the transaction.",
+                "If the transaction was sent by a user wallet directly, then this may just be",
+                "the wallet's pubkey. If, however, the transaction was initiated by a",
+                "program, then we require this to be a PDA derived from the sender program's",
+                "id and the string \"sender\". In this case, the sender program must also",
+                "attach its program id to the instruction data. If the PDA verification",
+                "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+                "transaction), then the program's id is attached to the VAA as the sender,",
+                "otherwise the transaction is rejected.",
+                "",
+                "Note that a program may opt to forego the PDA derivation and instead just",
+                "pass on the original wallet as the wallet account (or any other signer, as",
+                "long as they don't provide their program_id in the instruction data). The",
+                "sender address is provided as a means for protocols to verify on the",
+                "receiving end that the message was emitted by a contract they trust, so",
+                "foregoing this check is not advised. If the receiving contract needs to know",
+                "the sender wallet's address too, then that information can be included in",
+                "the additional payload, along with any other data that the protocol needs to",
+                "send across. The legitimacy of the attached data can be verified by checking",
+                "that the sender contract is a trusted one.",
+                "",
+                "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+                "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+                "that case the PDA's address will directly be encoded into the payload",
+                "instead of the sender program's id."
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "sender"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "wormhole",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetChainMap",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "arg",
+                    "type": "u16",
+                    "path": "target_chain"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "targetChain",
+          "type": "u16"
+        },
+        {
+          "name": "exactInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "owner",
+          "type": "bytes"
+        },
+        {
+          "name": "gasKickstart",
+          "type": "bool"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        },
+        {
+          "name": "targetTokenId",
+          "type": "u16"
+        },
+        {
+          "name": "targetMinOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        },
+        {
+          "name": "receiverData",
+          "type": {
+            "option": "bytes"
+          }
+        },
+        {
+          "name": "relayerExclusivity",
+          "type": {
+            "option": {
+              "defined": "RelayerExclusivity"
+            }
+          }
+        }
+      ],
+      "returns": "u64"
+    }
+  ],
+  "accounts": [
+    {
+      "name": "feePriceCache",
+      "docs": [
+        "Latest swimUSD per lamport rate used by the relay ixs to convert their (lamport) fees to swimUSD"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "propeller",
+            "type": "publicKey"
+          },
+          {
+            "name": "swimUsdAtomicPerLamport",
+            "type": {
+              "defined": "BorshDecimal"
+            }
+          },
+          {
+            "name": "lastUpdateTs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTracker",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "feesOwed",
+            "type": "u64"
+          },
+          {
+            "name": "feesMint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "targetChainMap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "targetChain",
+            "type": "u16"
+          },
+          {
+            "name": "targetAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "inboundRateLimit",
+            "docs": [
+              "flow limit on the swimUSD completed from `target_chain`"
+            ],
+            "type": {
+              "defined": "RateLimit"
+            }
+          },
+          {
+            "name": "outboundRateLimit",
+            "docs": [
+              "flow limit on the swimUSD transferred to `target_chain`"
+            ],
+            "type": {
+              "defined": "RateLimit"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenIdMap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outputTokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "poolTokenIndex",
+            "type": "u8"
+          },
+          {
+            "name": "poolTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "poolIx",
+            "type": {
+              "defined": "PoolInstruction"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nextSteps",
+            "docs": [
+              "pool ixs executed in order after `pool_ix`, each one on the output of the previous one.",
+              "the output of the last step is the target token"
+            ],
+            "type": {
+              "vec": {
+                "defined": "PoolStep"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "propeller",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nonce",
+            "type": "u32"
+          },
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "preparedAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "adminTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "pauseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isPaused",
+            "type": "bool"
+          },
+          {
+            "name": "isInboundPaused",
+            "type": "bool"
+          },
+          {
+            "name": "isOutboundPaused",
+            "type": "bool"
+          },
+          {
+            "name": "wormhole",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBridge",
+            "type": "publicKey"
+          },
+          {
+            "name": "swimUsdMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "senderBump",
+            "type": "u8"
+          },
+          {
+            "name": "redeemerBump",
+            "type": "u8"
+          },
+          {
+            "name": "gasKickstartAmount",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyInitFee",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyFee",
+            "type": "u64"
+          },
+          {
+            "name": "postVaaFee",
+            "type": "u64"
+          },
+          {
+            "name": "initAtaFee",
+            "type": "u64"
+          },
+          {
+            "name": "completeWithPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "processSwimPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "swimPayloadExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "feePriceCacheMaxAge",
+            "type": "i64"
+          },
+          {
+            "name": "inboundRateLimit",
+            "type": {
+              "defined": "RateLimit"
+            }
+          },
+          {
+            "name": "outboundRateLimit",
+            "type": {
+              "defined": "RateLimit"
+            }
+          },
+          {
+            "name": "rateLimitAction",
+            "type": {
+              "defined": "RateLimitAction"
+            }
+          },
+          {
+            "name": "largeTransferThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "largeTransferDelay",
+            "type": "i64"
+          },
+          {
+            "name": "marginalPricePool",
+            "type": "publicKey"
+          },
+          {
+            "name": "marginalPricePoolTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "marginalPricePoolTokenIndex",
+            "type": "u8"
+          },
+          {
+            "name": "marginalPriceTwapWindow",
+            "type": "u32"
+          },
+          {
+            "name": "feeVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "aggregator",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxStaleness",
+            "type": "i64"
+          },
+          {
+            "name": "maxConfidenceInterval",
+            "type": {
+              "defined": "BorshDecimal"
+            }
+          },
+          {
+            "name": "oraclePolicy",
+            "type": {
+              "defined": "OraclePolicy"
+            }
+          },
+          {
+            "name": "fallbackOracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "solUsdPool",
+            "type": "publicKey"
+          },
+          {
+            "name": "solUsdPoolSolIndex",
+            "type": "u8"
+          },
+          {
+            "name": "solUsdPoolTwapWindow",
+            "type": "u32"
+          },
+          {
+            "name": "solUsdPoolMaxStaleness",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swimClaim",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "claimed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swimPayloadMessage",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "swimPayloadMessagePayer",
+            "docs": [
+              "payer of `CompleteWithPayload` that will get the lamports",
+              "when `SwimPayloadMessage` is closed after `ProcessSwimPayload`"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "claim",
+            "type": "publicKey"
+          },
+          {
+            "name": "vaaEmitterAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "vaaEmitterChain",
+            "type": "u16"
+          },
+          {
+            "name": "vaaSequence",
+            "type": "u64"
+          },
+          {
+            "name": "transferAmount",
+            "type": "u64"
+          },
+          {
+            "name": "swimPayloadVersion",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "propellerEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "gasKickstart",
+            "type": "bool"
+          },
+          {
+            "name": "maxFee",
+            "type": "u64"
+          },
+          {
+            "name": "targetTokenId",
+            "type": "u16"
+          },
+          {
+            "name": "memo",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          {
+            "name": "minOutputAmount",
+            "docs": [
+              "minimum amount of the target token the owner has to receive whenever the swim payload",
+              "is processed (by the propeller engine or anyone else). 0 for `SwimPayloadVersion::V1` payloads"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "completedTs",
+            "docs": [
+              "unix timestamp of the `CompleteNativeWithPayload` that created this account"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "totalFees",
+            "docs": [
+              "sum of all fees (in swimUSD) charged by the propeller engine so far",
+              "across complete, create owner token accounts & process swim payload.",
+              "can never exceed `max_fee`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "address that initiated the token bridge transfer on the source chain"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "receiverData",
+            "docs": [
+              "set if `owner` is a program implementing the swim receiver callback.",
+              "passed to the receiver after the swim payload is processed"
+            ],
+            "type": {
+              "option": "bytes"
+            }
+          },
+          {
+            "name": "exclusiveRelayer",
+            "docs": [
+              "only this relayer (or the owner) can run the propeller ixs until `exclusivity_deadline`.",
+              "`Pubkey::default()` if the swim payload has no relayer exclusivity"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "exclusivityDeadline",
+            "docs": [
+              "unix timestamp after which anyone can run the propeller ixs"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "releaseTs",
+            "docs": [
+              "unix timestamp until which the swim payload can't be processed because its transfer",
+              "exceeded a rate limit or was a large transfer. 0 if it isn't held"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "UpdateFeesParams",
+      "docs": [
+        "all fees are in LAMPORTS and NOT including rent"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "secpVerifyInitFee",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyFee",
+            "type": "u64"
+          },
+          {
+            "name": "postVaaFee",
+            "type": "u64"
+          },
+          {
+            "name": "initAtaFee",
+            "type": "u64"
+          },
+          {
+            "name": "completeWithPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "processSwimPayloadFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateOracleConfigParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oraclePolicy",
+            "type": {
+              "defined": "OraclePolicy"
+            }
+          },
+          {
+            "name": "solUsdPoolSolIndex",
+            "type": "u8"
+          },
+          {
+            "name": "solUsdPoolTwapWindow",
+            "type": "u32"
+          },
+          {
+            "name": "solUsdPoolMaxStaleness",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateRateLimitsParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inbound",
+            "type": {
+              "defined": "RateLimitConfig"
+            }
+          },
+          {
+            "name": "outbound",
+            "type": {
+              "defined": "RateLimitConfig"
+            }
+          },
+          {
+            "name": "rateLimitAction",
+            "type": {
+              "defined": "RateLimitAction"
+            }
+          },
+          {
+            "name": "largeTransferThreshold",
+            "docs": [
+              "0 => large transfers aren't held"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "largeTransferDelay",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gasKickstartAmount",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyInitFee",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyFee",
+            "type": "u64"
+          },
+          {
+            "name": "postVaaFee",
+            "type": "u64"
+          },
+          {
+            "name": "completeWithPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "initAtaFee",
+            "type": "u64"
+          },
+          {
+            "name": "processSwimPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "swimPayloadExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "feePriceCacheMaxAge",
+            "type": "i64"
+          },
+          {
+            "name": "marginalPricePool",
+            "type": "publicKey"
+          },
+          {
+            "name": "marginalPricePoolTokenIndex",
+            "type": "u8"
+          },
+          {
+            "name": "marginalPricePoolTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "marginalPriceTwapWindow",
+            "type": "u32"
+          },
+          {
+            "name": "maxStaleness",
+            "type": "i64"
+          },
+          {
+            "name": "maxConfidenceInterval",
+            "type": {
+              "defined": "BorshDecimal"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "QuotePropellerFeesParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "swimPayloadLen",
+            "docs": [
+              "length of the encoded swim payload that will be delivered. Determines the size (& rent)",
+              "of the posted VAA account."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "gasKickstart",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PropellerFeesQuote",
+      "docs": [
+        "Fees (in swimUSD atomic units) a propeller engine will deduct when delivering a swim payload,",
+        "broken down by the ix that charges them."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "completeNativeWithPayloadFees",
+            "type": "u64"
+          },
+          {
+            "name": "createOwnerTokenAccountsFees",
+            "docs": [
+              "zero if all of the owner's token accounts already exist"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "processSwimPayloadFees",
+            "type": "u64"
+          },
+          {
+            "name": "totalFees",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolStep",
+      "docs": [
+        "A `RemoveExactBurn` or `SwapExactInput` on `pool` outputting `pool_token_mint`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "poolIx",
+            "type": {
+              "defined": "PoolInstruction"
+            }
+          },
+          {
+            "name": "poolTokenIndex",
+            "type": "u8"
+          },
+          {
+            "name": "poolTokenMint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CompleteNativeWithPayloadData",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "VerifySignaturesData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "signers",
+            "docs": [
+              "instruction indices of signers (-1 for missing)"
+            ],
+            "type": {
+              "array": [
+                "i8",
+                19
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferWithPayloadData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "type": "u32"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "targetAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "targetChain",
+            "type": "u16"
+          },
+          {
+            "name": "payload",
+            "type": "bytes"
+          },
+          {
+            "name": "cpiProgramId",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimit",
+      "docs": [
+        "Flow limit on the amount of swimUSD (in atomic units) that can be transferred within a rolling `window`.",
+        "",
+        "Uses a sliding window counter: the amount transferred in the previous fixed window is weighted by how much",
+        "of it still overlaps with the rolling window ending at `now`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAmount",
+            "docs": [
+              "max amount per `window`. 0 => no limit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window",
+            "docs": [
+              "in seconds"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "currentWindowStart",
+            "type": "i64"
+          },
+          {
+            "name": "currentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "previousAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAmount",
+            "docs": [
+              "0 => no limit"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window",
+            "docs": [
+              "in seconds"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RelayerExclusivity",
+      "docs": [
+        "Only `relayer` (or the owner) may run the propeller ixs for the swim payload",
+        "until `duration` seconds after the wormhole message was observed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "relayer",
+            "docs": [
+              "address of the relayer on the target chain"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "duration",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwimReceiveParams",
+      "docs": [
+        "Args of the `swim_receive` ix a receiver program has to implement.",
+        "",
+        "The ix data is the anchor sighash of `global:swim_receive` followed by the borsh",
+        "serialized `SwimReceiveParams` so that anchor programs can implement it as",
+        "`pub fn swim_receive(ctx: Context<SwimReceive>, params: SwimReceiveParams)`.",
+        "",
+        "Accounts:",
+        "0. `[signer]` swim receiver caller. PDA of the propeller derived from `[SWIM_RECEIVER_CALLER_SEED]`",
+        "1. `[writable]` receiver token account that received `amount` of `token_mint`",
+        "2. .. accounts passed to the propeller ix after the receiver program & the swim receiver caller.",
+        "They're forwarded as non-signers, so the swim receiver caller is the only signer of the callback"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sourceChain",
+            "docs": [
+              "wormhole chain id of the source chain"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "address that initiated the token bridge transfer on the source chain"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "tokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "data",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PostMessageData",
+      "docs": [
+        "Data that goes into a [`wormhole::Instruction::PostMessage`]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "Unique nonce for this message"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "payload",
+            "docs": [
+              "Message payload"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "consistencyLevel",
+            "docs": [
+              "Commitment Level required for an attestation to be produced"
+            ],
+            "type": {
+              "defined": "ConsistencyLevel"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetIndex",
+            "docs": [
+              "The current guardian set index, used to decide which signature sets to accept."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
+            "type": {
+              "defined": "BridgeConfig"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MessageData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vaaVersion",
+            "docs": [
+              "Header of the posted VAA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "consistencyLevel",
+            "docs": [
+              "Level of consistency requested by the emitter"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "vaaTime",
+            "docs": [
+              "Time the vaa was submitted"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "vaaSignatureAccount",
+            "docs": [
+              "Account where signatures are stored"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionTime",
+            "docs": [
+              "Time the posted message was created"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Unique nonce for this message"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "sequence",
+            "docs": [
+              "Sequence number of this message"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "emitterChain",
+            "docs": [
+              "Emitter of the message"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "emitterAddress",
+            "docs": [
+              "Emitter of the message"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "payload",
+            "docs": [
+              "Message payload aka `PayloadTransferWithPayload`"
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ClaimData",
+      "docs": [
+        "Wormhole Claim Account data"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "claimed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PostVAAData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "guardianSetIndex",
+            "type": "u32"
+          },
+          {
+            "name": "timestamp",
+            "type": "u32"
+          },
+          {
+            "name": "nonce",
+            "type": "u32"
+          },
+          {
+            "name": "emitterChain",
+            "type": "u16"
+          },
+          {
+            "name": "emitterAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "sequence",
+            "type": "u64"
+          },
+          {
+            "name": "consistencyLevel",
+            "type": "u8"
+          },
+          {
+            "name": "payload",
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PauseDirection",
+      "docs": [
+        "Inbound: completing transfers & processing swim payloads.",
+        "Outbound: transferring swimUSD to other chains."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "All"
+          },
+          {
+            "name": "Inbound"
+          },
+          {
+            "name": "Outbound"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolInstruction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Transfer"
+          },
+          {
+            "name": "RemoveExactBurn"
+          },
+          {
+            "name": "SwapExactInput"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OraclePolicy",
+      "docs": [
+        "How the SOL/USD prices of the propeller's oracles are combined"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Primary"
+          },
+          {
+            "name": "PrimaryWithFallback"
+          },
+          {
+            "name": "Median"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Switchboard"
+          },
+          {
+            "name": "Pyth"
+          },
+          {
+            "name": "SolUsdPool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitAction",
+      "docs": [
+        "What happens to an inbound transfer that would exceed a rate limit.",
+        "Outbound transfers exceeding a rate limit are always rejected."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Reject"
+          },
+          {
+            "name": "Queue"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwimPayloadVersion",
+      "docs": [
+        "Versions of the swim payload that can be found in a token bridge transfer with payload.",
+        "",
+        "Every version ever emitted by a routing contract has to stay decodable so that",
+        "routing contracts on other chains can be upgraded independently of the propeller."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "V1"
+          },
+          {
+            "name": "V2"
+          },
+          {
+            "name": "V3"
+          },
+          {
+            "name": "V4"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConsistencyLevel",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Confirmed"
+          },
+          {
+            "name": "Finalized"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Instruction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Initialize"
+          },
+          {
+            "name": "PostMessage"
+          },
+          {
+            "name": "PostVAA"
+          },
+          {
+            "name": "SetFees"
+          },
+          {
+            "name": "TransferFees"
+          },
+          {
+            "name": "UpgradeContract"
+          },
+          {
+            "name": "UpgradeGuardianSet"
+          },
+          {
+            "name": "VerifySignatures"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "TransferNativeWithPayloadEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "nonce",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "targetChain",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          },
+          "index": false
+        },
+        {
+          "name": "propellerEnabled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "gasKickstart",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "maxFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "targetTokenId",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "minOutputAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CompleteNativeWithPayloadEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claim",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vaaEmitterChain",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "vaaSequence",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "fees",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "transferAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProcessSwimPayloadEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "targetTokenId",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "fees",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "transferAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "outputAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InvokeSwimReceiverEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "receiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "receiverTokenAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateAggregatorConfigEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "maxStaleness",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "maxConfidenceInterval",
+          "type": {
+            "defined": "BorshDecimal"
           },
-          {
-            "name": "RemoveExactBurn"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateOracleConfigEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oraclePolicy",
+          "type": {
+            "defined": "OraclePolicy"
           },
-          {
-            "name": "SwapExactInput"
+          "index": false
+        },
+        {
+          "name": "fallbackOracle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solUsdPool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solUsdPoolSolIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "solUsdPoolTwapWindow",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "solUsdPoolMaxStaleness",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrepareAdminTransitionEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "upcomingAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "adminTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnactAdminTransitionEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateGasKickstartAmountEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousGasKickstartAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "gasKickstartAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateSwimPayloadExpiryEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousSwimPayloadExpiry",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "swimPayloadExpiry",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateFeePriceCacheMaxAgeEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousFeePriceCacheMaxAge",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "feePriceCacheMaxAge",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateFeesEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fees",
+          "type": {
+            "defined": "UpdateFeesParams"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateMarginalPricePoolEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marginalPricePool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marginalPricePoolTokenIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "marginalPricePoolTokenMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marginalPriceTwapWindow",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateFeeVaultEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousFeeVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newFeeVault",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateAggregatorEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousAggregator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAggregator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SetPausedEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "direction",
+          "type": {
+            "defined": "PauseDirection"
+          },
+          "index": false
+        },
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePauseKeyEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousPauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newPauseKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RefreshFeePriceCacheEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feePriceCache",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousSwimUsdAtomicPerLamport",
+          "type": {
+            "defined": "BorshDecimal"
+          },
+          "index": false
+        },
+        {
+          "name": "swimUsdAtomicPerLamport",
+          "type": {
+            "defined": "BorshDecimal"
+          },
+          "index": false
+        },
+        {
+          "name": "lastUpdateTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateRateLimitsEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "inbound",
+          "type": {
+            "defined": "RateLimitConfig"
+          },
+          "index": false
+        },
+        {
+          "name": "outbound",
+          "type": {
+            "defined": "RateLimitConfig"
+          },
+          "index": false
+        },
+        {
+          "name": "rateLimitAction",
+          "type": {
+            "defined": "RateLimitAction"
+          },
+          "index": false
+        },
+        {
+          "name": "largeTransferThreshold",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "largeTransferDelay",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateTargetChainRateLimitsEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "targetChain",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "inbound",
+          "type": {
+            "defined": "RateLimitConfig"
+          },
+          "index": false
+        },
+        {
+          "name": "outbound",
+          "type": {
+            "defined": "RateLimitConfig"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwimPayloadHeldEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vaaEmitterChain",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "transferAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "releaseTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReleaseSwimPayloadEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousReleaseTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InsufficientFunds",
+      "msg": "InsufficientFunds"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidAccount",
+      "msg": "InvalidAccount"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidRemainingAccounts",
+      "msg": "InvalidRemainingAccounts"
+    },
+    {
+      "code": 6003,
+      "name": "InvalidTokenBridgeAddress",
+      "msg": "InvalidTokenBridgeAddress"
+    },
+    {
+      "code": 6004,
+      "name": "InvalidTokenDecimals",
+      "msg": "InvalidTokenDecimals"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidTokenIndex",
+      "msg": "InvalidTokenIndex"
+    },
+    {
+      "code": 6006,
+      "name": "InvalidVaaAction",
+      "msg": "InvalidVaaAction"
+    },
+    {
+      "code": 6007,
+      "name": "InvalidWormholeAddress",
+      "msg": "InvalidWormholeAddress"
+    },
+    {
+      "code": 6008,
+      "name": "InvalidVaaPayload",
+      "msg": "InvalidVaaPayload"
+    },
+    {
+      "code": 6009,
+      "name": "NothingToClaim",
+      "msg": "NothingToClaim"
+    },
+    {
+      "code": 6010,
+      "name": "TransferNotAllowed",
+      "msg": "TransferNotAllowed"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidCpiReturnProgramId",
+      "msg": "Incorrect ProgramId for CPI return value"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidCpiReturnValue",
+      "msg": "Invalid CPI Return value"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidMint",
+      "msg": "Invalid Mint"
+    },
+    {
+      "code": 6014,
+      "name": "InvalidAddAndWormholeTransferMint",
+      "msg": "Invalid Mint for AddAndWormholeTransfer"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidSwapExactInputOutputTokenIndex",
+      "msg": "Invalid output token index for SwapExactInput params"
+    },
+    {
+      "code": 6016,
+      "name": "InvalidSwapExactInputInputAmount",
+      "msg": "Invalid input amount for SwapExactInput params"
+    },
+    {
+      "code": 6017,
+      "name": "InvalidSwimUsdMint",
+      "msg": "Invalid SwimUSD Mint"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidPayloadTypeInVaa",
+      "msg": "Invalid Payload Type in VAA"
+    },
+    {
+      "code": 6019,
+      "name": "SerializeError",
+      "msg": "Serializing error"
+    },
+    {
+      "code": 6020,
+      "name": "DeserializeError",
+      "msg": "Deserializing error"
+    },
+    {
+      "code": 6021,
+      "name": "UserRedeemerSignatureNotDetected",
+      "msg": "User redeemer needs to be signer"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidSwitchboardAccount",
+      "msg": "Not a valid Switchboard account"
+    },
+    {
+      "code": 6023,
+      "name": "StaleFeed",
+      "msg": "Switchboard feed has not been updated within max_staleness"
+    },
+    {
+      "code": 6024,
+      "name": "ConfidenceIntervalExceeded",
+      "msg": "Switchboard feed exceeded provided confidence interval"
+    },
+    {
+      "code": 6025,
+      "name": "InsufficientAmount",
+      "msg": "Insufficient Amount being transferred"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidClaimData",
+      "msg": "Invalid claim data"
+    },
+    {
+      "code": 6027,
+      "name": "ClaimNotClaimed",
+      "msg": "Claim Account not claimed"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidPropellerAdmin",
+      "msg": "Invalid Propeller Admin"
+    },
+    {
+      "code": 6029,
+      "name": "InvalidTokenIdMapPool",
+      "msg": "Invalid Pool for Token Id Map"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidOutputTokenIndex",
+      "msg": "Invalid Output Token Index"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidTokenIdMapPoolTokenIndex",
+      "msg": "Invalid Pool Token Index for Token Id Map"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidTokenIdMapPoolTokenMint",
+      "msg": "Invalid Pool Token Mint for Token Id Map"
+    },
+    {
+      "code": 6033,
+      "name": "InvalidTokenIdMapPoolIx",
+      "msg": "Invalid Pool Ix for Token Id Map"
+    },
+    {
+      "code": 6034,
+      "name": "InvalidSwimPayloadGasKickstart",
+      "msg": "Invalid Gas Kickstart parameter in Swim Payload"
+    },
+    {
+      "code": 6035,
+      "name": "InvalidMarginalPricePoolAccounts",
+      "msg": "Invalid Marginal Price Pool Accounts"
+    },
+    {
+      "code": 6036,
+      "name": "NotPropellerEnabled",
+      "msg": "Propeller Not Enabled in payload"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidRoutingContractAddress",
+      "msg": "Invalid Routing Contract Address"
+    },
+    {
+      "code": 6038,
+      "name": "IntegerOverflow",
+      "msg": "Integer Overflow"
+    },
+    {
+      "code": 6039,
+      "name": "ConversionError",
+      "msg": "Conversion Error"
+    },
+    {
+      "code": 6040,
+      "name": "UnableToRetrieveSwimUsdMintDecimals",
+      "msg": "Unable to retrieve SwimUSD mint decimals from marginal price pool information"
+    },
+    {
+      "code": 6041,
+      "name": "InvalidMetapoolTokenMint",
+      "msg": "Invalid Metapool Token Mint. token_mint[0] should == swim_usd_mint"
+    },
+    {
+      "code": 6042,
+      "name": "UnableToDeserializeTokenAccount",
+      "msg": "Unable to deserialize account info as token account"
+    },
+    {
+      "code": 6043,
+      "name": "InvalidTokenAccountDataLen",
+      "msg": "Invalid token account data length. != 0 && != TokenAccount::LEN"
+    },
+    {
+      "code": 6044,
+      "name": "PayerInsufficientFundsForGasKickstart",
+      "msg": "Payer has insufficient funds for gas kickstart"
+    },
+    {
+      "code": 6045,
+      "name": "IncorrectOwnerForCreateTokenAccount",
+      "msg": "Owner of token account != swimPayload.owner"
+    },
+    {
+      "code": 6046,
+      "name": "TokenIdMapExists",
+      "msg": "TokenIdMap exists. Please use the correct instruction"
+    },
+    {
+      "code": 6047,
+      "name": "InvalidTokenIdMapAccountAddress",
+      "msg": "Invalid address for TokenIdMap account"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidSwimPayloadVersion",
+      "msg": "Invalid Swim Payload version"
+    },
+    {
+      "code": 6049,
+      "name": "InvalidAggregator",
+      "msg": "Invalid Aggregator"
+    },
+    {
+      "code": 6050,
+      "name": "MaxFeeExceeded",
+      "msg": "Fees exceed the max fee set in the swim payload"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidAggregatorConfig",
+      "msg": "Invalid aggregator config. max_staleness must be > 0 and max_confidence_interval >= 0"
+    },
+    {
+      "code": 6052,
+      "name": "InsufficientDelay",
+      "msg": "Not enough time has passed since prepare instruction"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidEnact",
+      "msg": "Nothing to enact"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidPauseKey",
+      "msg": "Invalid pause key"
+    },
+    {
+      "code": 6055,
+      "name": "InboundIsPaused",
+      "msg": "Inbound transfers are paused"
+    },
+    {
+      "code": 6056,
+      "name": "OutboundIsPaused",
+      "msg": "Outbound transfers are paused"
+    },
+    {
+      "code": 6057,
+      "name": "SwimReceiverDataTooLong",
+      "msg": "Swim payload receiver data is too long"
+    },
+    {
+      "code": 6058,
+      "name": "MissingSwimReceiverAccounts",
+      "msg": "Missing swim receiver program & caller accounts"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidSwimReceiver",
+      "msg": "Swim receiver program != swimPayload.owner"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidSwimReceiverCaller",
+      "msg": "Invalid swim receiver caller"
+    },
+    {
+      "code": 6061,
+      "name": "InvalidSwimPayloadExpiry",
+      "msg": "Swim payload expiry must be > 0"
+    },
+    {
+      "code": 6062,
+      "name": "SwimPayloadNotExpired",
+      "msg": "Swim payload has not expired yet"
+    },
+    {
+      "code": 6063,
+      "name": "RelayerExclusivityActive",
+      "msg": "Only the exclusive relayer of the swim payload can run this ix until its exclusivity deadline"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidPythAccount",
+      "msg": "Invalid pyth price account"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidSolUsdPool",
+      "msg": "Invalid SOL/USD pool"
+    },
+    {
+      "code": 6066,
+      "name": "InsufficientOracleHistory",
+      "msg": "Not enough price observations for the SOL/USD pool TWAP"
+    },
+    {
+      "code": 6067,
+      "name": "NoValidOraclePrice",
+      "msg": "None of the oracles returned a valid price"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidOracleConfig",
+      "msg": "Invalid oracle config"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidFeePriceCacheMaxAge",
+      "msg": "Fee price cache max age must be > 0"
+    },
+    {
+      "code": 6070,
+      "name": "StaleFeePriceCache",
+      "msg": "Fee price cache is stale"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidRateLimitConfig",
+      "msg": "Invalid rate limit config"
+    },
+    {
+      "code": 6072,
+      "name": "RateLimitExceeded",
+      "msg": "Transfer exceeds a rate limit"
+    },
+    {
+      "code": 6073,
+      "name": "SwimPayloadHeld",
+      "msg": "Swim payload is held until its release timestamp"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidTargetChainMap",
+      "msg": "Target chain map doesn't match the chain of the transfer"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidTokenIdMapRoute",
+      "msg": "Invalid route for Token Id Map"
+    },
+    {
+      "code": 6076,
+      "name": "InvalidRouteAccounts",
+      "msg": "Invalid accounts for the route of the Token Id Map"
+    }
+  ]
+};
+
+export const IDL: Propeller = {
+  "version": "0.1.0",
+  "name": "propeller",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "propellerSender",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "propellerRedeemer",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "propellerRedeemerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "propellerFeeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "pauseKey",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_token_mint_0"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_token_mint_1"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenMint0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenMint1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "aggregator",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "InitializeParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createTokenIdMap",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "arg",
+                "type": "publicKey",
+                "path": "pool"
+              },
+              {
+                "kind": "arg",
+                "type": "publicKey",
+                "path": "pool"
+              },
+              {
+                "kind": "arg",
+                "type": "publicKey",
+                "path": "pool.lp_mint_key"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_token_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetTokenIndex",
+          "type": "u16"
+        },
+        {
+          "name": "pool",
+          "type": "publicKey"
+        },
+        {
+          "name": "poolTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "poolTokenMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "poolIx",
+          "type": {
+            "defined": "PoolInstruction"
+          }
+        },
+        {
+          "name": "nextSteps",
+          "type": {
+            "vec": {
+              "defined": "PoolStep"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTokenIdMap",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "TokenIdMap",
+                "path": "token_id_map.output_token_index"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "pool",
+          "type": "publicKey"
+        },
+        {
+          "name": "poolTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "poolTokenMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "poolIx",
+          "type": {
+            "defined": "PoolInstruction"
+          }
+        },
+        {
+          "name": "nextSteps",
+          "type": {
+            "vec": {
+              "defined": "PoolStep"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeTokenIdMap",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "TokenIdMap",
+                "path": "token_id_map.output_token_index"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentRecipient",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createTargetChainMap",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "targetChainMap",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_chain"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetChain",
+          "type": "u16"
+        },
+        {
+          "name": "targetAddress",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTargetChainMap",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "targetChainMap",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "TargetChainMap",
+                "path": "target_chain_map.target_chain"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "routingContract",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "closeTargetChainMap",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "targetChainMap",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "TargetChainMap",
+                "path": "target_chain_map.target_chain"
+              }
+            ]
+          }
+        },
+        {
+          "name": "rentRecipient",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "prepareAdminTransition",
+      "docs": [
+        "Admin Ixs *"
+      ],
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "upcomingAdmin",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "enactAdminTransition",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pauseKey",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "direction",
+          "type": {
+            "defined": "PauseDirection"
+          }
+        },
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "changePauseKey",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newPauseKey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "updateGasKickstartAmount",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "gasKickstartAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateSwimPayloadExpiry",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "swimPayloadExpiry",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateFeePriceCacheMaxAge",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "feePriceCacheMaxAge",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateFees",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "UpdateFeesParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMarginalPricePool",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "marginalPricePoolTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "marginalPriceTwapWindow",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "updateFeeVault",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "feeVault",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateAggregator",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "aggregator",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateAggregatorConfig",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "maxStaleness",
+          "type": "i64"
+        },
+        {
+          "name": "maxConfidenceInterval",
+          "type": {
+            "defined": "BorshDecimal"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateOracleConfig",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "fallbackOracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solUsdPool",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "UpdateOracleConfigParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateRateLimits",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "params",
+          "type": {
+            "defined": "UpdateRateLimitsParams"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateTargetChainRateLimits",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "targetChainMap",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "defined": "Box<Account<'info,Propeller>>"
+                },
+                "account": "CommonAdmin",
+                "path": "common_admin.propeller"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "TargetChainMap",
+                "path": "target_chain_map.target_chain"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "inbound",
+          "type": {
+            "defined": "RateLimitConfig"
+          }
+        },
+        {
+          "name": "outbound",
+          "type": {
+            "defined": "RateLimitConfig"
+          }
+        }
+      ]
+    },
+    {
+      "name": "releaseSwimPayload",
+      "accounts": [
+        {
+          "name": "commonAdmin",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "admin",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.claim"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeFeePriceCache",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feePriceCache",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refreshFeePriceCache",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feePriceCache",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              }
+            ]
+          }
+        },
+        {
+          "name": "aggregator",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "fallbackOracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "solUsdPool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "marginalPricePool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "marginal_price_pool_token_0_account.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "marginal_price_pool_token_1_account.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "marginal_price_pool_lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "marginalPricePoolToken0Account",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "marginalPricePoolToken1Account",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "marginalPricePoolLpMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeFeeTracker",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeTracker",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimFees",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeTracker",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "FeeTracker",
+                "path": "fee_tracker.fees_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "FeeTracker",
+                "path": "fee_tracker.payer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "feeAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "crossChainAdd",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          }
+        },
+        {
+          "name": "minimumMintAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerAdd",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          }
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "crossChainSwapExactInput",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerSwapExactInput",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "crossChainSwapExactOutput",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerSwapExactOutput",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "crossChainRemoveUniform",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minimumOutputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          }
+        }
+      ],
+      "returns": {
+        "vec": "u64"
+      }
+    },
+    {
+      "name": "propellerRemoveUniform",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "vec": "u64"
+      }
+    },
+    {
+      "name": "crossChainRemoveExactBurn",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerRemoveExactBurn",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "crossChainRemoveExactOutput",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerRemoveExactOutput",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "crossChainTransferNativeWithPayload",
+      "accounts": [
+        {
+          "name": "propeller",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenBridgeConfig",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "config"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "userSwimUsdAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBridge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodySigner",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "custody_signer"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "token_bridge"
+            }
           }
-        ]
-      }
-    },
-    {
-      "name": "SwimPayloadVersion",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "V0"
-          },
-          {
-            "name": "V1"
+        },
+        {
+          "name": "authoritySigner",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "authority_signer"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "token_bridge"
+            }
           }
-        ]
-      }
-    },
-    {
-      "name": "ConsistencyLevel",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Confirmed"
-          },
-          {
-            "name": "Finalized"
+        },
+        {
+          "name": "wormholeConfig",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "Bridge"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
           }
-        ]
-      }
-    },
-    {
-      "name": "Instruction",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Initialize"
-          },
-          {
-            "name": "PostMessage"
-          },
-          {
-            "name": "PostVAA"
-          },
-          {
-            "name": "SetFees"
-          },
-          {
-            "name": "TransferFees"
-          },
-          {
-            "name": "UpgradeContract"
-          },
-          {
-            "name": "UpgradeGuardianSet"
-          },
-          {
-            "name": "VerifySignatures"
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "wormholeEmitter",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "emitter"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "wormholeSequence",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "Sequence"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "wormhole_emitter"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "wormholeFeeCollector",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_collector"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Transfers with payload also include the address of the account or contract",
+            "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+            "EVM chains, i.e. it is the address of the immediate caller of the token",
+            "bridge transaction.",
+            "Since on Solana, a transaction can have multiple different signers, getting",
+            "this information is not so straightforward.",
+            "The strategy we use to figure out the sender of the transaction is to",
+            "require an additional signer ([`SenderAccount`]) for the transaction.",
+            "If the transaction was sent by a user wallet directly, then this may just be",
+            "the wallet's pubkey. If, however, the transaction was initiated by a",
+            "program, then we require this to be a PDA derived from the sender program's",
+            "id and the string \"sender\". In this case, the sender program must also",
+            "attach its program id to the instruction data. If the PDA verification",
+            "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+            "transaction), then the program's id is attached to the VAA as the sender,",
+            "otherwise the transaction is rejected.",
+            "",
+            "Note that a program may opt to forego the PDA derivation and instead just",
+            "pass on the original wallet as the wallet account (or any other signer, as",
+            "long as they don't provide their program_id in the instruction data). The",
+            "sender address is provided as a means for protocols to verify on the",
+            "receiving end that the message was emitted by a contract they trust, so",
+            "foregoing this check is not advised. If the receiving contract needs to know",
+            "the sender wallet's address too, then that information can be included in",
+            "the additional payload, along with any other data that the protocol needs to",
+            "send across. The legitimacy of the attached data can be verified by checking",
+            "that the sender contract is a trusted one.",
+            "",
+            "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+            "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+            "that case the PDA's address will directly be encoded into the payload",
+            "instead of the sender program's id."
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "sender"
+              }
+            ]
+          }
+        },
+        {
+          "name": "wormhole",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetChainMap",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_chain"
+              }
+            ]
           }
-        ]
-      }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InsufficientFunds",
-      "msg": "InsufficientFunds"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidAccount",
-      "msg": "InvalidAccount"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidRemainingAccounts",
-      "msg": "InvalidRemainingAccounts"
-    },
-    {
-      "code": 6003,
-      "name": "InvalidTokenBridgeAddress",
-      "msg": "InvalidTokenBridgeAddress"
-    },
-    {
-      "code": 6004,
-      "name": "InvalidTokenDecimals",
-      "msg": "InvalidTokenDecimals"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidTokenIndex",
-      "msg": "InvalidTokenIndex"
-    },
-    {
-      "code": 6006,
-      "name": "InvalidVaaAction",
-      "msg": "InvalidVaaAction"
-    },
-    {
-      "code": 6007,
-      "name": "InvalidWormholeAddress",
-      "msg": "InvalidWormholeAddress"
-    },
-    {
-      "code": 6008,
-      "name": "InvalidVaaPayload",
-      "msg": "InvalidVaaPayload"
-    },
-    {
-      "code": 6009,
-      "name": "NothingToClaim",
-      "msg": "NothingToClaim"
-    },
-    {
-      "code": 6010,
-      "name": "TransferNotAllowed",
-      "msg": "TransferNotAllowed"
-    },
-    {
-      "code": 6011,
-      "name": "InvalidCpiReturnProgramId",
-      "msg": "Incorrect ProgramId for CPI return value"
-    },
-    {
-      "code": 6012,
-      "name": "InvalidCpiReturnValue",
-      "msg": "Invalid CPI Return value"
-    },
-    {
-      "code": 6013,
-      "name": "InvalidMint",
-      "msg": "Invalid Mint"
-    },
-    {
-      "code": 6014,
-      "name": "InvalidAddAndWormholeTransferMint",
-      "msg": "Invalid Mint for AddAndWormholeTransfer"
-    },
-    {
-      "code": 6015,
-      "name": "InvalidSwapExactInputOutputTokenIndex",
-      "msg": "Invalid output token index for SwapExactInput params"
-    },
-    {
-      "code": 6016,
-      "name": "InvalidSwapExactInputInputAmount",
-      "msg": "Invalid input amount for SwapExactInput params"
-    },
-    {
-      "code": 6017,
-      "name": "InvalidSwimUsdMint",
-      "msg": "Invalid SwimUSD Mint"
-    },
-    {
-      "code": 6018,
-      "name": "InvalidPayloadTypeInVaa",
-      "msg": "Invalid Payload Type in VAA"
-    },
-    {
-      "code": 6019,
-      "name": "SerializeError",
-      "msg": "Serializing error"
-    },
-    {
-      "code": 6020,
-      "name": "DeserializeError",
-      "msg": "Deserializing error"
-    },
-    {
-      "code": 6021,
-      "name": "UserRedeemerSignatureNotDetected",
-      "msg": "User redeemer needs to be signer"
-    },
-    {
-      "code": 6022,
-      "name": "InvalidSwitchboardAccount",
-      "msg": "Not a valid Switchboard account"
-    },
-    {
-      "code": 6023,
-      "name": "StaleFeed",
-      "msg": "Switchboard feed has not been updated in 5 minutes"
-    },
-    {
-      "code": 6024,
-      "name": "ConfidenceIntervalExceeded",
-      "msg": "Switchboard feed exceeded provided confidence interval"
-    },
-    {
-      "code": 6025,
-      "name": "InsufficientAmount",
-      "msg": "Insufficient Amount being transferred"
-    },
-    {
-      "code": 6026,
-      "name": "InvalidClaimData",
-      "msg": "Invalid claim data"
-    },
-    {
-      "code": 6027,
-      "name": "ClaimNotClaimed",
-      "msg": "Claim Account not claimed"
-    },
-    {
-      "code": 6028,
-      "name": "InvalidPropellerAdmin",
-      "msg": "Invalid Propeller Admin"
-    },
-    {
-      "code": 6029,
-      "name": "InvalidTokenIdMapPool",
-      "msg": "Invalid Pool for Token Id Map"
-    },
-    {
-      "code": 6030,
-      "name": "InvalidOutputTokenIndex",
-      "msg": "Invalid Output Token Index"
-    },
-    {
-      "code": 6031,
-      "name": "InvalidTokenIdMapPoolTokenIndex",
-      "msg": "Invalid Pool Token Index for Token Id Map"
-    },
-    {
-      "code": 6032,
-      "name": "InvalidTokenIdMapPoolTokenMint",
-      "msg": "Invalid Pool Token Mint for Token Id Map"
-    },
-    {
-      "code": 6033,
-      "name": "InvalidTokenIdMapPoolIx",
-      "msg": "Invalid Pool Ix for Token Id Map"
-    },
-    {
-      "code": 6034,
-      "name": "InvalidSwimPayloadGasKickstart",
-      "msg": "Invalid Gas Kickstart parameter in Swim Payload"
-    },
-    {
-      "code": 6035,
-      "name": "InvalidMarginalPricePoolAccounts",
-      "msg": "Invalid Marginal Price Pool Accounts"
-    },
-    {
-      "code": 6036,
-      "name": "NotPropellerEnabled",
-      "msg": "Propeller Not Enabled in payload"
-    },
-    {
-      "code": 6037,
-      "name": "InvalidRoutingContractAddress",
-      "msg": "Invalid Routing Contract Address"
-    },
-    {
-      "code": 6038,
-      "name": "IntegerOverflow",
-      "msg": "Integer Overflow"
-    },
-    {
-      "code": 6039,
-      "name": "ConversionError",
-      "msg": "Conversion Error"
-    },
-    {
-      "code": 6040,
-      "name": "UnableToRetrieveSwimUsdMintDecimals",
-      "msg": "Unable to retrieve SwimUSD mint decimals from marginal price pool information"
-    },
-    {
-      "code": 6041,
-      "name": "InvalidMetapoolTokenMint",
-      "msg": "Invalid Metapool Token Mint. token_mint[0] should == swim_usd_mint"
-    },
-    {
-      "code": 6042,
-      "name": "UnableToDeserializeTokenAccount",
-      "msg": "Unable to deserialize account info as token account"
-    },
-    {
-      "code": 6043,
-      "name": "InvalidTokenAccountDataLen",
-      "msg": "Invalid token account data length. != 0 && != TokenAccount::LEN"
-    },
-    {
-      "code": 6044,
-      "name": "PayerInsufficientFundsForGasKickstart",
-      "msg": "Payer has insufficient funds for gas kickstart"
-    },
-    {
-      "code": 6045,
-      "name": "IncorrectOwnerForCreateTokenAccount",
-      "msg": "Owner of token account != swimPayload.owner"
-    },
-    {
-      "code": 6046,
-      "name": "TokenIdMapExists",
-      "msg": "TokenIdMap exists. Please use the correct instruction"
-    },
-    {
-      "code": 6047,
-      "name": "InvalidTokenIdMapAccountAddress",
-      "msg": "Invalid address for TokenIdMap account"
-    },
-    {
-      "code": 6048,
-      "name": "InvalidSwimPayloadVersion",
-      "msg": "Invalid Swim Payload version"
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "clock",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "targetChain",
+          "type": "u16"
+        },
+        {
+          "name": "owner",
+          "type": "bytes"
+        }
+      ]
     },
     {
-      "code": 6049,
-      "name": "InvalidAggregator",
-      "msg": "Invalid Aggregator"
-    }
-  ]
-};
-
-export const IDL: Propeller = {
-  "version": "0.1.0",
-  "name": "propeller",
-  "instructions": [
-    {
-      "name": "initialize",
+      "name": "propellerTransferNativeWithPayload",
       "accounts": [
         {
           "name": "propeller",
@@ -4790,72 +14281,350 @@ export const IDL: Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "swim_usd_mint"
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenBridgeConfig",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "config"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "userSwimUsdAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swimUsdMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "custody",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBridge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "custodySigner",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "custody_signer"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "token_bridge"
+            }
+          }
+        },
+        {
+          "name": "authoritySigner",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "authority_signer"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "token_bridge"
+            }
+          }
+        },
+        {
+          "name": "wormholeConfig",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "Bridge"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "wormholeMessage",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "wormholeEmitter",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "emitter"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "wormholeSequence",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "Sequence"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "wormhole_emitter"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "wormholeFeeCollector",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_collector"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "sender",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Transfers with payload also include the address of the account or contract",
+            "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+            "EVM chains, i.e. it is the address of the immediate caller of the token",
+            "bridge transaction.",
+            "Since on Solana, a transaction can have multiple different signers, getting",
+            "this information is not so straightforward.",
+            "The strategy we use to figure out the sender of the transaction is to",
+            "require an additional signer ([`SenderAccount`]) for the transaction.",
+            "If the transaction was sent by a user wallet directly, then this may just be",
+            "the wallet's pubkey. If, however, the transaction was initiated by a",
+            "program, then we require this to be a PDA derived from the sender program's",
+            "id and the string \"sender\". In this case, the sender program must also",
+            "attach its program id to the instruction data. If the PDA verification",
+            "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+            "transaction), then the program's id is attached to the VAA as the sender,",
+            "otherwise the transaction is rejected.",
+            "",
+            "Note that a program may opt to forego the PDA derivation and instead just",
+            "pass on the original wallet as the wallet account (or any other signer, as",
+            "long as they don't provide their program_id in the instruction data). The",
+            "sender address is provided as a means for protocols to verify on the",
+            "receiving end that the message was emitted by a contract they trust, so",
+            "foregoing this check is not advised. If the receiving contract needs to know",
+            "the sender wallet's address too, then that information can be included in",
+            "the additional payload, along with any other data that the protocol needs to",
+            "send across. The legitimacy of the attached data can be verified by checking",
+            "that the sender contract is a trusted one.",
+            "",
+            "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+            "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+            "that case the PDA's address will directly be encoded into the payload",
+            "instead of the sender program's id."
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "sender"
               }
             ]
           }
         },
         {
-          "name": "propellerSender",
+          "name": "wormhole",
           "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "sender"
-              }
-            ]
-          }
+          "isSigner": false
         },
         {
-          "name": "propellerRedeemer",
+          "name": "tokenProgram",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetChainMap",
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "redeemer"
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_chain"
               }
             ]
           }
         },
         {
-          "name": "propellerRedeemerEscrow",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "propellerFeeVault",
-          "isMut": true,
+          "name": "clock",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "rent",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
         },
         {
-          "name": "swimUsdMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "targetChain",
+          "type": "u16"
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "owner",
+          "type": "bytes"
         },
         {
-          "name": "pool",
+          "name": "gasKickstart",
+          "type": "bool"
+        },
+        {
+          "name": "maxFee",
+          "type": "u64"
+        },
+        {
+          "name": "targetTokenId",
+          "type": "u16"
+        },
+        {
+          "name": "minOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        },
+        {
+          "name": "receiverData",
+          "type": {
+            "option": "bytes"
+          }
+        },
+        {
+          "name": "relayerExclusivity",
+          "type": {
+            "option": {
+              "defined": "RelayerExclusivity"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "completeNativeWithPayload",
+      "accounts": [
+        {
+          "name": "propeller",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -4863,160 +14632,182 @@ export const IDL: Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "pool_token_mint_0"
+                "value": "propeller"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "pool_token_mint_1"
-              },
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenBridgeConfig",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
               {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+                "kind": "const",
+                "type": "string",
+                "value": "config"
               }
             ],
             "programId": {
               "kind": "account",
               "type": "publicKey",
-              "path": "two_pool_program"
+              "account": "Propeller",
+              "path": "propeller"
             }
           }
         },
         {
-          "name": "poolTokenMint0",
-          "isMut": false,
-          "isSigner": false
+          "name": "message",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "contains the VAA",
+            "{",
+            "...MessageData:",
+            "payload: PayloadTransferWithPayload = {",
+            "pub amount: U256,",
+            "}",
+            "}"
+          ]
         },
         {
-          "name": "poolTokenMint1",
-          "isMut": false,
-          "isSigner": false
+          "name": "claim",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "seeds = [",
+            "vaa.emitter_address, vaa.emitter_chain, vaa.sequence",
+            "],",
+            "seeds::program = token_bridge"
+          ]
         },
         {
-          "name": "lpMint",
+          "name": "endpoint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "to",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "owned by redeemer. \"redeemerEscrow\""
+          ]
         },
         {
-          "name": "aggregator",
+          "name": "redeemer",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)",
+            "TODO: this is a little weird. i think we can safely assume that the `vaa.to` will be this programId",
+            "and that the `redeemer` account will be the PDA derived from [\"redeemer\"], seeds::program = propeller::id()"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
+          }
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeRecipient",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "this is \"to_fees\"",
+            "recipient of fees for executing complete transfer (e.g. relayer)"
+          ]
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
+          "name": "custody",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "swimUsdMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "custodySigner",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "params",
-          "type": {
-            "defined": "InitializeParams"
-          }
-        }
-      ]
-    },
-    {
-      "name": "createTokenIdMap",
-      "accounts": [
+        },
         {
-          "name": "propeller",
+          "name": "rent",
           "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
-              }
-            ]
-          }
+          "isSigner": false
         },
         {
-          "name": "admin",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "wormhole",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "pool",
+          "name": "tokenProgram",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenBridge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "arg",
-                "type": "publicKey",
-                "path": "pool"
+                "value": "propeller"
               },
               {
-                "kind": "arg",
-                "type": "publicKey",
-                "path": "pool"
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
               },
               {
-                "kind": "arg",
+                "kind": "account",
                 "type": "publicKey",
-                "path": "pool.lp_mint_key"
+                "path": "claim"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
-            }
+            ]
           }
         },
         {
-          "name": "tokenIdMap",
+          "name": "sourceChainMap",
           "isMut": true,
           "isSigner": false,
+          "docs": [
+            "`TargetChainMap` of the chain the transfer was sent from. Holds the chain's inbound rate limit"
+          ],
           "pda": {
             "seeds": [
               {
@@ -5024,11 +14815,6 @@ export const IDL: Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "token_id"
-              },
               {
                 "kind": "account",
                 "type": "publicKey",
@@ -5036,51 +14822,19 @@ export const IDL: Propeller = {
                 "path": "propeller"
               },
               {
-                "kind": "arg",
+                "kind": "account",
                 "type": "u16",
-                "path": "target_token_index"
+                "account": "TargetChainMap",
+                "path": "source_chain_map.target_chain"
               }
             ]
           }
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "targetTokenIndex",
-          "type": "u16"
-        },
-        {
-          "name": "pool",
-          "type": "publicKey"
-        },
-        {
-          "name": "poolTokenIndex",
-          "type": "u8"
-        },
-        {
-          "name": "poolTokenMint",
-          "type": "publicKey"
-        },
-        {
-          "name": "poolIx",
-          "type": {
-            "defined": "PoolInstruction"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createTargetChainMap",
+      "name": "processSwimPayload",
       "accounts": [
         {
           "name": "propeller",
@@ -5102,69 +14856,52 @@ export const IDL: Propeller = {
             ]
           }
         },
-        {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "targetChainMap",
-          "isMut": true,
+          "name": "claim",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
               },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller"
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
               },
               {
-                "kind": "arg",
-                "type": "u16",
-                "path": "target_chain"
+                "kind": "account",
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
               }
-            ]
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
           }
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "targetChain",
-          "type": "u16"
-        },
-        {
-          "name": "targetAddress",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        }
-      ]
-    },
-    {
-      "name": "updateTargetChainMap",
-      "accounts": [
-        {
-          "name": "propeller",
-          "isMut": false,
+          "name": "swimClaim",
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -5173,27 +14910,21 @@ export const IDL: Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "claim"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
+                "path": "claim"
               }
             ]
           }
         },
         {
-          "name": "admin",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "targetChainMap",
+          "name": "swimPayloadMessage",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -5204,43 +14935,51 @@ export const IDL: Propeller = {
                 "value": "propeller"
               },
               {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller"
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
               },
               {
                 "kind": "account",
-                "type": "u16",
-                "account": "TargetChainMap",
-                "path": "target_chain_map.target_chain"
+                "type": "publicKey",
+                "path": "claim"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "swimPayloadMessagePayer",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "routingContract",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        }
-      ]
-    },
-    {
-      "name": "initializeFeeTracker",
-      "accounts": [
+          "name": "redeemer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "this used to be \"to_owner\".",
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
+          }
+        },
         {
-          "name": "propeller",
+          "name": "redeemerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenIdMap",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -5250,17 +14989,27 @@ export const IDL: Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "swim_usd_mint"
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "arg",
+                "type": "u16",
+                "path": "target_token_id"
               }
             ]
           }
         },
         {
-          "name": "feeTracker",
+          "name": "pool",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -5268,67 +15017,316 @@ export const IDL: Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "propeller"
+                "value": "two_pool"
               },
               {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "swim_usd_mint"
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "payer"
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "path": "two_pool_program"
+            }
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "twoPoolProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "targetTokenId",
+          "type": "u16"
+        },
+        {
+          "name": "minOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerCompleteNativeWithPayload",
+      "accounts": [
+        {
+          "name": "completeNativeWithPayload",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "tokenBridgeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "config"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "message",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "contains the VAA",
+                "{",
+                "...MessageData:",
+                "payload: PayloadTransferWithPayload = {",
+                "pub amount: U256,",
+                "}",
+                "}"
+              ]
+            },
+            {
+              "name": "claim",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "seeds = [",
+                "vaa.emitter_address, vaa.emitter_chain, vaa.sequence",
+                "],",
+                "seeds::program = token_bridge"
+              ]
+            },
+            {
+              "name": "endpoint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "to",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "owned by redeemer. \"redeemerEscrow\""
+              ]
+            },
+            {
+              "name": "redeemer",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+                "will have to be signed when it invokes complete_transfer_with_payload",
+                "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+                "(NOT the `to` account)",
+                "TODO: this is a little weird. i think we can safely assume that the `vaa.to` will be this programId",
+                "and that the `redeemer` account will be the PDA derived from [\"redeemer\"], seeds::program = propeller::id()"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "redeemer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "feeRecipient",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "this is \"to_fees\"",
+                "recipient of fees for executing complete transfer (e.g. relayer)"
+              ]
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custodySigner",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "wormhole",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swimPayloadMessage",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "swim_payload"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "claim"
+                  }
+                ]
               }
-            ]
-          }
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "swimUsdMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "claimFees",
-      "accounts": [
-        {
-          "name": "propeller",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
+            },
+            {
+              "name": "sourceChainMap",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "`TargetChainMap` of the chain the transfer was sent from. Holds the chain's inbound rate limit"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "u16",
+                    "account": "TargetChainMap",
+                    "path": "source_chain_map.target_chain"
+                  }
+                ]
               }
-            ]
-          }
+            }
+          ]
         },
         {
           "name": "feeTracker",
@@ -5348,47 +15346,25 @@ export const IDL: Propeller = {
               },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "FeeTracker",
-                "path": "fee_tracker.fees_mint"
+                "type": {
+                  "defined": "Box<Account<'info,Mint>>"
+                },
+                "account": "CompleteNativeWithPayload",
+                "path": "complete_native_with_payload.swim_usd_mint"
               },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "FeeTracker",
-                "path": "fee_tracker.payer"
+                "type": {
+                  "defined": "Signer<'info>"
+                },
+                "account": "CompleteNativeWithPayload",
+                "path": "complete_native_with_payload.payer"
               }
             ]
           }
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "feeAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "crossChainAdd",
-      "accounts": [
-        {
-          "name": "propeller",
+          "name": "feePriceCache",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -5398,122 +15374,35 @@ export const IDL: Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
+                "value": "fee_price_cache"
               },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
-            }
-          }
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userLpTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "inputAmounts",
-          "type": {
-            "array": [
-              "u64",
-              2
+                "type": {
+                  "defined": "Box<Account<'info,Propeller>>"
+                },
+                "account": "CompleteNativeWithPayload",
+                "path": "complete_native_with_payload.propeller"
+              }
             ]
           }
         },
         {
-          "name": "minimumMintAmount",
-          "type": "u64"
+          "name": "memo",
+          "isMut": false,
+          "isSigner": false
         }
       ],
-      "returns": "u64"
+      "args": []
     },
     {
-      "name": "propellerAdd",
+      "name": "propellerCreateOwnerTokenAccounts",
+      "docs": [
+        "Valid target_token_id *"
+      ],
       "accounts": [
         {
           "name": "propeller",
@@ -5529,123 +15418,51 @@ export const IDL: Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
               }
             ]
           }
         },
         {
-          "name": "pool",
+          "name": "payer",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "redeemer",
+          "isMut": false,
           "isSigner": false,
+          "docs": [
+            "this used to be \"to_owner\".",
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+                "value": "redeemer"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
-            }
+            ]
           }
         },
         {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
+          "name": "redeemerEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount1",
+          "name": "feeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userLpTokenAccount",
+          "name": "feeTracker",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "inputAmounts",
-          "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          }
-        },
-        {
-          "name": "maxFee",
-          "type": "u64"
-        }
-      ],
-      "returns": "u64"
-    },
-    {
-      "name": "crossChainSwapExactInput",
-      "accounts": [
-        {
-          "name": "propeller",
-          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -5654,120 +15471,89 @@ export const IDL: Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
               }
             ]
           }
         },
         {
-          "name": "pool",
-          "isMut": true,
+          "name": "claim",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
               },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
               },
               {
                 "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
               }
             ],
             "programId": {
               "kind": "account",
               "type": "publicKey",
-              "path": "two_pool_program"
+              "account": "Propeller",
+              "path": "propeller"
             }
           }
         },
         {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount1",
+          "name": "swimPayloadMessage",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "swimUsdMint",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "exactInputAmount",
-          "type": "u64"
-        },
-        {
-          "name": "minimumOutputAmount",
-          "type": "u64"
-        }
-      ],
-      "returns": "u64"
-    },
-    {
-      "name": "propellerSwapExactInput",
-      "accounts": [
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
         {
-          "name": "propeller",
+          "name": "tokenIdMap",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -5777,11 +15563,22 @@ export const IDL: Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "token_id"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
+                "account": "Propeller",
+                "path": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.target_token_id"
               }
             ]
           }
@@ -5800,20 +15597,20 @@ export const IDL: Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
+                "account": "Mint",
+                "path": "pool_token_0_mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
+                "account": "Mint",
+                "path": "pool_token_1_mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Mint",
-                "path": "lp_mint"
+                "path": "pool_lp_mint"
               }
             ],
             "programId": {
@@ -5824,74 +15621,58 @@ export const IDL: Propeller = {
           }
         },
         {
-          "name": "poolTokenAccount0",
-          "isMut": true,
+          "name": "poolToken0Mint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "poolTokenAccount1",
-          "isMut": true,
+          "name": "poolToken1Mint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lpMint",
-          "isMut": true,
+          "name": "poolLpMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "governanceFee",
-          "isMut": true,
+          "name": "user",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
+          "name": "userPoolToken0Account",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "userTokenAccount0",
+          "name": "userPoolToken1Account",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userTokenAccount1",
+          "name": "userLpTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "twoPoolProgram",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "swimUsdMint",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "exactInputAmount",
-          "type": "u64"
         },
         {
-          "name": "maxFee",
-          "type": "u64"
-        }
-      ],
-      "returns": "u64"
-    },
-    {
-      "name": "crossChainTransferNativeWithPayload",
-      "accounts": [
-        {
-          "name": "propeller",
-          "isMut": true,
+          "name": "feePriceCache",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -5900,249 +15681,348 @@ export const IDL: Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "swim_usd_mint"
+                "account": "Propeller",
+                "path": "propeller"
               }
             ]
           }
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "tokenBridgeConfig",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "config"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
-        },
-        {
-          "name": "userSwimUsdAta",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "swimUsdMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "custody",
-          "isMut": true,
+          "name": "twoPoolProgram",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenBridge",
+          "name": "memo",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "propellerProcessSwimPayload",
+      "docs": [
+        "Note: passing in target_token_id here due to PDA seed derivation.",
+        "for propeller_process_swim_payload, require_eq!(target_token_id, propeller_message.target_token_id);"
+      ],
+      "accounts": [
         {
-          "name": "custodySigner",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "custody_signer"
+          "name": "processSwimPayload",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller.swim_usd_mint"
+                  }
+                ]
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "token_bridge"
-            }
-          }
-        },
-        {
-          "name": "authoritySigner",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "authority_signer"
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "claim",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "type": {
+                      "array": [
+                        "u8",
+                        32
+                      ]
+                    },
+                    "account": "SwimPayloadMessage",
+                    "path": "swim_payload_message.vaa_emitter_address"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "u16",
+                    "account": "SwimPayloadMessage",
+                    "path": "swim_payload_message.vaa_emitter_chain"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "u64",
+                    "account": "SwimPayloadMessage",
+                    "path": "swim_payload_message.vaa_sequence"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "swimClaim",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "claim"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "claim"
+                  }
+                ]
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "token_bridge"
-            }
-          }
-        },
-        {
-          "name": "wormholeConfig",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "Bridge"
+            },
+            {
+              "name": "swimPayloadMessage",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "swim_payload"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "claim"
+                  }
+                ]
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
-        },
-        {
-          "name": "wormholeMessage",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "wormholeEmitter",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "emitter"
+            },
+            {
+              "name": "swimPayloadMessagePayer",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "redeemer",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "this used to be \"to_owner\".",
+                "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+                "will have to be signed when it invokes complete_transfer_with_payload",
+                "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+                "(NOT the `to` account)"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "redeemer"
+                  }
+                ]
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
-        },
-        {
-          "name": "wormholeSequence",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "Sequence"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "wormhole_emitter"
+            },
+            {
+              "name": "redeemerEscrow",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenIdMap",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "token_id"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "arg",
+                    "type": "u16",
+                    "path": "target_token_id"
+                  }
+                ]
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
-        },
-        {
-          "name": "wormholeFeeCollector",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee_collector"
+            },
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_1.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "two_pool_program"
+                }
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
+            },
+            {
+              "name": "poolTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lpMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "governanceFee",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTransferAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userLpTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
             }
-          }
+          ]
         },
         {
-          "name": "sender",
+          "name": "feePriceCache",
           "isMut": false,
           "isSigner": false,
-          "docs": [
-            "Transfers with payload also include the address of the account or contract",
-            "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
-            "EVM chains, i.e. it is the address of the immediate caller of the token",
-            "bridge transaction.",
-            "Since on Solana, a transaction can have multiple different signers, getting",
-            "this information is not so straightforward.",
-            "The strategy we use to figure out the sender of the transaction is to",
-            "require an additional signer ([`SenderAccount`]) for the transaction.",
-            "If the transaction was sent by a user wallet directly, then this may just be",
-            "the wallet's pubkey. If, however, the transaction was initiated by a",
-            "program, then we require this to be a PDA derived from the sender program's",
-            "id and the string \"sender\". In this case, the sender program must also",
-            "attach its program id to the instruction data. If the PDA verification",
-            "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
-            "transaction), then the program's id is attached to the VAA as the sender,",
-            "otherwise the transaction is rejected.",
-            "",
-            "Note that a program may opt to forego the PDA derivation and instead just",
-            "pass on the original wallet as the wallet account (or any other signer, as",
-            "long as they don't provide their program_id in the instruction data). The",
-            "sender address is provided as a means for protocols to verify on the",
-            "receiving end that the message was emitted by a contract they trust, so",
-            "foregoing this check is not advised. If the receiving contract needs to know",
-            "the sender wallet's address too, then that information can be included in",
-            "the additional payload, along with any other data that the protocol needs to",
-            "send across. The legitimacy of the attached data can be verified by checking",
-            "that the sender contract is a trusted one.",
-            "",
-            "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
-            "[[`cpi_program_id`]] field will result in a successful transaction, but in",
-            "that case the PDA's address will directly be encoded into the payload",
-            "instead of the sender program's id."
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "sender"
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": {
+                  "defined": "Box<Account<'info,Propeller>>"
+                },
+                "account": "ProcessSwimPayload",
+                "path": "process_swim_payload.propeller"
               }
             ]
           }
         },
         {
-          "name": "wormhole",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "this is \"to_fees\"",
+            "recipient of fees for executing complete transfer (e.g. relayer)"
+          ]
         },
         {
-          "name": "targetChainMap",
-          "isMut": false,
+          "name": "feeTracker",
+          "isMut": true,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -6151,57 +16031,61 @@ export const IDL: Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller"
+                "account": "ProcessSwimPayload",
+                "path": "process_swim_payload"
               },
               {
-                "kind": "arg",
-                "type": "u16",
-                "path": "target_chain"
+                "kind": "account",
+                "type": {
+                  "defined": "Signer<'info>"
+                },
+                "account": "ProcessSwimPayload",
+                "path": "process_swim_payload.payer"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is for transferring lamports for kickstart"
+          ]
         },
         {
-          "name": "rent",
+          "name": "memo",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "targetChain",
+          "name": "targetTokenId",
           "type": "u16"
-        },
-        {
-          "name": "owner",
-          "type": "bytes"
         }
-      ]
+      ],
+      "returns": "u64"
     },
     {
-      "name": "propellerTransferNativeWithPayload",
+      "name": "propellerCreateOwnerSwimUsdAta",
+      "docs": [
+        "This ix is used if a propeller engine detects (off-chain) that the target_token_id is not valid",
+        "\"Fallback\" behavior is to check/create just the token bridge mint (swimUSD) token account for the owner",
+        "then call propeller_process_swim_payload_fallback to finish transferring the swimUSD to the owner."
+      ],
       "accounts": [
         {
           "name": "propeller",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -6213,8 +16097,8 @@ export const IDL: Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "swim_usd_mint"
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
               }
             ]
           }
@@ -6225,118 +16109,94 @@ export const IDL: Propeller = {
           "isSigner": true
         },
         {
-          "name": "tokenBridgeConfig",
-          "isMut": true,
+          "name": "redeemer",
+          "isMut": false,
           "isSigner": false,
+          "docs": [
+            "this used to be \"to_owner\".",
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)"
+          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "config"
+                "value": "redeemer"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
+            ]
           }
         },
         {
-          "name": "userSwimUsdAta",
+          "name": "redeemerEscrow",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "swimUsdMint",
+          "name": "feeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "custody",
+          "name": "feeTracker",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenBridge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "custodySigner",
-          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "custody_signer"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "token_bridge"
-            }
-          }
-        },
-        {
-          "name": "authoritySigner",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
+                "value": "propeller"
+              },
               {
                 "kind": "const",
                 "type": "string",
-                "value": "authority_signer"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "token_bridge"
-            }
-          }
-        },
-        {
-          "name": "wormholeConfig",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
+                "value": "fee"
+              },
               {
-                "kind": "const",
-                "type": "string",
-                "value": "Bridge"
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
+            ]
           }
         },
         {
-          "name": "wormholeMessage",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "wormholeEmitter",
-          "isMut": true,
+          "name": "claim",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "type": "string",
-                "value": "emitter"
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
               }
             ],
             "programId": {
@@ -6348,7 +16208,7 @@ export const IDL: Propeller = {
           }
         },
         {
-          "name": "wormholeSequence",
+          "name": "swimPayloadMessage",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -6356,92 +16216,56 @@ export const IDL: Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "Sequence"
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "wormhole_emitter"
+                "path": "claim"
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
+            ]
           }
         },
         {
-          "name": "wormholeFeeCollector",
-          "isMut": true,
+          "name": "tokenIdMap",
+          "isMut": false,
           "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee_collector"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
+          "docs": [
+            "deseraizlied as a `TokenIdMap`. if it does exist, then engine should have called",
+            "propeller_create_owner_token_accounts instead"
+          ]
         },
         {
-          "name": "sender",
+          "name": "swimUsdMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
           "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerSwimUsdAta",
+          "isMut": true,
           "isSigner": false,
           "docs": [
-            "Transfers with payload also include the address of the account or contract",
-            "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
-            "EVM chains, i.e. it is the address of the immediate caller of the token",
-            "bridge transaction.",
-            "Since on Solana, a transaction can have multiple different signers, getting",
-            "this information is not so straightforward.",
-            "The strategy we use to figure out the sender of the transaction is to",
-            "require an additional signer ([`SenderAccount`]) for the transaction.",
-            "If the transaction was sent by a user wallet directly, then this may just be",
-            "the wallet's pubkey. If, however, the transaction was initiated by a",
-            "program, then we require this to be a PDA derived from the sender program's",
-            "id and the string \"sender\". In this case, the sender program must also",
-            "attach its program id to the instruction data. If the PDA verification",
-            "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
-            "transaction), then the program's id is attached to the VAA as the sender,",
-            "otherwise the transaction is rejected.",
-            "",
-            "Note that a program may opt to forego the PDA derivation and instead just",
-            "pass on the original wallet as the wallet account (or any other signer, as",
-            "long as they don't provide their program_id in the instruction data). The",
-            "sender address is provided as a means for protocols to verify on the",
-            "receiving end that the message was emitted by a contract they trust, so",
-            "foregoing this check is not advised. If the receiving contract needs to know",
-            "the sender wallet's address too, then that information can be included in",
-            "the additional payload, along with any other data that the protocol needs to",
-            "send across. The legitimacy of the attached data can be verified by checking",
-            "that the sender contract is a trusted one.",
-            "",
-            "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
-            "[[`cpi_program_id`]] field will result in a successful transaction, but in",
-            "that case the PDA's address will directly be encoded into the payload",
-            "instead of the sender program's id."
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "sender"
-              }
-            ]
-          }
+            "Note: specifying TokenAccount type here since only one token account to initialize so no need to",
+            "\"guess and check\" which token accounts need to be initialized"
+          ]
         },
         {
-          "name": "wormhole",
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -6451,7 +16275,7 @@ export const IDL: Propeller = {
           "isSigner": false
         },
         {
-          "name": "targetChainMap",
+          "name": "feePriceCache",
           "isMut": false,
           "isSigner": false,
           "pda": {
@@ -6461,27 +16285,22 @@ export const IDL: Propeller = {
                 "type": "string",
                 "value": "propeller"
               },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Propeller",
                 "path": "propeller"
-              },
-              {
-                "kind": "arg",
-                "type": "u16",
-                "path": "target_chain"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "clock",
+          "name": "memo",
           "isMut": false,
           "isSigner": false
         },
@@ -6491,46 +16310,14 @@ export const IDL: Propeller = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "targetChain",
-          "type": "u16"
-        },
-        {
-          "name": "owner",
-          "type": "bytes"
-        },
-        {
-          "name": "gasKickstart",
-          "type": "bool"
-        },
-        {
-          "name": "maxFee",
-          "type": "u64"
-        },
-        {
-          "name": "targetTokenId",
-          "type": "u16"
-        },
-        {
-          "name": "memo",
-          "type": {
-            "option": {
-              "array": [
-                "u8",
-                16
-              ]
-            }
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "completeNativeWithPayload",
+      "name": "propellerProcessSwimPayloadFallback",
+      "docs": [
+        "This ix is used if a propeller engine detects (off-chain) that the payload.target_token_id is not valid",
+        "this will transfer the swimUSD to the owner (will still kickstart if requested)"
+      ],
       "accounts": [
         {
           "name": "propeller",
@@ -6558,15 +16345,33 @@ export const IDL: Propeller = {
           "isSigner": true
         },
         {
-          "name": "tokenBridgeConfig",
-          "isMut": true,
+          "name": "claim",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "type": "string",
-                "value": "config"
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
               }
             ],
             "programId": {
@@ -6578,54 +16383,68 @@ export const IDL: Propeller = {
           }
         },
         {
-          "name": "message",
+          "name": "swimClaim",
           "isMut": true,
           "isSigner": false,
-          "docs": [
-            "contains the VAA",
-            "{",
-            "...MessageData:",
-            "payload: PayloadTransferWithPayload = {",
-            "pub amount: U256,",
-            "}",
-            "}"
-          ]
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "claim"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
         },
         {
-          "name": "claim",
+          "name": "swimPayloadMessagePayer",
           "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "seeds = [",
-            "vaa.emitter_address, vaa.emitter_chain, vaa.sequence",
-            "],",
-            "seeds::program = token_bridge"
-          ]
-        },
-        {
-          "name": "endpoint",
-          "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "to",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "owned by redeemer. \"redeemerEscrow\""
-          ]
-        },
         {
           "name": "redeemer",
           "isMut": false,
           "isSigner": false,
           "docs": [
+            "this used to be \"to_owner\".",
             "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
             "will have to be signed when it invokes complete_transfer_with_payload",
             "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)",
-            "TODO: this is a little weird. i think we can safely assume that the `vaa.to` will be this programId",
-            "and that the `redeemer` account will be the PDA derived from [\"redeemer\"], seeds::program = propeller::id()"
+            "(NOT the `to` account)"
           ],
           "pda": {
             "seeds": [
@@ -6638,56 +16457,80 @@ export const IDL: Propeller = {
           }
         },
         {
-          "name": "feeRecipient",
+          "name": "redeemerEscrow",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "this is \"to_fees\"",
-            "recipient of fees for executing complete transfer (e.g. relayer)"
+            "deserialized as a `TokenIdMap`. if it does exist, then engine should have called",
+            "propeller_create_owner_token_accounts instead"
           ]
         },
         {
-          "name": "custody",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "swimUsdMint",
+          "name": "userTransferAuthority",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "custodySigner",
-          "isMut": false,
+          "name": "userSwimUsdAta",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "memo",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "wormhole",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "feePriceCache",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              }
+            ]
+          }
         },
         {
-          "name": "tokenBridge",
-          "isMut": false,
-          "isSigner": false
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "this is \"to_fees\"",
+            "recipient of fees for executing complete transfer (e.g. relayer)"
+          ]
         },
         {
-          "name": "swimPayloadMessage",
+          "name": "feeTracker",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -6700,21 +16543,41 @@ export const IDL: Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "swim_payload"
+                "value": "fee"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "claim"
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
               }
             ]
           }
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "This is for transferring lamports for kickstart"
+          ]
         }
       ],
-      "args": []
+      "args": [],
+      "returns": "u64"
     },
     {
-      "name": "processSwimPayload",
+      "name": "propellerProcessExpiredSwimPayload",
+      "docs": [
+        "Permissionless swimUSD fallback for swim payloads that weren't processed within",
+        "`propeller.swim_payload_expiry` seconds. Fees are capped at the swim payload's `max_fee`",
+        "& the `swim_receive` callback of swim receivers is skipped"
+      ],
       "accounts": [
         {
           "name": "propeller",
@@ -6737,51 +16600,164 @@ export const IDL: Propeller = {
           }
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "claim",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_address"
+              },
+              {
+                "kind": "account",
+                "type": "u16",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_emitter_chain"
+              },
+              {
+                "kind": "account",
+                "type": "u64",
+                "account": "SwimPayloadMessage",
+                "path": "swim_payload_message.vaa_sequence"
+              }
+            ],
+            "programId": {
+              "kind": "account",
+              "type": "publicKey",
+              "account": "Propeller",
+              "path": "propeller"
+            }
+          }
+        },
+        {
+          "name": "swimClaim",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "claim"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "swimPayloadMessage",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "swim_payload"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "claim"
+              }
+            ]
+          }
+        },
+        {
+          "name": "swimPayloadMessagePayer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "redeemer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "this used to be \"to_owner\".",
+            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
+            "will have to be signed when it invokes complete_transfer_with_payload",
+            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
+            "(NOT the `to` account)"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "redeemer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "redeemerEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenIdMap",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "deserialized as a `TokenIdMap`. if it does exist, then engine should have called",
+            "propeller_create_owner_token_accounts instead"
+          ]
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userSwimUsdAta",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "memo",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "claim",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_address"
-              },
-              {
-                "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_chain"
-              },
-              {
-                "kind": "account",
-                "type": "u64",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_sequence"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
+          "isSigner": false
         },
         {
-          "name": "swimClaim",
-          "isMut": true,
+          "name": "feePriceCache",
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -6793,18 +16769,28 @@ export const IDL: Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "claim"
+                "value": "fee_price_cache"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "claim"
+                "account": "Propeller",
+                "path": "propeller"
               }
             ]
           }
         },
         {
-          "name": "swimPayloadMessage",
+          "name": "feeVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "this is \"to_fees\"",
+            "recipient of fees for executing complete transfer (e.g. relayer)"
+          ]
+        },
+        {
+          "name": "feeTracker",
           "isMut": true,
           "isSigner": false,
           "pda": {
@@ -6817,47 +16803,61 @@ export const IDL: Propeller = {
               {
                 "kind": "const",
                 "type": "string",
-                "value": "swim_payload"
+                "value": "fee"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "path": "claim"
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "path": "payer"
               }
             ]
           }
         },
         {
-          "name": "swimPayloadMessagePayer",
+          "name": "owner",
           "isMut": true,
-          "isSigner": false
-        },
+          "isSigner": false,
+          "docs": [
+            "This is for transferring lamports for kickstart"
+          ]
+        }
+      ],
+      "args": [],
+      "returns": "u64"
+    },
+    {
+      "name": "quotePropellerFees",
+      "docs": [
+        "Read-only. Returns (via return data) the swimUSD fees a propeller engine would currently charge",
+        "to deliver a swim payload of `params.swim_payload_len` bytes for `target_token_id`"
+      ],
+      "accounts": [
         {
-          "name": "redeemer",
+          "name": "propeller",
           "isMut": false,
           "isSigner": false,
-          "docs": [
-            "this used to be \"to_owner\".",
-            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-            "will have to be signed when it invokes complete_transfer_with_payload",
-            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)"
-          ],
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "type": "string",
-                "value": "redeemer"
+                "value": "propeller"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller.swim_usd_mint"
               }
             ]
           }
         },
-        {
-          "name": "redeemerEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "tokenIdMap",
           "isMut": false,
@@ -6890,7 +16890,7 @@ export const IDL: Propeller = {
         },
         {
           "name": "pool",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -6902,20 +16902,20 @@ export const IDL: Propeller = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
+                "account": "Mint",
+                "path": "pool_token_0_mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
+                "account": "Mint",
+                "path": "pool_token_1_mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
                 "account": "Mint",
-                "path": "lp_mint"
+                "path": "pool_lp_mint"
               }
             ],
             "programId": {
@@ -6926,57 +16926,70 @@ export const IDL: Propeller = {
           }
         },
         {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
+          "name": "poolToken0Mint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "lpMint",
-          "isMut": true,
+          "name": "poolToken1Mint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "governanceFee",
-          "isMut": true,
+          "name": "poolLpMint",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userTransferAuthority",
+          "name": "owner",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "(i.e. `SwimPayloadMessage::token_account_owner()`). Only used to derive the ATAs."
+          ]
         },
         {
-          "name": "userTokenAccount1",
-          "isMut": true,
+          "name": "ownerPoolToken0Account",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userLpTokenAccount",
-          "isMut": true,
+          "name": "ownerPoolToken1Account",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "ownerLpTokenAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "twoPoolProgram",
+          "name": "feePriceCache",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "propeller"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "fee_price_cache"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Propeller",
+                "path": "propeller"
+              }
+            ]
+          }
         },
         {
-          "name": "systemProgram",
+          "name": "twoPoolProgram",
           "isMut": false,
           "isSigner": false
         }
@@ -6987,21 +17000,137 @@ export const IDL: Propeller = {
           "type": "u16"
         },
         {
-          "name": "minOutputAmount",
-          "type": "u64"
-        }
-      ],
-      "returns": "u64"
-    },
-    {
-      "name": "propellerCompleteNativeWithPayload",
-      "accounts": [
-        {
-          "name": "completeNativeWithPayload",
+          "name": "params",
+          "type": {
+            "defined": "QuotePropellerFeesParams"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "PropellerFeesQuote"
+      }
+    },
+    {
+      "name": "crossChainAddAndTransfer",
+      "accounts": [
+        {
+          "name": "add",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_1.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "two_pool_program"
+                }
+              }
+            },
+            {
+              "name": "poolTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lpMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "governanceFee",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTransferAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userLpTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "transfer",
           "accounts": [
             {
               "name": "propeller",
-              "isMut": false,
+              "isMut": true,
               "isSigner": false,
               "pda": {
                 "seeds": [
@@ -7013,8 +17142,8 @@ export const IDL: Propeller = {
                   {
                     "kind": "account",
                     "type": "publicKey",
-                    "account": "Propeller",
-                    "path": "propeller.swim_usd_mint"
+                    "account": "Mint",
+                    "path": "swim_usd_mint"
                   }
                 ]
               }
@@ -7045,98 +17174,200 @@ export const IDL: Propeller = {
               }
             },
             {
-              "name": "message",
+              "name": "userSwimUsdAta",
               "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "contains the VAA",
-                "{",
-                "...MessageData:",
-                "payload: PayloadTransferWithPayload = {",
-                "pub amount: U256,",
-                "}",
-                "}"
-              ]
+              "isSigner": false
             },
             {
-              "name": "claim",
+              "name": "swimUsdMint",
               "isMut": true,
-              "isSigner": false,
-              "docs": [
-                "seeds = [",
-                "vaa.emitter_address, vaa.emitter_chain, vaa.sequence",
-                "],",
-                "seeds::program = token_bridge"
-              ]
+              "isSigner": false
             },
             {
-              "name": "endpoint",
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
               "isMut": false,
               "isSigner": false
             },
             {
-              "name": "to",
-              "isMut": true,
+              "name": "custodySigner",
+              "isMut": false,
               "isSigner": false,
-              "docs": [
-                "owned by redeemer. \"redeemerEscrow\""
-              ]
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "custody_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
             },
             {
-              "name": "redeemer",
+              "name": "authoritySigner",
               "isMut": false,
               "isSigner": false,
-              "docs": [
-                "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-                "will have to be signed when it invokes complete_transfer_with_payload",
-                "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-                "(NOT the `to` account)",
-                "TODO: this is a little weird. i think we can safely assume that the `vaa.to` will be this programId",
-                "and that the `redeemer` account will be the PDA derived from [\"redeemer\"], seeds::program = propeller::id()"
-              ],
               "pda": {
                 "seeds": [
                   {
                     "kind": "const",
                     "type": "string",
-                    "value": "redeemer"
+                    "value": "authority_signer"
                   }
-                ]
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
               }
             },
             {
-              "name": "feeRecipient",
+              "name": "wormholeConfig",
               "isMut": true,
               "isSigner": false,
-              "docs": [
-                "this is \"to_fees\"",
-                "recipient of fees for executing complete transfer (e.g. relayer)"
-              ]
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Bridge"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
             },
             {
-              "name": "custody",
+              "name": "wormholeMessage",
               "isMut": true,
-              "isSigner": false
+              "isSigner": true
             },
             {
-              "name": "swimUsdMint",
-              "isMut": false,
-              "isSigner": false
+              "name": "wormholeEmitter",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
             },
             {
-              "name": "custodySigner",
-              "isMut": false,
-              "isSigner": false
+              "name": "wormholeSequence",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Sequence"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "wormhole_emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
             },
             {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
+              "name": "wormholeFeeCollector",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "fee_collector"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
             },
             {
-              "name": "systemProgram",
+              "name": "sender",
               "isMut": false,
-              "isSigner": false
+              "isSigner": false,
+              "docs": [
+                "Transfers with payload also include the address of the account or contract",
+                "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+                "EVM chains, i.e. it is the address of the immediate caller of the token",
+                "bridge transaction.",
+                "Since on Solana, a transaction can have multiple different signers, getting",
+                "this information is not so straightforward.",
+                "The strategy we use to figure out the sender of the transaction is to",
+                "require an additional signer ([`SenderAccount`]) for the transaction.",
+                "If the transaction was sent by a user wallet directly, then this may just be",
+                "the wallet's pubkey. If, however, the transaction was initiated by a",
+                "program, then we require this to be a PDA derived from the sender program's",
+                "id and the string \"sender\". In this case, the sender program must also",
+                "attach its program id to the instruction data. If the PDA verification",
+                "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+                "transaction), then the program's id is attached to the VAA as the sender,",
+                "otherwise the transaction is rejected.",
+                "",
+                "Note that a program may opt to forego the PDA derivation and instead just",
+                "pass on the original wallet as the wallet account (or any other signer, as",
+                "long as they don't provide their program_id in the instruction data). The",
+                "sender address is provided as a means for protocols to verify on the",
+                "receiving end that the message was emitted by a contract they trust, so",
+                "foregoing this check is not advised. If the receiving contract needs to know",
+                "the sender wallet's address too, then that information can be included in",
+                "the additional payload, along with any other data that the protocol needs to",
+                "send across. The legitimacy of the attached data can be verified by checking",
+                "that the sender contract is a trusted one.",
+                "",
+                "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+                "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+                "that case the PDA's address will directly be encoded into the payload",
+                "instead of the sender program's id."
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "sender"
+                  }
+                ]
+              }
             },
             {
               "name": "wormhole",
@@ -7149,12 +17380,7 @@ export const IDL: Propeller = {
               "isSigner": false
             },
             {
-              "name": "tokenBridge",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "swimPayloadMessage",
+              "name": "targetChainMap",
               "isMut": true,
               "isSigner": false,
               "pda": {
@@ -7164,477 +17390,546 @@ export const IDL: Propeller = {
                     "type": "string",
                     "value": "propeller"
                   },
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "swim_payload"
-                  },
                   {
                     "kind": "account",
                     "type": "publicKey",
-                    "path": "claim"
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "arg",
+                    "type": "u16",
+                    "path": "target_chain"
                   }
                 ]
               }
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
             }
           ]
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "feeTracker",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
-              {
-                "kind": "account",
-                "type": {
-                  "defined": "Box<Account<'info,Mint>>"
-                },
-                "account": "CompleteNativeWithPayload",
-                "path": "complete_native_with_payload.swim_usd_mint"
-              },
-              {
-                "kind": "account",
-                "type": {
-                  "defined": "Signer<'info>"
-                },
-                "account": "CompleteNativeWithPayload",
-                "path": "complete_native_with_payload.payer"
-              }
-            ]
-          }
+          "name": "minimumOutputAmount",
+          "type": "u64"
         },
         {
-          "name": "aggregator",
-          "isMut": false,
-          "isSigner": false
+          "name": "targetChain",
+          "type": "u16"
         },
         {
-          "name": "marginalPricePool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
-            }
+          "name": "inputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
           }
         },
         {
-          "name": "marginalPricePoolToken0Account",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePoolToken1Account",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePoolLpMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "memo",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "type": "bytes"
         }
       ],
-      "args": []
+      "returns": "u64"
     },
     {
-      "name": "propellerCreateOwnerTokenAccounts",
-      "docs": [
-        "Valid target_token_id *"
-      ],
+      "name": "propellerAddAndTransfer",
       "accounts": [
         {
-          "name": "propeller",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "redeemer",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "this used to be \"to_owner\".",
-            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-            "will have to be signed when it invokes complete_transfer_with_payload",
-            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "redeemer"
-              }
-            ]
-          }
-        },
-        {
-          "name": "redeemerEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeTracker",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "payer"
+          "name": "add",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ]
               }
-            ]
-          }
-        },
-        {
-          "name": "claim",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_address"
-              },
-              {
-                "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_chain"
-              },
-              {
-                "kind": "account",
-                "type": "u64",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_sequence"
+            },
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_1.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "two_pool_program"
+                }
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
+            },
+            {
+              "name": "poolTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lpMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "governanceFee",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTransferAuthority",
+              "isMut": false,
+              "isSigner": true
+            },
+            {
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userLpTokenAccount",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
             }
-          }
+          ]
         },
         {
-          "name": "swimPayloadMessage",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "swim_payload"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "claim"
+          "name": "transfer",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "tokenBridgeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "config"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "userSwimUsdAta",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custodySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "custody_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "authoritySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "authority_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "wormholeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Bridge"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeMessage",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "wormholeEmitter",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeSequence",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Sequence"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "wormhole_emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
               }
-            ]
-          }
-        },
-        {
-          "name": "tokenIdMap",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "token_id"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.target_token_id"
+            },
+            {
+              "name": "wormholeFeeCollector",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "fee_collector"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
               }
-            ]
-          }
-        },
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "pool_token_0_mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "pool_token_1_mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "pool_lp_mint"
+            },
+            {
+              "name": "sender",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "Transfers with payload also include the address of the account or contract",
+                "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+                "EVM chains, i.e. it is the address of the immediate caller of the token",
+                "bridge transaction.",
+                "Since on Solana, a transaction can have multiple different signers, getting",
+                "this information is not so straightforward.",
+                "The strategy we use to figure out the sender of the transaction is to",
+                "require an additional signer ([`SenderAccount`]) for the transaction.",
+                "If the transaction was sent by a user wallet directly, then this may just be",
+                "the wallet's pubkey. If, however, the transaction was initiated by a",
+                "program, then we require this to be a PDA derived from the sender program's",
+                "id and the string \"sender\". In this case, the sender program must also",
+                "attach its program id to the instruction data. If the PDA verification",
+                "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+                "transaction), then the program's id is attached to the VAA as the sender,",
+                "otherwise the transaction is rejected.",
+                "",
+                "Note that a program may opt to forego the PDA derivation and instead just",
+                "pass on the original wallet as the wallet account (or any other signer, as",
+                "long as they don't provide their program_id in the instruction data). The",
+                "sender address is provided as a means for protocols to verify on the",
+                "receiving end that the message was emitted by a contract they trust, so",
+                "foregoing this check is not advised. If the receiving contract needs to know",
+                "the sender wallet's address too, then that information can be included in",
+                "the additional payload, along with any other data that the protocol needs to",
+                "send across. The legitimacy of the attached data can be verified by checking",
+                "that the sender contract is a trusted one.",
+                "",
+                "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+                "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+                "that case the PDA's address will directly be encoded into the payload",
+                "instead of the sender program's id."
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "sender"
+                  }
+                ]
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
+            },
+            {
+              "name": "wormhole",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetChainMap",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "arg",
+                    "type": "u16",
+                    "path": "target_chain"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
             }
-          }
-        },
-        {
-          "name": "poolToken0Mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "poolToken1Mint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "poolLpMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "user",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userPoolToken0Account",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userPoolToken1Account",
-          "isMut": true,
-          "isSigner": false
-        },
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "userLpTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "minimumOutputAmount",
+          "type": "u64"
         },
         {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "targetChain",
+          "type": "u16"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "inputAmounts",
+          "type": {
+            "array": [
+              "u64",
+              2
+            ]
+          }
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "type": "bytes"
         },
         {
-          "name": "aggregator",
-          "isMut": false,
-          "isSigner": false
+          "name": "gasKickstart",
+          "type": "bool"
         },
         {
-          "name": "marginalPricePool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
-            }
-          }
+          "name": "maxFee",
+          "type": "u64"
         },
         {
-          "name": "marginalPricePoolToken0Account",
-          "isMut": false,
-          "isSigner": false
+          "name": "targetTokenId",
+          "type": "u16"
         },
         {
-          "name": "marginalPricePoolToken1Account",
-          "isMut": false,
-          "isSigner": false
+          "name": "targetMinOutputAmount",
+          "type": "u64"
         },
         {
-          "name": "marginalPricePoolLpMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "memo",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
         },
         {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "receiverData",
+          "type": {
+            "option": "bytes"
+          }
         },
         {
-          "name": "memo",
-          "isMut": false,
-          "isSigner": false
+          "name": "relayerExclusivity",
+          "type": {
+            "option": {
+              "defined": "RelayerExclusivity"
+            }
+          }
         }
       ],
-      "args": []
+      "returns": "u64"
     },
     {
-      "name": "propellerProcessSwimPayload",
-      "docs": [
-        "Note: passing in target_token_id here due to PDA seed derivation.",
-        "for propeller_process_swim_payload, require_eq!(target_token_id, propeller_message.target_token_id);"
-      ],
+      "name": "crossChainSwapExactInputAndTransfer",
       "accounts": [
         {
-          "name": "processSwimPayload",
+          "name": "swap",
           "accounts": [
             {
               "name": "propeller",
@@ -7650,45 +17945,242 @@ export const IDL: Propeller = {
                   {
                     "kind": "account",
                     "type": "publicKey",
-                    "account": "Propeller",
-                    "path": "propeller.swim_usd_mint"
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
                   }
                 ]
               }
             },
             {
-              "name": "payer",
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_1.mint"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "lp_mint"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "two_pool_program"
+                }
+              }
+            },
+            {
+              "name": "poolTokenAccount0",
               "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "poolTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "lpMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "governanceFee",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTransferAuthority",
+              "isMut": false,
               "isSigner": true
             },
             {
-              "name": "claim",
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
               "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "transfer",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
               "isSigner": false,
               "pda": {
                 "seeds": [
                   {
-                    "kind": "account",
-                    "type": {
-                      "array": [
-                        "u8",
-                        32
-                      ]
-                    },
-                    "account": "SwimPayloadMessage",
-                    "path": "swim_payload_message.vaa_emitter_address"
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
                   },
                   {
                     "kind": "account",
-                    "type": "u16",
-                    "account": "SwimPayloadMessage",
-                    "path": "swim_payload_message.vaa_emitter_chain"
-                  },
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "tokenBridgeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "config"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "userSwimUsdAta",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custodySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "custody_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "authoritySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "authority_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "wormholeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Bridge"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "wormholeMessage",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "wormholeEmitter",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
                   {
-                    "kind": "account",
-                    "type": "u64",
-                    "account": "SwimPayloadMessage",
-                    "path": "swim_payload_message.vaa_sequence"
+                    "kind": "const",
+                    "type": "string",
+                    "value": "emitter"
                   }
                 ],
                 "programId": {
@@ -7700,7 +18192,7 @@ export const IDL: Propeller = {
               }
             },
             {
-              "name": "swimClaim",
+              "name": "wormholeSequence",
               "isMut": true,
               "isSigner": false,
               "pda": {
@@ -7708,23 +18200,24 @@ export const IDL: Propeller = {
                   {
                     "kind": "const",
                     "type": "string",
-                    "value": "propeller"
-                  },
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "claim"
+                    "value": "Sequence"
                   },
                   {
                     "kind": "account",
                     "type": "publicKey",
-                    "path": "claim"
+                    "path": "wormhole_emitter"
                   }
-                ]
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
               }
             },
             {
-              "name": "swimPayloadMessage",
+              "name": "wormholeFeeCollector",
               "isMut": true,
               "isSigner": false,
               "pda": {
@@ -7732,55 +18225,78 @@ export const IDL: Propeller = {
                   {
                     "kind": "const",
                     "type": "string",
-                    "value": "propeller"
-                  },
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "swim_payload"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "path": "claim"
+                    "value": "fee_collector"
                   }
-                ]
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
               }
             },
             {
-              "name": "swimPayloadMessagePayer",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "redeemer",
+              "name": "sender",
               "isMut": false,
               "isSigner": false,
               "docs": [
-                "this used to be \"to_owner\".",
-                "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-                "will have to be signed when it invokes complete_transfer_with_payload",
-                "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-                "(NOT the `to` account)"
+                "Transfers with payload also include the address of the account or contract",
+                "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+                "EVM chains, i.e. it is the address of the immediate caller of the token",
+                "bridge transaction.",
+                "Since on Solana, a transaction can have multiple different signers, getting",
+                "this information is not so straightforward.",
+                "The strategy we use to figure out the sender of the transaction is to",
+                "require an additional signer ([`SenderAccount`]) for the transaction.",
+                "If the transaction was sent by a user wallet directly, then this may just be",
+                "the wallet's pubkey. If, however, the transaction was initiated by a",
+                "program, then we require this to be a PDA derived from the sender program's",
+                "id and the string \"sender\". In this case, the sender program must also",
+                "attach its program id to the instruction data. If the PDA verification",
+                "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+                "transaction), then the program's id is attached to the VAA as the sender,",
+                "otherwise the transaction is rejected.",
+                "",
+                "Note that a program may opt to forego the PDA derivation and instead just",
+                "pass on the original wallet as the wallet account (or any other signer, as",
+                "long as they don't provide their program_id in the instruction data). The",
+                "sender address is provided as a means for protocols to verify on the",
+                "receiving end that the message was emitted by a contract they trust, so",
+                "foregoing this check is not advised. If the receiving contract needs to know",
+                "the sender wallet's address too, then that information can be included in",
+                "the additional payload, along with any other data that the protocol needs to",
+                "send across. The legitimacy of the attached data can be verified by checking",
+                "that the sender contract is a trusted one.",
+                "",
+                "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+                "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+                "that case the PDA's address will directly be encoded into the payload",
+                "instead of the sender program's id."
               ],
               "pda": {
                 "seeds": [
                   {
                     "kind": "const",
                     "type": "string",
-                    "value": "redeemer"
+                    "value": "sender"
                   }
                 ]
               }
             },
             {
-              "name": "redeemerEscrow",
-              "isMut": true,
+              "name": "wormhole",
+              "isMut": false,
               "isSigner": false
             },
             {
-              "name": "tokenIdMap",
+              "name": "tokenProgram",
               "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetChainMap",
+              "isMut": true,
               "isSigner": false,
               "pda": {
                 "seeds": [
@@ -7789,11 +18305,6 @@ export const IDL: Propeller = {
                     "type": "string",
                     "value": "propeller"
                   },
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "token_id"
-                  },
                   {
                     "kind": "account",
                     "type": "publicKey",
@@ -7803,7 +18314,71 @@ export const IDL: Propeller = {
                   {
                     "kind": "arg",
                     "type": "u16",
-                    "path": "target_token_id"
+                    "path": "target_chain"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "targetChain",
+          "type": "u16"
+        },
+        {
+          "name": "exactInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "owner",
+          "type": "bytes"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "propellerSwapExactInputAndTransfer",
+      "accounts": [
+        {
+          "name": "swap",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TokenAccount",
+                    "path": "pool_token_account_0.mint"
                   }
                 ]
               }
@@ -7871,804 +18446,1114 @@ export const IDL: Propeller = {
               "isSigner": true
             },
             {
-              "name": "userTokenAccount0",
+              "name": "userTokenAccount0",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "userTokenAccount1",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "twoPoolProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "transfer",
+          "accounts": [
+            {
+              "name": "propeller",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Mint",
+                    "path": "swim_usd_mint"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "tokenBridgeConfig",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "config"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
+            },
+            {
+              "name": "userSwimUsdAta",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "swimUsdMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "custody",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "tokenBridge",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "custodySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "custody_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "authoritySigner",
+              "isMut": false,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "authority_signer"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "path": "token_bridge"
+                }
+              }
+            },
+            {
+              "name": "wormholeConfig",
               "isMut": true,
-              "isSigner": false
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Bridge"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
+              }
             },
             {
-              "name": "userTokenAccount1",
+              "name": "wormholeMessage",
               "isMut": true,
-              "isSigner": false
+              "isSigner": true
             },
             {
-              "name": "userLpTokenAccount",
+              "name": "wormholeEmitter",
               "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "twoPoolProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
-        },
-        {
-          "name": "aggregator",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Assuming that USD:USDC 1:1"
-          ]
-        },
-        {
-          "name": "feeVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "this is \"to_fees\"",
-            "recipient of fees for executing complete transfer (e.g. relayer)"
-          ]
-        },
-        {
-          "name": "feeTracker",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "ProcessSwimPayload",
-                "path": "process_swim_payload"
-              },
-              {
-                "kind": "account",
-                "type": {
-                  "defined": "Signer<'info>"
-                },
-                "account": "ProcessSwimPayload",
-                "path": "process_swim_payload.payer"
-              }
-            ]
-          }
-        },
-        {
-          "name": "marginalPricePool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": {
-                "defined": "Program<'info,two_pool::program::TwoPool>"
-              },
-              "account": "ProcessSwimPayload",
-              "path": "process_swim_payload.two_pool_program"
-            }
-          }
-        },
-        {
-          "name": "marginalPricePoolToken0Account",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePoolToken1Account",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePoolLpMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "This is for transferring lamports for kickstart"
-          ]
-        },
-        {
-          "name": "memo",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "targetTokenId",
-          "type": "u16"
-        }
-      ],
-      "returns": "u64"
-    },
-    {
-      "name": "propellerCreateOwnerSwimUsdAta",
-      "docs": [
-        "This ix is used if a propeller engine detects (off-chain) that the target_token_id is not valid",
-        "\"Fallback\" behavior is to check/create just the token bridge mint (swimUSD) token account for the owner",
-        "then call propeller_process_swim_payload_fallback to finish transferring the swimUSD to the owner."
-      ],
-      "accounts": [
-        {
-          "name": "propeller",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "redeemer",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "this used to be \"to_owner\".",
-            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-            "will have to be signed when it invokes complete_transfer_with_payload",
-            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "redeemer"
-              }
-            ]
-          }
-        },
-        {
-          "name": "redeemerEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeVault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "feeTracker",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "payer"
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
               }
-            ]
-          }
-        },
-        {
-          "name": "claim",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_address"
-              },
-              {
-                "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_chain"
-              },
-              {
-                "kind": "account",
-                "type": "u64",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_sequence"
+            },
+            {
+              "name": "wormholeSequence",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "Sequence"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "path": "wormhole_emitter"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
-            }
-          }
-        },
-        {
-          "name": "swimPayloadMessage",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "swim_payload"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "claim"
+            },
+            {
+              "name": "wormholeFeeCollector",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "fee_collector"
+                  }
+                ],
+                "programId": {
+                  "kind": "account",
+                  "type": "publicKey",
+                  "account": "Propeller",
+                  "path": "propeller"
+                }
               }
-            ]
-          }
-        },
-        {
-          "name": "tokenIdMap",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "deseraizlied as a `TokenIdMap`. if it does exist, then engine should have called",
-            "propeller_create_owner_token_accounts instead"
-          ]
-        },
-        {
-          "name": "swimUsdMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "ownerSwimUsdAta",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Note: specifying TokenAccount type here since only one token account to initialize so no need to",
-            "\"guess and check\" which token accounts need to be initialized"
-          ]
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "aggregator",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
+            },
+            {
+              "name": "sender",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "Transfers with payload also include the address of the account or contract",
+                "that sent the transfer. Semantically this is identical to \"msg.sender\" on",
+                "EVM chains, i.e. it is the address of the immediate caller of the token",
+                "bridge transaction.",
+                "Since on Solana, a transaction can have multiple different signers, getting",
+                "this information is not so straightforward.",
+                "The strategy we use to figure out the sender of the transaction is to",
+                "require an additional signer ([`SenderAccount`]) for the transaction.",
+                "If the transaction was sent by a user wallet directly, then this may just be",
+                "the wallet's pubkey. If, however, the transaction was initiated by a",
+                "program, then we require this to be a PDA derived from the sender program's",
+                "id and the string \"sender\". In this case, the sender program must also",
+                "attach its program id to the instruction data. If the PDA verification",
+                "succeeds (thereby proving that [[`cpi_program_id`]] indeed signed the",
+                "transaction), then the program's id is attached to the VAA as the sender,",
+                "otherwise the transaction is rejected.",
+                "",
+                "Note that a program may opt to forego the PDA derivation and instead just",
+                "pass on the original wallet as the wallet account (or any other signer, as",
+                "long as they don't provide their program_id in the instruction data). The",
+                "sender address is provided as a means for protocols to verify on the",
+                "receiving end that the message was emitted by a contract they trust, so",
+                "foregoing this check is not advised. If the receiving contract needs to know",
+                "the sender wallet's address too, then that information can be included in",
+                "the additional payload, along with any other data that the protocol needs to",
+                "send across. The legitimacy of the attached data can be verified by checking",
+                "that the sender contract is a trusted one.",
+                "",
+                "Also note that attaching the correct PDA as [[`SenderAccount`]] but missing the",
+                "[[`cpi_program_id`]] field will result in a successful transaction, but in",
+                "that case the PDA's address will directly be encoded into the payload",
+                "instead of the sender program's id."
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "sender"
+                  }
+                ]
               }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
-            }
-          }
-        },
+            },
+            {
+              "name": "wormhole",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "targetChainMap",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "propeller"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "Propeller",
+                    "path": "propeller"
+                  },
+                  {
+                    "kind": "arg",
+                    "type": "u16",
+                    "path": "target_chain"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "clock",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "marginalPricePoolToken0Account",
-          "isMut": false,
-          "isSigner": false
+          "name": "minimumOutputAmount",
+          "type": "u64"
         },
         {
-          "name": "marginalPricePoolToken1Account",
-          "isMut": false,
-          "isSigner": false
+          "name": "targetChain",
+          "type": "u16"
         },
         {
-          "name": "marginalPricePoolLpMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "exactInputAmount",
+          "type": "u64"
         },
         {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "owner",
+          "type": "bytes"
         },
         {
-          "name": "memo",
-          "isMut": false,
-          "isSigner": false
+          "name": "gasKickstart",
+          "type": "bool"
         },
         {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "propellerProcessSwimPayloadFallback",
-      "docs": [
-        "This ix is used if a propeller engine detects (off-chain) that the payload.target_token_id is not valid",
-        "this will transfer the swimUSD to the owner (will still kickstart if requested)"
-      ],
-      "accounts": [
+          "name": "maxFee",
+          "type": "u64"
+        },
         {
-          "name": "propeller",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
-              }
-            ]
-          }
+          "name": "targetTokenId",
+          "type": "u16"
         },
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "targetMinOutputAmount",
+          "type": "u64"
         },
         {
-          "name": "claim",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "account",
-                "type": {
-                  "array": [
-                    "u8",
-                    32
-                  ]
-                },
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_address"
-              },
-              {
-                "kind": "account",
-                "type": "u16",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_emitter_chain"
-              },
-              {
-                "kind": "account",
-                "type": "u64",
-                "account": "SwimPayloadMessage",
-                "path": "swim_payload_message.vaa_sequence"
-              }
-            ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "account": "Propeller",
-              "path": "propeller"
+          "name": "memo",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                16
+              ]
             }
           }
         },
         {
-          "name": "swimClaim",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "claim"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "claim"
-              }
-            ]
+          "name": "receiverData",
+          "type": {
+            "option": "bytes"
           }
         },
         {
-          "name": "swimPayloadMessage",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "swim_payload"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "claim"
-              }
-            ]
+          "name": "relayerExclusivity",
+          "type": {
+            "option": {
+              "defined": "RelayerExclusivity"
+            }
           }
-        },
-        {
-          "name": "swimPayloadMessagePayer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "redeemer",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "this used to be \"to_owner\".",
-            "redeemer will be PDA derived from [\"redeemer\"], seeds::program = propeller::id()",
-            "will have to be signed when it invokes complete_transfer_with_payload",
-            "if complete transfer with payload not meant to be handled by a contract redeemer will be the same as vaa.to",
-            "(NOT the `to` account)"
-          ],
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "redeemer"
-              }
-            ]
+        }
+      ],
+      "returns": "u64"
+    }
+  ],
+  "accounts": [
+    {
+      "name": "feePriceCache",
+      "docs": [
+        "Latest swimUSD per lamport rate used by the relay ixs to convert their (lamport) fees to swimUSD"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "propeller",
+            "type": "publicKey"
+          },
+          {
+            "name": "swimUsdAtomicPerLamport",
+            "type": {
+              "defined": "BorshDecimal"
+            }
+          },
+          {
+            "name": "lastUpdateTs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeTracker",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "payer",
+            "type": "publicKey"
+          },
+          {
+            "name": "feesOwed",
+            "type": "u64"
+          },
+          {
+            "name": "feesMint",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "targetChainMap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "targetChain",
+            "type": "u16"
+          },
+          {
+            "name": "targetAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "inboundRateLimit",
+            "docs": [
+              "flow limit on the swimUSD completed from `target_chain`"
+            ],
+            "type": {
+              "defined": "RateLimit"
+            }
+          },
+          {
+            "name": "outboundRateLimit",
+            "docs": [
+              "flow limit on the swimUSD transferred to `target_chain`"
+            ],
+            "type": {
+              "defined": "RateLimit"
+            }
           }
-        },
-        {
-          "name": "redeemerEscrow",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenIdMap",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "deserialized as a `TokenIdMap`. if it does exist, then engine should have called",
-            "propeller_create_owner_token_accounts instead"
-          ]
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userSwimUsdAta",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "twoPoolProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "memo",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "aggregator",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "feeVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "this is \"to_fees\"",
-            "recipient of fees for executing complete transfer (e.g. relayer)"
-          ]
-        },
-        {
-          "name": "feeTracker",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "propeller"
-              },
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "fee"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Propeller",
-                "path": "propeller.swim_usd_mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "path": "payer"
+        ]
+      }
+    },
+    {
+      "name": "tokenIdMap",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outputTokenIndex",
+            "type": "u16"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "poolTokenIndex",
+            "type": "u8"
+          },
+          {
+            "name": "poolTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "poolIx",
+            "type": {
+              "defined": "PoolInstruction"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nextSteps",
+            "docs": [
+              "pool ixs executed in order after `pool_ix`, each one on the output of the previous one.",
+              "the output of the last step is the target token"
+            ],
+            "type": {
+              "vec": {
+                "defined": "PoolStep"
               }
-            ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "propeller",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "nonce",
+            "type": "u32"
+          },
+          {
+            "name": "admin",
+            "type": "publicKey"
+          },
+          {
+            "name": "preparedAdmin",
+            "type": "publicKey"
+          },
+          {
+            "name": "adminTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "pauseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "isPaused",
+            "type": "bool"
+          },
+          {
+            "name": "isInboundPaused",
+            "type": "bool"
+          },
+          {
+            "name": "isOutboundPaused",
+            "type": "bool"
+          },
+          {
+            "name": "wormhole",
+            "type": "publicKey"
+          },
+          {
+            "name": "tokenBridge",
+            "type": "publicKey"
+          },
+          {
+            "name": "swimUsdMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "senderBump",
+            "type": "u8"
+          },
+          {
+            "name": "redeemerBump",
+            "type": "u8"
+          },
+          {
+            "name": "gasKickstartAmount",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyInitFee",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyFee",
+            "type": "u64"
+          },
+          {
+            "name": "postVaaFee",
+            "type": "u64"
+          },
+          {
+            "name": "initAtaFee",
+            "type": "u64"
+          },
+          {
+            "name": "completeWithPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "processSwimPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "swimPayloadExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "feePriceCacheMaxAge",
+            "type": "i64"
+          },
+          {
+            "name": "inboundRateLimit",
+            "type": {
+              "defined": "RateLimit"
+            }
+          },
+          {
+            "name": "outboundRateLimit",
+            "type": {
+              "defined": "RateLimit"
+            }
+          },
+          {
+            "name": "rateLimitAction",
+            "type": {
+              "defined": "RateLimitAction"
+            }
+          },
+          {
+            "name": "largeTransferThreshold",
+            "type": "u64"
+          },
+          {
+            "name": "largeTransferDelay",
+            "type": "i64"
+          },
+          {
+            "name": "marginalPricePool",
+            "type": "publicKey"
+          },
+          {
+            "name": "marginalPricePoolTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "marginalPricePoolTokenIndex",
+            "type": "u8"
+          },
+          {
+            "name": "marginalPriceTwapWindow",
+            "type": "u32"
+          },
+          {
+            "name": "feeVault",
+            "type": "publicKey"
+          },
+          {
+            "name": "aggregator",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxStaleness",
+            "type": "i64"
+          },
+          {
+            "name": "maxConfidenceInterval",
+            "type": {
+              "defined": "BorshDecimal"
+            }
+          },
+          {
+            "name": "oraclePolicy",
+            "type": {
+              "defined": "OraclePolicy"
+            }
+          },
+          {
+            "name": "fallbackOracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "solUsdPool",
+            "type": "publicKey"
+          },
+          {
+            "name": "solUsdPoolSolIndex",
+            "type": "u8"
+          },
+          {
+            "name": "solUsdPoolTwapWindow",
+            "type": "u32"
+          },
+          {
+            "name": "solUsdPoolMaxStaleness",
+            "type": "i64"
           }
-        },
-        {
-          "name": "marginalPricePool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_0_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "marginal_price_pool_token_1_account.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "marginal_price_pool_lp_mint"
-              }
+        ]
+      }
+    },
+    {
+      "name": "swimClaim",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "claimed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swimPayloadMessage",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "swimPayloadMessagePayer",
+            "docs": [
+              "payer of `CompleteWithPayload` that will get the lamports",
+              "when `SwimPayloadMessage` is closed after `ProcessSwimPayload`"
             ],
-            "programId": {
-              "kind": "account",
-              "type": "publicKey",
-              "path": "two_pool_program"
+            "type": "publicKey"
+          },
+          {
+            "name": "claim",
+            "type": "publicKey"
+          },
+          {
+            "name": "vaaEmitterAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "vaaEmitterChain",
+            "type": "u16"
+          },
+          {
+            "name": "vaaSequence",
+            "type": "u64"
+          },
+          {
+            "name": "transferAmount",
+            "type": "u64"
+          },
+          {
+            "name": "swimPayloadVersion",
+            "type": "u8"
+          },
+          {
+            "name": "owner",
+            "type": "publicKey"
+          },
+          {
+            "name": "propellerEnabled",
+            "type": "bool"
+          },
+          {
+            "name": "gasKickstart",
+            "type": "bool"
+          },
+          {
+            "name": "maxFee",
+            "type": "u64"
+          },
+          {
+            "name": "targetTokenId",
+            "type": "u16"
+          },
+          {
+            "name": "memo",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          {
+            "name": "minOutputAmount",
+            "docs": [
+              "minimum amount of the target token the owner has to receive whenever the swim payload",
+              "is processed (by the propeller engine or anyone else). 0 for `SwimPayloadVersion::V1` payloads"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "completedTs",
+            "docs": [
+              "unix timestamp of the `CompleteNativeWithPayload` that created this account"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "totalFees",
+            "docs": [
+              "sum of all fees (in swimUSD) charged by the propeller engine so far",
+              "across complete, create owner token accounts & process swim payload.",
+              "can never exceed `max_fee`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "address that initiated the token bridge transfer on the source chain"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "receiverData",
+            "docs": [
+              "set if `owner` is a program implementing the swim receiver callback.",
+              "passed to the receiver after the swim payload is processed"
+            ],
+            "type": {
+              "option": "bytes"
             }
+          },
+          {
+            "name": "exclusiveRelayer",
+            "docs": [
+              "only this relayer (or the owner) can run the propeller ixs until `exclusivity_deadline`.",
+              "`Pubkey::default()` if the swim payload has no relayer exclusivity"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "exclusivityDeadline",
+            "docs": [
+              "unix timestamp after which anyone can run the propeller ixs"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "releaseTs",
+            "docs": [
+              "unix timestamp until which the swim payload can't be processed because its transfer",
+              "exceeded a rate limit or was a large transfer. 0 if it isn't held"
+            ],
+            "type": "i64"
           }
-        },
-        {
-          "name": "marginalPricePoolToken0Account",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePoolToken1Account",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "marginalPricePoolLpMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "This is for transferring lamports for kickstart"
-          ]
-        }
-      ],
-      "args": [],
-      "returns": "u64"
+        ]
+      }
     }
   ],
-  "accounts": [
+  "types": [
+    {
+      "name": "UpdateFeesParams",
+      "docs": [
+        "all fees are in LAMPORTS and NOT including rent"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "secpVerifyInitFee",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyFee",
+            "type": "u64"
+          },
+          {
+            "name": "postVaaFee",
+            "type": "u64"
+          },
+          {
+            "name": "initAtaFee",
+            "type": "u64"
+          },
+          {
+            "name": "completeWithPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "processSwimPayloadFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
-      "name": "feeTracker",
+      "name": "UpdateOracleConfigParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
+            "name": "oraclePolicy",
+            "type": {
+              "defined": "OraclePolicy"
+            }
+          },
+          {
+            "name": "solUsdPoolSolIndex",
             "type": "u8"
           },
           {
-            "name": "payer",
-            "type": "publicKey"
+            "name": "solUsdPoolTwapWindow",
+            "type": "u32"
           },
           {
-            "name": "feesOwed",
+            "name": "solUsdPoolMaxStaleness",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateRateLimitsParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inbound",
+            "type": {
+              "defined": "RateLimitConfig"
+            }
+          },
+          {
+            "name": "outbound",
+            "type": {
+              "defined": "RateLimitConfig"
+            }
+          },
+          {
+            "name": "rateLimitAction",
+            "type": {
+              "defined": "RateLimitAction"
+            }
+          },
+          {
+            "name": "largeTransferThreshold",
+            "docs": [
+              "0 => large transfers aren't held"
+            ],
             "type": "u64"
           },
           {
-            "name": "feesMint",
-            "type": "publicKey"
+            "name": "largeTransferDelay",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "targetChainMap",
+      "name": "InitializeParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
+            "name": "gasKickstartAmount",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyInitFee",
+            "type": "u64"
+          },
+          {
+            "name": "secpVerifyFee",
+            "type": "u64"
+          },
+          {
+            "name": "postVaaFee",
+            "type": "u64"
+          },
+          {
+            "name": "completeWithPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "initAtaFee",
+            "type": "u64"
+          },
+          {
+            "name": "processSwimPayloadFee",
+            "type": "u64"
+          },
+          {
+            "name": "swimPayloadExpiry",
+            "type": "i64"
+          },
+          {
+            "name": "feePriceCacheMaxAge",
+            "type": "i64"
+          },
+          {
+            "name": "marginalPricePool",
+            "type": "publicKey"
+          },
+          {
+            "name": "marginalPricePoolTokenIndex",
             "type": "u8"
           },
           {
-            "name": "targetChain",
-            "type": "u16"
+            "name": "marginalPricePoolTokenMint",
+            "type": "publicKey"
           },
           {
-            "name": "targetAddress",
+            "name": "marginalPriceTwapWindow",
+            "type": "u32"
+          },
+          {
+            "name": "maxStaleness",
+            "type": "i64"
+          },
+          {
+            "name": "maxConfidenceInterval",
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": "BorshDecimal"
             }
           }
         ]
       }
     },
     {
-      "name": "tokenIdMap",
+      "name": "QuotePropellerFeesParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "outputTokenIndex",
+            "name": "swimPayloadLen",
+            "docs": [
+              "length of the encoded swim payload that will be delivered. Determines the size (& rent)",
+              "of the posted VAA account."
+            ],
             "type": "u16"
           },
           {
-            "name": "pool",
-            "type": "publicKey"
+            "name": "gasKickstart",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PropellerFeesQuote",
+      "docs": [
+        "Fees (in swimUSD atomic units) a propeller engine will deduct when delivering a swim payload,",
+        "broken down by the ix that charges them."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "completeNativeWithPayloadFees",
+            "type": "u64"
           },
           {
-            "name": "poolTokenIndex",
-            "type": "u8"
+            "name": "createOwnerTokenAccountsFees",
+            "docs": [
+              "zero if all of the owner's token accounts already exist"
+            ],
+            "type": "u64"
           },
           {
-            "name": "poolTokenMint",
+            "name": "processSwimPayloadFees",
+            "type": "u64"
+          },
+          {
+            "name": "totalFees",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolStep",
+      "docs": [
+        "A `RemoveExactBurn` or `SwapExactInput` on `pool` outputting `pool_token_mint`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
             "type": "publicKey"
           },
           {
@@ -8678,622 +19563,1362 @@ export const IDL: Propeller = {
             }
           },
           {
-            "name": "bump",
+            "name": "poolTokenIndex",
             "type": "u8"
+          },
+          {
+            "name": "poolTokenMint",
+            "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "propeller",
+      "name": "CompleteNativeWithPayloadData",
+      "type": {
+        "kind": "struct",
+        "fields": []
+      }
+    },
+    {
+      "name": "VerifySignaturesData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
-          },
+            "name": "signers",
+            "docs": [
+              "instruction indices of signers (-1 for missing)"
+            ],
+            "type": {
+              "array": [
+                "i8",
+                19
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferWithPayloadData",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
             "name": "nonce",
             "type": "u32"
           },
           {
-            "name": "admin",
-            "type": "publicKey"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "wormhole",
-            "type": "publicKey"
+            "name": "targetAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "tokenBridge",
-            "type": "publicKey"
+            "name": "targetChain",
+            "type": "u16"
           },
           {
-            "name": "swimUsdMint",
-            "type": "publicKey"
+            "name": "payload",
+            "type": "bytes"
           },
           {
-            "name": "senderBump",
-            "type": "u8"
+            "name": "cpiProgramId",
+            "type": {
+              "option": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimit",
+      "docs": [
+        "Flow limit on the amount of swimUSD (in atomic units) that can be transferred within a rolling `window`.",
+        "",
+        "Uses a sliding window counter: the amount transferred in the previous fixed window is weighted by how much",
+        "of it still overlaps with the rolling window ending at `now`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxAmount",
+            "docs": [
+              "max amount per `window`. 0 => no limit"
+            ],
+            "type": "u64"
           },
           {
-            "name": "redeemerBump",
-            "type": "u8"
+            "name": "window",
+            "docs": [
+              "in seconds"
+            ],
+            "type": "i64"
           },
           {
-            "name": "gasKickstartAmount",
-            "type": "u64"
+            "name": "currentWindowStart",
+            "type": "i64"
           },
           {
-            "name": "secpVerifyInitFee",
+            "name": "currentAmount",
             "type": "u64"
           },
           {
-            "name": "secpVerifyFee",
+            "name": "previousAmount",
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "RateLimitConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "postVaaFee",
+            "name": "maxAmount",
+            "docs": [
+              "0 => no limit"
+            ],
             "type": "u64"
           },
           {
-            "name": "initAtaFee",
-            "type": "u64"
+            "name": "window",
+            "docs": [
+              "in seconds"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RelayerExclusivity",
+      "docs": [
+        "Only `relayer` (or the owner) may run the propeller ixs for the swim payload",
+        "until `duration` seconds after the wormhole message was observed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "relayer",
+            "docs": [
+              "address of the relayer on the target chain"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "completeWithPayloadFee",
-            "type": "u64"
-          },
+            "name": "duration",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwimReceiveParams",
+      "docs": [
+        "Args of the `swim_receive` ix a receiver program has to implement.",
+        "",
+        "The ix data is the anchor sighash of `global:swim_receive` followed by the borsh",
+        "serialized `SwimReceiveParams` so that anchor programs can implement it as",
+        "`pub fn swim_receive(ctx: Context<SwimReceive>, params: SwimReceiveParams)`.",
+        "",
+        "Accounts:",
+        "0. `[signer]` swim receiver caller. PDA of the propeller derived from `[SWIM_RECEIVER_CALLER_SEED]`",
+        "1. `[writable]` receiver token account that received `amount` of `token_mint`",
+        "2. .. accounts passed to the propeller ix after the receiver program & the swim receiver caller.",
+        "They're forwarded as non-signers, so the swim receiver caller is the only signer of the callback"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "processSwimPayloadFee",
-            "type": "u64"
+            "name": "sourceChain",
+            "docs": [
+              "wormhole chain id of the source chain"
+            ],
+            "type": "u16"
           },
           {
-            "name": "marginalPricePool",
-            "type": "publicKey"
+            "name": "sender",
+            "docs": [
+              "address that initiated the token bridge transfer on the source chain"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "marginalPricePoolTokenMint",
+            "name": "tokenMint",
             "type": "publicKey"
           },
           {
-            "name": "marginalPricePoolTokenIndex",
-            "type": "u8"
-          },
-          {
-            "name": "feeVault",
-            "type": "publicKey"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "aggregator",
-            "type": "publicKey"
+            "name": "data",
+            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "swimClaim",
+      "name": "PostMessageData",
+      "docs": [
+        "Data that goes into a [`wormhole::Instruction::PostMessage`]"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "nonce",
+            "docs": [
+              "Unique nonce for this message"
+            ],
+            "type": "u32"
           },
           {
-            "name": "claimed",
-            "type": "bool"
+            "name": "payload",
+            "docs": [
+              "Message payload"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "consistencyLevel",
+            "docs": [
+              "Commitment Level required for an attestation to be produced"
+            ],
+            "type": {
+              "defined": "ConsistencyLevel"
+            }
           }
         ]
       }
     },
     {
-      "name": "swimPayloadMessage",
+      "name": "BridgeData",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "swimPayloadMessagePayer",
+            "name": "guardianSetIndex",
             "docs": [
-              "payer of `CompleteWithPayload` that will get the lamports",
-              "when `SwimPayloadMessage` is closed after `ProcessSwimPayload`"
+              "The current guardian set index, used to decide which signature sets to accept."
             ],
-            "type": "publicKey"
+            "type": "u32"
           },
           {
-            "name": "claim",
-            "type": "publicKey"
+            "name": "lastLamports",
+            "docs": [
+              "Lamports in the collection account"
+            ],
+            "type": "u64"
           },
           {
-            "name": "vaaEmitterAddress",
+            "name": "config",
+            "docs": [
+              "Bridge configuration, which is set once upon initialization."
+            ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "defined": "BridgeConfig"
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "vaaEmitterChain",
-            "type": "u16"
+            "name": "guardianSetExpirationTime",
+            "docs": [
+              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
+              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
+              "this period we still trust the old guardian set."
+            ],
+            "type": "u32"
           },
           {
-            "name": "vaaSequence",
+            "name": "fee",
+            "docs": [
+              "Amount of lamports that needs to be paid to the protocol to post a message"
+            ],
             "type": "u64"
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "MessageData",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "transferAmount",
-            "type": "u64"
+            "name": "vaaVersion",
+            "docs": [
+              "Header of the posted VAA"
+            ],
+            "type": "u8"
           },
           {
-            "name": "swimPayloadVersion",
+            "name": "consistencyLevel",
+            "docs": [
+              "Level of consistency requested by the emitter"
+            ],
             "type": "u8"
           },
           {
-            "name": "owner",
+            "name": "vaaTime",
+            "docs": [
+              "Time the vaa was submitted"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "vaaSignatureAccount",
+            "docs": [
+              "Account where signatures are stored"
+            ],
             "type": "publicKey"
           },
           {
-            "name": "propellerEnabled",
-            "type": "bool"
+            "name": "submissionTime",
+            "docs": [
+              "Time the posted message was created"
+            ],
+            "type": "u32"
           },
           {
-            "name": "gasKickstart",
-            "type": "bool"
+            "name": "nonce",
+            "docs": [
+              "Unique nonce for this message"
+            ],
+            "type": "u32"
           },
           {
-            "name": "maxFee",
+            "name": "sequence",
+            "docs": [
+              "Sequence number of this message"
+            ],
             "type": "u64"
           },
           {
-            "name": "targetTokenId",
+            "name": "emitterChain",
+            "docs": [
+              "Emitter of the message"
+            ],
             "type": "u16"
           },
           {
-            "name": "memo",
+            "name": "emitterAddress",
+            "docs": [
+              "Emitter of the message"
+            ],
             "type": {
               "array": [
                 "u8",
-                16
+                32
               ]
             }
+          },
+          {
+            "name": "payload",
+            "docs": [
+              "Message payload aka `PayloadTransferWithPayload`"
+            ],
+            "type": "bytes"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "InitializeParams",
+      "name": "ClaimData",
+      "docs": [
+        "Wormhole Claim Account data"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "gasKickstartAmount",
-            "type": "u64"
-          },
+            "name": "claimed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PostVAAData",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "secpVerifyInitFee",
-            "type": "u64"
+            "name": "version",
+            "type": "u8"
           },
           {
-            "name": "secpVerifyFee",
-            "type": "u64"
+            "name": "guardianSetIndex",
+            "type": "u32"
           },
           {
-            "name": "postVaaFee",
-            "type": "u64"
+            "name": "timestamp",
+            "type": "u32"
           },
           {
-            "name": "completeWithPayloadFee",
-            "type": "u64"
+            "name": "nonce",
+            "type": "u32"
           },
           {
-            "name": "initAtaFee",
-            "type": "u64"
+            "name": "emitterChain",
+            "type": "u16"
           },
           {
-            "name": "processSwimPayloadFee",
-            "type": "u64"
+            "name": "emitterAddress",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
-          {
-            "name": "marginalPricePool",
-            "type": "publicKey"
+          {
+            "name": "sequence",
+            "type": "u64"
           },
           {
-            "name": "marginalPricePoolTokenIndex",
+            "name": "consistencyLevel",
             "type": "u8"
           },
           {
-            "name": "marginalPricePoolTokenMint",
-            "type": "publicKey"
+            "name": "payload",
+            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "CompleteNativeWithPayloadData",
+      "name": "PauseDirection",
+      "docs": [
+        "Inbound: completing transfers & processing swim payloads.",
+        "Outbound: transferring swimUSD to other chains."
+      ],
       "type": {
-        "kind": "struct",
-        "fields": []
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "All"
+          },
+          {
+            "name": "Inbound"
+          },
+          {
+            "name": "Outbound"
+          }
+        ]
       }
     },
     {
-      "name": "VerifySignaturesData",
+      "name": "PoolInstruction",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "signers",
-            "docs": [
-              "instruction indices of signers (-1 for missing)"
-            ],
-            "type": {
-              "array": [
-                "i8",
-                19
-              ]
-            }
+            "name": "Transfer"
+          },
+          {
+            "name": "RemoveExactBurn"
+          },
+          {
+            "name": "SwapExactInput"
           }
         ]
       }
     },
     {
-      "name": "TransferWithPayloadData",
+      "name": "OraclePolicy",
+      "docs": [
+        "How the SOL/USD prices of the propeller's oracles are combined"
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "nonce",
-            "type": "u32"
+            "name": "Primary"
           },
           {
-            "name": "amount",
-            "type": "u64"
+            "name": "PrimaryWithFallback"
           },
           {
-            "name": "targetAddress",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
+            "name": "Median"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
           {
-            "name": "targetChain",
-            "type": "u16"
+            "name": "Switchboard"
           },
           {
-            "name": "payload",
-            "type": "bytes"
+            "name": "Pyth"
           },
           {
-            "name": "cpiProgramId",
-            "type": {
-              "option": "publicKey"
-            }
+            "name": "SolUsdPool"
           }
         ]
       }
     },
     {
-      "name": "PostMessageData",
+      "name": "RateLimitAction",
       "docs": [
-        "Data that goes into a [`wormhole::Instruction::PostMessage`]"
+        "What happens to an inbound transfer that would exceed a rate limit.",
+        "Outbound transfers exceeding a rate limit are always rejected."
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nonce",
-            "docs": [
-              "Unique nonce for this message"
-            ],
-            "type": "u32"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "payload",
-            "docs": [
-              "Message payload"
-            ],
-            "type": "bytes"
+            "name": "Reject"
           },
           {
-            "name": "consistencyLevel",
-            "docs": [
-              "Commitment Level required for an attestation to be produced"
-            ],
-            "type": {
-              "defined": "ConsistencyLevel"
-            }
+            "name": "Queue"
           }
         ]
       }
     },
     {
-      "name": "BridgeData",
+      "name": "SwimPayloadVersion",
+      "docs": [
+        "Versions of the swim payload that can be found in a token bridge transfer with payload.",
+        "",
+        "Every version ever emitted by a routing contract has to stay decodable so that",
+        "routing contracts on other chains can be upgraded independently of the propeller."
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "guardianSetIndex",
-            "docs": [
-              "The current guardian set index, used to decide which signature sets to accept."
-            ],
-            "type": "u32"
+            "name": "V1"
           },
           {
-            "name": "lastLamports",
-            "docs": [
-              "Lamports in the collection account"
-            ],
-            "type": "u64"
+            "name": "V2"
           },
           {
-            "name": "config",
-            "docs": [
-              "Bridge configuration, which is set once upon initialization."
-            ],
-            "type": {
-              "defined": "BridgeConfig"
-            }
+            "name": "V3"
+          },
+          {
+            "name": "V4"
           }
         ]
       }
     },
     {
-      "name": "BridgeConfig",
+      "name": "ConsistencyLevel",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "guardianSetExpirationTime",
-            "docs": [
-              "Period for how long a guardian set is valid after it has been replaced by a new one.  This",
-              "guarantees that VAAs issued by that set can still be submitted for a certain period.  In",
-              "this period we still trust the old guardian set."
-            ],
-            "type": "u32"
+            "name": "Confirmed"
           },
           {
-            "name": "fee",
-            "docs": [
-              "Amount of lamports that needs to be paid to the protocol to post a message"
-            ],
-            "type": "u64"
+            "name": "Finalized"
           }
         ]
       }
     },
     {
-      "name": "MessageData",
+      "name": "Instruction",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "vaaVersion",
-            "docs": [
-              "Header of the posted VAA"
-            ],
-            "type": "u8"
+            "name": "Initialize"
           },
           {
-            "name": "consistencyLevel",
-            "docs": [
-              "Level of consistency requested by the emitter"
-            ],
-            "type": "u8"
+            "name": "PostMessage"
           },
           {
-            "name": "vaaTime",
-            "docs": [
-              "Time the vaa was submitted"
-            ],
-            "type": "u32"
+            "name": "PostVAA"
           },
           {
-            "name": "vaaSignatureAccount",
-            "docs": [
-              "Account where signatures are stored"
-            ],
-            "type": "publicKey"
+            "name": "SetFees"
           },
           {
-            "name": "submissionTime",
-            "docs": [
-              "Time the posted message was created"
-            ],
-            "type": "u32"
+            "name": "TransferFees"
           },
           {
-            "name": "nonce",
-            "docs": [
-              "Unique nonce for this message"
-            ],
-            "type": "u32"
+            "name": "UpgradeContract"
           },
           {
-            "name": "sequence",
-            "docs": [
-              "Sequence number of this message"
-            ],
-            "type": "u64"
+            "name": "UpgradeGuardianSet"
           },
           {
-            "name": "emitterChain",
-            "docs": [
-              "Emitter of the message"
-            ],
-            "type": "u16"
+            "name": "VerifySignatures"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "TransferNativeWithPayloadEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "nonce",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "targetChain",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
           },
-          {
-            "name": "emitterAddress",
-            "docs": [
-              "Emitter of the message"
-            ],
-            "type": {
+          "index": false
+        },
+        {
+          "name": "propellerEnabled",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "gasKickstart",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "maxFee",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "targetTokenId",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "minOutputAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": {
               "array": [
                 "u8",
-                32
+                16
               ]
             }
           },
-          {
-            "name": "payload",
-            "docs": [
-              "Message payload aka `PayloadTransferWithPayload`"
-            ],
-            "type": "bytes"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "ClaimData",
-      "docs": [
-        "Wormhole Claim Account data"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "claimed",
-            "type": "bool"
-          }
-        ]
-      }
+      "name": "CompleteNativeWithPayloadEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "claim",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vaaEmitterChain",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "vaaSequence",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "fees",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "transferAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "PostVAAData",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "version",
-            "type": "u8"
-          },
-          {
-            "name": "guardianSetIndex",
-            "type": "u32"
-          },
-          {
-            "name": "timestamp",
-            "type": "u32"
-          },
-          {
-            "name": "nonce",
-            "type": "u32"
-          },
-          {
-            "name": "emitterChain",
-            "type": "u16"
+      "name": "ProcessSwimPayloadEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "payer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": {
+            "option": "publicKey"
           },
-          {
-            "name": "emitterAddress",
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+          "index": false
+        },
+        {
+          "name": "targetTokenId",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "fees",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "transferAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "outputAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "InvokeSwimReceiverEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "receiver",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "receiverTokenAccount",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateAggregatorConfigEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "maxStaleness",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "maxConfidenceInterval",
+          "type": {
+            "defined": "BorshDecimal"
           },
-          {
-            "name": "sequence",
-            "type": "u64"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateOracleConfigEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oraclePolicy",
+          "type": {
+            "defined": "OraclePolicy"
           },
-          {
-            "name": "consistencyLevel",
-            "type": "u8"
+          "index": false
+        },
+        {
+          "name": "fallbackOracle",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solUsdPool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "solUsdPoolSolIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "solUsdPoolTwapWindow",
+          "type": "u32",
+          "index": false
+        },
+        {
+          "name": "solUsdPoolMaxStaleness",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrepareAdminTransitionEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "upcomingAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "adminTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnactAdminTransitionEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousAdmin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAdmin",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateGasKickstartAmountEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousGasKickstartAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "gasKickstartAmount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateSwimPayloadExpiryEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousSwimPayloadExpiry",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "swimPayloadExpiry",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateFeePriceCacheMaxAgeEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousFeePriceCacheMaxAge",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "feePriceCacheMaxAge",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateFeesEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "fees",
+          "type": {
+            "defined": "UpdateFeesParams"
           },
-          {
-            "name": "payload",
-            "type": "bytes"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateMarginalPricePoolEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marginalPricePool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marginalPricePoolTokenIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "marginalPricePoolTokenMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "marginalPriceTwapWindow",
+          "type": "u32",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "PoolInstruction",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Transfer"
-          },
-          {
-            "name": "RemoveExactBurn"
-          },
-          {
-            "name": "SwapExactInput"
-          }
-        ]
-      }
+      "name": "UpdateFeeVaultEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousFeeVault",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newFeeVault",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "SwimPayloadVersion",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "V0"
-          },
-          {
-            "name": "V1"
-          }
-        ]
-      }
+      "name": "UpdateAggregatorEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousAggregator",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newAggregator",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "ConsistencyLevel",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Confirmed"
+      "name": "SetPausedEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "direction",
+          "type": {
+            "defined": "PauseDirection"
           },
-          {
-            "name": "Finalized"
-          }
-        ]
-      }
+          "index": false
+        },
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "Instruction",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Initialize"
+      "name": "ChangePauseKeyEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousPauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newPauseKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RefreshFeePriceCacheEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "feePriceCache",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousSwimUsdAtomicPerLamport",
+          "type": {
+            "defined": "BorshDecimal"
           },
-          {
-            "name": "PostMessage"
+          "index": false
+        },
+        {
+          "name": "swimUsdAtomicPerLamport",
+          "type": {
+            "defined": "BorshDecimal"
           },
-          {
-            "name": "PostVAA"
+          "index": false
+        },
+        {
+          "name": "lastUpdateTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateRateLimitsEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "inbound",
+          "type": {
+            "defined": "RateLimitConfig"
           },
-          {
-            "name": "SetFees"
+          "index": false
+        },
+        {
+          "name": "outbound",
+          "type": {
+            "defined": "RateLimitConfig"
           },
-          {
-            "name": "TransferFees"
+          "index": false
+        },
+        {
+          "name": "rateLimitAction",
+          "type": {
+            "defined": "RateLimitAction"
           },
-          {
-            "name": "UpgradeContract"
+          "index": false
+        },
+        {
+          "name": "largeTransferThreshold",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "largeTransferDelay",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateTargetChainRateLimitsEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "targetChain",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "inbound",
+          "type": {
+            "defined": "RateLimitConfig"
           },
-          {
-            "name": "UpgradeGuardianSet"
+          "index": false
+        },
+        {
+          "name": "outbound",
+          "type": {
+            "defined": "RateLimitConfig"
           },
-          {
-            "name": "VerifySignatures"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwimPayloadHeldEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "vaaEmitterChain",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "transferAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "releaseTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ReleaseSwimPayloadEvent",
+      "fields": [
+        {
+          "name": "propeller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "swimPayloadMessage",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousReleaseTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -9415,7 +21040,7 @@ export const IDL: Propeller = {
     {
       "code": 6023,
       "name": "StaleFeed",
-      "msg": "Switchboard feed has not been updated in 5 minutes"
+      "msg": "Switchboard feed has not been updated within max_staleness"
     },
     {
       "code": 6024,
@@ -9546,6 +21171,141 @@ export const IDL: Propeller = {
       "code": 6049,
       "name": "InvalidAggregator",
       "msg": "Invalid Aggregator"
+    },
+    {
+      "code": 6050,
+      "name": "MaxFeeExceeded",
+      "msg": "Fees exceed the max fee set in the swim payload"
+    },
+    {
+      "code": 6051,
+      "name": "InvalidAggregatorConfig",
+      "msg": "Invalid aggregator config. max_staleness must be > 0 and max_confidence_interval >= 0"
+    },
+    {
+      "code": 6052,
+      "name": "InsufficientDelay",
+      "msg": "Not enough time has passed since prepare instruction"
+    },
+    {
+      "code": 6053,
+      "name": "InvalidEnact",
+      "msg": "Nothing to enact"
+    },
+    {
+      "code": 6054,
+      "name": "InvalidPauseKey",
+      "msg": "Invalid pause key"
+    },
+    {
+      "code": 6055,
+      "name": "InboundIsPaused",
+      "msg": "Inbound transfers are paused"
+    },
+    {
+      "code": 6056,
+      "name": "OutboundIsPaused",
+      "msg": "Outbound transfers are paused"
+    },
+    {
+      "code": 6057,
+      "name": "SwimReceiverDataTooLong",
+      "msg": "Swim payload receiver data is too long"
+    },
+    {
+      "code": 6058,
+      "name": "MissingSwimReceiverAccounts",
+      "msg": "Missing swim receiver program & caller accounts"
+    },
+    {
+      "code": 6059,
+      "name": "InvalidSwimReceiver",
+      "msg": "Swim receiver program != swimPayload.owner"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidSwimReceiverCaller",
+      "msg": "Invalid swim receiver caller"
+    },
+    {
+      "code": 6061,
+      "name": "InvalidSwimPayloadExpiry",
+      "msg": "Swim payload expiry must be > 0"
+    },
+    {
+      "code": 6062,
+      "name": "SwimPayloadNotExpired",
+      "msg": "Swim payload has not expired yet"
+    },
+    {
+      "code": 6063,
+      "name": "RelayerExclusivityActive",
+      "msg": "Only the exclusive relayer of the swim payload can run this ix until its exclusivity deadline"
+    },
+    {
+      "code": 6064,
+      "name": "InvalidPythAccount",
+      "msg": "Invalid pyth price account"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidSolUsdPool",
+      "msg": "Invalid SOL/USD pool"
+    },
+    {
+      "code": 6066,
+      "name": "InsufficientOracleHistory",
+      "msg": "Not enough price observations for the SOL/USD pool TWAP"
+    },
+    {
+      "code": 6067,
+      "name": "NoValidOraclePrice",
+      "msg": "None of the oracles returned a valid price"
+    },
+    {
+      "code": 6068,
+      "name": "InvalidOracleConfig",
+      "msg": "Invalid oracle config"
+    },
+    {
+      "code": 6069,
+      "name": "InvalidFeePriceCacheMaxAge",
+      "msg": "Fee price cache max age must be > 0"
+    },
+    {
+      "code": 6070,
+      "name": "StaleFeePriceCache",
+      "msg": "Fee price cache is stale"
+    },
+    {
+      "code": 6071,
+      "name": "InvalidRateLimitConfig",
+      "msg": "Invalid rate limit config"
+    },
+    {
+      "code": 6072,
+      "name": "RateLimitExceeded",
+      "msg": "Transfer exceeds a rate limit"
+    },
+    {
+      "code": 6073,
+      "name": "SwimPayloadHeld",
+      "msg": "Swim payload is held until its release timestamp"
+    },
+    {
+      "code": 6074,
+      "name": "InvalidTargetChainMap",
+      "msg": "Target chain map doesn't match the chain of the transfer"
+    },
+    {
+      "code": 6075,
+      "name": "InvalidTokenIdMapRoute",
+      "msg": "Invalid route for Token Id Map"
+    },
+    {
+      "code": 6076,
+      "name": "InvalidRouteAccounts",
+      "msg": "Invalid accounts for the route of the Token Id Map"
     }
   ]
 };
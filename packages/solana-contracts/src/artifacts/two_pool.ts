@@ -209,10 +209,7 @@ export type TwoPool = {
         {
           "name": "inputAmounts",
           "type": {
-            "array": [
-              "u64",
-              2
-            ]
+            "vec": "u64"
           }
         },
         {
@@ -302,10 +299,7 @@ export type TwoPool = {
         {
           "name": "exactInputAmounts",
           "type": {
-            "array": [
-              "u64",
-              2
-            ]
+            "vec": "u64"
           }
         },
         {
@@ -407,10 +401,7 @@ export type TwoPool = {
         {
           "name": "exactOutputAmounts",
           "type": {
-            "array": [
-              "u64",
-              2
-            ]
+            "vec": "u64"
           }
         }
       ],
@@ -507,10 +498,7 @@ export type TwoPool = {
         {
           "name": "minimumOutputAmounts",
           "type": {
-            "array": [
-              "u64",
-              2
-            ]
+            "vec": "u64"
           }
         }
       ],
@@ -704,10 +692,7 @@ export type TwoPool = {
         {
           "name": "exactOutputAmounts",
           "type": {
-            "array": [
-              "u64",
-              2
-            ]
+            "vec": "u64"
           }
         }
       ],
@@ -716,7 +701,11 @@ export type TwoPool = {
       }
     },
     {
-      "name": "marginalPrices",
+      "name": "quoteAdd",
+      "docs": [
+        "Quote Ixs *",
+        "Same checks & calculations as `add` without moving any tokens"
+      ],
       "accounts": [
         {
           "name": "pool",
@@ -766,352 +755,237 @@ export type TwoPool = {
           "isSigner": false
         }
       ],
-      "args": [],
-      "returns": {
-        "array": [
-          {
-            "defined": "BorshDecimal"
-          },
-          2
-        ]
-      }
-    },
-    {
-      "name": "prepareGovernanceTransition",
-      "docs": [
-        "Governance Ixs *"
-      ],
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
-            }
-          ]
-        }
-      ],
       "args": [
         {
-          "name": "upcomingGovernanceKey",
-          "type": "publicKey"
-        }
-      ]
-    },
-    {
-      "name": "enactGovernanceTransition",
-      "accounts": [
+          "name": "inputAmounts",
+          "type": {
+            "vec": "u64"
+          }
+        },
         {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
-            }
-          ]
+          "name": "minimumMintAmount",
+          "type": "u64"
         }
       ],
-      "args": []
+      "returns": {
+        "defined": "PoolQuote"
+      }
     },
     {
-      "name": "prepareFeeChange",
+      "name": "quoteSwapExactInput",
       "accounts": [
         {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
-            }
-          ]
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "lpFee",
+          "name": "exactInputAmounts",
           "type": {
-            "defined": "DecimalU64Anchor"
+            "vec": "u64"
           }
         },
         {
-          "name": "governanceFee",
-          "type": {
-            "defined": "DecimalU64Anchor"
-          }
+          "name": "outputTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
         }
-      ]
+      ],
+      "returns": {
+        "defined": "PoolQuote"
+      }
     },
     {
-      "name": "enactFeeChange",
+      "name": "quoteSwapExactOutput",
       "accounts": [
         {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
-            }
-          ]
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "changeGovernanceFeeAccount",
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
-            }
-          ]
+            ]
+          }
         },
         {
-          "name": "newGovernanceFee",
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "newGovernanceFeeKey",
-          "type": "publicKey"
+          "name": "maximumInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "inputTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
+          }
         }
-      ]
+      ],
+      "returns": {
+        "defined": "PoolQuote"
+      }
     },
     {
-      "name": "adjustAmpFactor",
+      "name": "quoteRemoveUniform",
       "accounts": [
         {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
-            }
-          ]
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "targetTs",
-          "type": "i64"
+          "name": "exactBurnAmount",
+          "type": "u64"
         },
         {
-          "name": "targetValue",
+          "name": "minimumOutputAmounts",
           "type": {
-            "defined": "DecimalU64Anchor"
+            "vec": "u64"
           }
         }
-      ]
+      ],
+      "returns": {
+        "vec": "u64"
+      }
     },
     {
-      "name": "setPaused",
+      "name": "quoteRemoveExactBurn",
       "accounts": [
         {
           "name": "pool",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false,
           "pda": {
             "seeds": [
@@ -1123,1589 +997,5796 @@ export type TwoPool = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TwoPool",
-                "path": "pool"
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TwoPool",
-                "path": "pool"
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TwoPool",
-                "path": "pool.lp_mint_key"
+                "account": "Mint",
+                "path": "lp_mint"
               }
             ]
           }
         },
         {
-          "name": "pauseKey",
+          "name": "poolTokenAccount0",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "paused",
-          "type": "bool"
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "outputTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
         }
-      ]
+      ],
+      "returns": {
+        "defined": "PoolQuote"
+      }
     },
     {
-      "name": "changePauseKey",
+      "name": "quoteRemoveExactOutput",
       "accounts": [
         {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
-            }
-          ]
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "newPauseKey",
-          "type": "publicKey"
+          "name": "maximumBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
+          }
         }
-      ]
+      ],
+      "returns": {
+        "defined": "PoolQuote"
+      }
     },
     {
-      "name": "createLpMetadata",
+      "name": "marginalPrices",
       "accounts": [
         {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
-            }
-          ]
-        },
-        {
-          "name": "createMetadataAccounts",
-          "accounts": [
-            {
-              "name": "metadata",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mintAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "payer",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "updateAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+            ]
+          }
         },
         {
-          "name": "mplTokenMetadata",
+          "name": "poolTokenAccount0",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "data",
-          "type": {
-            "defined": "AnchorDataV2"
-          }
         },
         {
-          "name": "isMutable",
-          "type": "bool"
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "updateAuthorityIsSigner",
-          "type": "bool"
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": [],
+      "returns": {
+        "vec": {
+          "defined": "BorshDecimal"
+        }
+      }
     },
     {
-      "name": "updateLpMetadata",
+      "name": "observe",
+      "docs": [
+        "Returns the time weighted average marginal prices over the last `window` seconds"
+      ],
       "accounts": [
         {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
-            }
-          ]
+            ]
+          }
         },
         {
-          "name": "updateMetadataAccounts",
-          "accounts": [
-            {
-              "name": "metadata",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "updateAuthority",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "mplTokenMetadata",
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "newUpdateAuthority",
-          "type": {
-            "option": "publicKey"
+          "name": "window",
+          "type": "i64"
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": "BorshDecimal"
+        }
+      }
+    },
+    {
+      "name": "virtualPrice",
+      "docs": [
+        "Returns the pool's depth per lp token"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
         },
         {
-          "name": "data",
-          "type": {
-            "option": {
-              "defined": "AnchorDataV2"
-            }
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "BorshDecimal"
+      }
+    },
+    {
+      "name": "initializeVirtualPriceCheckpoints",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "virtualPriceCheckpoints",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "virtual_price_checkpoints"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              }
+            ]
           }
         },
         {
-          "name": "primarySaleHappened",
-          "type": {
-            "option": "bool"
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "checkpointVirtualPrice",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
         },
         {
-          "name": "isMutable",
-          "type": {
-            "option": "bool"
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "virtualPriceCheckpoints",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "virtual_price_checkpoints"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              }
+            ]
           }
         }
-      ]
-    }
-  ],
-  "accounts": [
+      ],
+      "args": []
+    },
     {
-      "name": "twoPool",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "isPaused",
-            "type": "bool"
-          },
-          {
-            "name": "ampFactor",
-            "type": {
-              "defined": "AmpFactor"
-            }
-          },
-          {
-            "name": "lpFee",
-            "type": {
-              "defined": "PoolFee"
-            }
-          },
-          {
-            "name": "governanceFee",
-            "type": {
-              "defined": "PoolFee"
-            }
-          },
-          {
-            "name": "lpMintKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "lpDecimalEqualizer",
-            "type": "u8"
-          },
-          {
-            "name": "tokenMintKeys",
-            "type": {
-              "array": [
-                "publicKey",
-                2
-              ]
-            }
-          },
-          {
-            "name": "tokenDecimalEqualizers",
-            "type": {
-              "array": [
-                "u8",
-                2
-              ]
-            }
-          },
-          {
-            "name": "tokenKeys",
-            "type": {
-              "array": [
-                "publicKey",
-                2
-              ]
-            }
-          },
-          {
-            "name": "pauseKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "governanceKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "governanceFeeKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "preparedGovernanceKey",
-            "type": "publicKey"
-          },
-          {
-            "name": "governanceTransitionTs",
-            "type": "i64"
-          },
-          {
-            "name": "preparedLpFee",
-            "type": {
-              "defined": "PoolFee"
-            }
-          },
-          {
-            "name": "preparedGovernanceFee",
-            "type": {
-              "defined": "PoolFee"
+      "name": "prepareGovernanceTransition",
+      "docs": [
+        "Governance Ixs *"
+      ],
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
             }
-          },
-          {
-            "name": "feeTransitionTs",
-            "type": "i64"
-          },
-          {
-            "name": "previousDepth",
-            "type": "u128"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "upcomingGovernanceKey",
+          "type": "publicKey"
+        }
+      ]
+    },
     {
-      "name": "AmpFactor",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "initialValue",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
-          },
-          {
-            "name": "initialTs",
-            "type": "i64"
-          },
-          {
-            "name": "targetValue",
-            "type": {
-              "defined": "DecimalU64Anchor"
+      "name": "enactGovernanceTransition",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
             }
-          },
-          {
-            "name": "targetTs",
-            "type": "i64"
-          }
-        ]
-      }
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "name": "AddParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "inputAmounts",
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
-          },
-          {
-            "name": "minimumMintAmount",
-            "type": "u64"
+      "name": "cancelGovernanceTransition",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "prepareFeeChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "lpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          }
+        },
+        {
+          "name": "governanceFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          }
+        }
+      ]
+    },
+    {
+      "name": "enactFeeChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelFeeChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "prepareEnactDelayChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "enactDelay",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "enactEnactDelayChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelEnactDelayChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "prepareGovernanceFeeAccountChange",
+      "docs": [
+        "There is no instant governance fee account change since the account receives the governance fees"
+      ],
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "newGovernanceFee",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "upcomingGovernanceFeeKey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "enactGovernanceFeeAccountChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "newGovernanceFee",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelGovernanceFeeAccountChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "adjustAmpFactor",
+      "docs": [
+        "`interpolation` is a required arg: callers built against the previous",
+        "`adjust_amp_factor(target_ts, target_value)` layout have to pass it (`Linear` keeps the old behavior)"
+      ],
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "targetTs",
+          "type": "i64"
+        },
+        {
+          "name": "targetValue",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          }
+        },
+        {
+          "name": "interpolation",
+          "type": {
+            "defined": "AmpFactorInterpolation"
+          }
+        }
+      ]
+    },
+    {
+      "name": "stopAmpFactorAdjustment",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool.lp_mint_key"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pauseKey",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPauseFlags",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool.lp_mint_key"
+              }
+            ]
+          }
+        },
+        {
+          "name": "pauseKey",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "pauseFlags",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "changePauseKey",
+      "docs": [
+        "Changes the pause key without the enact delay so that a compromised pause key can be replaced",
+        "immediately. The pause key can only pause & unpause the pool, it can't move any funds"
+      ],
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newPauseKey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "preparePauseKeyChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "upcomingPauseKey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "enactPauseKeyChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelPauseKeyChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createLpMetadata",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "createMetadataAccounts",
+          "accounts": [
+            {
+              "name": "metadata",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mintAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "updateAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "mplTokenMetadata",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "data",
+          "type": {
+            "defined": "AnchorDataV2"
+          }
+        },
+        {
+          "name": "isMutable",
+          "type": "bool"
+        },
+        {
+          "name": "updateAuthorityIsSigner",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updateLpMetadata",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        },
+        {
+          "name": "updateMetadataAccounts",
+          "accounts": [
+            {
+              "name": "metadata",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "updateAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "mplTokenMetadata",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newUpdateAuthority",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "data",
+          "type": {
+            "option": {
+              "defined": "AnchorDataV2"
+            }
+          }
+        },
+        {
+          "name": "primarySaleHappened",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "isMutable",
+          "type": {
+            "option": "bool"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "twoPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pauseFlags",
+            "docs": [
+              "bitflags of the operations disabled by the pause key. see [`pause_flags`]"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "ampFactor",
+            "type": {
+              "defined": "AmpFactor"
+            }
+          },
+          {
+            "name": "lpFee",
+            "type": {
+              "defined": "PoolFee"
+            }
+          },
+          {
+            "name": "governanceFee",
+            "type": {
+              "defined": "PoolFee"
+            }
+          },
+          {
+            "name": "lpMintKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "lpDecimalEqualizer",
+            "type": "u8"
+          },
+          {
+            "name": "tokenCount",
+            "type": "u8"
+          },
+          {
+            "name": "tokenMintKeys",
+            "type": {
+              "array": [
+                "publicKey",
+                6
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimalEqualizers",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "tokenKeys",
+            "type": {
+              "array": [
+                "publicKey",
+                6
+              ]
+            }
+          },
+          {
+            "name": "pauseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "governanceKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "governanceFeeKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "preparedGovernanceKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "governanceTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "preparedLpFee",
+            "type": {
+              "defined": "PoolFee"
+            }
+          },
+          {
+            "name": "preparedGovernanceFee",
+            "type": {
+              "defined": "PoolFee"
+            }
+          },
+          {
+            "name": "feeTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "previousDepth",
+            "type": "u128"
+          },
+          {
+            "name": "priceOracle",
+            "type": {
+              "defined": "PriceOracle"
+            }
+          },
+          {
+            "name": "enactDelay",
+            "docs": [
+              "delay (in seconds) between preparing & enacting a governance change"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "preparedEnactDelay",
+            "type": "i64"
+          },
+          {
+            "name": "enactDelayTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "preparedGovernanceFeeKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "governanceFeeKeyTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "preparedPauseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "pauseKeyTransitionTs",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "virtualPriceCheckpoints",
+      "docs": [
+        "Ring buffer of `(timestamp, virtual_price)` samples of a pool",
+        "",
+        "Appended to by the permissionless `checkpoint_virtual_price` crank at most once every",
+        "`CHECKPOINT_INTERVAL` seconds. The yield of the pool's lp token between two checkpoints",
+        "is the ratio of their virtual prices. Unused checkpoints have a `ts` of 0."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "checkpointIndex",
+            "docs": [
+              "index of the latest checkpoint"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "checkpoints",
+            "docs": [
+              "always contains `CHECKPOINT_COUNT` entries"
+            ],
+            "type": {
+              "vec": {
+                "defined": "VirtualPriceCheckpoint"
+              }
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "AmpFactor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialValue",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          },
+          {
+            "name": "initialTs",
+            "type": "i64"
+          },
+          {
+            "name": "targetValue",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          },
+          {
+            "name": "targetTs",
+            "type": "i64"
+          },
+          {
+            "name": "interpolation",
+            "type": {
+              "defined": "AmpFactorInterpolation"
+            }
+          },
+          {
+            "name": "perSecondRatio",
+            "type": {
+              "defined": "BorshDecimal"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inputAmounts",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "minimumMintAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdjustAmpFactorParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "targetTs",
+            "type": "i64"
+          },
+          {
+            "name": "targetValue",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          },
+          {
+            "name": "interpolation",
+            "type": {
+              "defined": "AmpFactorInterpolation"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CreateLpMetadataParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "defined": "AnchorDataV2"
+            }
+          },
+          {
+            "name": "isMutable",
+            "type": "bool"
+          },
+          {
+            "name": "updateAuthorityIsSigner",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AnchorDataV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "docs": [
+              "The name of the asset"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "docs": [
+              "The symbol for the asset"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "docs": [
+              "URI pointing to JSON representing the asset"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "docs": [
+              "Royalty basis points that goes to creators in secondary sales (0-10000)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "docs": [
+              "Array of creators, optional"
+            ],
+            "type": {
+              "option": {
+                "vec": {
+                  "defined": "AnchorCreator"
+                }
+              }
+            }
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "Collection"
+            ],
+            "type": {
+              "option": {
+                "defined": "AnchorCollection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "docs": [
+              "Uses"
+            ],
+            "type": {
+              "option": {
+                "defined": "AnchorUses"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AnchorCreator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AnchorUses",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "useMethod",
+            "type": {
+              "defined": "AnchorUseMethod"
+            }
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AnchorCollection",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PrepareFeeChangeParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lpFee",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          },
+          {
+            "name": "governanceFee",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "UpdateLpMetadataParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newUpdateAuthority",
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "data",
+            "type": {
+              "option": {
+                "defined": "AnchorDataV2"
+              }
+            }
+          },
+          {
+            "name": "primarySaleHappened",
+            "type": {
+              "option": "bool"
+            }
+          },
+          {
+            "name": "isMutable",
+            "type": {
+              "option": "bool"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InitializeParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ampFactor",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          },
+          {
+            "name": "lpFee",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          },
+          {
+            "name": "governanceFee",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolQuote",
+      "docs": [
+        "Result of a quote ix. All amounts are in atomic units."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "output amount of `add`, `swap_exact_input` & `remove_exact_burn`.",
+              "input amount of `swap_exact_output` & `remove_exact_output`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "governanceMintAmount",
+            "docs": [
+              "lp tokens minted to the governance fee account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lpFeeAmount",
+            "docs": [
+              "share of the fees (denominated in the same token as `amount`) kept by the pool"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "governanceFeeAmount",
+            "docs": [
+              "share of the fees (denominated in the same token as `amount`) paid out via `governance_mint_amount`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lpFee",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          },
+          {
+            "name": "governanceFee",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemoveExactBurnParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "exactBurnAmount",
+            "type": "u64"
+          },
+          {
+            "name": "outputTokenIndex",
+            "type": "u8"
+          },
+          {
+            "name": "minimumOutputAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemoveExactOutputParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maximumBurnAmount",
+            "type": "u64"
+          },
+          {
+            "name": "exactOutputAmounts",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemoveUniformParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "exactBurnAmount",
+            "type": "u64"
+          },
+          {
+            "name": "minimumOutputAmounts",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapExactInputParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "exactInputAmounts",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "outputTokenIndex",
+            "type": "u8"
+          },
+          {
+            "name": "minimumOutputAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapExactOutputParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maximumInputAmount",
+            "type": "u64"
+          },
+          {
+            "name": "inputTokenIndex",
+            "type": "u8"
+          },
+          {
+            "name": "exactOutputAmounts",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "value",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceObservation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ts",
+            "type": "i64"
+          },
+          {
+            "name": "priceCumulatives",
+            "type": {
+              "array": [
+                "u128",
+                6
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceOracle",
+      "docs": [
+        "Time weighted average price (TWAP) oracle for the pool's marginal prices",
+        "",
+        "Every ix that mutates the pool's balances accumulates the `last_marginal_prices` recorded",
+        "by the previous such ix (i.e. the prices that were in effect since `last_update_ts`) and",
+        "then records the prices resulting from its own balance changes. Prices are never derived",
+        "from the pool's live token balances, so tokens sent to the pool through a direct transfer",
+        "don't affect the twap until an ix of the pool records them. Skewing the pool within a",
+        "transaction doesn't affect the twap either since no time elapses between the manipulation",
+        "and its reversal.",
+        "",
+        "Every `OBSERVATION_INTERVAL` seconds a snapshot of the cumulative prices is written",
+        "to `observations` so that twaps can be calculated for windows chosen by the caller."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "priceCumulatives",
+            "type": {
+              "array": [
+                "u128",
+                6
+              ]
+            }
+          },
+          {
+            "name": "lastUpdateTs",
+            "type": "i64"
+          },
+          {
+            "name": "lastMarginalPrices",
+            "docs": [
+              "marginal prices (as fixed point numbers) recorded by the last ix that mutated the pool"
+            ],
+            "type": {
+              "array": [
+                "u128",
+                6
+              ]
+            }
+          },
+          {
+            "name": "observationIndex",
+            "type": "u8"
+          },
+          {
+            "name": "observations",
+            "type": {
+              "array": [
+                {
+                  "defined": "PriceObservation"
+                },
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VirtualPriceCheckpoint",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ts",
+            "type": "i64"
+          },
+          {
+            "name": "virtualPrice",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DecimalU64Anchor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "value",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BorshDecimal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mantissa",
+            "type": "i128"
+          },
+          {
+            "name": "scale",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AmpFactorInterpolation",
+      "docs": [
+        "How the [`AmpFactor`] moves from its initial to its target value during an adjustment"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
+          },
+          {
+            "name": "Geometric"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AnchorUseMethod",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Burn"
+          },
+          {
+            "name": "Multiple"
+          },
+          {
+            "name": "Single"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "InitializeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenMints",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governanceFee",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "ampFactor",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        },
+        {
+          "name": "lpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        },
+        {
+          "name": "governanceFeeRate",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AddEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "inputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "mintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapExactInputEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "exactInputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "outputTokenIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "outputAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapExactOutputEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "inputTokenIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "inputAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemoveUniformEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "exactBurnAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "outputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemoveExactBurnEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "exactBurnAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "outputTokenIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "outputAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemoveExactOutputEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "burnAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrepareGovernanceTransitionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "upcomingGovernanceKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governanceTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnactGovernanceTransitionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousGovernanceKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newGovernanceKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrepareFeeChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        },
+        {
+          "name": "governanceFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        },
+        {
+          "name": "feeTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnactFeeChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        },
+        {
+          "name": "governanceFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CancelGovernanceTransitionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledGovernanceKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CancelFeeChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledLpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        },
+        {
+          "name": "cancelledGovernanceFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrepareEnactDelayChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "enactDelay",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "enactDelayTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnactEnactDelayChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousEnactDelay",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "newEnactDelay",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CancelEnactDelayChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledEnactDelay",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrepareGovernanceFeeAccountChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "upcomingGovernanceFeeKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governanceFeeKeyTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnactGovernanceFeeAccountChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousGovernanceFeeKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newGovernanceFeeKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CancelGovernanceFeeAccountChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledGovernanceFeeKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PreparePauseKeyChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "upcomingPauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseKeyTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnactPauseKeyChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousPauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newPauseKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CancelPauseKeyChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledPauseKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AdjustAmpFactorEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currentTs",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "targetValue",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        },
+        {
+          "name": "targetTs",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "interpolation",
+          "type": {
+            "defined": "AmpFactorInterpolation"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "StopAmpFactorAdjustmentEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currentTs",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "value",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SetPausedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseFlags",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ChangePauseKeyEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousPauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newPauseKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CreateLpMetadataEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "metadata",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "UpdateLpMetadataEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "metadata",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CheckpointVirtualPriceEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currentTs",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "virtualPrice",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InvalidAmpFactorValue",
+      "msg": "Specified amp factor is out of bounds"
+    },
+    {
+      "code": 6001,
+      "name": "InvalidAmpFactorTimestamp",
+      "msg": "Amp factor adjustment window is too short"
+    },
+    {
+      "code": 6002,
+      "name": "InvalidFeeInput",
+      "msg": "Given fee is invalid"
+    },
+    {
+      "code": 6003,
+      "name": "DuplicateAccount",
+      "msg": "Can't pass the same account twice here"
+    },
+    {
+      "code": 6004,
+      "name": "MintHasBalance",
+      "msg": "LP token mint has a positive balance"
+    },
+    {
+      "code": 6005,
+      "name": "InvalidMintAuthority",
+      "msg": "Pool does not have mint authority of LP token mint"
+    },
+    {
+      "code": 6006,
+      "name": "MintHasFreezeAuthority",
+      "msg": "LP token mint's freeze authority is set"
+    },
+    {
+      "code": 6007,
+      "name": "TokenAccountHasBalance",
+      "msg": "Token account has a positive balance"
+    },
+    {
+      "code": 6008,
+      "name": "TokenAccountHasDelegate",
+      "msg": "Token account's delegate is set"
+    },
+    {
+      "code": 6009,
+      "name": "TokenAccountHasCloseAuthority",
+      "msg": "Token account's close authority is set"
+    },
+    {
+      "code": 6010,
+      "name": "InvalidGovernanceAccount",
+      "msg": "Invalid governance account"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidGovernanceFeeAccount",
+      "msg": "Invalid governance fee account"
+    },
+    {
+      "code": 6012,
+      "name": "InvalidPoolAuthorityAccount",
+      "msg": "Invalid pool authority account"
+    },
+    {
+      "code": 6013,
+      "name": "InvalidMintAccount",
+      "msg": "Invalid mint account"
+    },
+    {
+      "code": 6014,
+      "name": "InsufficientDelay",
+      "msg": "Not enough time has passed since prepare instruction"
+    },
+    {
+      "code": 6015,
+      "name": "InvalidEnact",
+      "msg": "Nothing to enact"
+    },
+    {
+      "code": 6016,
+      "name": "PoolIsPaused",
+      "msg": "Pool is paused"
+    },
+    {
+      "code": 6017,
+      "name": "PoolTokenAccountExpected",
+      "msg": "Expected a token account that belongs to the pool"
+    },
+    {
+      "code": 6018,
+      "name": "OutsideSpecifiedLimits",
+      "msg": "The instruction could not be completed within the specified limits"
+    },
+    {
+      "code": 6019,
+      "name": "InitialAddRequiresAllTokens",
+      "msg": "Initial add to pool must include all tokens"
+    },
+    {
+      "code": 6020,
+      "name": "ImpossibleRemove",
+      "msg": "Remove can't be completed due to the approximative nature of fee math implementation"
+    },
+    {
+      "code": 6021,
+      "name": "MaxDecimalDifferenceExceeded",
+      "msg": "The maximum difference in decimals between tokens in the pool has been exceeded"
+    },
+    {
+      "code": 6022,
+      "name": "InvalidTimestamp",
+      "msg": "Invalid timestamp from Clock sysvar"
+    },
+    {
+      "code": 6023,
+      "name": "AddRequiresAtLeastOneToken",
+      "msg": "Add Requires at least one token"
+    },
+    {
+      "code": 6024,
+      "name": "InvalidSwapExactInputParameters",
+      "msg": "Invalid parameters for Swap Exact Input"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidSwapExactOutputParameters",
+      "msg": "Invalid parameters for Swap Exact Output"
+    },
+    {
+      "code": 6026,
+      "name": "InvalidRemoveUniformParameters",
+      "msg": "Invalid parameters for Remove Uniform"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidRemoveExactBurnParameters",
+      "msg": "Invalid parameters for Remove Exact Burn"
+    },
+    {
+      "code": 6028,
+      "name": "InvalidRemoveExactOutputParameters",
+      "msg": "Invalid parameters for Remove Exact Output"
+    },
+    {
+      "code": 6029,
+      "name": "InsufficientPoolTokenAccountBalance",
+      "msg": "Invalid parameters for Remove Exact Output"
+    },
+    {
+      "code": 6030,
+      "name": "InvalidTokenIndex",
+      "msg": "Invalid Token Index"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidPauseKey",
+      "msg": "Invalid Pause Key"
+    },
+    {
+      "code": 6032,
+      "name": "InvalidSwitchboardAccount",
+      "msg": "Not a valid Switchboard account"
+    },
+    {
+      "code": 6033,
+      "name": "StaleFeed",
+      "msg": "Switchboard feed has not been updated in 5 minutes"
+    },
+    {
+      "code": 6034,
+      "name": "ConfidenceIntervalExceeded",
+      "msg": "Switchboard feed exceeded provided confidence interval"
+    },
+    {
+      "code": 6035,
+      "name": "MaxDecimalsExceeded",
+      "msg": "Maximum decimals exceeded"
+    },
+    {
+      "code": 6036,
+      "name": "ConversionError",
+      "msg": "Conversion error"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidTokenCount",
+      "msg": "Invalid token count"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidTwapWindow",
+      "msg": "Invalid TWAP window"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidCancel",
+      "msg": "Nothing to cancel"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidEnactDelay",
+      "msg": "Invalid enact delay"
+    },
+    {
+      "code": 6041,
+      "name": "AmpFactorNotAdjusting",
+      "msg": "Amp factor is not being adjusted"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidPauseFlags",
+      "msg": "Invalid pause flags"
+    },
+    {
+      "code": 6043,
+      "name": "PoolHasNoLiquidity",
+      "msg": "Pool has no liquidity"
+    },
+    {
+      "code": 6044,
+      "name": "VirtualPriceCheckpointTooEarly",
+      "msg": "Virtual price was checkpointed too recently"
+    }
+  ]
+};
+
+export const IDL: TwoPool = {
+  "version": "0.1.0",
+  "name": "two_pool",
+  "instructions": [
+    {
+      "name": "initialize",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_mint_0"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "pool_mint_1"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "poolMint0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolMint1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pauseKey",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "governanceAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFeeAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "ampFactor",
+          "type": {
+            "defined": "DecimalU64Anchor"
           }
-        ]
-      }
-    },
-    {
-      "name": "AdjustAmpFactorParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "targetTs",
-            "type": "i64"
-          },
-          {
-            "name": "targetValue",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
+        },
+        {
+          "name": "lpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
           }
-        ]
-      }
+        },
+        {
+          "name": "governanceFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          }
+        }
+      ]
     },
     {
-      "name": "CreateLpMetadataParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "data",
-            "type": {
-              "defined": "AnchorDataV2"
-            }
-          },
-          {
-            "name": "isMutable",
-            "type": "bool"
-          },
-          {
-            "name": "updateAuthorityIsSigner",
-            "type": "bool"
+      "name": "add",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputAmounts",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "minimumMintAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
     },
     {
-      "name": "AnchorDataV2",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "docs": [
-              "The name of the asset"
-            ],
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "docs": [
-              "The symbol for the asset"
-            ],
-            "type": "string"
-          },
-          {
-            "name": "uri",
-            "docs": [
-              "URI pointing to JSON representing the asset"
-            ],
-            "type": "string"
-          },
-          {
-            "name": "sellerFeeBasisPoints",
-            "docs": [
-              "Royalty basis points that goes to creators in secondary sales (0-10000)"
-            ],
-            "type": "u16"
-          },
-          {
-            "name": "creators",
-            "docs": [
-              "Array of creators, optional"
-            ],
-            "type": {
-              "option": {
-                "vec": {
-                  "defined": "AnchorCreator"
-                }
-              }
-            }
-          },
-          {
-            "name": "collection",
-            "docs": [
-              "Collection"
-            ],
-            "type": {
-              "option": {
-                "defined": "AnchorCollection"
-              }
-            }
-          },
-          {
-            "name": "uses",
-            "docs": [
-              "Uses"
-            ],
-            "type": {
-              "option": {
-                "defined": "AnchorUses"
+      "name": "swapExactInput",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactInputAmounts",
+          "type": {
+            "vec": "u64"
           }
-        ]
-      }
+        },
+        {
+          "name": "outputTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
     },
     {
-      "name": "AnchorCreator",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "address",
-            "type": "publicKey"
-          },
-          {
-            "name": "verified",
-            "type": "bool"
-          },
-          {
-            "name": "share",
-            "type": "u8"
+      "name": "swapExactOutput",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "AnchorUses",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "useMethod",
-            "type": {
-              "defined": "AnchorUseMethod"
-            }
-          },
-          {
-            "name": "remaining",
-            "type": "u64"
-          },
-          {
-            "name": "total",
-            "type": "u64"
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "inputTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
           }
-        ]
+        }
+      ],
+      "returns": {
+        "vec": "u64"
       }
     },
     {
-      "name": "AnchorCollection",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "verified",
-            "type": "bool"
-          },
-          {
-            "name": "key",
-            "type": "publicKey"
+      "name": "removeUniform",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minimumOutputAmounts",
+          "type": {
+            "vec": "u64"
           }
-        ]
+        }
+      ],
+      "returns": {
+        "vec": "u64"
       }
     },
     {
-      "name": "PrepareFeeChangeParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "lpFee",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
-          },
-          {
-            "name": "governanceFee",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
+      "name": "removeExactBurn",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
-        ]
-      }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "outputTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
     },
     {
-      "name": "UpdateLpMetadataParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "newUpdateAuthority",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "data",
-            "type": {
-              "option": {
-                "defined": "AnchorDataV2"
+      "name": "removeExactOutput",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
               }
-            }
-          },
-          {
-            "name": "primarySaleHappened",
-            "type": {
-              "option": "bool"
-            }
-          },
-          {
-            "name": "isMutable",
-            "type": {
-              "option": "bool"
-            }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "InitializeParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "ampFactor",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
-          },
-          {
-            "name": "lpFee",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
-          },
-          {
-            "name": "governanceFee",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "governanceFee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTransferAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userTokenAccount0",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userTokenAccount1",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userLpTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
           }
-        ]
+        }
+      ],
+      "returns": {
+        "vec": "u64"
       }
     },
     {
-      "name": "RemoveExactBurnParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "exactBurnAmount",
-            "type": "u64"
-          },
-          {
-            "name": "outputTokenIndex",
-            "type": "u8"
-          },
-          {
-            "name": "minimumOutputAmount",
-            "type": "u64"
+      "name": "quoteAdd",
+      "docs": [
+        "Quote Ixs *",
+        "Same checks & calculations as `add` without moving any tokens"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "RemoveExactOutputParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "maximumBurnAmount",
-            "type": "u64"
-          },
-          {
-            "name": "exactOutputAmounts",
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "inputAmounts",
+          "type": {
+            "vec": "u64"
           }
-        ]
+        },
+        {
+          "name": "minimumMintAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "PoolQuote"
       }
     },
     {
-      "name": "RemoveUniformParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "exactBurnAmount",
-            "type": "u64"
-          },
-          {
-            "name": "minimumOutputAmounts",
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
+      "name": "quoteSwapExactInput",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
-        ]
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactInputAmounts",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "outputTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "PoolQuote"
       }
     },
     {
-      "name": "SwapExactInputParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "exactInputAmounts",
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
-          },
-          {
-            "name": "outputTokenIndex",
-            "type": "u8"
-          },
-          {
-            "name": "minimumOutputAmount",
-            "type": "u64"
+      "name": "quoteSwapExactOutput",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumInputAmount",
+          "type": "u64"
+        },
+        {
+          "name": "inputTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
           }
-        ]
+        }
+      ],
+      "returns": {
+        "defined": "PoolQuote"
       }
     },
     {
-      "name": "SwapExactOutputParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "maximumInputAmount",
-            "type": "u64"
-          },
-          {
-            "name": "inputTokenIndex",
-            "type": "u8"
-          },
-          {
-            "name": "exactOutputAmounts",
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
+      "name": "quoteRemoveUniform",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
-        ]
-      }
-    },
-    {
-      "name": "PoolFee",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "value",
-            "type": "u32"
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minimumOutputAmounts",
+          "type": {
+            "vec": "u64"
           }
-        ]
+        }
+      ],
+      "returns": {
+        "vec": "u64"
       }
     },
     {
-      "name": "DecimalU64Anchor",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "value",
-            "type": "u64"
-          },
-          {
-            "name": "decimals",
-            "type": "u8"
+      "name": "quoteRemoveExactBurn",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
-        ]
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "exactBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "outputTokenIndex",
+          "type": "u8"
+        },
+        {
+          "name": "minimumOutputAmount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": "PoolQuote"
       }
     },
     {
-      "name": "BorshDecimal",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mantissa",
-            "type": "i128"
-          },
-          {
-            "name": "scale",
-            "type": "u32"
+      "name": "quoteRemoveExactOutput",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
-        ]
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maximumBurnAmount",
+          "type": "u64"
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
+          }
+        }
+      ],
+      "returns": {
+        "defined": "PoolQuote"
       }
     },
     {
-      "name": "AnchorUseMethod",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Burn"
-          },
-          {
-            "name": "Multiple"
-          },
-          {
-            "name": "Single"
+      "name": "marginalPrices",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
           }
-        ]
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "vec": {
+          "defined": "BorshDecimal"
+        }
       }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InvalidAmpFactorValue",
-      "msg": "Specified amp factor is out of bounds"
-    },
-    {
-      "code": 6001,
-      "name": "InvalidAmpFactorTimestamp",
-      "msg": "Amp factor adjustment window is too short"
-    },
-    {
-      "code": 6002,
-      "name": "InvalidFeeInput",
-      "msg": "Given fee is invalid"
-    },
-    {
-      "code": 6003,
-      "name": "DuplicateAccount",
-      "msg": "Can't pass the same account twice here"
-    },
-    {
-      "code": 6004,
-      "name": "MintHasBalance",
-      "msg": "LP token mint has a positive balance"
-    },
-    {
-      "code": 6005,
-      "name": "InvalidMintAuthority",
-      "msg": "Pool does not have mint authority of LP token mint"
-    },
-    {
-      "code": 6006,
-      "name": "MintHasFreezeAuthority",
-      "msg": "LP token mint's freeze authority is set"
-    },
-    {
-      "code": 6007,
-      "name": "TokenAccountHasBalance",
-      "msg": "Token account has a positive balance"
-    },
-    {
-      "code": 6008,
-      "name": "TokenAccountHasDelegate",
-      "msg": "Token account's delegate is set"
-    },
-    {
-      "code": 6009,
-      "name": "TokenAccountHasCloseAuthority",
-      "msg": "Token account's close authority is set"
-    },
-    {
-      "code": 6010,
-      "name": "InvalidGovernanceAccount",
-      "msg": "Invalid governance account"
-    },
-    {
-      "code": 6011,
-      "name": "InvalidGovernanceFeeAccount",
-      "msg": "Invalid governance fee account"
-    },
-    {
-      "code": 6012,
-      "name": "InvalidPoolAuthorityAccount",
-      "msg": "Invalid pool authority account"
-    },
-    {
-      "code": 6013,
-      "name": "InvalidMintAccount",
-      "msg": "Invalid mint account"
-    },
-    {
-      "code": 6014,
-      "name": "InsufficientDelay",
-      "msg": "Not enough time has passed since prepare instruction"
-    },
-    {
-      "code": 6015,
-      "name": "InvalidEnact",
-      "msg": "Nothing to enact"
-    },
-    {
-      "code": 6016,
-      "name": "PoolIsPaused",
-      "msg": "Pool is paused"
-    },
-    {
-      "code": 6017,
-      "name": "PoolTokenAccountExpected",
-      "msg": "Expected a token account that belongs to the pool"
     },
     {
-      "code": 6018,
-      "name": "OutsideSpecifiedLimits",
-      "msg": "The instruction could not be completed within the specified limits"
-    },
-    {
-      "code": 6019,
-      "name": "InitialAddRequiresAllTokens",
-      "msg": "Initial add to pool must include all tokens"
-    },
-    {
-      "code": 6020,
-      "name": "ImpossibleRemove",
-      "msg": "Remove can't be completed due to the approximative nature of fee math implementation"
-    },
-    {
-      "code": 6021,
-      "name": "MaxDecimalDifferenceExceeded",
-      "msg": "The maximum difference in decimals between tokens in the pool has been exceeded"
-    },
-    {
-      "code": 6022,
-      "name": "InvalidTimestamp",
-      "msg": "Invalid timestamp from Clock sysvar"
-    },
-    {
-      "code": 6023,
-      "name": "AddRequiresAtLeastOneToken",
-      "msg": "Add Requires at least one token"
+      "name": "observe",
+      "docs": [
+        "Returns the time weighted average marginal prices over the last `window` seconds"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "window",
+          "type": "i64"
+        }
+      ],
+      "returns": {
+        "vec": {
+          "defined": "BorshDecimal"
+        }
+      }
     },
     {
-      "code": 6024,
-      "name": "InvalidSwapExactInputParameters",
-      "msg": "Invalid parameters for Swap Exact Input"
+      "name": "virtualPrice",
+      "docs": [
+        "Returns the pool's depth per lp token"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "BorshDecimal"
+      }
     },
     {
-      "code": 6025,
-      "name": "InvalidSwapExactOutputParameters",
-      "msg": "Invalid parameters for Swap Exact Output"
+      "name": "initializeVirtualPriceCheckpoints",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "virtualPriceCheckpoints",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "virtual_price_checkpoints"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              }
+            ]
+          }
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6026,
-      "name": "InvalidRemoveUniformParameters",
-      "msg": "Invalid parameters for Remove Uniform"
+      "name": "checkpointVirtualPrice",
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_0.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TokenAccount",
+                "path": "pool_token_account_1.mint"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "Mint",
+                "path": "lp_mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "poolTokenAccount0",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolTokenAccount1",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "lpMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "virtualPriceCheckpoints",
+          "isMut": true,
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "virtual_price_checkpoints"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6027,
-      "name": "InvalidRemoveExactBurnParameters",
-      "msg": "Invalid parameters for Remove Exact Burn"
+      "name": "prepareGovernanceTransition",
+      "docs": [
+        "Governance Ixs *"
+      ],
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "upcomingGovernanceKey",
+          "type": "publicKey"
+        }
+      ]
     },
     {
-      "code": 6028,
-      "name": "InvalidRemoveExactOutputParameters",
-      "msg": "Invalid parameters for Remove Exact Output"
+      "name": "enactGovernanceTransition",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6029,
-      "name": "InsufficientPoolTokenAccountBalance",
-      "msg": "Invalid parameters for Remove Exact Output"
+      "name": "cancelGovernanceTransition",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6030,
-      "name": "InvalidTokenIndex",
-      "msg": "Invalid Token Index"
+      "name": "prepareFeeChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "lpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          }
+        },
+        {
+          "name": "governanceFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          }
+        }
+      ]
     },
     {
-      "code": 6031,
-      "name": "InvalidPauseKey",
-      "msg": "Invalid Pause Key"
+      "name": "enactFeeChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6032,
-      "name": "InvalidSwitchboardAccount",
-      "msg": "Not a valid Switchboard account"
+      "name": "cancelFeeChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6033,
-      "name": "StaleFeed",
-      "msg": "Switchboard feed has not been updated in 5 minutes"
+      "name": "prepareEnactDelayChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "enactDelay",
+          "type": "i64"
+        }
+      ]
     },
     {
-      "code": 6034,
-      "name": "ConfidenceIntervalExceeded",
-      "msg": "Switchboard feed exceeded provided confidence interval"
+      "name": "enactEnactDelayChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6035,
-      "name": "MaxDecimalsExceeded",
-      "msg": "Maximum decimals exceeded"
+      "name": "cancelEnactDelayChange",
+      "accounts": [
+        {
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "code": 6036,
-      "name": "ConversionError",
-      "msg": "Conversion error"
-    }
-  ]
-};
-
-export const IDL: TwoPool = {
-  "version": "0.1.0",
-  "name": "two_pool",
-  "instructions": [
-    {
-      "name": "initialize",
+      "name": "prepareGovernanceFeeAccountChange",
+      "docs": [
+        "There is no instant governance fee account change since the account receives the governance fees"
+      ],
       "accounts": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "pool_mint_0"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "pool_mint_1"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
               }
-            ]
-          }
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "poolMint0",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "poolMint1",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "pauseKey",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "governanceAccount",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFeeAccount",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         },
         {
-          "name": "rent",
+          "name": "newGovernanceFee",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "ampFactor",
-          "type": {
-            "defined": "DecimalU64Anchor"
-          }
-        },
-        {
-          "name": "lpFee",
-          "type": {
-            "defined": "DecimalU64Anchor"
-          }
-        },
-        {
-          "name": "governanceFee",
-          "type": {
-            "defined": "DecimalU64Anchor"
-          }
+          "name": "upcomingGovernanceFeeKey",
+          "type": "publicKey"
         }
       ]
     },
     {
-      "name": "add",
+      "name": "enactGovernanceFeeAccountChange",
       "accounts": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
               }
-            ]
-          }
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userLpTokenAccount",
-          "isMut": true,
-          "isSigner": false
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         },
         {
-          "name": "tokenProgram",
+          "name": "newGovernanceFee",
           "isMut": false,
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "inputAmounts",
-          "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          }
-        },
-        {
-          "name": "minimumMintAmount",
-          "type": "u64"
-        }
-      ],
-      "returns": "u64"
+      "args": []
     },
     {
-      "name": "swapExactInput",
+      "name": "cancelGovernanceFeeAccountChange",
       "accounts": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
-              }
-            ]
-          }
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "exactInputAmounts",
-          "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          }
-        },
-        {
-          "name": "outputTokenIndex",
-          "type": "u8"
-        },
-        {
-          "name": "minimumOutputAmount",
-          "type": "u64"
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         }
       ],
-      "returns": "u64"
+      "args": []
     },
     {
-      "name": "swapExactOutput",
+      "name": "adjustAmpFactor",
+      "docs": [
+        "`interpolation` is a required arg: callers built against the previous",
+        "`adjust_amp_factor(target_ts, target_value)` layout have to pass it (`Linear` keeps the old behavior)"
+      ],
       "accounts": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
               }
-            ]
-          }
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         }
       ],
       "args": [
         {
-          "name": "maximumInputAmount",
-          "type": "u64"
+          "name": "targetTs",
+          "type": "i64"
         },
         {
-          "name": "inputTokenIndex",
-          "type": "u8"
+          "name": "targetValue",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          }
         },
         {
-          "name": "exactOutputAmounts",
+          "name": "interpolation",
           "type": {
-            "array": [
-              "u64",
-              2
-            ]
+            "defined": "AmpFactorInterpolation"
           }
         }
-      ],
-      "returns": {
-        "vec": "u64"
-      }
+      ]
     },
     {
-      "name": "removeUniform",
+      "name": "stopAmpFactorAdjustment",
       "accounts": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
               }
-            ]
-          }
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "accounts": [
         {
-          "name": "userLpTokenAccount",
+          "name": "pool",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "type": "string",
+                "value": "two_pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool"
+              },
+              {
+                "kind": "account",
+                "type": "publicKey",
+                "account": "TwoPool",
+                "path": "pool.lp_mint_key"
+              }
+            ]
+          }
         },
         {
-          "name": "tokenProgram",
+          "name": "pauseKey",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         }
       ],
       "args": [
         {
-          "name": "exactBurnAmount",
-          "type": "u64"
-        },
-        {
-          "name": "minimumOutputAmounts",
-          "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          }
+          "name": "paused",
+          "type": "bool"
         }
-      ],
-      "returns": {
-        "vec": "u64"
-      }
+      ]
     },
     {
-      "name": "removeExactBurn",
+      "name": "setPauseFlags",
       "accounts": [
         {
           "name": "pool",
@@ -2721,252 +6802,353 @@ export const IDL: TwoPool = {
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
+                "account": "TwoPool",
+                "path": "pool"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
+                "account": "TwoPool",
+                "path": "pool"
               },
               {
                 "kind": "account",
                 "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+                "account": "TwoPool",
+                "path": "pool.lp_mint_key"
               }
             ]
           }
         },
         {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
+          "name": "pauseKey",
           "isMut": false,
           "isSigner": true
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "pauseFlags",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "changePauseKey",
+      "docs": [
+        "Changes the pause key without the enact delay so that a compromised pause key can be replaced",
+        "immediately. The pause key can only pause & unpause the pool, it can't move any funds"
+      ],
+      "accounts": [
         {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": [
         {
-          "name": "userLpTokenAccount",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "newPauseKey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "preparePauseKeyChange",
+      "accounts": [
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         }
       ],
       "args": [
         {
-          "name": "exactBurnAmount",
-          "type": "u64"
-        },
-        {
-          "name": "outputTokenIndex",
-          "type": "u8"
-        },
+          "name": "upcomingPauseKey",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "enactPauseKeyChange",
+      "accounts": [
         {
-          "name": "minimumOutputAmount",
-          "type": "u64"
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         }
       ],
-      "returns": "u64"
+      "args": []
     },
     {
-      "name": "removeExactOutput",
+      "name": "cancelPauseKeyChange",
       "accounts": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
               }
-            ]
-          }
-        },
-        {
-          "name": "poolTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "lpMint",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "governanceFee",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userTransferAuthority",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userTokenAccount0",
-          "isMut": true,
-          "isSigner": false
-        },
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createLpMetadata",
+      "accounts": [
         {
-          "name": "userTokenAccount1",
-          "isMut": true,
-          "isSigner": false
+          "name": "commonGovernance",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "type": "string",
+                    "value": "two_pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool"
+                  },
+                  {
+                    "kind": "account",
+                    "type": "publicKey",
+                    "account": "TwoPool",
+                    "path": "pool.lp_mint_key"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "governance",
+              "isMut": false,
+              "isSigner": true
+            }
+          ]
         },
         {
-          "name": "userLpTokenAccount",
-          "isMut": true,
-          "isSigner": false
+          "name": "createMetadataAccounts",
+          "accounts": [
+            {
+              "name": "metadata",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "mint",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "mintAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "payer",
+              "isMut": true,
+              "isSigner": true
+            },
+            {
+              "name": "updateAuthority",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "rent",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
         },
         {
-          "name": "tokenProgram",
+          "name": "mplTokenMetadata",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "maximumBurnAmount",
-          "type": "u64"
-        },
-        {
-          "name": "exactOutputAmounts",
+          "name": "data",
           "type": {
-            "array": [
-              "u64",
-              2
-            ]
-          }
-        }
-      ],
-      "returns": {
-        "vec": "u64"
-      }
-    },
-    {
-      "name": "marginalPrices",
-      "accounts": [
-        {
-          "name": "pool",
-          "isMut": false,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_0.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TokenAccount",
-                "path": "pool_token_account_1.mint"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "Mint",
-                "path": "lp_mint"
-              }
-            ]
+            "defined": "AnchorDataV2"
           }
         },
         {
-          "name": "poolTokenAccount0",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "poolTokenAccount1",
-          "isMut": false,
-          "isSigner": false
+          "name": "isMutable",
+          "type": "bool"
         },
         {
-          "name": "lpMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "updateAuthorityIsSigner",
+          "type": "bool"
         }
-      ],
-      "args": [],
-      "returns": {
-        "array": [
-          {
-            "defined": "BorshDecimal"
-          },
-          2
-        ]
-      }
+      ]
     },
     {
-      "name": "prepareGovernanceTransition",
-      "docs": [
-        "Governance Ixs *"
-      ],
+      "name": "updateLpMetadata",
       "accounts": [
         {
           "name": "commonGovernance",
@@ -3009,1176 +7191,1736 @@ export const IDL: TwoPool = {
               "isSigner": true
             }
           ]
+        },
+        {
+          "name": "updateMetadataAccounts",
+          "accounts": [
+            {
+              "name": "metadata",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "updateAuthority",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "mplTokenMetadata",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "upcomingGovernanceKey",
-          "type": "publicKey"
+          "name": "newUpdateAuthority",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "data",
+          "type": {
+            "option": {
+              "defined": "AnchorDataV2"
+            }
+          }
+        },
+        {
+          "name": "primarySaleHappened",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "isMutable",
+          "type": {
+            "option": "bool"
+          }
         }
       ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "twoPool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pauseFlags",
+            "docs": [
+              "bitflags of the operations disabled by the pause key. see [`pause_flags`]"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "ampFactor",
+            "type": {
+              "defined": "AmpFactor"
+            }
+          },
+          {
+            "name": "lpFee",
+            "type": {
+              "defined": "PoolFee"
+            }
+          },
+          {
+            "name": "governanceFee",
+            "type": {
+              "defined": "PoolFee"
+            }
+          },
+          {
+            "name": "lpMintKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "lpDecimalEqualizer",
+            "type": "u8"
+          },
+          {
+            "name": "tokenCount",
+            "type": "u8"
+          },
+          {
+            "name": "tokenMintKeys",
+            "type": {
+              "array": [
+                "publicKey",
+                6
+              ]
+            }
+          },
+          {
+            "name": "tokenDecimalEqualizers",
+            "type": {
+              "array": [
+                "u8",
+                6
+              ]
+            }
+          },
+          {
+            "name": "tokenKeys",
+            "type": {
+              "array": [
+                "publicKey",
+                6
+              ]
+            }
+          },
+          {
+            "name": "pauseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "governanceKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "governanceFeeKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "preparedGovernanceKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "governanceTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "preparedLpFee",
+            "type": {
+              "defined": "PoolFee"
+            }
+          },
+          {
+            "name": "preparedGovernanceFee",
+            "type": {
+              "defined": "PoolFee"
+            }
+          },
+          {
+            "name": "feeTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "previousDepth",
+            "type": "u128"
+          },
+          {
+            "name": "priceOracle",
+            "type": {
+              "defined": "PriceOracle"
+            }
+          },
+          {
+            "name": "enactDelay",
+            "docs": [
+              "delay (in seconds) between preparing & enacting a governance change"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "preparedEnactDelay",
+            "type": "i64"
+          },
+          {
+            "name": "enactDelayTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "preparedGovernanceFeeKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "governanceFeeKeyTransitionTs",
+            "type": "i64"
+          },
+          {
+            "name": "preparedPauseKey",
+            "type": "publicKey"
+          },
+          {
+            "name": "pauseKeyTransitionTs",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "enactGovernanceTransition",
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
+      "name": "virtualPriceCheckpoints",
+      "docs": [
+        "Ring buffer of `(timestamp, virtual_price)` samples of a pool",
+        "",
+        "Appended to by the permissionless `checkpoint_virtual_price` crank at most once every",
+        "`CHECKPOINT_INTERVAL` seconds. The yield of the pool's lp token between two checkpoints",
+        "is the ratio of their virtual prices. Unused checkpoints have a `ts` of 0."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "pool",
+            "type": "publicKey"
+          },
+          {
+            "name": "checkpointIndex",
+            "docs": [
+              "index of the latest checkpoint"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "checkpoints",
+            "docs": [
+              "always contains `CHECKPOINT_COUNT` entries"
+            ],
+            "type": {
+              "vec": {
+                "defined": "VirtualPriceCheckpoint"
               }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
             }
-          ]
-        }
-      ],
-      "args": []
-    },
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
     {
-      "name": "prepareFeeChange",
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
+      "name": "AmpFactor",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initialValue",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          },
+          {
+            "name": "initialTs",
+            "type": "i64"
+          },
+          {
+            "name": "targetValue",
+            "type": {
+              "defined": "DecimalU64Anchor"
+            }
+          },
+          {
+            "name": "targetTs",
+            "type": "i64"
+          },
+          {
+            "name": "interpolation",
+            "type": {
+              "defined": "AmpFactorInterpolation"
+            }
+          },
+          {
+            "name": "perSecondRatio",
+            "type": {
+              "defined": "BorshDecimal"
             }
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "lpFee",
-          "type": {
-            "defined": "DecimalU64Anchor"
           }
-        },
-        {
-          "name": "governanceFee",
-          "type": {
-            "defined": "DecimalU64Anchor"
+        ]
+      }
+    },
+    {
+      "name": "AddParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "inputAmounts",
+            "type": {
+              "vec": "u64"
+            }
+          },
+          {
+            "name": "minimumMintAmount",
+            "type": "u64"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "enactFeeChange",
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
+      "name": "AdjustAmpFactorParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "targetTs",
+            "type": "i64"
+          },
+          {
+            "name": "targetValue",
+            "type": {
+              "defined": "DecimalU64Anchor"
             }
-          ]
-        }
-      ],
-      "args": []
+          },
+          {
+            "name": "interpolation",
+            "type": {
+              "defined": "AmpFactorInterpolation"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "changeGovernanceFeeAccount",
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
+      "name": "CreateLpMetadataParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "defined": "AnchorDataV2"
             }
-          ]
-        },
-        {
-          "name": "newGovernanceFee",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "newGovernanceFeeKey",
-          "type": "publicKey"
-        }
-      ]
+          },
+          {
+            "name": "isMutable",
+            "type": "bool"
+          },
+          {
+            "name": "updateAuthorityIsSigner",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "name": "adjustAmpFactor",
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
+      "name": "AnchorDataV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "docs": [
+              "The name of the asset"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "docs": [
+              "The symbol for the asset"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "docs": [
+              "URI pointing to JSON representing the asset"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "sellerFeeBasisPoints",
+            "docs": [
+              "Royalty basis points that goes to creators in secondary sales (0-10000)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "creators",
+            "docs": [
+              "Array of creators, optional"
+            ],
+            "type": {
+              "option": {
+                "vec": {
+                  "defined": "AnchorCreator"
+                }
+              }
+            }
+          },
+          {
+            "name": "collection",
+            "docs": [
+              "Collection"
+            ],
+            "type": {
+              "option": {
+                "defined": "AnchorCollection"
+              }
+            }
+          },
+          {
+            "name": "uses",
+            "docs": [
+              "Uses"
+            ],
+            "type": {
+              "option": {
+                "defined": "AnchorUses"
               }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
             }
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "targetTs",
-          "type": "i64"
-        },
-        {
-          "name": "targetValue",
-          "type": {
-            "defined": "DecimalU64Anchor"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "setPaused",
-      "accounts": [
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "type": "string",
-                "value": "two_pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TwoPool",
-                "path": "pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TwoPool",
-                "path": "pool"
-              },
-              {
-                "kind": "account",
-                "type": "publicKey",
-                "account": "TwoPool",
-                "path": "pool.lp_mint_key"
-              }
-            ]
+      "name": "AnchorCreator",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "address",
+            "type": "publicKey"
+          },
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "share",
+            "type": "u8"
           }
-        },
-        {
-          "name": "pauseKey",
-          "isMut": false,
-          "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "paused",
-          "type": "bool"
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "changePauseKey",
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
+      "name": "AnchorUses",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "useMethod",
+            "type": {
+              "defined": "AnchorUseMethod"
             }
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "newPauseKey",
-          "type": "publicKey"
-        }
-      ]
+          },
+          {
+            "name": "remaining",
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "createLpMetadata",
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
+      "name": "AnchorCollection",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "verified",
+            "type": "bool"
+          },
+          {
+            "name": "key",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PrepareFeeChangeParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lpFee",
+            "type": {
+              "defined": "DecimalU64Anchor"
             }
-          ]
-        },
-        {
-          "name": "createMetadataAccounts",
-          "accounts": [
-            {
-              "name": "metadata",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "mint",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "mintAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "payer",
-              "isMut": true,
-              "isSigner": true
-            },
-            {
-              "name": "updateAuthority",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "rent",
-              "isMut": false,
-              "isSigner": false
+          },
+          {
+            "name": "governanceFee",
+            "type": {
+              "defined": "DecimalU64Anchor"
             }
-          ]
-        },
-        {
-          "name": "mplTokenMetadata",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "data",
-          "type": {
-            "defined": "AnchorDataV2"
           }
-        },
-        {
-          "name": "isMutable",
-          "type": "bool"
-        },
-        {
-          "name": "updateAuthorityIsSigner",
-          "type": "bool"
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "updateLpMetadata",
-      "accounts": [
-        {
-          "name": "commonGovernance",
-          "accounts": [
-            {
-              "name": "pool",
-              "isMut": true,
-              "isSigner": false,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "type": "string",
-                    "value": "two_pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool"
-                  },
-                  {
-                    "kind": "account",
-                    "type": "publicKey",
-                    "account": "TwoPool",
-                    "path": "pool.lp_mint_key"
-                  }
-                ]
-              }
-            },
-            {
-              "name": "governance",
-              "isMut": false,
-              "isSigner": true
+      "name": "UpdateLpMetadataParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "newUpdateAuthority",
+            "type": {
+              "option": "publicKey"
             }
-          ]
-        },
-        {
-          "name": "updateMetadataAccounts",
-          "accounts": [
-            {
-              "name": "metadata",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "updateAuthority",
-              "isMut": false,
-              "isSigner": false
+          },
+          {
+            "name": "data",
+            "type": {
+              "option": {
+                "defined": "AnchorDataV2"
+              }
             }
-          ]
-        },
-        {
-          "name": "mplTokenMetadata",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "newUpdateAuthority",
-          "type": {
-            "option": "publicKey"
-          }
-        },
-        {
-          "name": "data",
-          "type": {
-            "option": {
-              "defined": "AnchorDataV2"
+          },
+          {
+            "name": "primarySaleHappened",
+            "type": {
+              "option": "bool"
             }
-          }
-        },
-        {
-          "name": "primarySaleHappened",
-          "type": {
-            "option": "bool"
-          }
-        },
-        {
-          "name": "isMutable",
-          "type": {
-            "option": "bool"
-          }
-        }
-      ]
-    }
-  ],
-  "accounts": [
+          },
+          {
+            "name": "isMutable",
+            "type": {
+              "option": "bool"
+            }
+          }
+        ]
+      }
+    },
     {
-      "name": "twoPool",
+      "name": "InitializeParams",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "isPaused",
-            "type": "bool"
-          },
           {
             "name": "ampFactor",
             "type": {
-              "defined": "AmpFactor"
+              "defined": "DecimalU64Anchor"
             }
           },
           {
             "name": "lpFee",
             "type": {
-              "defined": "PoolFee"
+              "defined": "DecimalU64Anchor"
             }
           },
           {
             "name": "governanceFee",
             "type": {
-              "defined": "PoolFee"
+              "defined": "DecimalU64Anchor"
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolQuote",
+      "docs": [
+        "Result of a quote ix. All amounts are in atomic units."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "output amount of `add`, `swap_exact_input` & `remove_exact_burn`.",
+              "input amount of `swap_exact_output` & `remove_exact_output`"
+            ],
+            "type": "u64"
           },
           {
-            "name": "lpMintKey",
-            "type": "publicKey"
+            "name": "governanceMintAmount",
+            "docs": [
+              "lp tokens minted to the governance fee account"
+            ],
+            "type": "u64"
           },
           {
-            "name": "lpDecimalEqualizer",
-            "type": "u8"
+            "name": "lpFeeAmount",
+            "docs": [
+              "share of the fees (denominated in the same token as `amount`) kept by the pool"
+            ],
+            "type": "u64"
           },
           {
-            "name": "tokenMintKeys",
-            "type": {
-              "array": [
-                "publicKey",
-                2
-              ]
-            }
+            "name": "governanceFeeAmount",
+            "docs": [
+              "share of the fees (denominated in the same token as `amount`) paid out via `governance_mint_amount`"
+            ],
+            "type": "u64"
           },
           {
-            "name": "tokenDecimalEqualizers",
+            "name": "lpFee",
             "type": {
-              "array": [
-                "u8",
-                2
-              ]
+              "defined": "DecimalU64Anchor"
             }
           },
           {
-            "name": "tokenKeys",
+            "name": "governanceFee",
             "type": {
-              "array": [
-                "publicKey",
-                2
-              ]
+              "defined": "DecimalU64Anchor"
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemoveExactBurnParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "pauseKey",
-            "type": "publicKey"
+            "name": "exactBurnAmount",
+            "type": "u64"
           },
           {
-            "name": "governanceKey",
-            "type": "publicKey"
+            "name": "outputTokenIndex",
+            "type": "u8"
           },
           {
-            "name": "governanceFeeKey",
-            "type": "publicKey"
-          },
+            "name": "minimumOutputAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemoveExactOutputParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "preparedGovernanceKey",
-            "type": "publicKey"
+            "name": "maximumBurnAmount",
+            "type": "u64"
           },
           {
-            "name": "governanceTransitionTs",
-            "type": "i64"
+            "name": "exactOutputAmounts",
+            "type": {
+              "vec": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RemoveUniformParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "exactBurnAmount",
+            "type": "u64"
           },
           {
-            "name": "preparedLpFee",
+            "name": "minimumOutputAmounts",
             "type": {
-              "defined": "PoolFee"
+              "vec": "u64"
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapExactInputParams",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "preparedGovernanceFee",
+            "name": "exactInputAmounts",
             "type": {
-              "defined": "PoolFee"
+              "vec": "u64"
             }
           },
           {
-            "name": "feeTransitionTs",
-            "type": "i64"
+            "name": "outputTokenIndex",
+            "type": "u8"
           },
           {
-            "name": "previousDepth",
-            "type": "u128"
+            "name": "minimumOutputAmount",
+            "type": "u64"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "AmpFactor",
+      "name": "SwapExactOutputParams",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "initialValue",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
+            "name": "maximumInputAmount",
+            "type": "u64"
           },
           {
-            "name": "initialTs",
-            "type": "i64"
+            "name": "inputTokenIndex",
+            "type": "u8"
           },
           {
-            "name": "targetValue",
+            "name": "exactOutputAmounts",
             "type": {
-              "defined": "DecimalU64Anchor"
+              "vec": "u64"
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "PoolFee",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "targetTs",
+            "name": "value",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceObservation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ts",
             "type": "i64"
+          },
+          {
+            "name": "priceCumulatives",
+            "type": {
+              "array": [
+                "u128",
+                6
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "AddParams",
+      "name": "PriceOracle",
+      "docs": [
+        "Time weighted average price (TWAP) oracle for the pool's marginal prices",
+        "",
+        "Every ix that mutates the pool's balances accumulates the `last_marginal_prices` recorded",
+        "by the previous such ix (i.e. the prices that were in effect since `last_update_ts`) and",
+        "then records the prices resulting from its own balance changes. Prices are never derived",
+        "from the pool's live token balances, so tokens sent to the pool through a direct transfer",
+        "don't affect the twap until an ix of the pool records them. Skewing the pool within a",
+        "transaction doesn't affect the twap either since no time elapses between the manipulation",
+        "and its reversal.",
+        "",
+        "Every `OBSERVATION_INTERVAL` seconds a snapshot of the cumulative prices is written",
+        "to `observations` so that twaps can be calculated for windows chosen by the caller."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "inputAmounts",
+            "name": "priceCumulatives",
             "type": {
               "array": [
-                "u64",
-                2
+                "u128",
+                6
               ]
             }
           },
           {
-            "name": "minimumMintAmount",
-            "type": "u64"
+            "name": "lastUpdateTs",
+            "type": "i64"
+          },
+          {
+            "name": "lastMarginalPrices",
+            "docs": [
+              "marginal prices (as fixed point numbers) recorded by the last ix that mutated the pool"
+            ],
+            "type": {
+              "array": [
+                "u128",
+                6
+              ]
+            }
+          },
+          {
+            "name": "observationIndex",
+            "type": "u8"
+          },
+          {
+            "name": "observations",
+            "type": {
+              "array": [
+                {
+                  "defined": "PriceObservation"
+                },
+                8
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "AdjustAmpFactorParams",
+      "name": "VirtualPriceCheckpoint",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "targetTs",
+            "name": "ts",
             "type": "i64"
           },
           {
-            "name": "targetValue",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
+            "name": "virtualPrice",
+            "type": "u128"
           }
         ]
       }
     },
     {
-      "name": "CreateLpMetadataParams",
+      "name": "DecimalU64Anchor",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "data",
-            "type": {
-              "defined": "AnchorDataV2"
-            }
+            "name": "value",
+            "type": "u64"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BorshDecimal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mantissa",
+            "type": "i128"
           },
           {
-            "name": "isMutable",
-            "type": "bool"
+            "name": "scale",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AmpFactorInterpolation",
+      "docs": [
+        "How the [`AmpFactor`] moves from its initial to its target value during an adjustment"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Linear"
           },
           {
-            "name": "updateAuthorityIsSigner",
-            "type": "bool"
+            "name": "Geometric"
           }
         ]
       }
     },
     {
-      "name": "AnchorDataV2",
+      "name": "AnchorUseMethod",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "name",
-            "docs": [
-              "The name of the asset"
-            ],
-            "type": "string"
+            "name": "Burn"
           },
           {
-            "name": "symbol",
-            "docs": [
-              "The symbol for the asset"
-            ],
-            "type": "string"
+            "name": "Multiple"
           },
           {
-            "name": "uri",
-            "docs": [
-              "URI pointing to JSON representing the asset"
-            ],
-            "type": "string"
+            "name": "Single"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "InitializeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "tokenMints",
+          "type": {
+            "vec": "publicKey"
           },
-          {
-            "name": "sellerFeeBasisPoints",
-            "docs": [
-              "Royalty basis points that goes to creators in secondary sales (0-10000)"
-            ],
-            "type": "u16"
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governanceFee",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "ampFactor",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "creators",
-            "docs": [
-              "Array of creators, optional"
-            ],
-            "type": {
-              "option": {
-                "vec": {
-                  "defined": "AnchorCreator"
-                }
-              }
-            }
+          "index": false
+        },
+        {
+          "name": "lpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "collection",
-            "docs": [
-              "Collection"
-            ],
-            "type": {
-              "option": {
-                "defined": "AnchorCollection"
-              }
-            }
+          "index": false
+        },
+        {
+          "name": "governanceFeeRate",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "uses",
-            "docs": [
-              "Uses"
-            ],
-            "type": {
-              "option": {
-                "defined": "AnchorUses"
-              }
-            }
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AddEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "inputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "mintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapExactInputEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "exactInputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "outputTokenIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "outputAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapExactOutputEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "inputTokenIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "inputAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemoveUniformEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "exactBurnAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "outputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemoveExactBurnEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "exactBurnAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "outputTokenIndex",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "outputAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RemoveExactOutputEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "user",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "burnAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "exactOutputAmounts",
+          "type": {
+            "vec": "u64"
+          },
+          "index": false
+        },
+        {
+          "name": "governanceMintAmount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "latestDepth",
+          "type": "u128",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrepareGovernanceTransitionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "upcomingGovernanceKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governanceTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EnactGovernanceTransitionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousGovernanceKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newGovernanceKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PrepareFeeChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        },
+        {
+          "name": "governanceFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
+          },
+          "index": false
+        },
+        {
+          "name": "feeTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "AnchorCreator",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "address",
-            "type": "publicKey"
+      "name": "EnactFeeChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "verified",
-            "type": "bool"
+          "index": false
+        },
+        {
+          "name": "governanceFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "share",
-            "type": "u8"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "AnchorUses",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "useMethod",
-            "type": {
-              "defined": "AnchorUseMethod"
-            }
+      "name": "CancelGovernanceTransitionEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledGovernanceKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CancelFeeChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledLpFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "remaining",
-            "type": "u64"
+          "index": false
+        },
+        {
+          "name": "cancelledGovernanceFee",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "total",
-            "type": "u64"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "AnchorCollection",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "verified",
-            "type": "bool"
-          },
-          {
-            "name": "key",
-            "type": "publicKey"
-          }
-        ]
-      }
+      "name": "PrepareEnactDelayChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "enactDelay",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "enactDelayTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "PrepareFeeChangeParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "lpFee",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
-          },
-          {
-            "name": "governanceFee",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
-          }
-        ]
-      }
+      "name": "EnactEnactDelayChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousEnactDelay",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "newEnactDelay",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "UpdateLpMetadataParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "newUpdateAuthority",
-            "type": {
-              "option": "publicKey"
-            }
-          },
-          {
-            "name": "data",
-            "type": {
-              "option": {
-                "defined": "AnchorDataV2"
-              }
-            }
-          },
-          {
-            "name": "primarySaleHappened",
-            "type": {
-              "option": "bool"
-            }
-          },
-          {
-            "name": "isMutable",
-            "type": {
-              "option": "bool"
-            }
-          }
-        ]
-      }
+      "name": "CancelEnactDelayChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledEnactDelay",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "InitializeParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "ampFactor",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
-          },
-          {
-            "name": "lpFee",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
-          },
-          {
-            "name": "governanceFee",
-            "type": {
-              "defined": "DecimalU64Anchor"
-            }
-          }
-        ]
-      }
+      "name": "PrepareGovernanceFeeAccountChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "upcomingGovernanceFeeKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governanceFeeKeyTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "RemoveExactBurnParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "exactBurnAmount",
-            "type": "u64"
-          },
-          {
-            "name": "outputTokenIndex",
-            "type": "u8"
-          },
-          {
-            "name": "minimumOutputAmount",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "EnactGovernanceFeeAccountChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousGovernanceFeeKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newGovernanceFeeKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CancelGovernanceFeeAccountChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledGovernanceFeeKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PreparePauseKeyChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "upcomingPauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseKeyTransitionTs",
+          "type": "i64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "RemoveExactOutputParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "maximumBurnAmount",
-            "type": "u64"
-          },
-          {
-            "name": "exactOutputAmounts",
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
-          }
-        ]
-      }
+      "name": "EnactPauseKeyChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousPauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newPauseKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "RemoveUniformParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "exactBurnAmount",
-            "type": "u64"
-          },
-          {
-            "name": "minimumOutputAmounts",
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
-          }
-        ]
-      }
+      "name": "CancelPauseKeyChangeEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "cancelledPauseKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "SwapExactInputParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "exactInputAmounts",
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
+      "name": "AdjustAmpFactorEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currentTs",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "targetValue",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "outputTokenIndex",
-            "type": "u8"
+          "index": false
+        },
+        {
+          "name": "targetTs",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "interpolation",
+          "type": {
+            "defined": "AmpFactorInterpolation"
           },
-          {
-            "name": "minimumOutputAmount",
-            "type": "u64"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "SwapExactOutputParams",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "maximumInputAmount",
-            "type": "u64"
-          },
-          {
-            "name": "inputTokenIndex",
-            "type": "u8"
+      "name": "StopAmpFactorAdjustmentEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currentTs",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "value",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "exactOutputAmounts",
-            "type": {
-              "array": [
-                "u64",
-                2
-              ]
-            }
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "PoolFee",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "value",
-            "type": "u32"
-          }
-        ]
-      }
+      "name": "SetPausedEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pauseFlags",
+          "type": "u8",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "DecimalU64Anchor",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "value",
-            "type": "u64"
-          },
-          {
-            "name": "decimals",
-            "type": "u8"
-          }
-        ]
-      }
+      "name": "ChangePauseKeyEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousPauseKey",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "newPauseKey",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "BorshDecimal",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mantissa",
-            "type": "i128"
-          },
-          {
-            "name": "scale",
-            "type": "u32"
-          }
-        ]
-      }
+      "name": "CreateLpMetadataEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "metadata",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "AnchorUseMethod",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Burn"
-          },
-          {
-            "name": "Multiple"
+      "name": "UpdateLpMetadataEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governance",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "lpMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "metadata",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CheckpointVirtualPriceEvent",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "currentTs",
+          "type": "i64",
+          "index": false
+        },
+        {
+          "name": "virtualPrice",
+          "type": {
+            "defined": "DecimalU64Anchor"
           },
-          {
-            "name": "Single"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -4366,6 +9108,46 @@ export const IDL: TwoPool = {
       "code": 6036,
       "name": "ConversionError",
       "msg": "Conversion error"
+    },
+    {
+      "code": 6037,
+      "name": "InvalidTokenCount",
+      "msg": "Invalid token count"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidTwapWindow",
+      "msg": "Invalid TWAP window"
+    },
+    {
+      "code": 6039,
+      "name": "InvalidCancel",
+      "msg": "Nothing to cancel"
+    },
+    {
+      "code": 6040,
+      "name": "InvalidEnactDelay",
+      "msg": "Invalid enact delay"
+    },
+    {
+      "code": 6041,
+      "name": "AmpFactorNotAdjusting",
+      "msg": "Amp factor is not being adjusted"
+    },
+    {
+      "code": 6042,
+      "name": "InvalidPauseFlags",
+      "msg": "Invalid pause flags"
+    },
+    {
+      "code": 6043,
+      "name": "PoolHasNoLiquidity",
+      "msg": "Pool has no liquidity"
+    },
+    {
+      "code": 6044,
+      "name": "VirtualPriceCheckpointTooEarly",
+      "msg": "Virtual price was checkpointed too recently"
     }
   ]
 };